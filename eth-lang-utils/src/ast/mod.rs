@@ -1,5 +1,5 @@
 
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Serialize};
 
 pub type NodeID = i64;
 
@@ -78,3 +78,25 @@ pub enum NodeType {
     YulVariableDeclaration,
     YulBlock,
 }
+
+/// Retrieves the `nodeType` of a JSON AST node.
+pub fn node_type<'de, D: serde::Deserializer<'de>>(json: &serde_json::Value) -> Result<String, D::Error> {
+    match json.get("nodeType") {
+        Some(serde_json::Value::String(node_type)) => Ok(node_type.clone()),
+        Some(_) => Err(D::Error::custom("nodeType is not a string")),
+        None => Err(D::Error::missing_field("nodeType")),
+    }
+}
+
+/// Deserializes a field of a JSON AST node, propagating any error through the outer deserializer.
+pub fn field<'de, D: serde::Deserializer<'de>, T: serde::de::DeserializeOwned>(json: &serde_json::Value, name: &'static str) -> Result<T, D::Error> {
+    match json.get(name) {
+        Some(value) => from_value::<D, _>(value.clone()),
+        None => Err(D::Error::missing_field(name)),
+    }
+}
+
+/// Deserializes a JSON AST node, propagating any error through the outer deserializer.
+pub fn from_value<'de, D: serde::Deserializer<'de>, T: serde::de::DeserializeOwned>(json: serde_json::Value) -> Result<T, D::Error> {
+    serde_json::from_value(json).map_err(D::Error::custom)
+}
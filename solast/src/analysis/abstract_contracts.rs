@@ -37,7 +37,7 @@ impl AstVisitor for AbstractContractsVisitor {
         if let None | Some(false) = context.contract_definition.is_abstract {
             self.report.borrow_mut().add_entry(
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                Some(context.current_source_unit.source_line(context.function_definition.src.as_str())?),
+                Some(context.current_source_unit.source_range(context.function_definition.src.as_str())?),
                 format!(
                     "{} is marked {} instead of marking `{}` as abstract",
                     context.contract_definition.definition_node_location(context.definition_node),
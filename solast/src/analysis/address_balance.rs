@@ -16,13 +16,13 @@ impl AddressBalanceVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         expression: &dyn std::fmt::Display,
         external: bool,
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains `{}` usage, which can be optimized with assembly: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -73,7 +73,7 @@ impl AstVisitor for AddressBalanceVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.member_access.src.as_str())?,
+                context.current_source_unit.source_range(context.member_access.src.as_str())?,
                 context.member_access,
                 name != "this"
             );
@@ -16,14 +16,14 @@ impl ArrayAssignmentVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         index_access: &IndexAccess,
         operator: &str,
         expression: &Expression,
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains an inefficient array assignment which can be optimized to `{} {}= {};`",
                 contract_definition.definition_node_location(definition_node),
@@ -65,7 +65,7 @@ impl AstVisitor for ArrayAssignmentVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.assignment.src.as_str())?,
+                context.current_source_unit.source_range(context.assignment.src.as_str())?,
                 index_access,
                 binary_operation.operator.as_str(),
                 binary_operation.right_expression.as_ref(),
@@ -16,13 +16,13 @@ impl AssignmentComparisonsVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         message: String,
         expression: &dyn std::fmt::Display
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains {} that performs an assignment: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -45,7 +45,7 @@ impl AstVisitor for AssignmentComparisonsVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.function_call.src.as_str())?,
+                context.current_source_unit.source_range(context.function_call.src.as_str())?,
                 format!("a call to `{}`", called_function_name),
                 context.function_call
             );
@@ -60,7 +60,7 @@ impl AstVisitor for AssignmentComparisonsVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.if_statement.src.as_str())?,
+                context.current_source_unit.source_range(context.if_statement.src.as_str())?,
                 "an if statement".to_string(),
                 &context.if_statement.condition
             );
@@ -76,7 +76,7 @@ impl AstVisitor for AssignmentComparisonsVisitor {
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                     context.contract_definition,
                     context.definition_node,
-                    context.current_source_unit.source_line(context.for_statement.src.as_str())?,
+                    context.current_source_unit.source_range(context.for_statement.src.as_str())?,
                     "a for statement".to_string(),
                     condition
                 );
@@ -92,7 +92,7 @@ impl AstVisitor for AssignmentComparisonsVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.while_statement.src.as_str())?,
+                context.current_source_unit.source_range(context.while_statement.src.as_str())?,
                 "a while statement".to_string(),
                 &context.while_statement.condition
             );
@@ -107,7 +107,7 @@ impl AstVisitor for AssignmentComparisonsVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.conditional.src.as_str())?,
+                context.current_source_unit.source_range(context.conditional.src.as_str())?,
                 "a conditional expression".to_string(),
                 context.conditional
             );
@@ -87,7 +87,7 @@ impl CheckEffectsInteractionsVisitor {
         function_id: NodeID,
         block_id: NodeID,
         expression: &Expression,
-        source_line: usize,
+        source_range: SourceRange,
     ) -> io::Result<()> {
         let mut makes_post_external_call_assignment = false;
         
@@ -139,7 +139,7 @@ impl CheckEffectsInteractionsVisitor {
 
             self.report.borrow_mut().add_entry(
                 source_unit_path,
-                Some(source_line),
+                Some(source_range),
                 format!(
                     "{} ignores the Check-Effects-Interactions pattern",
                     contract_definition.definition_node_location(definition_node),
@@ -444,7 +444,7 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
             definition_id,
             block_id,
             context.assignment.left_hand_side.as_ref(),
-            context.current_source_unit.source_line(context.assignment.src.as_str())?
+            context.current_source_unit.source_range(context.assignment.src.as_str())?
         )
     }
     
@@ -502,7 +502,7 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
             definition_id,
             block_id,
             context.unary_operation.sub_expression.as_ref(),
-            context.current_source_unit.source_line(context.unary_operation.src.as_str())?
+            context.current_source_unit.source_range(context.unary_operation.src.as_str())?
         )
     }
 
@@ -575,7 +575,7 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
             definition_id,
             block_id,
             expression,
-            context.current_source_unit.source_line(context.function_call.src.as_str())?
+            context.current_source_unit.source_range(context.function_call.src.as_str())?
         )
     }
 }
@@ -28,7 +28,7 @@ impl AstVisitor for DivideBeforeMultiplyVisitor {
             if left_operation.contains_operation("/") {
                 self.report.borrow_mut().add_entry(
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                    Some(context.current_source_unit.source_line(context.binary_operation.src.as_str())?),
+                    Some(context.current_source_unit.source_range(context.binary_operation.src.as_str())?),
                     format!(
                         "{} performs a multiplication on the result of a division",
                         context.contract_definition.definition_node_location(context.definition_node),
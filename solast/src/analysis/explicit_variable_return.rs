@@ -66,7 +66,7 @@ impl AstVisitor for ExplicitVariableReturnVisitor {
         if let Some(description) = description {
             self.report.borrow_mut().add_entry(
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                Some(context.current_source_unit.source_line(context.return_statement.src.as_str())?),
+                Some(context.current_source_unit.source_range(context.return_statement.src.as_str())?),
                 format!(
                     "{} returns {} explicitly: `{}`",
                     context.contract_definition.definition_node_location(context.definition_node),
@@ -23,12 +23,12 @@ impl ExternalCallsInLoopVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         expression: &dyn std::fmt::Display
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} makes an external call inside a loop: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -115,7 +115,7 @@ impl AstVisitor for ExternalCallsInLoopVisitor {
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                     context.contract_definition,
                     context.definition_node,
-                    context.current_source_unit.source_line(context.identifier.src.as_str())?,
+                    context.current_source_unit.source_range(context.identifier.src.as_str())?,
                     &self.function_calls.last().unwrap().clone(),
                 );
                 break;
@@ -142,7 +142,7 @@ impl AstVisitor for ExternalCallsInLoopVisitor {
                             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                             context.contract_definition,
                             context.definition_node,
-                            context.current_source_unit.source_line(context.member_access.src.as_str())?,
+                            context.current_source_unit.source_range(context.member_access.src.as_str())?,
                             &self.function_calls.last().unwrap().clone(),
                         );
                         break;
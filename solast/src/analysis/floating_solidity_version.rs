@@ -37,7 +37,7 @@ impl AstVisitor for FloatingSolidityVersionVisitor {
                 if floating {
                     self.report.borrow_mut().add_entry(
                         context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                        Some(context.current_source_unit.source_line(context.pragma_directive.src.as_str())?),
+                        Some(context.current_source_unit.source_range(context.pragma_directive.src.as_str())?),
                         format!("Floating solidity version: {pragma_string}; Consider locking before deployment"),
                     );
                 }
@@ -17,12 +17,12 @@ impl InlineAssemblyVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         description: &str
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains {}",
                 contract_definition.definition_node_location(definition_node),
@@ -38,7 +38,7 @@ impl AstVisitor for InlineAssemblyVisitor {
             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
             context.contract_definition,
             context.definition_node,
-            context.current_source_unit.source_line(context.inline_assembly.src.as_str())?,
+            context.current_source_unit.source_range(context.inline_assembly.src.as_str())?,
             "inline assembly usage"
         );
 
@@ -72,7 +72,7 @@ impl AstVisitor for InlineAssemblyVisitor {
                         context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                         context.contract_definition,
                         context.definition_node,
-                        context.current_source_unit.source_line(context.inline_assembly.src.as_str())?,
+                        context.current_source_unit.source_range(context.inline_assembly.src.as_str())?,
                         "inline assembly which loads the free memory pointer"
                     );
                 }
@@ -81,8 +81,9 @@ impl AstVisitor for InlineAssemblyVisitor {
             "calldatacopy" => {
                 let arguments = match context.yul_function_call.arguments.get(2) {
                     Some(YulExpression::YulFunctionCall(YulFunctionCall {
-                        function_name: YulIdentifier { name },
+                        function_name: YulIdentifier { name, .. },
                         arguments,
+                        ..
                     })) if name == "sub" => arguments,
 
                     _ => return Ok(())
@@ -90,7 +91,7 @@ impl AstVisitor for InlineAssemblyVisitor {
 
                 match arguments.get(0) {
                     Some(YulExpression::YulFunctionCall(YulFunctionCall {
-                        function_name: YulIdentifier { name },
+                        function_name: YulIdentifier { name, .. },
                         ..
                     })) if name == "calldatasize" => {}
 
@@ -121,7 +122,7 @@ impl AstVisitor for InlineAssemblyVisitor {
                         context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                         context.contract_definition,
                         context.definition_node,
-                        context.current_source_unit.source_line(context.inline_assembly.src.as_str())?,
+                        context.current_source_unit.source_range(context.inline_assembly.src.as_str())?,
                         "inline assembly which copies arbitrary function arguments"
                     );
                 }
@@ -16,12 +16,12 @@ impl InvalidUsingForDirectivesVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         using_for_directive: &UsingForDirective
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains an invalid using-for directive: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -37,8 +37,8 @@ impl AstVisitor for InvalidUsingForDirectivesVisitor {
         // Get the identifier of the contract definition associated with the used library
         //
 
-        let using_contract_id = match context.using_for_directive.library_name.referenced_declaration.as_ref() {
-            Some(&id) => id,
+        let using_contract_id = match context.using_for_directive.library_name.as_ref().and_then(|library_name| library_name.referenced_declaration) {
+            Some(id) => id,
             None => return Ok(())
         };
 
@@ -140,7 +140,7 @@ impl AstVisitor for InvalidUsingForDirectivesVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.using_for_directive.src.as_str())?,
+                context.current_source_unit.source_range(context.using_for_directive.src.as_str())?,
                 context.using_for_directive
             );
         }
@@ -20,7 +20,7 @@ impl AstVisitor for LargeLiteralsVisitor {
             if value.chars().all(char::is_numeric) && (n > 6) && ((n % 3) != 0) {
                 self.report.borrow_mut().add_entry(
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                    Some(context.current_source_unit.source_line(context.literal.src.as_str())?),
+                    Some(context.current_source_unit.source_range(context.literal.src.as_str())?),
                     format!(
                         "{} contains a large literal, which may be difficult to read: `{}`",
                         context.contract_definition.definition_node_location(context.definition_node),
@@ -22,12 +22,12 @@ impl ManipulatableBalanceUsageVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         expression: &dyn std::fmt::Display
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains manipulatable balance usage: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -82,7 +82,7 @@ impl AstVisitor for ManipulatableBalanceUsageVisitor {
             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
             context.contract_definition,
             context.definition_node,
-            context.current_source_unit.source_line(context.member_access.src.as_str())?,
+            context.current_source_unit.source_range(context.member_access.src.as_str())?,
             context.member_access
         );
 
@@ -142,7 +142,7 @@ impl AstVisitor for ManipulatableBalanceUsageVisitor {
             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
             context.contract_definition,
             context.definition_node,
-            context.current_source_unit.source_line(context.function_call.src.as_str())?,
+            context.current_source_unit.source_range(context.function_call.src.as_str())?,
             context.function_call
         );
 
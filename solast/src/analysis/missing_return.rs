@@ -25,11 +25,11 @@ impl MissingReturnVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize
+        source_range: SourceRange
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} is missing an explicit return statement",
                 contract_definition.definition_node_location(definition_node),
@@ -61,7 +61,8 @@ impl AstVisitor for MissingReturnVisitor {
             None => return Ok(())
         };
 
-        if BlockOrStatement::Block(Box::new(context.function_definition.body.as_ref().unwrap().clone())).contains_returns() {
+        // Functions which return or revert on every path do not need to assign their return variables
+        if !ControlFlowGraph::new(context.function_definition.body.as_ref().unwrap()).falls_through() {
             return Ok(());
         }
 
@@ -76,7 +77,7 @@ impl AstVisitor for MissingReturnVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.function_definition.src.as_str())?
+                context.current_source_unit.source_range(context.function_definition.src.as_str())?
             );
         }
 
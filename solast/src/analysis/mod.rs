@@ -27,6 +27,7 @@ mod redundant_state_variable_access;
 mod require_without_message;
 mod safe_erc20_functions;
 mod secure_ether_transfer;
+mod selector_collisions;
 mod selfdestruct_usage;
 mod state_variable_mutability;
 mod state_variable_shadowing;
@@ -50,59 +51,648 @@ use self::{
     node_modules_imports::*, redundant_assignments::*, redundant_comparisons::*,
     redundant_getter_function::*, redundant_imports::*, redundant_state_variable_access::*,
     require_without_message::*, safe_erc20_functions::*, secure_ether_transfer::*,
-    selfdestruct_usage::*, state_variable_mutability::*, state_variable_shadowing::*,
+    selector_collisions::*, selfdestruct_usage::*, state_variable_mutability::*, state_variable_shadowing::*,
     storage_array_loop::*, tight_variable_packing::*, unchecked_casting::*,
     unchecked_erc20_transfer::*, unnecessary_pragmas::*, unpaid_payable_functions::*,
     unreferenced_state_variables::*, unrestricted_setter_functions::*, unused_return::*,
 };
 
-use crate::report::Report;
+use crate::report::{Category, Confidence, Report, Severity};
 use solidity::ast::AstVisitor;
 use std::{rc::Rc, cell::RefCell};
 
 type VisitorConstructor = fn(report: Rc<RefCell<Report>>) -> Box<dyn AstVisitor>;
-type VisitorEntry = (&'static str, VisitorConstructor);
 
-pub const VISITOR_TYPES: &[VisitorEntry] = &[
-    ("no_spdx_identifier", |report: Rc<RefCell<Report>>| Box::new(NoSpdxIdentifierVisitor::new(report))),
-    ("floating_solidity_version", |report: Rc<RefCell<Report>>| Box::new(FloatingSolidityVersionVisitor::new(report))),
-    ("node_modules_imports", |report: Rc<RefCell<Report>>| Box::new(NodeModulesImportsVisitor::new(report))),
-    ("redundant_imports", |report: Rc<RefCell<Report>>| Box::new(RedundantImportsVisitor::new(report))),
-    ("abstract_contracts", |report: Rc<RefCell<Report>>| Box::new(AbstractContractsVisitor::new(report))),
-    ("large_literals", |report: Rc<RefCell<Report>>| Box::new(LargeLiteralsVisitor::new(report))),
-    ("tight_variable_packing", |report: Rc<RefCell<Report>>| Box::new(TightVariablePackingVisitor::new(report))),
-    ("redundant_getter_function", |report: Rc<RefCell<Report>>| Box::new(RedundantGetterFunctionVisitor::new(report))),
-    ("require_without_message", |report: Rc<RefCell<Report>>| Box::new(RequireWithoutMessageVisitor::new(report))),
-    ("state_variable_shadowing", |report: Rc<RefCell<Report>>| Box::new(StateVariableShadowingVisitor::new(report))),
-    ("explicit_variable_return", |report: Rc<RefCell<Report>>| Box::new(ExplicitVariableReturnVisitor::new(report))),
-    ("unused_return", |report: Rc<RefCell<Report>>| Box::new(UnusedReturnVisitor::new(report))),
-    ("storage_array_loop", |report: Rc<RefCell<Report>>| Box::new(StorageArrayLoopVisitor::new(report))),
-    ("external_calls_in_loop", |report: Rc<RefCell<Report>>| Box::new(ExternalCallsInLoopVisitor::new(report))),
-    ("check_effects_interactions", |report: Rc<RefCell<Report>>| Box::new(CheckEffectsInteractionsVisitor::new(report))),
-    ("secure_ether_transfer", |report: Rc<RefCell<Report>>| Box::new(SecureEtherTransferVisitor::new(report))),
-    ("safe_erc20_functions", |report: Rc<RefCell<Report>>| Box::new(SafeERC20FunctionsVisitor::new(report))),
-    ("unchecked_erc20_transfer", |report: Rc<RefCell<Report>>| Box::new(UncheckedERC20TransferVisitor::new(report))),
-    ("unpaid_payable_functions", |report: Rc<RefCell<Report>>| Box::new(UnpaidPayableFunctionsVisitor::new(report))),
-    ("divide_before_multiply", |report: Rc<RefCell<Report>>| Box::new(DivideBeforeMultiplyVisitor::new(report))),
-    ("comparison_utilization", |report: Rc<RefCell<Report>>| Box::new(ComparisonUtilizationVisitor::new(report))),
-    ("assignment_comparisons", |report: Rc<RefCell<Report>>| Box::new(AssignmentComparisonsVisitor::new(report))),
-    ("state_variable_mutability", |report: Rc<RefCell<Report>>| Box::new(StateVariableMutabilityVisitor::new(report))),
-    ("unused_state_variables", |report: Rc<RefCell<Report>>| Box::new(UnusedStateVariablesVisitor::new(report))),
-    ("ineffectual_statements", |report: Rc<RefCell<Report>>| Box::new(IneffectualStatementsVisitor::new(report))),
-    ("inline_assembly", |report: Rc<RefCell<Report>>| Box::new(InlineAssemblyVisitor::new(report))),
-    ("unchecked_casting", |report: Rc<RefCell<Report>>| Box::new(UncheckedCastingVisitor::new(report))),
-    ("unnecessary_pragmas", |report: Rc<RefCell<Report>>| Box::new(UnnecessaryPragmasVisitor::new(report))),
-    ("missing_return", |report: Rc<RefCell<Report>>| Box::new(MissingReturnVisitor::new(report))),
-    ("redundant_state_variable_access", |report: Rc<RefCell<Report>>| Box::new(RedundantStateVariableAccessVisitor::new(report))),
-    ("redundant_comparisons", |report: Rc<RefCell<Report>>| Box::new(RedundantComparisonsVisitor::new(report))),
-    ("assert_usage", |report: Rc<RefCell<Report>>| Box::new(AssertUsageVisitor::new(report))),
-    ("selfdestruct_usage", |report: Rc<RefCell<Report>>| Box::new(SelfdestructUsageVisitor::new(report))),
-    ("unrestricted_setter_functions", |report: Rc<RefCell<Report>>| Box::new(UnrestrictedSetterFunctionsVisitor::new(report))),
-    ("manipulatable_balance_usage", |report: Rc<RefCell<Report>>| Box::new(ManipulatableBalanceUsageVisitor::new(report))),
-    ("redundant_assignments", |report: Rc<RefCell<Report>>| Box::new(RedundantAssignmentsVisitor::new(report))),
-    ("invalid_using_for_directives", |report: Rc<RefCell<Report>>| Box::new(InvalidUsingForDirectivesVisitor::new(report))),
-    ("abi_encoding", |report: Rc<RefCell<Report>>| Box::new(AbiEncodingVisitor::new(report))),
-    ("address_balance", |report: Rc<RefCell<Report>>| Box::new(AddressBalanceVisitor::new(report))),
-    ("address_zero", |report: Rc<RefCell<Report>>| Box::new(AddressZeroVisitor::new(report))),
-    ("array_assignment", |report: Rc<RefCell<Report>>| Box::new(ArrayAssignmentVisitor::new(report))),
+pub struct VisitorType {
+    /// The stable rule ID of the visitor, which is also used to enable it from the command line.
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Why the reported pattern is a problem.
+    pub rationale: &'static str,
+    /// Example code which the visitor reports.
+    pub example: &'static str,
+    pub severity: Severity,
+    pub confidence: Confidence,
+    pub category: Category,
+    pub create: VisitorConstructor,
+}
+
+pub const VISITOR_TYPES: &[VisitorType] = &[
+    VisitorType {
+        name: "no_spdx_identifier",
+        description: "Determines if a source file was compiled without a `SPDX` identifier specified.",
+        rationale: "Source files without a license identifier are ambiguous about how the code may be reused, and the compiler emits a warning for them. Verified sources on block explorers also display the license, so it should be stated explicitly.",
+        example: r#"
+pragma solidity ^0.8.0;
+
+contract Token {}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(NoSpdxIdentifierVisitor::new(report)),
+    },
+    VisitorType {
+        name: "floating_solidity_version",
+        description: "Determines if a pragma directive specifies a floating/unlocked Solidity version.",
+        rationale: "Contracts should be deployed with the same compiler version they were tested with. A floating pragma allows the contract to be compiled with a newer version which may contain bugs or behavior changes which were never tested.",
+        example: r#"
+pragma solidity ^0.8.0;
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::High,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(FloatingSolidityVersionVisitor::new(report)),
+    },
+    VisitorType {
+        name: "node_modules_imports",
+        description: "Determines if an import directive attempts to locally import from the `node_modules` directory.",
+        rationale: "Importing through a relative `node_modules` path ties the source to one particular directory layout. Package imports (i.e: `@openzeppelin/...`) are resolved by every build system.",
+        example: r#"
+import "../node_modules/@openzeppelin/contracts/token/ERC20/ERC20.sol";
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(NodeModulesImportsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "redundant_imports",
+        description: "Determines if any import directives are redundant due to the specified path being already previously imported.",
+        rationale: "Importing the same file more than once has no effect and makes the dependencies of a source file harder to follow.",
+        example: r#"
+import "./Token.sol";
+import "./Token.sol";
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(RedundantImportsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "abstract_contracts",
+        description: "Determines if a contract specifies an internal constructor without declaring the contract `abstract`.",
+        rationale: "Internal constructors were used to prevent a contract from being deployed directly before the `abstract` keyword existed. Since Solidity 0.6.0 the intent should be stated with `abstract` instead.",
+        example: r#"
+contract Base {
+    constructor() internal {}
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(AbstractContractsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "large_literals",
+        description: "Determines if an expression contains a large literal value, which may be difficult to read or interpreted incorrectly.",
+        rationale: "Long literals are easy to misread by an order of magnitude. Scientific notation, ether units or underscore separators make the intended value obvious.",
+        example: r#"
+uint256 constant MAX_SUPPLY = 1000000000000000000000000;
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::Medium,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(LargeLiteralsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "tight_variable_packing",
+        description: "Determines if a contract or structure contains loose variable packing which can be more efficiently packed in order to decrease the number of required storage slots.",
+        rationale: "Consecutive variables smaller than 32 bytes are packed into a single storage slot. Ordering variables poorly uses more slots than necessary, which makes every access and deployment more expensive.",
+        example: r#"
+contract Vault {
+    uint128 a;
+    uint256 b;
+    uint128 c;
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::Low,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(TightVariablePackingVisitor::new(report)),
+    },
+    VisitorType {
+        name: "redundant_getter_function",
+        description: "Determines if a contract contains a function which returns a state variable instead of providing outside access to the state variable.",
+        rationale: "A `public` state variable already provides a getter function. Writing one by hand adds bytecode without adding functionality.",
+        example: r#"
+contract Token {
+    uint256 private totalSupply_;
+
+    function totalSupply() external view returns (uint256) {
+        return totalSupply_;
+    }
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::Medium,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(RedundantGetterFunctionVisitor::new(report)),
+    },
+    VisitorType {
+        name: "require_without_message",
+        description: "Determines if a `require` statement does not contain a message string.",
+        rationale: "A failed `require` without a message reverts with empty data, which makes failed transactions difficult to diagnose for both users and developers.",
+        example: r#"
+function withdraw(uint256 amount) external {
+    require(balances[msg.sender] >= amount);
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(RequireWithoutMessageVisitor::new(report)),
+    },
+    VisitorType {
+        name: "state_variable_shadowing",
+        description: "Determines if a contract declares a local or state variable which shadows another state variable in the contract's inheritance hierarchy.",
+        rationale: "A variable which shadows a state variable makes it unclear which of the two is being read or written, and writes intended for the state variable can silently go to the local copy instead.",
+        example: r#"
+contract Base {
+    address owner;
+}
+
+contract Derived is Base {
+    function setOwner(address owner) external {
+        owner = owner;
+    }
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::High,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(StateVariableShadowingVisitor::new(report)),
+    },
+    VisitorType {
+        name: "explicit_variable_return",
+        description: "Determines if a function returns local variables explicitly over declaring and utilizing named return variables.",
+        rationale: "Returning a local variable which could have been declared as a named return variable adds an unnecessary declaration.",
+        example: r#"
+function total() external view returns (uint256) {
+    uint256 result = a + b;
+    return result;
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::Medium,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(ExplicitVariableReturnVisitor::new(report)),
+    },
+    VisitorType {
+        name: "unused_return",
+        description: "Determines if the values returned from a function call go unused.",
+        rationale: "Ignoring the values returned by a call usually means a success flag or a result was not checked, so failures go unnoticed.",
+        example: r#"
+function deposit(uint256 amount) external {
+    token.transferFrom(msg.sender, address(this), amount);
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(UnusedReturnVisitor::new(report)),
+    },
+    VisitorType {
+        name: "storage_array_loop",
+        description: "Determines if a loop's condition relies on the `length` member of an array state variable.",
+        rationale: "Reading the `length` of a storage array in a loop condition performs a storage read on every iteration. Caching the length in a local variable is cheaper.",
+        example: r#"
+for (uint256 i = 0; i < holders.length; i++) {
+    total += balances[holders[i]];
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Medium,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(StorageArrayLoopVisitor::new(report)),
+    },
+    VisitorType {
+        name: "external_calls_in_loop",
+        description: "Determines if any functions or modifiers contain any loops which performs calls to external functions.",
+        rationale: "A single failing call inside of a loop reverts the entire transaction, which can permanently block the function. Loops over user-controlled data can also exceed the block gas limit. Prefer a pull-payment pattern.",
+        example: r#"
+for (uint256 i = 0; i < recipients.length; i++) {
+    token.transfer(recipients[i], amounts[i]);
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(ExternalCallsInLoopVisitor::new(report)),
+    },
+    VisitorType {
+        name: "check_effects_interactions",
+        description: "Determines if any functions or modifiers ignore the [Check Effects Interactions](https://fravoll.github.io/solidity-patterns/checks_effects_interactions.html) pattern.",
+        rationale: "State changes performed after an external call can be bypassed by a reentrant call from the callee. All checks and state updates should happen before any interaction with other contracts.",
+        example: r#"
+function withdraw() external {
+    uint256 amount = balances[msg.sender];
+    (bool success, ) = msg.sender.call{value: amount}("");
+    require(success, "Transfer failed");
+    balances[msg.sender] = 0;
+}
+"#,
+        severity: Severity::High,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(CheckEffectsInteractionsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "secure_ether_transfer",
+        description: "Determines if any functions or modifiers ignores the [Secure Ether Transfer](https://fravoll.github.io/solidity-patterns/secure_ether_transfer.html) pattern.",
+        rationale: "`transfer` and `send` forward a fixed 2300 gas stipend, which breaks when the recipient's fallback function needs more gas and gas costs change over time. Use `call` along with the Check Effects Interactions pattern instead.",
+        example: r#"
+function withdraw() external {
+    uint256 amount = balances[msg.sender];
+    balances[msg.sender] = 0;
+    payable(msg.sender).transfer(amount);
+}
+"#,
+        severity: Severity::High,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(SecureEtherTransferVisitor::new(report)),
+    },
+    VisitorType {
+        name: "safe_erc20_functions",
+        description: "Determines if any functions or modifiers utilize unsafe ERC-20 functionality.",
+        rationale: "Many tokens do not return a value from `transfer`, `transferFrom` and `approve`, or return `false` instead of reverting. The `SafeERC20` wrappers handle both cases consistently.",
+        example: r#"
+function deposit(uint256 amount) external {
+    token.transferFrom(msg.sender, address(this), amount);
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(SafeERC20FunctionsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "unchecked_erc20_transfer",
+        description: "Determines if any functions or modifiers perform ERC-20 transfers without checking the value being transferred, which can revert if zero.",
+        rationale: "Some tokens revert when transferring a value of zero. Checking the value first avoids unexpected reverts and wasted gas.",
+        example: r#"
+function claim() external {
+    token.safeTransfer(msg.sender, rewards[msg.sender]);
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(UncheckedERC20TransferVisitor::new(report)),
+    },
+    VisitorType {
+        name: "unpaid_payable_functions",
+        description: "Determines if any functions or modifiers perform calls to `payable` functions without paying.",
+        rationale: "Calling a `payable` function without sending any value may indicate that the payment was forgotten.",
+        example: r#"
+function forward(address target) external {
+    IVault(target).deposit();
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Low,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(UnpaidPayableFunctionsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "divide_before_multiply",
+        description: "Determines if any functions or modifiers perform multiplication on the result of a division, which can truncate.",
+        rationale: "Integer division truncates, so multiplying the result of a division loses precision. Multiplying before dividing preserves it.",
+        example: r#"
+function fee(uint256 amount) external pure returns (uint256) {
+    return amount / 10000 * 30;
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::Low,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(DivideBeforeMultiplyVisitor::new(report)),
+    },
+    VisitorType {
+        name: "comparison_utilization",
+        description: "Determines if an `if` statement's condition contains a comparison without utilizing either compared value in its `true` or `false` branches.",
+        rationale: "An `if` statement which compares two values without using either of them in its branches may be checking the wrong condition.",
+        example: r#"
+if (balance > limit) {
+    emit LimitExceeded();
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Low,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(ComparisonUtilizationVisitor::new(report)),
+    },
+    VisitorType {
+        name: "assignment_comparisons",
+        description: "Determines if any conditional expressions contain assignments, i.e: `require(owner = msg.sender);`, `if (releaseTime = block.timestamp)`, etc.",
+        rationale: "An assignment inside of a condition is almost always a typo of a comparison, and the condition then depends on the assigned value instead.",
+        example: r#"
+require(owner = msg.sender, "Not owner");
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::High,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(AssignmentComparisonsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "state_variable_mutability",
+        description: "Determines if any state variables can be made `constant` or `immutable`.",
+        rationale: "Variables which are never modified after construction can be declared `constant` or `immutable`, which avoids storage reads entirely.",
+        example: r#"
+contract Token {
+    uint256 decimals = 18;
+    address owner;
+
+    constructor() {
+        owner = msg.sender;
+    }
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::Medium,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(StateVariableMutabilityVisitor::new(report)),
+    },
+    VisitorType {
+        name: "unused_state_variables",
+        description: "Determines if any state variables are unused within a contract.",
+        rationale: "Unused state variables take up storage and bytecode and may indicate incomplete or incorrect logic.",
+        example: r#"
+contract Vault {
+    uint256 lastUpdate;
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(UnusedStateVariablesVisitor::new(report)),
+    },
+    VisitorType {
+        name: "ineffectual_statements",
+        description: "Determines if any statements are ineffectual, i.e: `balance[msg.sender];`",
+        rationale: "A statement which has no effect usually means an intended assignment or call was left incomplete.",
+        example: r#"
+function touch() external {
+    balances[msg.sender];
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::High,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(IneffectualStatementsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "inline_assembly",
+        description: "Determines if any functions or modifiers contain inline Yul assembly usage and checks for arbitrary data passing.",
+        rationale: "Inline assembly bypasses many of the compiler's safety checks. Arbitrary data passed to assembly blocks can corrupt memory or storage, so assembly usage should be reviewed carefully.",
+        example: r#"
+function read(uint256 slot) external view returns (uint256 value) {
+    assembly {
+        value := sload(slot)
+    }
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(InlineAssemblyVisitor::new(report)),
+    },
+    VisitorType {
+        name: "unchecked_casting",
+        description: "Determines if a value expression is cast without checking its value beforehand, which can result it invalid values.",
+        rationale: "Casting to a smaller type silently truncates values which do not fit. Values should be checked against the bounds of the target type before casting.",
+        example: r#"
+function store(uint256 amount) external {
+    packedAmount = uint128(amount);
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::Low,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(UncheckedCastingVisitor::new(report)),
+    },
+    VisitorType {
+        name: "unnecessary_pragmas",
+        description: "Determines if any pragma directives are unnecessary for a specific Solidity version.",
+        rationale: "Some pragma directives are enabled by default in newer compiler versions, i.e: `pragma experimental ABIEncoderV2;` since Solidity 0.8.0.",
+        example: r#"
+pragma solidity ^0.8.0;
+pragma experimental ABIEncoderV2;
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::Low,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(UnnecessaryPragmasVisitor::new(report)),
+    },
+    VisitorType {
+        name: "missing_return",
+        description: "Determines if a function is missing an explicit return statement without assigning to a named return variable.",
+        rationale: "A function which declares return values but never returns or assigns them always returns default values, which is rarely intended.",
+        example: r#"
+function balanceOf(address account) external view returns (uint256) {
+    balances[account];
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(MissingReturnVisitor::new(report)),
+    },
+    VisitorType {
+        name: "redundant_state_variable_access",
+        description: "Determines if any functions or modifiers access state variables multiple times without updating their value between each access.",
+        rationale: "Each state variable access is a storage read. Reading the same value multiple times without modifying it can be replaced with a single read into a local variable.",
+        example: r#"
+function claimable(address account) external view returns (uint256) {
+    return rewards[account] * rate / 1e18 + rewards[account];
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::Low,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(RedundantStateVariableAccessVisitor::new(report)),
+    },
+    VisitorType {
+        name: "redundant_comparisons",
+        description: "Determines if any comparisons are redundant, i.e: `true != false`, `uint16(uint8(x)) < 256`, etc.",
+        rationale: "A comparison which always has the same result is dead logic, which may hide a mistake in the intended condition.",
+        example: r#"
+function check(uint8 x) external pure returns (bool) {
+    return uint16(x) < 256;
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Low,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(RedundantComparisonsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "assert_usage",
+        description: "Determines if any functions or modifiers utilize `assert(...)`, which should not be used in production.",
+        rationale: "`assert` is intended for checking invariants which should never fail. Before Solidity 0.8.0 a failed `assert` consumes all remaining gas, and input validation should use `require` instead.",
+        example: r#"
+function withdraw(uint256 amount) external {
+    assert(balances[msg.sender] >= amount);
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::High,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(AssertUsageVisitor::new(report)),
+    },
+    VisitorType {
+        name: "selfdestruct_usage",
+        description: "Determines if any functions or modifiers perform a `selfdestruct`.",
+        rationale: "`selfdestruct` permanently removes a contract's code and can forcibly send ether to any address. It is deprecated, and any access control mistake around it is irreversible.",
+        example: r#"
+function destroy() external {
+    selfdestruct(payable(msg.sender));
+}
+"#,
+        severity: Severity::High,
+        confidence: Confidence::High,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(SelfdestructUsageVisitor::new(report)),
+    },
+    VisitorType {
+        name: "unrestricted_setter_functions",
+        description: "Determines if any functions allow setting of state variable values without any access restriction or requirements.",
+        rationale: "A function which sets state variables without any access restriction allows anyone to change the contract's configuration.",
+        example: r#"
+function setFeeRecipient(address recipient) external {
+    feeRecipient = recipient;
+}
+"#,
+        severity: Severity::High,
+        confidence: Confidence::Low,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(UnrestrictedSetterFunctionsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "manipulatable_balance_usage",
+        description: "Determines if any functions or modifiers contain `balance` usage which can potentially be manipulated, i.e: `address(this).balance`, `IERC20(token).balance()`, etc.",
+        rationale: "Anyone can send ether or tokens to a contract, including through `selfdestruct`, so logic which depends on a contract's balance can be manipulated. Track deposits in a state variable instead.",
+        example: r#"
+function isFunded() public view returns (bool) {
+    return address(this).balance >= goal;
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::Low,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(ManipulatableBalanceUsageVisitor::new(report)),
+    },
+    VisitorType {
+        name: "redundant_assignments",
+        description: "Determines if any functions or modifiers perform assignments which are redundant, i.e: `(x, x) = getValues();`",
+        rationale: "Assigning to the same variable more than once in a single assignment discards all but one of the values.",
+        example: r#"
+function update() external {
+    (x, x) = getValues();
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Low,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(RedundantAssignmentsVisitor::new(report)),
+    },
+    VisitorType {
+        name: "invalid_using_for_directives",
+        description: "Determines if any using-for directives specify types which do not have functions provided by the specified library.",
+        rationale: "A using-for directive for a type which the library has no functions for has no effect, which may indicate the wrong type or library was specified.",
+        example: r#"
+library SafeMath {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+}
+
+contract Token {
+    using SafeMath for address;
+}
+"#,
+        severity: Severity::Low,
+        confidence: Confidence::Medium,
+        category: Category::Style,
+        create: |report: Rc<RefCell<Report>>| Box::new(InvalidUsingForDirectivesVisitor::new(report)),
+    },
+    VisitorType {
+        name: "abi_encoding",
+        description: "Determines if any functions or modifiers attempt to use `abi.encodePacked` on multiple arguments when any of are variably-sized arrays, which can result in hash collisions.",
+        rationale: "`abi.encodePacked` does not encode the lengths of dynamic values, so different arguments can produce the same encoding. Hashing such an encoding can lead to collisions, i.e: when verifying signatures.",
+        example: r#"
+function hash(string memory a, string memory b) external pure returns (bytes32) {
+    return keccak256(abi.encodePacked(a, b));
+}
+"#,
+        severity: Severity::High,
+        confidence: Confidence::Medium,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(AbiEncodingVisitor::new(report)),
+    },
+    VisitorType {
+        name: "address_balance",
+        description: "Determines if any functions or modifiers use `address(...).balance`, which can be optimized with assembly.",
+        rationale: "`address(this).balance` can be read with the `selfbalance()` opcode in assembly, which is cheaper.",
+        example: r#"
+function balance() external view returns (uint256) {
+    return address(this).balance;
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(AddressBalanceVisitor::new(report)),
+    },
+    VisitorType {
+        name: "address_zero",
+        description: "Determines if any functions or modifiers compare addresses against `address(0)`, which can be optimized with assembly.",
+        rationale: "Comparing against `address(0)` in assembly avoids the extra operations generated for the comparison.",
+        example: r#"
+function setOwner(address newOwner) external {
+    require(newOwner != address(0), "Zero address");
+    owner = newOwner;
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(AddressZeroVisitor::new(report)),
+    },
+    VisitorType {
+        name: "array_assignment",
+        description: "Determines if any array element assignments can be optimized to use a compound assignment operator.",
+        rationale: "A compound assignment operator only computes the array element's location once.",
+        example: r#"
+function deposit(uint256 index, uint256 amount) external {
+    balances[index] = balances[index] + amount;
+}
+"#,
+        severity: Severity::Info,
+        confidence: Confidence::High,
+        category: Category::Gas,
+        create: |report: Rc<RefCell<Report>>| Box::new(ArrayAssignmentVisitor::new(report)),
+    },
+    VisitorType {
+        name: "selector_collisions",
+        description: "Determines if any external functions or public state variable getters within a contract's inheritance hierarchy share the same function selector.",
+        rationale: "Functions are dispatched by the first 4 bytes of the keccak256 hash of their signature, so two functions with the same selector can not both be called. This is especially dangerous for proxies, where a selector in the proxy silently shadows a function of the implementation.",
+        example: r#"
+contract Vault {
+    function burn(uint256 amount) external {}
+
+    function collate_propagate_storage(bytes16 data) external {}
+}
+"#,
+        severity: Severity::Medium,
+        confidence: Confidence::High,
+        category: Category::Security,
+        create: |report: Rc<RefCell<Report>>| Box::new(SelectorCollisionsVisitor::new(report)),
+    },
 ];
+
+/// Finds the visitor type with the supplied rule ID.
+pub fn find_visitor_type(name: &str) -> Option<&'static VisitorType> {
+    VISITOR_TYPES.iter().find(|visitor_type| visitor_type.name == name)
+}
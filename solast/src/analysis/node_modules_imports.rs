@@ -20,7 +20,7 @@ impl AstVisitor for NodeModulesImportsVisitor {
         if context.import_directive.file.contains("../node_modules") {
             self.report.borrow_mut().add_entry(
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                Some(context.current_source_unit.source_line(context.import_directive.src.as_str())?),
+                Some(context.current_source_unit.source_range(context.import_directive.src.as_str())?),
                 format!("Unnecessary relative node_modules import: `{}`", context.import_directive.file),
             );
         }
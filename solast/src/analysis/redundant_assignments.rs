@@ -22,12 +22,12 @@ impl RedundantAssignmentsVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         assignment: &Assignment
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains a redundant assignment: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -54,7 +54,7 @@ impl AstVisitor for RedundantAssignmentsVisitor {
                         context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                         context.contract_definition,
                         context.definition_node,
-                        context.current_source_unit.source_line(context.assignment.src.as_str())?,
+                        context.current_source_unit.source_range(context.assignment.src.as_str())?,
                         context.assignment
                     );
                 }
@@ -17,12 +17,12 @@ impl RedundantComparisonsVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         binary_operation: &BinaryOperation
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains a redundant comparison: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -110,7 +110,7 @@ impl AstVisitor for RedundantComparisonsVisitor {
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                 context.contract_definition,
                 context.definition_node,
-                context.current_source_unit.source_line(context.binary_operation.src.as_str())?,
+                context.current_source_unit.source_range(context.binary_operation.src.as_str())?,
                 context.binary_operation
             );
         }
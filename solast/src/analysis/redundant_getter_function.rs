@@ -61,7 +61,7 @@ impl AstVisitor for RedundantGetterFunctionVisitor {
 
         self.report.borrow_mut().add_entry(
             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-            Some(context.current_source_unit.source_line(context.function_definition.src.as_str())?),
+            Some(context.current_source_unit.source_range(context.function_definition.src.as_str())?),
             format!(
                 "{} is a redundant getter function for the {} `{}.{}` state variable",
                 context.contract_definition.definition_node_location(context.definition_node),
@@ -37,7 +37,7 @@ impl AstVisitor for RedundantImportsVisitor {
             Some(reported) => if !*reported {
                 self.report.borrow_mut().add_entry(
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                    Some(context.current_source_unit.source_line(context.import_directive.src.as_str())?),
+                    Some(context.current_source_unit.source_range(context.import_directive.src.as_str())?),
                     format!("Redundant import specified: `{}`", context.import_directive.file),
                 );
                 *reported = true;
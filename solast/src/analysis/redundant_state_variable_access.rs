@@ -22,13 +22,13 @@ impl RedundantStateVariableAccessVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         message: &str,
         expression: &dyn std::fmt::Display
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains {} which redundantly accesses storage: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -56,7 +56,7 @@ impl AstVisitor for RedundantStateVariableAccessVisitor {
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                     context.contract_definition,
                     context.definition_node,
-                    condition.source_line(context.current_source_unit)?,
+                    condition.source_range(context.current_source_unit)?,
                     "a for statement with a condition",
                     condition
                 );
@@ -78,7 +78,7 @@ impl AstVisitor for RedundantStateVariableAccessVisitor {
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                     context.contract_definition,
                     context.definition_node,
-                    context.while_statement.condition.source_line(context.current_source_unit)?,
+                    context.while_statement.condition.source_range(context.current_source_unit)?,
                     "a while statement with a condition",
                     &context.while_statement.condition
                 );
@@ -18,7 +18,7 @@ impl AstVisitor for RequireWithoutMessageVisitor {
             if name == "require" && context.function_call.arguments.len() < 2 {
                 self.report.borrow_mut().add_entry(
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                    Some(context.current_source_unit.source_line(context.function_call.src.as_str())?),
+                    Some(context.current_source_unit.source_range(context.function_call.src.as_str())?),
                     format!(
                         "{} contains a requirement without a message: `{}`",
                         context.contract_definition.definition_node_location(context.definition_node),
@@ -16,13 +16,13 @@ impl SafeERC20FunctionsVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         unsafe_name: &str,
         safe_name: &str
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} uses `ERC20.{}` instead of `SafeERC20.{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -62,7 +62,7 @@ impl AstVisitor for SafeERC20FunctionsVisitor {
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                     context.contract_definition,
                     context.definition_node,
-                    context.current_source_unit.source_line(context.function_call.src.as_str())?,
+                    context.current_source_unit.source_range(context.function_call.src.as_str())?,
                     unsafe_name,
                     safe_name
                 );
@@ -16,12 +16,12 @@ impl SecureEtherTransferVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
         expression: &dyn std::fmt::Display
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} ignores the Secure-Ether-Transfer pattern: `{}`",
                 contract_definition.definition_node_location(definition_node),
@@ -51,7 +51,7 @@ impl AstVisitor for SecureEtherTransferVisitor {
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                     context.contract_definition,
                     context.definition_node,
-                    context.current_source_unit.source_line(context.function_call.src.as_str())?,
+                    context.current_source_unit.source_range(context.function_call.src.as_str())?,
                     context.function_call
                 );
             }
@@ -79,7 +79,7 @@ impl AstVisitor for SecureEtherTransferVisitor {
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                     context.contract_definition,
                     context.definition_node,
-                    context.current_source_unit.source_line(context.function_call_options.src.as_str())?,
+                    context.current_source_unit.source_range(context.function_call_options.src.as_str())?,
                     context.function_call_options
                 );
             }
@@ -0,0 +1,215 @@
+use crate::report::Report;
+use eth_lang_utils::ast::*;
+use solidity::ast::*;
+use std::{cell::RefCell, collections::HashMap, io, rc::Rc};
+
+pub struct SelectorCollisionsVisitor {
+    report: Rc<RefCell<Report>>,
+}
+
+impl SelectorCollisionsVisitor {
+    pub fn new(report: Rc<RefCell<Report>>) -> Self {
+        Self { report }
+    }
+}
+
+impl SelectorCollisionsVisitor {
+    fn member_kind(definition_node: &ContractDefinitionNode) -> &'static str {
+        match definition_node {
+            ContractDefinitionNode::VariableDeclaration(_) => "state variable getter",
+            _ => "function",
+        }
+    }
+
+    /// Determines if a base contract of the current contract inherits all of the colliding members, in which case the collision
+    /// is reported for that base contract instead.
+    fn is_inherited_collision(
+        &self,
+        context: &ContractDefinitionContext,
+        contract_ids: &[NodeID],
+        members: &[(&ContractDefinition, &ContractDefinitionNode, &str)],
+    ) -> bool {
+        contract_ids.iter()
+            .filter(|&&contract_id| contract_id != context.contract_definition.id)
+            .filter_map(|&contract_id| context.source_units.iter().find_map(|source_unit| source_unit.contract_definition(contract_id)))
+            .any(|base_contract_definition| {
+                let Some(base_contract_ids) = base_contract_definition.linearized_base_contracts.as_ref() else { return false };
+                members.iter().all(|(contract_definition, _, _)| base_contract_ids.contains(&contract_definition.id))
+            })
+    }
+}
+
+impl AstVisitor for SelectorCollisionsVisitor {
+    fn visit_contract_definition<'a>(&mut self, context: &mut ContractDefinitionContext<'a>) -> io::Result<()> {
+        let contract_ids = match context.contract_definition.linearized_base_contracts.as_ref() {
+            Some(contract_ids) => contract_ids.clone(),
+            None => vec![context.contract_definition.id],
+        };
+
+        //
+        // Collect the selectors of all external functions and getters in the hierarchy, starting with the most derived contract
+        //
+
+        let mut selectors: HashMap<String, Vec<(&ContractDefinition, &ContractDefinitionNode, &str)>> = HashMap::new();
+
+        for &contract_id in contract_ids.iter() {
+            let Some(contract_definition) = context.source_units.iter().find_map(|source_unit| source_unit.contract_definition(contract_id)) else { continue };
+
+            for definition_node in contract_definition.nodes.iter() {
+                // Selectors which were not supplied by the compiler are computed from the signature
+                let (name, selector) = match definition_node {
+                    ContractDefinitionNode::FunctionDefinition(function_definition) => (
+                        function_definition.name.as_str(),
+                        function_definition.function_selector.clone()
+                            .or_else(|| function_signature(context.source_units, function_definition).map(|signature| signature_selector(&signature))),
+                    ),
+
+                    ContractDefinitionNode::VariableDeclaration(variable_declaration) => (
+                        variable_declaration.name.as_str(),
+                        variable_declaration.function_selector.clone()
+                            .or_else(|| getter_signature(context.source_units, variable_declaration).map(|signature| signature_selector(&signature))),
+                    ),
+
+                    _ => continue,
+                };
+
+                let Some(selector) = selector else { continue };
+                let members = selectors.entry(selector).or_default();
+
+                // Overridden functions share the same name and selector
+                if !members.iter().any(|&(_, _, existing_name)| existing_name == name) {
+                    members.push((contract_definition, definition_node, name));
+                }
+            }
+        }
+
+        //
+        // Collisions involving the current contract are reported at its members. Collisions between base contracts are
+        // reported once, at the most derived contract which inherits all of the colliding members.
+        //
+
+        let mut collisions: Vec<_> = selectors.into_iter().filter(|(_, members)| members.len() > 1).collect();
+        collisions.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (selector, members) in collisions {
+            if !members.iter().any(|&(contract_definition, _, _)| contract_definition.id == context.contract_definition.id) {
+                if self.is_inherited_collision(context, contract_ids.as_slice(), members.as_slice()) {
+                    continue;
+                }
+
+                let (first_contract_definition, first_definition_node, _) = members[0];
+
+                self.report.borrow_mut().add_entry(
+                    context.current_source_unit.absolute_path.clone().unwrap_or_default(),
+                    Some(context.current_source_unit.source_range(context.contract_definition.src.as_str())?),
+                    format!(
+                        "The `{}` {} inherits {} which {} the same selector (0x{}) as {}",
+                        context.contract_definition.name,
+                        context.contract_definition.kind,
+                        first_contract_definition.definition_node_location(first_definition_node)
+                            .replacen("The ", "the ", 1),
+                        if members.len() > 2 { "share" } else { "has" },
+                        selector,
+                        members.iter()
+                            .skip(1)
+                            .map(|&(contract_definition, definition_node, name)| format!(
+                                "the `{}` {} in the `{}` {}",
+                                name,
+                                Self::member_kind(definition_node),
+                                contract_definition.name,
+                                contract_definition.kind,
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(" and "),
+                    ),
+                );
+
+                continue;
+            }
+
+            for (index, &(contract_definition, definition_node, _)) in members.iter().enumerate() {
+                if contract_definition.id != context.contract_definition.id {
+                    continue;
+                }
+
+                for &(other_contract_definition, other_definition_node, other_name) in members.iter().skip(index + 1) {
+                    let src = match definition_node {
+                        ContractDefinitionNode::FunctionDefinition(function_definition) => function_definition.src.as_str(),
+                        ContractDefinitionNode::VariableDeclaration(variable_declaration) => variable_declaration.src.as_str(),
+                        _ => continue,
+                    };
+
+                    self.report.borrow_mut().add_entry(
+                        context.current_source_unit.absolute_path.clone().unwrap_or_default(),
+                        Some(context.current_source_unit.source_range(src)?),
+                        format!(
+                            "{} has the same selector (0x{}) as the `{}` {} in the `{}` {}",
+                            contract_definition.definition_node_location(definition_node),
+                            selector,
+                            other_name,
+                            Self::member_kind(other_definition_node),
+                            other_contract_definition.name,
+                            other_contract_definition.kind,
+                        ),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::{ImportResolver, Project, TestProject};
+    use std::collections::HashSet;
+
+    // `burn(uint256)` and `collate_propagate_storage(bytes16)` share the selector 0x42966c68
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+contract A {
+    function burn(uint256 amount) external {}
+}
+
+contract B {
+    function collate_propagate_storage(bytes16 data) external {}
+}
+
+contract C is A, B {}
+
+contract D is C {}
+
+contract E is A {
+    function collate_propagate_storage(bytes16 data) external {}
+}
+"#;
+
+    #[test]
+    fn test_selector_collisions() {
+        let project = TestProject::new("selector-collisions", &[("Vault.sol", SOURCE)]);
+        let path = project.path.join("Vault.sol");
+        let source_units = Project::from_source_files(&[&path], &ImportResolver::default()).unwrap().source_units;
+
+        let report = Rc::new(RefCell::new(Report::default()));
+
+        let mut data = AstVisitorData {
+            analyzed_paths: HashSet::new(),
+            visitors: vec![Box::new(SelectorCollisionsVisitor::new(report.clone()))],
+        };
+
+        let mut context = SourceUnitContext { source_units: source_units.as_slice(), current_source_unit: &source_units[0] };
+        data.visit_source_unit(&mut context).unwrap();
+        data.leave_source_unit(&mut context).unwrap();
+
+        let report = report.borrow();
+        let entries: Vec<_> = report.entries[&path].iter().map(|entry| (entry.line, entry.text.as_str())).collect();
+
+        // The collision between the base contracts is only reported for `C`, not again for `D`
+        assert_eq!(entries, vec![
+            (Some(11), "The `C` contract inherits the external `collate_propagate_storage` function in the `B` contract which has the same selector (0x42966c68) as the `burn` function in the `A` contract"),
+            (Some(16), "The external `collate_propagate_storage` function in the `E` contract has the same selector (0x42966c68) as the `burn` function in the `A` contract"),
+        ]);
+    }
+}
@@ -16,11 +16,11 @@ impl SelfdestructUsageVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} contains `selfdestruct` usage",
                 contract_definition.definition_node_location(definition_node),
@@ -44,7 +44,7 @@ impl AstVisitor for SelfdestructUsageVisitor {
                         context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
                         context.contract_definition,
                         context.definition_node,
-                        context.current_source_unit.source_line(src.as_str())?,
+                        context.current_source_unit.source_range(src.as_str())?,
                     );
                 }
             }
@@ -39,7 +39,7 @@ impl AstVisitor for StateVariableShadowingVisitor {
                         if variable_declaration.name == base_variable_declaration.name {
                             self.report.borrow_mut().add_entry(
                                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                                Some(context.current_source_unit.source_line(variable_declaration.src.as_str())?),
+                                Some(context.current_source_unit.source_range(variable_declaration.src.as_str())?),
                                 format!(
                                     "{:?} {} {} has a {} {} parameter '{}' which shadows the {} {} {} state variable",
     
@@ -98,7 +98,7 @@ impl AstVisitor for StorageArrayLoopVisitor {
             if function_info.loops_over_storage_array {
                 self.report.borrow_mut().add_entry(
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                    Some(context.current_source_unit.source_line(context.function_definition.src.as_str())?),
+                    Some(context.current_source_unit.source_range(context.function_definition.src.as_str())?),
                     format!(
                         "{:?} {} {} performs a loop over a storage array, querying the length over each iteration",
     
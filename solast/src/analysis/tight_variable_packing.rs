@@ -1,14 +1,16 @@
 use crate::report::Report;
 use eth_lang_utils::ast::*;
+use primitive_types::U256;
 use solidity::ast::*;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::{BTreeMap, HashMap}, rc::Rc};
 
 struct StorageSlot {
-    member_sizes: Vec<usize>
+    member_sizes: Vec<u32>
 }
 
 pub struct TightVariablePackingVisitor {
     report: Rc<RefCell<Report>>,
+    storage_layout_builder: Option<StorageLayoutBuilder>,
     storage_slots: HashMap<NodeID, Vec<StorageSlot>>
 }
 
@@ -16,116 +18,33 @@ impl TightVariablePackingVisitor {
     pub fn new(report: Rc<RefCell<Report>>) -> Self {
         Self {
             report,
+            storage_layout_builder: None,
             storage_slots: HashMap::new(),
         }
     }
-}
-
-fn type_name_size(source_units: &[SourceUnit], type_name: &TypeName) -> std::io::Result<usize> {
-    Ok(match type_name {
-        TypeName::ElementaryTypeName(ElementaryTypeName { name, .. }) => match name.as_str() {
-            "bool" => 1,
-    
-            "address" => 20,
-    
-            type_name if type_name.starts_with("uint") => {
-                let size_in_bits = match type_name.trim_start_matches("uint") {
-                    "" => 256,
-                    s => s.parse().map_err(|err| {
-                        std::io::Error::new(
-                            std::io::ErrorKind::InvalidData,
-                            format!("unhandled {} type: {}", type_name, err)
-                        )
-                    })?
-                };
-    
-                size_in_bits / 8
-            }
-    
-            type_name if type_name.starts_with("int") => {
-                let size_in_bits = match type_name.trim_start_matches("int") {
-                    "" => 256,
-                    s => s.parse().map_err(|err| {
-                        std::io::Error::new(
-                            std::io::ErrorKind::InvalidData,
-                            format!("unhandled {} type: {}", type_name, err)
-                        )
-                    })?
-                };
-                
-                size_in_bits / 8
-            }
-    
-            type_name if type_name.starts_with("bytes") => {
-                let size_in_bytes: usize = match type_name.trim_start_matches("bytes") {
-                    "" => 32,
-                    s => s.parse().map_err(|err| {
-                        std::io::Error::new(
-                            std::io::ErrorKind::InvalidData,
-                            format!("unhandled {} type: {}", type_name, err)
-                        )
-                    })?
-                };
-                
-                size_in_bytes
-            }
-    
-            _ => 32 // TODO: handle this correctly...
-        }
-
-        TypeName::UserDefinedTypeName(UserDefinedTypeName { referenced_declaration, .. }) => {
-            let id = *referenced_declaration;
-
-            for source_unit in source_units.iter() {
-                if source_unit.enum_definition(id).is_some() {
-                    return Ok(1)
-                } else if let Some(struct_definition) = source_unit.struct_definition(id) {
-                    let mut size = 0;
-
-                    for member in struct_definition.members.iter() {
-                        size += type_name_size(source_units, member.type_name.as_ref().unwrap())?;
-                    }
-
-                    return Ok(size)
-                } else {
-                    for contract_definition in source_unit.contract_definitions() {
-                        if contract_definition.enum_definition(id).is_some() {
-                            return Ok(1)
-                        } else if let Some(struct_definition) = contract_definition.struct_definition(id) {
-                            let mut size = 0;
-        
-                            for member in struct_definition.members.iter() {
-                                size += type_name_size(source_units, member.type_name.as_ref().unwrap())?;
-                            }
-        
-                            return Ok(size)
-                        }
-                    }
-                }
-            }
 
-            return Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("User-defined type not found: {}", type_name)))
-        }
+    fn storage_layout_builder(&mut self, source_units: &[SourceUnit]) -> &StorageLayoutBuilder {
+        self.storage_layout_builder.get_or_insert_with(|| StorageLayoutBuilder::new(source_units))
+    }
+}
 
-        TypeName::ArrayTypeName(ArrayTypeName { base_type, length: Some(Literal { value, .. }), .. }) => {
-            let value = value.as_ref().unwrap();
+/// Groups the supplied (slot, bytes) positions into the storage slots they occupy.
+fn storage_slots(positions: impl Iterator<Item = (U256, u32)>) -> Vec<StorageSlot> {
+    let mut storage_slots: BTreeMap<U256, StorageSlot> = BTreeMap::new();
 
-            if let Ok(length) = if value.starts_with("0x") {
-                i64::from_str_radix(value.trim_start_matches("0x"), 16)
-            } else {
-                value.parse()
-            } {
-                return Ok(type_name_size(source_units, base_type.as_ref())? * length as usize)
-            }
+    for (slot, bytes) in positions {
+        storage_slots.entry(slot).or_insert_with(|| StorageSlot { member_sizes: vec![] }).member_sizes.push(bytes);
+    }
 
-            return Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("Unhandled array type: {}", type_name)))
-        }
+    storage_slots.into_values().collect()
+}
 
-        TypeName::ArrayTypeName(ArrayTypeName { length: None, .. }) => 32,
-        TypeName::FunctionTypeName(_) => 32,
-        TypeName::Mapping(_) => 32,
-        TypeName::String(_) => 32,
-    })
+fn has_loose_variable_packing(storage_slots: &[StorageSlot]) -> bool {
+    storage_slots.split_last()
+        .map(|x| x.1)
+        .unwrap_or(&[])
+        .iter()
+        .any(|slot| slot.member_sizes.iter().sum::<u32>() != 32)
 }
 
 impl AstVisitor for TightVariablePackingVisitor {
@@ -134,44 +53,14 @@ impl AstVisitor for TightVariablePackingVisitor {
             return Ok(())
         }
 
-        self.storage_slots.insert(context.struct_definition.id, vec![]);
-
-        let storage_slots = self.storage_slots.get_mut(&context.struct_definition.id).unwrap();
-
-        let mut current_slot = None;
-
-        for member in context.struct_definition.members.iter() {
-            if current_slot.is_none() {
-                current_slot = Some(StorageSlot { member_sizes: vec![] });
-            }
-
-            let current_slot_size: usize = current_slot.as_ref().unwrap().member_sizes.iter().sum();
-
-            let member_type_name_size = match type_name_size(context.source_units, member.type_name.as_ref().unwrap()) {
-                Ok(x) => x,
-                Err(_) => continue,
-            };
-
-            if current_slot_size + member_type_name_size > 32 {
-                storage_slots.push(current_slot.unwrap());
-                current_slot = Some(StorageSlot { member_sizes: vec![] });
-            }
-
-            current_slot.as_mut().unwrap().member_sizes.push(member_type_name_size);
-        }
-
-        if let Some(false) = current_slot.as_ref().map(|slot| slot.member_sizes.is_empty()) {
-            storage_slots.push(current_slot.unwrap());
-        }
+        let positions = self.storage_layout_builder(context.source_units)
+            .struct_member_positions(context.struct_definition.id)
+            .unwrap_or_default();
 
-        let mut has_loose_variable_packing = false;
+        let storage_slots = storage_slots(positions.into_iter().map(|(_, position)| (position.slot, position.bytes)));
+        let has_loose_variable_packing = has_loose_variable_packing(storage_slots.as_slice());
 
-        for slot in storage_slots.split_last().map(|x| x.1).unwrap_or(&[]) {
-            if slot.member_sizes.iter().sum::<usize>() != 32 {
-                has_loose_variable_packing = true;
-                break;
-            }
-        }
+        self.storage_slots.insert(context.struct_definition.id, storage_slots);
 
         //
         // TODO: try to see if looseness can be made tight
@@ -195,44 +84,24 @@ impl AstVisitor for TightVariablePackingVisitor {
             return Ok(())
         }
 
-        self.storage_slots.insert(context.contract_definition.id, vec![]);
-
-        let storage_slots = self.storage_slots.get_mut(&context.contract_definition.id).unwrap();
+        let storage_layout = match self.storage_layout_builder(context.source_units).contract_layout(context.source_units, context.contract_definition) {
+            Ok(storage_layout) => storage_layout,
+            Err(_) => return Ok(()),
+        };
 
-        let mut current_slot = None;
+        let storage_slots = storage_slots(storage_layout.storage.iter().map(|entry| {
+            // Values larger than a slot occupy entire slots
+            let bytes = storage_layout.type_info(entry.type_key.as_str())
+                .and_then(|type_info| type_info.number_of_bytes.parse::<u32>().ok())
+                .map(|bytes| bytes.min(32))
+                .unwrap_or(32);
 
-        for member in context.contract_definition.variable_declarations() {
-            if current_slot.is_none() {
-                current_slot = Some(StorageSlot { member_sizes: vec![] });
-            }
+            (U256::from_dec_str(entry.slot.as_str()).unwrap_or_default(), bytes)
+        }));
 
-            let current_slot_size: usize = current_slot.as_ref().unwrap().member_sizes.iter().sum();
+        let has_loose_variable_packing = has_loose_variable_packing(storage_slots.as_slice());
 
-            let member_type_name_size = match type_name_size(context.source_units, member.type_name.as_ref().unwrap()) {
-                Ok(x) => x,
-                Err(_) => continue,
-            };
-
-            if current_slot_size + member_type_name_size > 32 {
-                storage_slots.push(current_slot.unwrap());
-                current_slot = Some(StorageSlot { member_sizes: vec![] });
-            }
-
-            current_slot.as_mut().unwrap().member_sizes.push(member_type_name_size);
-        }
-
-        if let Some(false) = current_slot.as_ref().map(|slot| slot.member_sizes.is_empty()) {
-            storage_slots.push(current_slot.unwrap());
-        }
-
-        let mut has_loose_variable_packing = false;
-
-        for slot in storage_slots.split_last().map(|x| x.1).unwrap_or(&[]) {
-            if slot.member_sizes.iter().sum::<usize>() != 32 {
-                has_loose_variable_packing = true;
-                break;
-            }
-        }
+        self.storage_slots.insert(context.contract_definition.id, storage_slots);
 
         //
         // TODO: try to see if looseness can be made tight
@@ -64,7 +64,7 @@ impl AstVisitor for UncheckedCastingVisitor {
 
             self.report.borrow_mut().add_entry(
                 context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                Some(context.current_source_unit.source_line(context.function_call.src.as_str())?),
+                Some(context.current_source_unit.source_range(context.function_call.src.as_str())?),
                 format!(
                     "The {name} in the `{}` {} contains a redundant cast: `{}`",
                     context.contract_definition.name,
@@ -67,7 +67,7 @@ impl AstVisitor for UncheckedERC20TransferVisitor {
             if let Some(name) = name {
                 self.report.borrow_mut().add_entry(
                     context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                    Some(context.current_source_unit.source_line(context.function_definition.src.as_str())?),
+                    Some(context.current_source_unit.source_range(context.function_definition.src.as_str())?),
                     format!(
                         "The {name} in the `{}` {} makes {} without checking the {}, which can revert {} zero",
     
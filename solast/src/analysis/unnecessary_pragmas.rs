@@ -14,7 +14,7 @@ impl UnnecessaryPragmasVisitor {
     fn check_pragma_directives(
         &mut self,
         source_unit_path: String,
-        source_line: Option<usize>,
+        source_range: Option<SourceRange>,
         solidity: &mut Vec<&str>,
         abicoder: &mut Vec<&str>,
     ) {
@@ -69,7 +69,7 @@ impl UnnecessaryPragmasVisitor {
                 if lower >= 0.8 {
                     self.report.borrow_mut().add_entry(
                         source_unit_path,
-                        source_line,
+                        source_range,
                         "Unnecessary specification of `pragma abicoder v2`, which is enabled in Solidity v0.8.0 and above",
                     )
                 }
@@ -92,7 +92,7 @@ impl AstVisitor for UnnecessaryPragmasVisitor {
                     if !solidity.is_empty() {
                         self.check_pragma_directives(
                             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                            Some(context.current_source_unit.source_line(pragma_directive.src.as_str())?),
+                            Some(context.current_source_unit.source_range(pragma_directive.src.as_str())?),
                             &mut solidity,
                             &mut abicoder
                         );
@@ -105,7 +105,7 @@ impl AstVisitor for UnnecessaryPragmasVisitor {
                     if !abicoder.is_empty() {
                         self.check_pragma_directives(
                             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                            Some(context.current_source_unit.source_line(pragma_directive.src.as_str())?),
+                            Some(context.current_source_unit.source_range(pragma_directive.src.as_str())?),
                             &mut solidity,
                             &mut abicoder
                         );
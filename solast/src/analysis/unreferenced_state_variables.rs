@@ -41,7 +41,7 @@ impl AstVisitor for UnusedStateVariablesVisitor {
                     if !referenced {
                         self.report.borrow_mut().add_entry(
                             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                            Some(context.current_source_unit.source_line(variable_declaration.src.as_str())?),
+                            Some(context.current_source_unit.source_range(variable_declaration.src.as_str())?),
                             format!(
                                 "The {} `{}.{}` {} state variable is never referenced",
                                 variable_declaration.visibility,
@@ -16,11 +16,11 @@ impl UnrestrictedSetterFunctionsVisitor {
         source_unit_path: String,
         contract_definition: &ContractDefinition,
         definition_node: &ContractDefinitionNode,
-        source_line: usize,
+        source_range: SourceRange,
     ) {
         self.report.borrow_mut().add_entry(
             source_unit_path,
-            Some(source_line),
+            Some(source_range),
             format!(
                 "{} is an unprotected setter function",
                 contract_definition.definition_node_location(definition_node),
@@ -70,7 +70,7 @@ impl AstVisitor for UnrestrictedSetterFunctionsVisitor {
             context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
             context.contract_definition,
             context.definition_node,
-            context.current_source_unit.source_line(context.function_definition.src.as_str())?,
+            context.current_source_unit.source_range(context.function_definition.src.as_str())?,
         );
 
         Ok(())
@@ -44,7 +44,7 @@ impl AstVisitor for UnusedReturnVisitor {
                 if !called_function_definition.return_parameters.parameters.is_empty() {
                     self.report.borrow_mut().add_entry(
                         context.current_source_unit.absolute_path.clone().unwrap_or_else(String::new),
-                        Some(context.current_source_unit.source_line(src)?),
+                        Some(context.current_source_unit.source_range(src)?),
                         format!(
                             "{} makes a call to the {}, ignoring the returned {}",
 
@@ -0,0 +1,159 @@
+use crate::{
+    analysis::VisitorType,
+    baseline::{containing_definitions, find_source_unit},
+    report::{Entry, Report, Severity},
+};
+use solidity::ast::SourceUnit;
+use std::path::PathBuf;
+
+/// The maximum number of source lines included in a code excerpt.
+const MAX_EXCERPT_LINES: usize = 12;
+
+/// The source lines covered by a finding.
+pub struct Excerpt {
+    pub start_line: usize,
+    pub lines: Vec<String>,
+}
+
+pub struct Finding<'a> {
+    pub entry: &'a Entry,
+    pub function: Option<String>,
+    pub excerpt: Option<Excerpt>,
+}
+
+/// The findings of a single contract, or the file-level findings of a source file if `contract` is not set.
+pub struct Section<'a> {
+    pub path: PathBuf,
+    pub contract: Option<String>,
+    pub findings: Vec<Finding<'a>>,
+}
+
+impl Section<'_> {
+    pub fn title(&self) -> String {
+        match self.contract.as_ref() {
+            Some(contract) => format!("{contract} ({})", self.path.to_string_lossy()),
+            None => self.path.to_string_lossy().to_string(),
+        }
+    }
+}
+
+pub struct RuleSummary<'a> {
+    pub rule: &'a str,
+    pub severity: Severity,
+    pub count: usize,
+    pub visitor_type: Option<&'a VisitorType>,
+}
+
+/// A report organized for human readers, which is rendered as Markdown or HTML.
+pub struct AuditReport<'a> {
+    pub severity_counts: Vec<(Severity, usize)>,
+    pub rules: Vec<RuleSummary<'a>>,
+    pub sections: Vec<Section<'a>>,
+    pub fixed_entries: Vec<(&'a PathBuf, &'a Entry)>,
+}
+
+impl<'a> AuditReport<'a> {
+    pub fn new(report: &'a Report, source_units: &[SourceUnit], visitor_types: &[&'a VisitorType]) -> Self {
+        let mut sections: Vec<Section> = vec![];
+        let mut rules: Vec<RuleSummary> = vec![];
+
+        for (path, entries) in report.sorted_entries() {
+            let source_unit = find_source_unit(source_units, path);
+            let lines: Vec<&str> = source_unit
+                .and_then(|source_unit| source_unit.source())
+                .map(|source| source.lines().collect())
+                .unwrap_or_default();
+
+            for entry in entries.iter() {
+                let (contract, function) = source_unit
+                    .map(|source_unit| containing_definitions(entry, source_unit))
+                    .unwrap_or_default();
+
+                let finding = Finding {
+                    entry,
+                    function,
+                    excerpt: excerpt(entry, lines.as_slice()),
+                };
+
+                match sections.iter_mut().find(|section| section.path == *path && section.contract == contract) {
+                    Some(section) => section.findings.push(finding),
+
+                    None => sections.push(Section {
+                        path: path.clone(),
+                        contract,
+                        findings: vec![finding],
+                    }),
+                }
+
+                match rules.iter_mut().find(|rule| rule.rule == entry.rule) {
+                    Some(rule) => rule.count += 1,
+
+                    None => rules.push(RuleSummary {
+                        rule: entry.rule.as_str(),
+                        severity: entry.severity,
+                        count: 1,
+                        visitor_type: visitor_types.iter().find(|visitor_type| visitor_type.name == entry.rule).copied(),
+                    }),
+                }
+            }
+        }
+
+        //
+        // File-level findings are listed before the contracts of each file
+        //
+
+        sections.sort_by(|lhs, rhs| (&lhs.path, lhs.contract.is_some()).cmp(&(&rhs.path, rhs.contract.is_some())));
+
+        rules.sort_by(|lhs, rhs| rhs.severity.cmp(&lhs.severity).then_with(|| lhs.rule.cmp(rhs.rule)));
+
+        let severity_counts = [Severity::High, Severity::Medium, Severity::Low, Severity::Info]
+            .into_iter()
+            .map(|severity| (severity, report.entries.values().flatten().filter(|entry| entry.severity == severity).count()))
+            .collect();
+
+        let fixed_entries = report.sorted_fixed_entries()
+            .into_iter()
+            .flat_map(|(path, entries)| entries.iter().map(move |entry| (path, entry)))
+            .collect();
+
+        Self {
+            severity_counts,
+            rules,
+            sections,
+            fixed_entries,
+        }
+    }
+
+    pub fn total_count(&self) -> usize {
+        self.severity_counts.iter().map(|(_, count)| count).sum()
+    }
+
+    /// The visitor types of all rules with findings, which are explained in the report's appendix.
+    pub fn explained_rules(&self) -> Vec<&'a VisitorType> {
+        self.rules.iter().filter_map(|rule| rule.visitor_type).collect()
+    }
+
+    pub fn is_explained(&self, rule: &str) -> bool {
+        self.rules.iter().any(|summary| summary.rule == rule && summary.visitor_type.is_some())
+    }
+}
+
+/// The anchor used to link to the explanation of a rule.
+pub fn rule_anchor(rule: &str) -> String {
+    format!("rule-{}", rule.replace('_', "-"))
+}
+
+fn excerpt(entry: &Entry, lines: &[&str]) -> Option<Excerpt> {
+    let range = entry.range?;
+
+    if range.start_line == 0 || range.start_line > lines.len() {
+        return None;
+    }
+
+    let end_line = range.end_line.clamp(range.start_line, lines.len()).min(range.start_line + MAX_EXCERPT_LINES - 1);
+
+    Some(Excerpt {
+        start_line: range.start_line,
+        lines: lines[range.start_line - 1..end_line].iter().map(|line| line.to_string()).collect(),
+    })
+}
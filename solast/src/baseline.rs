@@ -0,0 +1,332 @@
+use crate::report::{Entry, Report};
+use serde::{Deserialize, Serialize};
+use solidity::ast::{SourceRange, SourceUnit};
+use std::{collections::HashMap, fs::File, io, path::{Path, PathBuf}};
+
+pub const BASELINE_VERSION: u32 = 1;
+
+/// A fingerprint which identifies a finding independently of its line number.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Fingerprint {
+    pub hash: String,
+    pub contract: Option<String>,
+    pub function: Option<String>,
+    pub snippet: String,
+}
+
+impl Fingerprint {
+    pub fn new(entry: &Entry, source_unit: Option<&SourceUnit>) -> Self {
+        let (contract, function) = source_unit
+            .map(|source_unit| containing_definitions(entry, source_unit))
+            .unwrap_or_default();
+
+        let snippet = match (source_unit.and_then(|source_unit| source_unit.source()), entry.range) {
+            (Some(source), Some(range)) => Some(source_snippet(source, &range)),
+            _ => None,
+        };
+
+        //
+        // Entries without a location are identified by their text instead
+        //
+
+        let snippet = normalize(snippet.as_deref().unwrap_or(entry.text.as_str()));
+
+        let hash = fnv1a([
+            entry.rule.as_str(),
+            contract.as_deref().unwrap_or(""),
+            function.as_deref().unwrap_or(""),
+            snippet.as_str(),
+        ]);
+
+        Self {
+            hash: format!("{hash:016x}"),
+            contract,
+            function,
+            snippet,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BaselineEntry {
+    pub path: PathBuf,
+    pub fingerprint: Fingerprint,
+    #[serde(flatten)]
+    pub entry: Entry,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn new(report: &Report, source_units: &[SourceUnit]) -> Self {
+        let mut paths: Vec<_> = report.entries.keys().collect();
+        paths.sort();
+
+        let mut entries = vec![];
+
+        for path in paths {
+            let source_unit = find_source_unit(source_units, path);
+
+            for entry in report.entries[path].iter() {
+                entries.push(BaselineEntry {
+                    path: path.clone(),
+                    fingerprint: Fingerprint::new(entry, source_unit),
+                    entry: entry.clone(),
+                });
+            }
+        }
+
+        Self {
+            version: BASELINE_VERSION,
+            entries,
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+
+        let baseline: Baseline = simd_json::from_reader(File::open(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid baseline file {}: {e}", path.to_string_lossy())))?;
+
+        if baseline.version != BASELINE_VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Unsupported baseline version: {}", baseline.version)));
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, json + "\n")
+    }
+
+    /// Removes all entries which are present in the baseline from the report.
+    /// Baseline entries which are no longer reported are moved into the report's fixed entries.
+    pub fn apply(&self, report: &mut Report, source_units: &[SourceUnit]) {
+        //
+        // Identical findings can occur multiple times, so the number of occurrences of each fingerprint is tracked
+        //
+
+        let mut remaining: HashMap<&str, usize> = HashMap::new();
+
+        for baseline_entry in self.entries.iter() {
+            *remaining.entry(baseline_entry.fingerprint.hash.as_str()).or_default() += 1;
+        }
+
+        for (path, entries) in report.entries.iter_mut() {
+            let source_unit = find_source_unit(source_units, path);
+
+            entries.retain(|entry| {
+                let fingerprint = Fingerprint::new(entry, source_unit);
+
+                match remaining.get_mut(fingerprint.hash.as_str()) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+
+                    _ => true,
+                }
+            });
+        }
+
+        report.entries.retain(|_, entries| !entries.is_empty());
+
+        for baseline_entry in self.entries.iter() {
+            if let Some(count) = remaining.get_mut(baseline_entry.fingerprint.hash.as_str()) {
+                if *count > 0 {
+                    *count -= 1;
+
+                    report.fixed_entries
+                        .entry(baseline_entry.path.clone())
+                        .or_default()
+                        .push(baseline_entry.entry.clone());
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn find_source_unit<'a>(source_units: &'a [SourceUnit], path: &Path) -> Option<&'a SourceUnit> {
+    source_units.iter().find(|source_unit| source_unit.absolute_path.as_deref().map(Path::new) == Some(path))
+}
+
+/// Finds the names of the innermost contract and function (or modifier) definitions containing the start of the entry's range.
+pub(crate) fn containing_definitions(entry: &Entry, source_unit: &SourceUnit) -> (Option<String>, Option<String>) {
+    let mut contract = None;
+    let mut function = None;
+
+    let range = match entry.range {
+        Some(range) => range,
+        None => return (contract, function),
+    };
+
+    let contains = |src: &str| {
+        source_unit.source_range(src)
+            .map(|definition_range| contains_position(&definition_range, &range))
+            .unwrap_or(false)
+    };
+
+    if let Some(contract_definition) = source_unit.contract_definitions().into_iter().find(|c| contains(c.src.as_str())) {
+        contract = Some(contract_definition.name.clone());
+
+        if let Some(function_definition) = contract_definition.function_definitions().into_iter().find(|f| {
+            contains(f.src.as_str()) || f.body.as_ref().map(|body| contains(body.src.as_str())).unwrap_or(false)
+        }) {
+            function = Some(if function_definition.name.is_empty() {
+                function_definition.kind.to_string()
+            } else {
+                function_definition.name.clone()
+            });
+        } else if let Some(modifier_definition) = contract_definition.modifier_definitions().into_iter().find(|m| {
+            contains(m.src.as_str()) || contains(m.body.src.as_str())
+        }) {
+            function = Some(modifier_definition.name.clone());
+        }
+    }
+
+    (contract, function)
+}
+
+fn contains_position(outer: &SourceRange, inner: &SourceRange) -> bool {
+    (outer.start_line, outer.start_column) <= (inner.start_line, inner.start_column)
+        && (inner.start_line, inner.start_column) < (outer.end_line, outer.end_column)
+}
+
+/// Gets the source text covered by the supplied range.
+fn source_snippet(source: &str, range: &SourceRange) -> String {
+    let mut result = String::new();
+
+    for (line_index, line_text) in source.lines().enumerate().skip(range.start_line.saturating_sub(1)) {
+        let line = line_index + 1;
+
+        if line > range.end_line {
+            break;
+        }
+
+        let start_column = if line == range.start_line { range.start_column } else { 1 };
+        let end_column = if line == range.end_line { Some(range.end_column) } else { None };
+
+        result.extend(
+            line_text.chars()
+                .enumerate()
+                .filter(|&(i, _)| i + 1 >= start_column && end_column.map(|end_column| i + 1 < end_column).unwrap_or(true))
+                .map(|(_, c)| c)
+        );
+
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Collapses all whitespace so that formatting changes do not affect fingerprints.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A 64-bit FNV-1a hash, which is stable across platforms and compiler versions.
+fn fnv1a<'a, I: IntoIterator<Item = &'a str>>(parts: I) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::{parse_source_file, TestProject};
+    use solidity::ast::{AstBuilder, LineTable, SourceLocation};
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+contract Token {
+    mapping(address => uint256) balances;
+
+    function transfer(address to, uint256 amount) external {
+        balances[msg.sender] -= amount;
+        balances[to] += amount;
+    }
+}
+"#;
+
+    /// Creates an entry for the first occurrence of the supplied text in the source.
+    fn entry(rule: &str, source: &str, text: &str) -> Entry {
+        let start = source.find(text).unwrap();
+        let range = LineTable::new(source).range(source, SourceLocation { start, length: text.len(), file_index: None });
+
+        Entry {
+            rule: rule.to_string(),
+            line: Some(range.start_line),
+            range: Some(range),
+            text: format!("Finding for `{text}`"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let project = TestProject::new("fingerprint", &[("Token.sol", SOURCE)]);
+        let source_unit = parse_source_file(&mut AstBuilder::default(), &project.path.join("Token.sol"), 0).unwrap();
+        let fingerprint = Fingerprint::new(&entry("rule", SOURCE, "balances[to] += amount;"), Some(&source_unit));
+
+        assert_eq!(fingerprint.contract.as_deref(), Some("Token"));
+        assert_eq!(fingerprint.function.as_deref(), Some("transfer"));
+        assert_eq!(fingerprint.snippet, "balances[to] += amount;");
+
+        // Entries without a location are identified by their text
+        let fingerprint = Fingerprint::new(&Entry { rule: "rule".to_string(), text: "Some  finding".to_string(), ..Default::default() }, None);
+        assert_eq!((fingerprint.contract, fingerprint.function, fingerprint.snippet.as_str()), (None, None, "Some finding"));
+    }
+
+    #[test]
+    fn test_apply_baseline() {
+        let project = TestProject::new("baseline", &[("Token.sol", SOURCE)]);
+        let path = project.path.join("Token.sol");
+        let source_units = vec![parse_source_file(&mut AstBuilder::default(), &path, 0).unwrap()];
+
+        let report = Report {
+            entries: HashMap::from([(path.clone(), vec![
+                entry("unchecked_arithmetic", SOURCE, "balances[to] += amount;"),
+                entry("unchecked_arithmetic", SOURCE, "balances[msg.sender] -= amount;"),
+            ])]),
+            ..Default::default()
+        };
+
+        let baseline = Baseline::new(&report, source_units.as_slice());
+        assert_eq!(baseline.entries.len(), 2);
+
+        // Moving code does not change the fingerprint of its findings
+        let source = SOURCE.replace("balances[msg.sender] -= amount;\n", "").replace("contract Token {", "// Moved\ncontract Token {");
+        std::fs::write(&path, &source).unwrap();
+        let source_units = vec![parse_source_file(&mut AstBuilder::default(), &path, 0).unwrap()];
+
+        let mut report = Report {
+            entries: HashMap::from([(path.clone(), vec![
+                entry("unchecked_arithmetic", source.as_str(), "balances[to] += amount;"),
+                entry("unused_parameter", source.as_str(), "address to"),
+            ])]),
+            ..Default::default()
+        };
+
+        baseline.apply(&mut report, source_units.as_slice());
+
+        let rules: Vec<_> = report.entries[&path].iter().map(|entry| entry.rule.as_str()).collect();
+        assert_eq!(rules, vec!["unused_parameter"]);
+
+        // The finding which is no longer reported has been fixed
+        let fixed_texts: Vec<_> = report.fixed_entries[&path].iter().map(|entry| entry.text.as_str()).collect();
+        assert_eq!(fixed_texts, vec!["Finding for `balances[msg.sender] -= amount;`"]);
+    }
+}
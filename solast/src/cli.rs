@@ -0,0 +1,286 @@
+use clap::{builder::PossibleValuesParser, ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use solast::{analysis::VISITOR_TYPES, config::Config, errors::LoaderError, project::{foundry::Remapping, StandardJsonLoader}, report::Severity};
+use std::{io::{self, IsTerminal}, path::PathBuf};
+
+const EXIT_CODES_HELP: &str = "\
+Exit codes:
+   0  No entries were reported at or above the `--fail-on` severity
+   1  Entries were reported at or above the `--fail-on` severity
+   2  Invalid arguments or an internal error
+  10  The project directory does not exist
+  11  No supported project configuration was found
+  12  The project configuration is invalid
+  13  The project has not been compiled
+  14  A build artifact is malformed
+  15  A source file could not be found
+  16  A source file could not be parsed
+  17  Any other I/O error occurred while loading the project
+  18  A contract's inheritance hierarchy is invalid
+  19  A file imported by a source file could not be found";
+
+#[derive(Debug, Parser)]
+#[command(name = "solast", version, about = "Static analysis of Solidity smart contracts", after_help = EXIT_CODES_HELP)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Analyzes the supplied sources and prints a report of all findings
+    #[command(after_help = EXIT_CODES_HELP)]
+    Analyze(AnalyzeArgs),
+
+    /// Lists all available analyzers along with their severity, confidence and category
+    ListAnalyzers,
+
+    /// Explains what an analyzer reports and why, with example code
+    Explain {
+        /// The rule ID of the analyzer, i.e: `check_effects_interactions`
+        #[arg(value_parser = analyzer_names(), hide_possible_values = true)]
+        rule: String,
+    },
+
+    /// Generates a TODO list for each contract's contents in Markdown format
+    TodoList(SourceArgs),
+
+    /// Prints the call graph of all functions and modifiers in Graphviz DOT format
+    CallGraph(SourceArgs),
+
+    /// Prints the storage layout of each contract in the same JSON format as solc's `storageLayout` output
+    StorageLayout(SourceArgs),
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("sources").required(true).multiple(true).args(["project", "contract_paths", "standard_json"])))]
+pub struct SourceArgs {
+    /// A precompiled project directory to include in analysis
+    pub project: Option<PathBuf>,
+
+    /// A Solidity source file to include in analysis
+    #[arg(long = "contract-path", alias = "contract_path", value_name = "PATH")]
+    pub contract_paths: Vec<PathBuf>,
+
+    /// Remaps imports of Solidity source files, i.e: `@openzeppelin/=lib/openzeppelin-contracts/`. Remappings are also read from `remappings.txt`
+    #[arg(long = "remap", value_name = "[CONTEXT:]PREFIX=PATH", value_parser = parse_remapping)]
+    pub remappings: Vec<Remapping>,
+
+    /// A directory to search for the files imported by Solidity source files
+    #[arg(long = "include-path", alias = "include_path", value_name = "PATH")]
+    pub include_paths: Vec<PathBuf>,
+
+    /// A `solc --standard-json` input and output file pair to include in analysis
+    #[arg(long = "standard-json", alias = "standard_json", value_name = "INPUT:OUTPUT", value_parser = parse_standard_json)]
+    pub standard_json: Vec<StandardJsonLoader>,
+
+    /// Only includes source files containing the specified contract
+    #[arg(long = "contract", value_name = "NAME")]
+    pub contract_names: Vec<String>,
+
+    /// Includes library sources (i.e: Foundry `libs` and remapped dependencies)
+    #[arg(long, alias = "include_libs")]
+    pub include_libs: bool,
+
+    /// The configuration file to use instead of the project's `solast.toml`
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct AnalyzeArgs {
+    #[command(flatten)]
+    pub sources: SourceArgs,
+
+    /// The report output format
+    #[arg(long, alias = "output_format", value_name = "FORMAT")]
+    pub output_format: Option<OutputFormat>,
+
+    /// When to use ANSI colors in plain text output
+    #[arg(long, value_name = "WHEN", default_value = "auto")]
+    pub color: ColorChoice,
+
+    /// Only reports entries with at least the specified severity
+    #[arg(long, alias = "min_severity", value_name = "SEVERITY", value_parser = parse_severity)]
+    pub min_severity: Option<Severity>,
+
+    /// Exits with a failure code if any reported entries have at least the specified severity [default: low]
+    #[arg(long, alias = "fail_on", value_name = "SEVERITY", value_parser = parse_severity)]
+    pub fail_on: Option<Severity>,
+
+    /// Enables the specified analyzer (see `solast list-analyzers`). All analyzers are enabled by default
+    #[arg(long = "analyzer", value_name = "ANALYZER", value_parser = analyzer_names(), hide_possible_values = true)]
+    pub analyzers: Vec<String>,
+
+    /// Disables the specified analyzer
+    #[arg(long = "exclude", value_name = "ANALYZER", value_parser = analyzer_names(), hide_possible_values = true)]
+    pub excluded_analyzers: Vec<String>,
+
+    /// Reports suppression comments which do not suppress any findings
+    #[arg(long, alias = "report_unused_suppressions")]
+    pub report_unused_suppressions: bool,
+
+    /// Only reports findings which are not in the specified baseline file
+    #[arg(long, value_name = "PATH")]
+    pub baseline: Option<PathBuf>,
+
+    /// Writes all current findings to a baseline file
+    #[arg(long, alias = "write_baseline", value_name = "PATH")]
+    pub write_baseline: Option<PathBuf>,
+}
+
+impl AnalyzeArgs {
+    /// The severity at which reported entries fail the analysis, which falls back to the configuration file and then `low`.
+    pub fn fail_on(&self, config: &Config) -> Severity {
+        self.fail_on.or(config.fail_on).unwrap_or(Severity::Low)
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum OutputFormat {
+    #[value(name = "plain-text", alias = "plain_text", alias = "plaintext")]
+    PlainText,
+    Json,
+    Sarif,
+    Markdown,
+    Html,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ColorChoice {
+    /// Colors are used when printing to a terminal and `NO_COLOR` is not set
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn enabled(&self) -> bool {
+        match self {
+            ColorChoice::Auto => io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+impl TryFrom<&str> for OutputFormat {
+    type Error = io::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        <Self as ValueEnum>::from_str(value, true)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid output format: {value}")))
+    }
+}
+
+fn analyzer_names() -> PossibleValuesParser {
+    PossibleValuesParser::new(VISITOR_TYPES.iter().map(|visitor_type| visitor_type.name))
+}
+
+fn parse_severity(value: &str) -> io::Result<Severity> {
+    Severity::try_from(value)
+}
+
+fn parse_remapping(value: &str) -> io::Result<Remapping> {
+    Remapping::try_from(value)
+}
+
+fn parse_standard_json(value: &str) -> Result<StandardJsonLoader, LoaderError> {
+    StandardJsonLoader::try_from(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::error::ErrorKind;
+
+    fn analyze_args(args: &[&str]) -> AnalyzeArgs {
+        match Cli::try_parse_from([&["solast", "analyze"], args].concat()).unwrap().command {
+            Command::Analyze(args) => args,
+            command => panic!("Expected the analyze command, found {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_subcommands() {
+        let args = analyze_args(&["project", "--contract-path", "A.sol", "--output-format", "sarif", "--exclude", "no_spdx_identifier"]);
+        assert_eq!(args.sources.project, Some(PathBuf::from("project")));
+        assert_eq!(args.sources.contract_paths, vec![PathBuf::from("A.sol")]);
+        assert!(matches!(args.output_format, Some(OutputFormat::Sarif)));
+        assert!(matches!(args.color, ColorChoice::Auto));
+        assert_eq!(args.excluded_analyzers, vec!["no_spdx_identifier".to_string()]);
+
+        assert!(matches!(Cli::try_parse_from(["solast", "list-analyzers"]).unwrap().command, Command::ListAnalyzers));
+
+        match Cli::try_parse_from(["solast", "explain", "no_spdx_identifier"]).unwrap().command {
+            Command::Explain { rule } => assert_eq!(rule, "no_spdx_identifier"),
+            command => panic!("Expected the explain command, found {command:?}"),
+        }
+
+        match Cli::try_parse_from(["solast", "todo-list", "--contract-path", "A.sol", "--contract", "A"]).unwrap().command {
+            Command::TodoList(args) => {
+                assert_eq!(args.project, None);
+                assert_eq!(args.contract_names, vec!["A".to_string()]);
+            }
+            command => panic!("Expected the todo-list command, found {command:?}"),
+        }
+
+        match Cli::try_parse_from(["solast", "call-graph", "project", "--include-libs"]).unwrap().command {
+            Command::CallGraph(args) => assert!(args.include_libs),
+            command => panic!("Expected the call-graph command, found {command:?}"),
+        }
+
+        match Cli::try_parse_from(["solast", "storage-layout", "project", "--config", "custom.toml"]).unwrap().command {
+            Command::StorageLayout(args) => assert_eq!(args.config, Some(PathBuf::from("custom.toml"))),
+            command => panic!("Expected the storage-layout command, found {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_fail_on() {
+        // Entries of any severity other than info fail the analysis by default
+        let args = analyze_args(&["project"]);
+        assert_eq!(args.fail_on, None);
+        assert_eq!(args.fail_on(&Config::default()), Severity::Low);
+
+        // The configuration file takes effect unless overridden on the command line
+        let config = Config { fail_on: Some(Severity::Medium), ..Default::default() };
+        assert_eq!(args.fail_on(&config), Severity::Medium);
+
+        let args = analyze_args(&["project", "--fail-on", "HIGH"]);
+        assert_eq!(args.fail_on, Some(Severity::High));
+        assert_eq!(args.fail_on(&config), Severity::High);
+
+        assert_eq!(analyze_args(&["project", "--fail_on", "informational"]).fail_on, Some(Severity::Info));
+
+        let error = Cli::try_parse_from(["solast", "analyze", "project", "--fail-on", "critical"]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_rejected_arguments() {
+        let rejected = [
+            // At least one source is required
+            (vec!["solast", "analyze"], ErrorKind::MissingRequiredArgument),
+            (vec!["solast", "todo-list", "--include-libs"], ErrorKind::MissingRequiredArgument),
+
+            // Analysis options are only accepted by the analyze command
+            (vec!["solast", "todo-list", "project", "--fail-on", "high"], ErrorKind::UnknownArgument),
+            (vec!["solast", "storage-layout", "project", "--output-format", "json"], ErrorKind::UnknownArgument),
+            (vec!["solast", "list-analyzers", "--contract-path", "A.sol"], ErrorKind::UnknownArgument),
+
+            // Only a single project can be supplied positionally
+            (vec!["solast", "analyze", "project", "other"], ErrorKind::UnknownArgument),
+
+            (vec!["solast", "analyze", "project", "--analyzer", "missing"], ErrorKind::InvalidValue),
+            (vec!["solast", "analyze", "project", "--color", "sometimes"], ErrorKind::InvalidValue),
+            (vec!["solast", "analyze", "project", "--standard-json", "input.json"], ErrorKind::ValueValidation),
+            (vec!["solast", "analyze", "project", "--remap", "missing-path"], ErrorKind::ValueValidation),
+            (vec!["solast", "explain", "missing"], ErrorKind::InvalidValue),
+        ];
+
+        for (args, kind) in rejected {
+            let error = Cli::try_parse_from(args.iter()).unwrap_err();
+            assert_eq!(error.kind(), kind, "{args:?}");
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{analysis::{VisitorType, VISITOR_TYPES}, errors::LoaderError, report::Severity};
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::{Path, PathBuf}};
+
+pub const CONFIG_FILE_NAME: &str = "solast.toml";
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AnalyzersConfig {
+    /// The analyzers to enable. All analyzers are enabled if unset.
+    pub enable: Option<Vec<String>>,
+    pub disable: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PathsConfig {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    pub analyzers: AnalyzersConfig,
+    pub severity: HashMap<String, Severity>,
+    pub paths: PathsConfig,
+    pub contracts: Vec<String>,
+    pub output_format: Option<String>,
+    pub min_severity: Option<Severity>,
+    pub fail_on: Option<Severity>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    pub path: Option<PathBuf>,
+    pub enabled_analyzers: Option<Vec<String>>,
+    pub disabled_analyzers: Vec<String>,
+    pub severity: HashMap<String, Severity>,
+    pub include: Vec<Pattern>,
+    pub exclude: Vec<Pattern>,
+    pub contracts: Vec<String>,
+    pub output_format: Option<String>,
+    pub min_severity: Option<Severity>,
+    pub fail_on: Option<Severity>,
+}
+
+impl Config {
+    /// Loads `solast.toml` from the supplied directory, or returns the default configuration if the file does not exist.
+    pub fn discover(directory: &Path) -> Result<Self, LoaderError> {
+        let config_path = directory.join(CONFIG_FILE_NAME);
+
+        if config_path.is_file() {
+            Self::load(config_path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn load<P: Into<PathBuf>>(config_path: P) -> Result<Self, LoaderError> {
+        let config_path = config_path.into();
+
+        let invalid_config = |message: String| LoaderError::InvalidConfig { path: config_path.clone(), message };
+
+        let config_file: ConfigFile = toml::from_str(std::fs::read_to_string(&config_path).map_err(LoaderError::io(&config_path))?.as_str())
+            .map_err(|e| invalid_config(e.to_string()))?;
+
+        //
+        // Make sure all of the referenced analyzers exist
+        //
+
+        let analyzer_names = config_file.analyzers.enable.iter().flatten()
+            .chain(config_file.analyzers.disable.iter())
+            .chain(config_file.severity.keys());
+
+        for analyzer_name in analyzer_names {
+            if !VISITOR_TYPES.iter().any(|visitor_type| visitor_type.name == analyzer_name) {
+                return Err(invalid_config(format!("unknown analyzer: {analyzer_name}")));
+            }
+        }
+
+        let parse_patterns = |patterns: &[String]| -> Result<Vec<Pattern>, LoaderError> {
+            patterns.iter()
+                .map(|pattern| Pattern::new(pattern).map_err(|e| invalid_config(format!("invalid path pattern \"{pattern}\": {e}"))))
+                .collect()
+        };
+
+        Ok(Self {
+            include: parse_patterns(config_file.paths.include.as_slice())?,
+            exclude: parse_patterns(config_file.paths.exclude.as_slice())?,
+            path: Some(config_path),
+            enabled_analyzers: config_file.analyzers.enable,
+            disabled_analyzers: config_file.analyzers.disable,
+            severity: config_file.severity,
+            contracts: config_file.contracts,
+            output_format: config_file.output_format,
+            min_severity: config_file.min_severity,
+            fail_on: config_file.fail_on,
+        })
+    }
+
+    /// Determines if the supplied analyzer is enabled by the configuration.
+    pub fn is_analyzer_enabled(&self, name: &str) -> bool {
+        if let Some(enabled_analyzers) = self.enabled_analyzers.as_ref() {
+            if !enabled_analyzers.iter().any(|enabled_name| enabled_name == name) {
+                return false;
+            }
+        }
+
+        !self.disabled_analyzers.iter().any(|disabled_name| disabled_name == name)
+    }
+
+    /// The severity reported for the supplied analyzer, which may be overridden by the `[severity]` table.
+    pub fn rule_severity(&self, visitor_type: &VisitorType) -> Severity {
+        self.severity.get(visitor_type.name).copied().unwrap_or(visitor_type.severity)
+    }
+
+    /// Determines if a source file should be analyzed according to the configured include and exclude patterns.
+    pub fn is_path_included(&self, path: &str) -> bool {
+        let path = path.trim_start_matches("./");
+
+        (self.include.is_empty() || self.include.iter().any(|pattern| pattern.matches(path)))
+            && !self.exclude.iter().any(|pattern| pattern.matches(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::TestProject;
+
+    #[test]
+    fn test_load_config() {
+        let project = TestProject::new("config", &[(CONFIG_FILE_NAME, r#"
+contracts = ["Token"]
+min_severity = "low"
+
+[analyzers]
+disable = ["floating_solidity_version"]
+
+[severity]
+no_spdx_identifier = "high"
+
+[paths]
+include = ["contracts/**/*.sol"]
+exclude = ["contracts/mocks/**"]
+"#)]);
+
+        let config = Config::discover(&project.path).unwrap();
+
+        assert!(config.path.is_some());
+        assert_eq!(config.contracts, vec!["Token"]);
+        assert_eq!(config.min_severity, Some(Severity::Low));
+        assert_eq!(config.severity.get("no_spdx_identifier"), Some(&Severity::High));
+
+        assert!(config.is_analyzer_enabled("no_spdx_identifier"));
+        assert!(!config.is_analyzer_enabled("floating_solidity_version"));
+
+        assert!(config.is_path_included("./contracts/Token.sol"));
+        assert!(config.is_path_included("contracts/token/ERC20.sol"));
+        assert!(!config.is_path_included("contracts/mocks/MockToken.sol"));
+        assert!(!config.is_path_included("test/Token.t.sol"));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let project = TestProject::new("unknown-analyzer", &[(CONFIG_FILE_NAME, "[analyzers]\ndisable = [\"no_such_analyzer\"]\n")]);
+        let error = Config::discover(&project.path).unwrap_err();
+        assert!(error.to_string().contains("unknown analyzer: no_such_analyzer"));
+
+        let project = TestProject::new("unknown-field", &[(CONFIG_FILE_NAME, "unknown = true\n")]);
+        assert!(matches!(Config::discover(&project.path), Err(LoaderError::InvalidConfig { .. })));
+
+        let config = Config::discover(&std::env::temp_dir().join("solast-missing-config")).unwrap();
+        assert!(config.path.is_none());
+        assert!(config.is_analyzer_enabled("no_spdx_identifier"));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::project::ProjectKind;
+use solidity::ast::InheritanceError;
+use std::{error::Error, fmt::Display, io, path::PathBuf};
+
+/// The process exit code when no findings at or above the failure threshold were reported.
+pub const EXIT_SUCCESS: i32 = 0;
+
+/// The process exit code when findings at or above the failure threshold were reported.
+pub const EXIT_FINDINGS: i32 = 1;
+
+/// The process exit code for invalid arguments, internal errors and I/O errors which are not reported by a project loader.
+pub const EXIT_INTERNAL_ERROR: i32 = 2;
+
+#[derive(Debug)]
+pub enum LoaderError {
+    ProjectNotFound {
+        path: PathBuf,
+    },
+    UnknownProjectType {
+        path: PathBuf,
+    },
+    InvalidConfig {
+        path: PathBuf,
+        message: String,
+    },
+    BuildDirectoryNotFound {
+        kind: ProjectKind,
+        path: PathBuf,
+    },
+    MalformedArtifact {
+        kind: ProjectKind,
+        path: PathBuf,
+        message: String,
+    },
+    SourceFileNotFound {
+        kind: Option<ProjectKind>,
+        path: PathBuf,
+    },
+    ImportNotFound {
+        path: PathBuf,
+        import: String,
+    },
+    SourceParseFailed {
+        path: PathBuf,
+        errors: Vec<String>,
+    },
+    InvalidInheritance {
+        error: InheritanceError,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl LoaderError {
+    /// The process exit code reported for the error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            LoaderError::ProjectNotFound { .. } => 10,
+            LoaderError::UnknownProjectType { .. } => 11,
+            LoaderError::InvalidConfig { .. } => 12,
+            LoaderError::BuildDirectoryNotFound { .. } => 13,
+            LoaderError::MalformedArtifact { .. } => 14,
+            LoaderError::SourceFileNotFound { .. } => 15,
+            LoaderError::SourceParseFailed { .. } => 16,
+            LoaderError::Io { .. } => 17,
+            LoaderError::InvalidInheritance { .. } => 18,
+            LoaderError::ImportNotFound { .. } => 19,
+        }
+    }
+
+    pub fn io<P: Into<PathBuf>>(path: P) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |error| LoaderError::Io { path, error }
+    }
+}
+
+/// The process exit code reported for an error, which is `EXIT_INTERNAL_ERROR` unless it was produced by a project loader.
+pub fn error_exit_code(error: &io::Error) -> i32 {
+    error.get_ref()
+        .and_then(|error| error.downcast_ref::<LoaderError>())
+        .map(LoaderError::exit_code)
+        .unwrap_or(EXIT_INTERNAL_ERROR)
+}
+
+impl Display for LoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoaderError::ProjectNotFound { path } => write!(
+                f,
+                "Project directory not found: {}",
+                path.to_string_lossy(),
+            ),
+
+            LoaderError::UnknownProjectType { path } => write!(
+                f,
+                "No supported project configuration found in {}; expected one of `brownie-config.yaml`, `hardhat.config.js`, `hardhat.config.ts`, `truffle-config.js` or `foundry.toml`. Use `--contract_path=<Contract.sol>` to analyze individual source files instead",
+                path.to_string_lossy(),
+            ),
+
+            LoaderError::InvalidConfig { path, message } => write!(
+                f,
+                "Invalid project configuration in {}: {}",
+                path.to_string_lossy(),
+                message,
+            ),
+
+            LoaderError::BuildDirectoryNotFound { kind, path } => write!(
+                f,
+                "{} project has not been compiled: build directory {} not found. Run `{}` and try again",
+                kind,
+                path.to_string_lossy(),
+                kind.build_command(),
+            ),
+
+            LoaderError::MalformedArtifact { kind, path, message } => write!(
+                f,
+                "Malformed {} artifact {}: {}. Run `{}` to regenerate the project's artifacts and try again",
+                kind,
+                path.to_string_lossy(),
+                message,
+                kind.build_command(),
+            ),
+
+            LoaderError::SourceFileNotFound { kind: Some(kind), path } => write!(
+                f,
+                "Source file not found: {}. The {} project's artifacts may be out of date; run `{}` and try again",
+                path.to_string_lossy(),
+                kind,
+                kind.build_command(),
+            ),
+
+            LoaderError::SourceFileNotFound { kind: None, path } => write!(
+                f,
+                "Source file not found: {}",
+                path.to_string_lossy(),
+            ),
+
+            LoaderError::ImportNotFound { path, import } => write!(
+                f,
+                "Source file not found: \"{}\" imported by {}. Use `--remap <PREFIX=PATH>` or `--include-path <PATH>` to locate it",
+                import,
+                path.to_string_lossy(),
+            ),
+
+            LoaderError::SourceParseFailed { path, errors } if errors.is_empty() => write!(
+                f,
+                "Failed to parse contract {}",
+                path.to_string_lossy(),
+            ),
+
+            LoaderError::SourceParseFailed { path, errors } => write!(
+                f,
+                "Failed to parse contract {}: {}",
+                path.to_string_lossy(),
+                errors.join("; "),
+            ),
+
+            LoaderError::InvalidInheritance { error } => write!(
+                f,
+                "Invalid inheritance hierarchy: {}",
+                error,
+            ),
+
+            LoaderError::Io { path, error } => write!(
+                f,
+                "Failed to read {}: {}",
+                path.to_string_lossy(),
+                error,
+            ),
+        }
+    }
+}
+
+impl Error for LoaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoaderError::Io { error, .. } => Some(error),
+            LoaderError::InvalidInheritance { error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<LoaderError> for io::Error {
+    fn from(error: LoaderError) -> Self {
+        let kind = match &error {
+            LoaderError::ProjectNotFound { .. }
+            | LoaderError::BuildDirectoryNotFound { .. }
+            | LoaderError::SourceFileNotFound { .. }
+            | LoaderError::ImportNotFound { .. } => io::ErrorKind::NotFound,
+
+            LoaderError::UnknownProjectType { .. } => io::ErrorKind::Unsupported,
+
+            LoaderError::InvalidConfig { .. }
+            | LoaderError::MalformedArtifact { .. }
+            | LoaderError::SourceParseFailed { .. }
+            | LoaderError::InvalidInheritance { .. } => io::ErrorKind::InvalidData,
+
+            LoaderError::Io { error, .. } => error.kind(),
+        };
+
+        io::Error::new(kind, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        let inheritance_error = InheritanceError::CyclicInheritance {
+            path: None,
+            contract_name: "A".to_string(),
+            src: "0:0:0".to_string(),
+        };
+
+        let errors = [
+            (LoaderError::ProjectNotFound { path: PathBuf::from("project") }, 10),
+            (LoaderError::UnknownProjectType { path: PathBuf::from("project") }, 11),
+            (LoaderError::InvalidConfig { path: PathBuf::from("solast.toml"), message: String::new() }, 12),
+            (LoaderError::BuildDirectoryNotFound { kind: ProjectKind::Foundry, path: PathBuf::from("out") }, 13),
+            (LoaderError::MalformedArtifact { kind: ProjectKind::Hardhat, path: PathBuf::from("A.json"), message: String::new() }, 14),
+            (LoaderError::SourceFileNotFound { kind: None, path: PathBuf::from("A.sol") }, 15),
+            (LoaderError::SourceParseFailed { path: PathBuf::from("A.sol"), errors: vec![] }, 16),
+            (LoaderError::Io { path: PathBuf::from("A.sol"), error: io::Error::from(io::ErrorKind::PermissionDenied) }, 17),
+            (LoaderError::InvalidInheritance { error: inheritance_error }, 18),
+            (LoaderError::ImportNotFound { path: PathBuf::from("A.sol"), import: "B.sol".to_string() }, 19),
+        ];
+
+        for (error, exit_code) in errors {
+            assert_eq!(error.exit_code(), exit_code, "{error:?}");
+
+            // Loader errors keep their exit code when propagated as I/O errors
+            assert_eq!(error_exit_code(&io::Error::from(error)), exit_code);
+        }
+
+        assert_eq!(error_exit_code(&io::Error::from(io::ErrorKind::NotFound)), EXIT_INTERNAL_ERROR);
+        assert_eq!(error_exit_code(&io::Error::new(io::ErrorKind::InvalidData, "invalid baseline")), EXIT_INTERNAL_ERROR);
+        assert_eq!((EXIT_SUCCESS, EXIT_FINDINGS, EXIT_INTERNAL_ERROR), (0, 1, 2));
+    }
+}
@@ -0,0 +1,266 @@
+use crate::audit_report::{rule_anchor, AuditReport, Finding};
+use std::fmt::Write;
+
+const STYLE: &str = "
+body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Helvetica, Arial, sans-serif; line-height: 1.5; max-width: 960px; margin: 2em auto; padding: 0 1em; color: #24292f; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border: 1px solid #d0d7de; padding: 4px 12px; text-align: left; }
+td.count { text-align: right; }
+pre { background: #f6f8fa; padding: 12px; overflow-x: auto; border-radius: 6px; }
+.line-number { color: #8c959f; user-select: none; display: inline-block; min-width: 3em; }
+.finding { border-left: 4px solid #d0d7de; padding-left: 1em; margin: 1.5em 0; }
+.finding.high { border-color: #cf222e; }
+.finding.medium { border-color: #bf8700; }
+.finding.low { border-color: #0969da; }
+.finding.info { border-color: #1a7f37; }
+.severity { font-weight: bold; text-transform: uppercase; }
+.meta { color: #57606a; }
+";
+
+/// Renders an audit report as a self-contained HTML document.
+pub fn render(audit_report: &AuditReport) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "<!DOCTYPE html>").unwrap();
+    writeln!(output, "<html lang=\"en\">").unwrap();
+    writeln!(output, "<head>").unwrap();
+    writeln!(output, "<meta charset=\"utf-8\">").unwrap();
+    writeln!(output, "<title>Audit Report</title>").unwrap();
+    writeln!(output, "<style>{STYLE}</style>").unwrap();
+    writeln!(output, "</head>").unwrap();
+    writeln!(output, "<body>").unwrap();
+    writeln!(output, "<h1>Audit Report</h1>").unwrap();
+
+    //
+    // Summary tables
+    //
+
+    writeln!(output, "<h2>Summary</h2>").unwrap();
+    writeln!(output, "<table>").unwrap();
+    writeln!(output, "<tr><th>Severity</th><th>Findings</th></tr>").unwrap();
+
+    for (severity, count) in audit_report.severity_counts.iter() {
+        writeln!(output, "<tr><td>{severity}</td><td class=\"count\">{count}</td></tr>").unwrap();
+    }
+
+    writeln!(output, "<tr><th>Total</th><th class=\"count\">{}</th></tr>", audit_report.total_count()).unwrap();
+    writeln!(output, "</table>").unwrap();
+
+    if !audit_report.rules.is_empty() {
+        writeln!(output, "<table>").unwrap();
+        writeln!(output, "<tr><th>Rule</th><th>Severity</th><th>Findings</th></tr>").unwrap();
+
+        for rule in audit_report.rules.iter() {
+            writeln!(
+                output,
+                "<tr><td>{}</td><td>{}</td><td class=\"count\">{}</td></tr>",
+                rule_link(audit_report, rule.rule),
+                rule.severity,
+                rule.count,
+            ).unwrap();
+        }
+
+        writeln!(output, "</table>").unwrap();
+    }
+
+    //
+    // Findings, grouped by contract
+    //
+
+    if !audit_report.sections.is_empty() {
+        writeln!(output, "<h2>Findings</h2>").unwrap();
+    }
+
+    let mut finding_number = 0;
+
+    for section in audit_report.sections.iter() {
+        writeln!(output, "<h3>{}</h3>", escape(section.title().as_str())).unwrap();
+
+        for finding in section.findings.iter() {
+            finding_number += 1;
+            render_finding(&mut output, audit_report, section.path.to_string_lossy().as_ref(), finding_number, finding);
+        }
+    }
+
+    if !audit_report.fixed_entries.is_empty() {
+        writeln!(output, "<h2>Fixed Since Baseline</h2>").unwrap();
+        writeln!(output, "<ul>").unwrap();
+
+        for (path, entry) in audit_report.fixed_entries.iter() {
+            let location = match entry.line {
+                Some(line) => format!("{}:{line}", path.to_string_lossy()),
+                None => path.to_string_lossy().to_string(),
+            };
+
+            writeln!(
+                output,
+                "<li><span class=\"severity\">{}</span> {}: {} (<code>{}</code>)</li>",
+                entry.severity,
+                rule_link(audit_report, entry.rule.as_str()),
+                inline_code(entry.text.as_str()),
+                escape(location.as_str()),
+            ).unwrap();
+        }
+
+        writeln!(output, "</ul>").unwrap();
+    }
+
+    //
+    // Rule explanations, which are linked to from the findings
+    //
+
+    let explained_rules = audit_report.explained_rules();
+
+    if !explained_rules.is_empty() {
+        writeln!(output, "<h2>Rules</h2>").unwrap();
+
+        for visitor_type in explained_rules {
+            writeln!(output, "<h3 id=\"{}\"><code>{}</code></h3>", rule_anchor(visitor_type.name), visitor_type.name).unwrap();
+            writeln!(
+                output,
+                "<p class=\"meta\">Severity: {} | Confidence: {} | Category: {}</p>",
+                visitor_type.severity,
+                visitor_type.confidence,
+                visitor_type.category,
+            ).unwrap();
+            writeln!(output, "<p>{}</p>", inline_code(visitor_type.description)).unwrap();
+            writeln!(output, "<p>{}</p>", inline_code(visitor_type.rationale)).unwrap();
+            writeln!(output, "<pre><code>{}</code></pre>", escape(visitor_type.example.trim_matches('\n'))).unwrap();
+        }
+    }
+
+    writeln!(output, "</body>").unwrap();
+    writeln!(output, "</html>").unwrap();
+
+    output
+}
+
+fn render_finding(output: &mut String, audit_report: &AuditReport, path: &str, number: usize, finding: &Finding) {
+    let entry = finding.entry;
+
+    writeln!(output, "<div class=\"finding {}\">", entry.severity).unwrap();
+    writeln!(output, "<h4>{number}. {}</h4>", inline_code(entry.text.as_str())).unwrap();
+    writeln!(
+        output,
+        "<p class=\"meta\">Rule: {} | Severity: <span class=\"severity\">{}</span> | Confidence: {} | Category: {}</p>",
+        rule_link(audit_report, entry.rule.as_str()),
+        entry.severity,
+        entry.confidence,
+        entry.category,
+    ).unwrap();
+
+    let location = match (entry.range, entry.line) {
+        (Some(range), _) => format!("{path}:{}:{}", range.start_line, range.start_column),
+        (None, Some(line)) => format!("{path}:{line}"),
+        (None, None) => path.to_string(),
+    };
+
+    match finding.function.as_ref() {
+        Some(function) => writeln!(output, "<p>Location: <code>{}</code> in <code>{}</code></p>", escape(location.as_str()), escape(function)).unwrap(),
+        None => writeln!(output, "<p>Location: <code>{}</code></p>", escape(location.as_str())).unwrap(),
+    }
+
+    if let Some(excerpt) = finding.excerpt.as_ref() {
+        write!(output, "<pre><code>").unwrap();
+
+        for (i, line) in excerpt.lines.iter().enumerate() {
+            if i > 0 {
+                writeln!(output).unwrap();
+            }
+
+            write!(output, "<span class=\"line-number\">{}</span>{}", excerpt.start_line + i, escape(line)).unwrap();
+        }
+
+        writeln!(output, "</code></pre>").unwrap();
+    }
+
+    writeln!(output, "</div>").unwrap();
+}
+
+fn rule_link(audit_report: &AuditReport, rule: &str) -> String {
+    if audit_report.is_explained(rule) {
+        format!("<a href=\"#{}\"><code>{}</code></a>", rule_anchor(rule), escape(rule))
+    } else {
+        format!("<code>{}</code>", escape(rule))
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Escapes the supplied text, converting Markdown-style `code` spans into `<code>` elements.
+fn inline_code(text: &str) -> String {
+    let mut result = String::new();
+
+    for (i, part) in text.split('`').enumerate() {
+        if i % 2 == 1 {
+            write!(result, "<code>{}</code>", escape(part)).unwrap();
+        } else {
+            result.push_str(escape(part).as_str());
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{Entry, Report, Severity};
+    use solidity::ast::{SourceRange, SourceUnit};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_escaping() {
+        let source = "contract C {\n    function f(uint a, uint b) public { if (a < b && b > 0) revert(\"'x'\"); }\n}\n";
+
+        let mut source_unit = SourceUnit::new(1);
+        source_unit.absolute_path = Some("/project/C&D.sol".to_string());
+        source_unit.set_source(Some(source.to_string()));
+
+        let mut report = Report::default();
+        report.entries.insert(
+            PathBuf::from("/project/C&D.sol"),
+            vec![Entry {
+                rule: "<rule>".to_string(),
+                severity: Severity::High,
+                range: Some(SourceRange { start_line: 2, start_column: 5, end_line: 2, end_column: 80 }),
+                text: "Comparison `a < b` in <script>alert(\"x\")</script> & more".to_string(),
+                ..Default::default()
+            }],
+        );
+
+        let source_units = vec![source_unit];
+        let output = render(&AuditReport::new(&report, source_units.as_slice(), &[]));
+
+        // Finding text is escaped, both inside and outside of code spans
+        assert!(output.contains(
+            "<h4>1. Comparison <code>a &lt; b</code> in &lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt; &amp; more</h4>"
+        ));
+
+        // Source snippets, paths and rule names are escaped
+        assert!(output.contains(
+            "<span class=\"line-number\">2</span>    function f(uint a, uint b) public { if (a &lt; b &amp;&amp; b &gt; 0) revert(&quot;&#39;x&#39;&quot;); }"
+        ));
+        assert!(output.contains("<h3>/project/C&amp;D.sol</h3>"));
+        assert!(output.contains("<p>Location: <code>/project/C&amp;D.sol:2:5</code></p>"));
+        assert!(output.contains("Rule: <code>&lt;rule&gt;</code>"));
+
+        assert!(!output.contains("<script>"));
+        assert!(!output.contains("a < b"));
+    }
+}
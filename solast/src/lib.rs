@@ -0,0 +1,13 @@
+pub mod analysis;
+pub mod audit_report;
+pub mod baseline;
+pub mod config;
+pub mod errors;
+pub mod html;
+pub mod markdown;
+pub mod plain_text;
+pub mod project;
+pub mod report;
+pub mod sarif;
+pub mod suppressions;
+pub mod todo_list;
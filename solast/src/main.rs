@@ -1,308 +1,271 @@
-mod analysis;
-mod brownie;
-mod foundry;
-mod hardhat;
-mod report;
-mod todo_list;
-mod truffle;
-
-use report::Report;
+mod cli;
+
+use clap::Parser;
+use cli::{AnalyzeArgs, Cli, Command, OutputFormat, SourceArgs};
+use solast::{
+    analysis::{self, VisitorType},
+    audit_report::AuditReport,
+    baseline::Baseline,
+    plain_text::PlainTextRenderer,
+    config::Config,
+    errors::{error_exit_code, LoaderError, EXIT_FINDINGS, EXIT_INTERNAL_ERROR, EXIT_SUCCESS},
+    html,
+    markdown,
+    project::{ImportResolver, Project, ProjectLoader},
+    report::{Report, Severity},
+    sarif,
+    suppressions,
+    todo_list,
+};
 use solidity::ast::*;
-use std::{cell::RefCell, collections::HashSet, env, fs::File, io, path::PathBuf, rc::Rc};
+use std::{cell::RefCell, collections::{BTreeMap, HashSet}, env, io, panic::{self, AssertUnwindSafe}, rc::Rc};
 
-#[derive(Debug)]
-enum OutputFormat {
-    PlainText,
-    Json,
-}
+fn main() {
+    let cli = Cli::parse();
+
+    //
+    // Panics are reported as internal errors instead of the default panic exit code
+    //
 
-impl TryFrom<&str> for OutputFormat {
-    type Error = io::Error;
+    let exit_code = match panic::catch_unwind(AssertUnwindSafe(|| run(cli))) {
+        Ok(Ok(exit_code)) => exit_code,
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value.to_lowercase().as_str() {
-            "plaintext" | "plain-text" | "plain_text" => Ok(Self::PlainText),
-            "json" => Ok(Self::Json),
-            s => Err(io::Error::new(io::ErrorKind::Unsupported, s)),
+        Ok(Err(error)) => {
+            eprintln!("ERROR: {error}");
+            error_exit_code(&error)
         }
-    }
+
+        Err(_) => EXIT_INTERNAL_ERROR,
+    };
+
+    std::process::exit(exit_code);
 }
 
-fn main() -> io::Result<()> {
-    let mut args = env::args();
-    args.next().ok_or_else(|| io::Error::from(io::ErrorKind::BrokenPipe))?;
-
-    let mut project_path: Option<PathBuf> = None;
-    let mut should_print_todo_list = false;
-    let mut visitor_names: HashSet<String> = HashSet::new();
-    let mut contract_names: Vec<String> = vec![];
-    let mut contract_paths: Vec<PathBuf> = vec![];
-    let mut output_format = OutputFormat::PlainText;
-
-    for arg in args {
-        match arg {
-            arg if arg.starts_with("--") => match &arg.as_str()[2..] {
-                "todo-list" | "todo_list" => {
-                    should_print_todo_list = true;
-                }
+fn run(cli: Cli) -> io::Result<i32> {
+    match cli.command {
+        Command::Analyze(args) => analyze(args),
 
-                s if s.starts_with("contract=") => {
-                    contract_names.push(s.trim_start_matches("contract=").into());
-                }
+        Command::ListAnalyzers => {
+            list_analyzers();
+            Ok(EXIT_SUCCESS)
+        }
 
-                s if s.starts_with("contract-path=") || s.starts_with("contract_path=") => {
-                    contract_paths.push(PathBuf::from(&arg.as_str()[16..]));
-                }
+        Command::Explain { rule } => {
+            explain(rule.as_str())?;
+            Ok(EXIT_SUCCESS)
+        }
 
-                s if s.starts_with("output-format=") || s.starts_with("output_format=") => {
-                    output_format = OutputFormat::try_from(&arg.as_str()[16..])?;
-                }
+        Command::TodoList(args) => {
+            let config = load_config(&args)?;
+            let (source_units, _) = load_source_units(args, &config)?;
+            todo_list::print(source_units.as_slice());
+            Ok(EXIT_SUCCESS)
+        }
 
-                s if analysis::VISITOR_TYPES.iter().any(|visitor| visitor.0 == s) => {
-                    if !visitor_names.contains(s) {
-                        visitor_names.insert(s.into());
-                    }
-                }
+        Command::CallGraph(args) => {
+            let config = load_config(&args)?;
+            let (source_units, _) = load_source_units(args, &config)?;
+            print!("{}", CallGraph::new(source_units.as_slice())?.to_dot());
+            Ok(EXIT_SUCCESS)
+        }
 
-                _ => {
-                    return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid argument: {}", arg)));
-                }
-            }
+        Command::StorageLayout(args) => {
+            let config = load_config(&args)?;
+            let (source_units, _) = load_source_units(args, &config)?;
+            let storage_layout_builder = StorageLayoutBuilder::new(source_units.as_slice());
+            let mut storage_layouts: BTreeMap<String, BTreeMap<String, StorageLayout>> = BTreeMap::new();
 
-            _ => {
-                if project_path.is_some() {
-                    return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple project paths specified: {} {}", project_path.unwrap().to_string_lossy(), arg)));
-                }
+            for source_unit in source_units.iter() {
+                for contract_definition in source_unit.contract_definitions() {
+                    let storage_layout = storage_layout_builder.contract_layout(source_units.as_slice(), contract_definition)
+                        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
 
-                project_path = Some(PathBuf::from(arg));
+                    storage_layouts.entry(source_unit.absolute_path.clone().unwrap_or_default())
+                        .or_default()
+                        .insert(contract_definition.name.clone(), storage_layout);
+                }
             }
+
+            println!("{}", serde_json::to_string_pretty(&storage_layouts)?);
+            Ok(EXIT_SUCCESS)
         }
     }
+}
 
-    if contract_paths.is_empty() && project_path.is_none() {
-        return Err(io::Error::new(io::ErrorKind::InvalidInput, "No paths were supplied"));
-    }
+fn list_analyzers() {
+    let name_width = analysis::VISITOR_TYPES.iter().map(|visitor_type| visitor_type.name.len()).max().unwrap_or(0);
 
-    let mut source_units: Vec<SourceUnit> = vec![];
+    println!("{:name_width$}  {:8}  {:10}  {:8}  DESCRIPTION", "NAME", "SEVERITY", "CONFIDENCE", "CATEGORY");
+
+    for visitor_type in analysis::VISITOR_TYPES {
+        println!(
+            "{:name_width$}  {:8}  {:10}  {:8}  {}",
+            visitor_type.name,
+            visitor_type.severity.to_string(),
+            visitor_type.confidence.to_string(),
+            visitor_type.category.to_string(),
+            visitor_type.description,
+        );
+    }
+}
 
-    if !contract_paths.is_empty() {
-        let mut file_no = 0;
+fn explain(rule: &str) -> io::Result<()> {
+    let visitor_type = analysis::find_visitor_type(rule)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("Unknown analyzer: {rule}")))?;
+
+    println!(
+        "{} (severity: {}, confidence: {}, category: {})",
+        visitor_type.name,
+        visitor_type.severity,
+        visitor_type.confidence,
+        visitor_type.category,
+    );
+
+    println!();
+    println!("{}", visitor_type.description);
+    println!();
+    println!("Rationale:");
+    println!("    {}", visitor_type.rationale);
+    println!();
+    println!("Example:");
+
+    for line in visitor_type.example.trim_matches('\n').lines() {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("    {line}");
+        }
+    }
 
-        for contract_path in contract_paths {
-            let src = std::fs::read_to_string(contract_path.clone())?;
+    Ok(())
+}
 
-            let (source_unit, comments) = solang_parser::parse(src.as_str(), file_no)
-                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("Failed to parse contract \"{}\"", contract_path.to_string_lossy())))?;
+/// Loads the configuration file, which is discovered in the project root unless explicitly supplied.
+fn load_config(sources: &SourceArgs) -> io::Result<Config> {
+    Ok(match sources.config.as_ref() {
+        Some(config_path) => Config::load(config_path)?,
+        None => Config::discover(sources.project.clone().unwrap_or(env::current_dir()?).as_path())?,
+    })
+}
 
-            let mut builder = AstBuilder::default();
-            let mut source_unit = builder.build_source_unit(&source_unit);
+/// Loads all of the supplied source units, along with the paths of any library sources which should not be analyzed.
+fn load_source_units(sources: SourceArgs, config: &Config) -> io::Result<(Vec<SourceUnit>, HashSet<String>)> {
+    //
+    // Contracts supplied on the command line take precedence over the configuration file
+    //
 
-            let mut license = None;
+    let contract_names = if sources.contract_names.is_empty() {
+        config.contracts.clone()
+    } else {
+        sources.contract_names
+    };
 
-            for comment in comments.iter() {
-                if let solang_parser::pt::Comment::Line(_, text) = comment {
-                    let text = text.trim_start_matches("//").trim_start_matches(' ');
-                    if text.starts_with("SPDX-License-Identifier:") {
-                        license = Some(text.trim_start_matches("SPDX-License-Identifier:").trim_start_matches(' ').to_string());
-                    }
-                }
-            }
+    let mut source_units: Vec<SourceUnit> = vec![];
+    let mut library_paths: HashSet<String> = HashSet::new();
 
-            source_unit.absolute_path = Some(contract_path.to_string_lossy().to_string());
-            source_unit.source = Some(src);
-            source_unit.license = license;
+    if !sources.contract_paths.is_empty() {
+        let mut import_resolver = ImportResolver {
+            include_paths: sources.include_paths,
+            remappings: sources.remappings,
+            ..Default::default()
+        };
 
-            source_units.push(source_unit);
+        import_resolver.load_remappings_file()?;
 
-            file_no += 1;
-        }
-    }
+        let project = Project::from_source_files(sources.contract_paths.as_slice(), &import_resolver)?;
 
-    if let Some(project_path) = project_path {
-        if !project_path.exists() {
-            return Err(io::Error::new(io::ErrorKind::NotFound, project_path.to_string_lossy()))
+        if !sources.include_libs {
+            library_paths.extend(project.library_paths);
         }
-        
-        let brownie_config_path = project_path.join("brownie-config.yaml");
-        let hardhat_config_js_path = project_path.join("hardhat.config.js");
-        let hardhat_config_ts_path = project_path.join("hardhat.config.ts");
-        let truffle_config_path = project_path.join("truffle-config.js");
-        let foundry_config_path = project_path.join("foundry.toml");
-
-        if brownie_config_path.is_file() {
-            //
-            // TODO: load the brownie config and get the actual build paths
-            //
-
-            let build_paths = &[
-                project_path.join("build").join("contracts"),
-                project_path.join("build").join("interfaces"),
-            ];
-
-            for build_path in build_paths {
-                if !build_path.exists() || !build_path.is_dir() {
-                    todo!("brownie project not compiled")
-                }
 
-                for path in std::fs::read_dir(build_path)? {
-                    let path = path?.path();
+        source_units.extend(project.source_units);
+    }
 
-                    if !path.is_file() || !path.extension().map(|extension| extension == "json").unwrap_or(false) {
-                        continue;
-                    }
+    let mut projects = vec![];
 
-                    let file: brownie::File = simd_json::from_reader(File::open(path)?)?;
+    for loader in sources.standard_json {
+        projects.push(loader.load(env::current_dir()?.as_path())?);
+    }
 
-                    if let Some(mut source_unit) = file.ast {
-                        if !contract_names.is_empty() && !contract_names.iter().any(|contract_name| source_unit.contract_definitions().iter().any(|c| c.name == *contract_name)) {
-                            continue;
-                        }
+    if let Some(project_path) = sources.project {
+        projects.push(Project::load(project_path)?);
+    }
 
-                        if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
-                            source_unit.source = file.source.clone();
-                            source_units.push(source_unit);
-                        }
-                    }
-                }
-            }
-        } else if hardhat_config_js_path.is_file() || hardhat_config_ts_path.is_file() {
-            let build_path = project_path.join("artifacts").join("build-info");
+    for project in projects {
+        if !sources.include_libs {
+            library_paths.extend(project.library_paths);
+        }
 
-            if !build_path.exists() || !build_path.is_dir() {
-                todo!("hardhat project not compiled")
+        for source_unit in project.source_units {
+            if source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
+                continue;
             }
 
-            let console_path = PathBuf::new()
-                .join("hardhat")
-                .join("console.sol")
-                .to_string_lossy()
-                .to_string();
-
-            for path in std::fs::read_dir(build_path)? {
-                let path = path?.path();
-
-                if !path.is_file() || !path.extension().map(|extension| extension == "json").unwrap_or(false) {
-                    continue;
-                }
-
-                let file: hardhat::File = simd_json::from_reader(File::open(path)?)?;
-
-                for (source_path, source) in file.output.sources {
-                    let mut source_unit = source.ast;
-
-                    if source_unit.absolute_path.as_deref().unwrap_or("").ends_with(console_path.as_str()) {
-                        continue;
-                    }
-                    
-                    if !contract_names.is_empty() && !contract_names.iter().any(|contract_name| source_unit.contract_definitions().iter().any(|c| c.name == *contract_name)) {
-                        continue;
-                    }
-
-                    if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
-                        if let Some(source) = file.input.sources.get(&source_path) {
-                            source_unit.source = Some(source.content.clone());
-                            source_units.push(source_unit);
-                        }
-                    }
-                }
-            }
-        } else if truffle_config_path.is_file() {
-            let build_path = project_path.join("build").join("contracts");
+            source_units.push(source_unit);
+        }
+    }
 
-            if !build_path.exists() || !build_path.is_dir() {
-                todo!("truffle project not compiled")
-            }
+    if !contract_names.is_empty() {
+        source_units.retain(|source_unit| {
+            contract_names.iter().any(|contract_name| source_unit.contract_definitions().iter().any(|c| c.name == *contract_name))
+        });
+    }
 
-            let migrations_path = PathBuf::new()
-                .join("contracts")
-                .join("Migrations.sol")
-                .to_string_lossy()
-                .to_string();
+    source_units.sort_by(|lhs, rhs| {
+        let lhs = lhs.absolute_path.as_deref().unwrap_or("");
+        let rhs = rhs.absolute_path.as_deref().unwrap_or("");
+        lhs.cmp(rhs)
+    });
 
-            for path in std::fs::read_dir(build_path)? {
-                let path = path?.path();
+    Ok((source_units, library_paths))
+}
 
-                if !path.is_file() || !path.extension().map(|extension| extension == "json").unwrap_or(false) {
-                    continue;
-                }
+/// Analyzes the supplied sources, returning `EXIT_FINDINGS` if any reported entries meet the failure threshold.
+fn analyze(args: AnalyzeArgs) -> io::Result<i32> {
+    //
+    // Options supplied on the command line take precedence over the configuration file
+    //
 
-                let file: truffle::File = simd_json::from_reader(File::open(path)?)?;
-
-                if let Some(mut source_unit) = file.ast {
-                    if source_unit.absolute_path.as_deref().unwrap_or("").ends_with(migrations_path.as_str()) {
-                        continue;
-                    }
-                    
-                    if !contract_names.is_empty() && !contract_names.iter().any(|contract_name| source_unit.contract_definitions().iter().any(|c| c.name == *contract_name)) {
-                        continue;
-                    }
-
-                    if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
-                        source_unit.source = file.source.clone();
-                        source_units.push(source_unit);
-                    }
-                }
-            }
-        } else if foundry_config_path.is_file() {
-            //
-            // TODO:
-            //   * load build_path from `foundry.toml`
-            //   * ignore contracts under lib paths from `foundry.toml`
-            //
+    let config = load_config(&args.sources)?;
 
-            let build_path = project_path.join("out");
+    let output_format = match (args.output_format, config.output_format.as_deref()) {
+        (Some(output_format), _) => output_format,
 
-            if !build_path.exists() || !build_path.is_dir() {
-                todo!("foundry project not compiled")
-            }
+        (None, Some(output_format)) => OutputFormat::try_from(output_format).map_err(|e| LoaderError::InvalidConfig {
+            path: config.path.clone().unwrap_or_default(),
+            message: e.to_string(),
+        })?,
 
-            for path in std::fs::read_dir(build_path)? {
-                let path = path?.path();
+        (None, None) => OutputFormat::PlainText,
+    };
 
-                if !path.is_dir() {
-                    continue;
-                }
+    let min_severity = args.min_severity.or(config.min_severity);
+    let fail_on = args.fail_on(&config);
 
-                for path in std::fs::read_dir(path)? {
-                    let path = path?.path();
+    let (source_units, library_paths) = load_source_units(args.sources, &config)?;
 
-                    if !path.is_file() || !path.extension().map(|extension| extension == "json").unwrap_or(false) {
-                        continue;
-                    }
+    //
+    // Each visitor writes to its own report so its entries can be tagged with the visitor's rule details
+    //
 
-                    let mut file: foundry::File = simd_json::from_reader(File::open(path)?)?;
-                    
-                    if !contract_names.is_empty() && !contract_names.iter().any(|contract_name| file.ast.contract_definitions().iter().any(|c| c.name == *contract_name)) {
-                        continue;
-                    }
+    let mut visitor_types: Vec<&VisitorType> = vec![];
+    let mut rule_reports = vec![];
+    let mut visitors: Vec<Box<dyn AstVisitor>> = vec![];
 
-                    if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == file.ast.absolute_path) {
-                        file.ast.source = Some(std::fs::read_to_string(project_path.join(file.ast.absolute_path.clone().unwrap()))?);
-                        source_units.push(file.ast);
-                    }
-                }
-            }
+    for visitor_type in analysis::VISITOR_TYPES {
+        let enabled = if args.analyzers.is_empty() {
+            config.is_analyzer_enabled(visitor_type.name)
         } else {
-            unimplemented!("No supported project configuration found")
-        }
-    }
-
-    source_units.sort_by(|lhs, rhs| {
-        let lhs = lhs.absolute_path.as_deref().unwrap_or("");
-        let rhs = rhs.absolute_path.as_deref().unwrap_or("");
-        lhs.cmp(rhs)
-    });
-
-    if should_print_todo_list {
-        todo_list::print(source_units.as_slice());
-    }
+            args.analyzers.iter().any(|name| name == visitor_type.name)
+        };
 
-    let report = Rc::new(RefCell::new(Report::default()));
-    let mut visitors: Vec<Box<dyn AstVisitor>> = vec![];
+        let enabled = enabled && !args.excluded_analyzers.iter().any(|name| name == visitor_type.name);
 
-    for &(visitor_name, create_visitor) in analysis::VISITOR_TYPES {
-        if visitor_names.is_empty() || visitor_names.contains(visitor_name) {
-            visitors.push(create_visitor(report.clone()));
+        if enabled {
+            let rule_report = Rc::new(RefCell::new(Report::default()));
+            visitors.push((visitor_type.create)(rule_report.clone()));
+            visitor_types.push(visitor_type);
+            rule_reports.push((visitor_type, rule_report));
         }
     }
 
@@ -320,6 +283,22 @@ fn main() -> io::Result<()> {
             continue;
         }
 
+        //
+        // Skip library sources unless they were explicitly requested
+        //
+
+        if source_unit.absolute_path.as_ref().map(|path| library_paths.contains(path)).unwrap_or(false) {
+            continue;
+        }
+
+        //
+        // Skip sources which are not matched by the configured path patterns
+        //
+
+        if !config.is_path_included(source_unit.absolute_path.as_deref().unwrap_or("")) {
+            continue;
+        }
+
         //
         // Don't analyze the same source unit multiple times
         //
@@ -345,21 +324,95 @@ fn main() -> io::Result<()> {
         data.leave_source_unit(&mut context)?;
     }
 
+    let mut report = Report::default();
+
+    for (visitor_type, rule_report) in rule_reports {
+        report.merge_rule_report(
+            visitor_type.name,
+            config.rule_severity(visitor_type),
+            visitor_type.confidence,
+            visitor_type.category,
+            rule_report.take(),
+        );
+    }
+
+    let rules: Vec<&str> = visitor_types.iter().map(|visitor_type| visitor_type.name).collect();
+    suppressions::apply(&mut report, source_units.as_slice(), rules.as_slice(), args.report_unused_suppressions);
+
+    if let Some(write_baseline_path) = args.write_baseline {
+        Baseline::new(&report, source_units.as_slice()).save(write_baseline_path)?;
+    }
+
+    if let Some(baseline_path) = args.baseline {
+        Baseline::load(baseline_path)?.apply(&mut report, source_units.as_slice());
+    }
+
+    if let Some(min_severity) = min_severity {
+        report.retain_min_severity(min_severity);
+    }
+
     //
     // Display the report in the desired format
     //
 
-    report.borrow_mut().sort_entries();
+    report.sort_entries();
 
     match output_format {
         OutputFormat::PlainText => {
-            println!("{}", report.borrow());
+            print!("{}", PlainTextRenderer::new(source_units.as_slice(), args.color.enabled()).render(&report));
         }
 
         OutputFormat::Json => {
-            println!("{}", simd_json::to_string(&report.borrow().clone()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?);
+            println!("{}", simd_json::to_string(&report).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?);
+        }
+
+        OutputFormat::Sarif => {
+            let log = sarif::Log::new(&report, visitor_types.as_slice(), &config);
+            println!("{}", serde_json::to_string_pretty(&log).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?);
+        }
+
+        OutputFormat::Markdown => {
+            print!("{}", markdown::render(&AuditReport::new(&report, source_units.as_slice(), visitor_types.as_slice())));
+        }
+
+        OutputFormat::Html => {
+            print!("{}", html::render(&AuditReport::new(&report, source_units.as_slice(), visitor_types.as_slice())));
         }
     }
 
-    Ok(())
+    Ok(findings_exit_code(&report, fail_on))
+}
+
+/// The exit code of an analysis, which fails if any reported entries have at least the `fail_on` severity.
+fn findings_exit_code(report: &Report, fail_on: Severity) -> i32 {
+    if report.has_min_severity(fail_on) { EXIT_FINDINGS } else { EXIT_SUCCESS }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solast::report::Entry;
+
+    #[test]
+    fn test_findings_exit_code() {
+        let mut report = Report::default();
+        assert_eq!(findings_exit_code(&report, Severity::Info), EXIT_SUCCESS);
+
+        report.entries.insert(
+            "A.sol".into(),
+            vec![
+                Entry { severity: Severity::Info, ..Default::default() },
+                Entry { severity: Severity::Medium, ..Default::default() },
+            ],
+        );
+
+        assert_eq!(findings_exit_code(&report, Severity::Info), EXIT_FINDINGS);
+        assert_eq!(findings_exit_code(&report, Severity::Low), EXIT_FINDINGS);
+        assert_eq!(findings_exit_code(&report, Severity::Medium), EXIT_FINDINGS);
+        assert_eq!(findings_exit_code(&report, Severity::High), EXIT_SUCCESS);
+
+        // Entries filtered out by `--min-severity` don't fail the analysis
+        report.retain_min_severity(Severity::High);
+        assert_eq!(findings_exit_code(&report, Severity::Info), EXIT_SUCCESS);
+    }
 }
@@ -0,0 +1,299 @@
+use crate::audit_report::{rule_anchor, AuditReport, Finding};
+use std::fmt::Write;
+
+/// Renders an audit report as a self-contained Markdown document.
+pub fn render(audit_report: &AuditReport) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "# Audit Report").unwrap();
+    writeln!(output).unwrap();
+
+    //
+    // Summary tables
+    //
+
+    writeln!(output, "## Summary").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "| Severity | Findings |").unwrap();
+    writeln!(output, "|-|-:|").unwrap();
+
+    for (severity, count) in audit_report.severity_counts.iter() {
+        writeln!(output, "| {severity} | {count} |").unwrap();
+    }
+
+    writeln!(output, "| **Total** | **{}** |", audit_report.total_count()).unwrap();
+    writeln!(output).unwrap();
+
+    if !audit_report.rules.is_empty() {
+        writeln!(output, "| Rule | Severity | Findings |").unwrap();
+        writeln!(output, "|-|-|-:|").unwrap();
+
+        for rule in audit_report.rules.iter() {
+            writeln!(output, "| {} | {} | {} |", rule_link(audit_report, rule.rule), rule.severity, rule.count).unwrap();
+        }
+
+        writeln!(output).unwrap();
+    }
+
+    //
+    // Findings, grouped by contract
+    //
+
+    if !audit_report.sections.is_empty() {
+        writeln!(output, "## Findings").unwrap();
+        writeln!(output).unwrap();
+    }
+
+    let mut finding_number = 0;
+
+    for section in audit_report.sections.iter() {
+        writeln!(output, "### {}", section.title()).unwrap();
+        writeln!(output).unwrap();
+
+        for finding in section.findings.iter() {
+            finding_number += 1;
+            render_finding(&mut output, audit_report, section.path.to_string_lossy().as_ref(), finding_number, finding);
+        }
+    }
+
+    if !audit_report.fixed_entries.is_empty() {
+        writeln!(output, "## Fixed Since Baseline").unwrap();
+        writeln!(output).unwrap();
+
+        for (path, entry) in audit_report.fixed_entries.iter() {
+            let location = match entry.line {
+                Some(line) => format!("{}:{line}", path.to_string_lossy()),
+                None => path.to_string_lossy().to_string(),
+            };
+
+            writeln!(output, "- **{}** {}: {} (`{location}`)", entry.severity, rule_link(audit_report, entry.rule.as_str()), entry.text).unwrap();
+        }
+
+        writeln!(output).unwrap();
+    }
+
+    //
+    // Rule explanations, which are linked to from the findings
+    //
+
+    let explained_rules = audit_report.explained_rules();
+
+    if !explained_rules.is_empty() {
+        writeln!(output, "## Rules").unwrap();
+        writeln!(output).unwrap();
+
+        for visitor_type in explained_rules {
+            writeln!(output, "<a id=\"{}\"></a>", rule_anchor(visitor_type.name)).unwrap();
+            writeln!(output).unwrap();
+            writeln!(output, "### `{}`", visitor_type.name).unwrap();
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "**Severity:** {} | **Confidence:** {} | **Category:** {}",
+                visitor_type.severity,
+                visitor_type.confidence,
+                visitor_type.category,
+            ).unwrap();
+            writeln!(output).unwrap();
+            writeln!(output, "{}", visitor_type.description).unwrap();
+            writeln!(output).unwrap();
+            writeln!(output, "{}", visitor_type.rationale).unwrap();
+            writeln!(output).unwrap();
+            writeln!(output, "```solidity").unwrap();
+            writeln!(output, "{}", visitor_type.example.trim_matches('\n')).unwrap();
+            writeln!(output, "```").unwrap();
+            writeln!(output).unwrap();
+        }
+    }
+
+    output
+}
+
+fn render_finding(output: &mut String, audit_report: &AuditReport, path: &str, number: usize, finding: &Finding) {
+    let entry = finding.entry;
+
+    writeln!(output, "#### {number}. {}", entry.text).unwrap();
+    writeln!(output).unwrap();
+    writeln!(
+        output,
+        "**Rule:** {} | **Severity:** {} | **Confidence:** {} | **Category:** {}",
+        rule_link(audit_report, entry.rule.as_str()),
+        entry.severity,
+        entry.confidence,
+        entry.category,
+    ).unwrap();
+    writeln!(output).unwrap();
+
+    let location = match (entry.range, entry.line) {
+        (Some(range), _) => format!("{path}:{}:{}", range.start_line, range.start_column),
+        (None, Some(line)) => format!("{path}:{line}"),
+        (None, None) => path.to_string(),
+    };
+
+    match finding.function.as_ref() {
+        Some(function) => writeln!(output, "**Location:** `{location}` in `{function}`").unwrap(),
+        None => writeln!(output, "**Location:** `{location}`").unwrap(),
+    }
+
+    writeln!(output).unwrap();
+
+    if let Some(excerpt) = finding.excerpt.as_ref() {
+        writeln!(output, "```solidity").unwrap();
+
+        for line in excerpt.lines.iter() {
+            writeln!(output, "{line}").unwrap();
+        }
+
+        writeln!(output, "```").unwrap();
+        writeln!(output).unwrap();
+    }
+}
+
+fn rule_link(audit_report: &AuditReport, rule: &str) -> String {
+    if audit_report.is_explained(rule) {
+        format!("[`{rule}`](#{})", rule_anchor(rule))
+    } else {
+        format!("`{rule}`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::find_visitor_type,
+        report::{Entry, Report, Severity},
+    };
+    use solidity::ast::{SourceRange, SourceUnit};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render_empty_report() {
+        let report = Report::default();
+        let output = render(&AuditReport::new(&report, &[], &[]));
+
+        assert_eq!(
+            output,
+            [
+                "# Audit Report",
+                "",
+                "## Summary",
+                "",
+                "| Severity | Findings |",
+                "|-|-:|",
+                "| high | 0 |",
+                "| medium | 0 |",
+                "| low | 0 |",
+                "| info | 0 |",
+                "| **Total** | **0** |",
+                "",
+                "",
+            ].join("\n"),
+        );
+    }
+
+    #[test]
+    fn test_render_multiple_severities() {
+        let mut source_unit = SourceUnit::new(1);
+        source_unit.absolute_path = Some("/project/A.sol".to_string());
+        source_unit.set_source(Some("contract A {\n    uint x;\n}\n".to_string()));
+
+        let mut report = Report::default();
+        report.entries.insert(
+            PathBuf::from("/project/A.sol"),
+            vec![
+                Entry {
+                    rule: "unused_state_variable".to_string(),
+                    severity: Severity::Low,
+                    line: Some(2),
+                    range: Some(SourceRange { start_line: 2, start_column: 5, end_line: 2, end_column: 12 }),
+                    text: "`x` is never used".to_string(),
+                    ..Default::default()
+                },
+                Entry {
+                    rule: "reentrancy".to_string(),
+                    severity: Severity::High,
+                    line: Some(1),
+                    text: "reentrant call".to_string(),
+                    ..Default::default()
+                },
+            ],
+        );
+        report.entries.insert(
+            PathBuf::from("/project/B.sol"),
+            vec![Entry {
+                rule: "no_spdx_identifier".to_string(),
+                severity: Severity::Info,
+                text: "missing SPDX identifier".to_string(),
+                ..Default::default()
+            }],
+        );
+
+        report.sort_entries();
+
+        let source_units = vec![source_unit];
+        let visitor_types = vec![find_visitor_type("no_spdx_identifier").unwrap()];
+        let output = render(&AuditReport::new(&report, source_units.as_slice(), visitor_types.as_slice()));
+
+        assert!(output.contains(
+            &[
+                "| Severity | Findings |",
+                "|-|-:|",
+                "| high | 1 |",
+                "| medium | 0 |",
+                "| low | 1 |",
+                "| info | 1 |",
+                "| **Total** | **3** |",
+                "",
+                "| Rule | Severity | Findings |",
+                "|-|-|-:|",
+                "| `reentrancy` | high | 1 |",
+                "| `unused_state_variable` | low | 1 |",
+                "| [`no_spdx_identifier`](#rule-no-spdx-identifier) | info | 1 |",
+                "",
+                "## Findings",
+                "",
+            ].join("\n")
+        ));
+
+        // Findings are numbered across sections, and only ranged findings include an excerpt
+        assert!(output.contains(
+            &[
+                "### /project/A.sol",
+                "",
+                "#### 1. reentrant call",
+                "",
+                "**Rule:** `reentrancy` | **Severity:** high | **Confidence:** low | **Category:** security",
+                "",
+                "**Location:** `/project/A.sol:1`",
+                "",
+                "#### 2. `x` is never used",
+                "",
+                "**Rule:** `unused_state_variable` | **Severity:** low | **Confidence:** low | **Category:** security",
+                "",
+                "**Location:** `/project/A.sol:2:5`",
+                "",
+                "```solidity",
+                "    uint x;",
+                "```",
+                "",
+                "### /project/B.sol",
+                "",
+                "#### 3. missing SPDX identifier",
+                "",
+                "**Rule:** [`no_spdx_identifier`](#rule-no-spdx-identifier) | **Severity:** info | **Confidence:** low | **Category:** security",
+                "",
+                "**Location:** `/project/B.sol`",
+                "",
+                "## Rules",
+                "",
+                "<a id=\"rule-no-spdx-identifier\"></a>",
+                "",
+                "### `no_spdx_identifier`",
+                "",
+            ].join("\n")
+        ));
+
+        assert!(!output.contains("## Fixed Since Baseline"));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{
+    baseline::find_source_unit,
+    report::{Entry, Report, Severity},
+};
+use solidity::ast::SourceUnit;
+use std::{fmt::Write, path::Path};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const BLUE: &str = "\x1b[34m";
+const CYAN: &str = "\x1b[36m";
+
+/// The number of columns a tab character is expanded to in source snippets.
+const TAB_WIDTH: usize = 4;
+
+/// Renders reports in a rustc-style plain text format, with source snippets underlining each entry's range.
+pub struct PlainTextRenderer<'a> {
+    source_units: &'a [SourceUnit],
+    color: bool,
+}
+
+impl<'a> PlainTextRenderer<'a> {
+    pub fn new(source_units: &'a [SourceUnit], color: bool) -> Self {
+        Self {
+            source_units,
+            color,
+        }
+    }
+
+    /// Renders the supplied report, which is expected to be sorted with `Report::sort_entries`.
+    pub fn render(&self, report: &Report) -> String {
+        let mut output = String::new();
+
+        for (path, entries) in report.sorted_entries() {
+            let lines: Vec<&str> = find_source_unit(self.source_units, path)
+                .and_then(|source_unit| source_unit.source())
+                .map(|source| source.lines().collect())
+                .unwrap_or_default();
+
+            for entry in entries.iter() {
+                self.render_entry(&mut output, path, entry, lines.as_slice());
+            }
+        }
+
+        if !report.fixed_entries.is_empty() {
+            writeln!(output, "{}", self.paint("Fixed since baseline:", &[BOLD])).unwrap();
+            writeln!(output).unwrap();
+
+            for (path, entries) in report.sorted_fixed_entries() {
+                for entry in entries.iter() {
+                    self.render_header(&mut output, entry);
+                    self.render_location(&mut output, path, entry, 0);
+                }
+            }
+
+            writeln!(output).unwrap();
+        }
+
+        self.render_summary(&mut output, report);
+
+        output
+    }
+
+    fn render_entry(&self, output: &mut String, path: &Path, entry: &Entry, lines: &[&str]) {
+        self.render_header(output, entry);
+
+        let range = match entry.range {
+            Some(range) if range.start_line > 0 && range.start_line <= lines.len() => range,
+
+            _ => {
+                self.render_location(output, path, entry, 0);
+                writeln!(output).unwrap();
+                return;
+            }
+        };
+
+        let end_line = range.end_line.clamp(range.start_line, lines.len());
+        let gutter_width = end_line.to_string().len();
+
+        self.render_location(output, path, entry, gutter_width);
+
+        let gutter = self.paint(format!("{:gutter_width$} |", "").as_str(), &[BOLD, BLUE]);
+        writeln!(output, "{gutter}").unwrap();
+
+        //
+        // Multi-line ranges only show their first and last lines
+        //
+
+        let snippet_lines: Vec<usize> = if end_line - range.start_line > 1 {
+            vec![range.start_line, end_line]
+        } else {
+            (range.start_line..=end_line).collect()
+        };
+
+        for (i, &line) in snippet_lines.iter().enumerate() {
+            if i > 0 && line > snippet_lines[i - 1] + 1 {
+                writeln!(output, "{}", self.paint("...", &[BOLD, BLUE])).unwrap();
+            }
+
+            let line_text = lines[line - 1];
+            let line_length = line_text.chars().count();
+
+            let start_column = if line == range.start_line { range.start_column.max(1) } else { first_non_whitespace_column(line_text) };
+            let end_column = if line == range.end_line { range.end_column } else { line_length + 1 };
+            let end_column = end_column.clamp(start_column + 1, line_length.max(start_column) + 1);
+
+            writeln!(
+                output,
+                "{} {}",
+                self.paint(format!("{line:gutter_width$} |").as_str(), &[BOLD, BLUE]),
+                expand_tabs(line_text),
+            ).unwrap();
+
+            let padding = display_width(line_text, start_column);
+            let underline_width = display_width(line_text, end_column) - padding;
+
+            writeln!(
+                output,
+                "{gutter} {}{}",
+                " ".repeat(padding),
+                self.paint("^".repeat(underline_width.max(1)).as_str(), &[BOLD, severity_color(entry.severity)]),
+            ).unwrap();
+        }
+
+        writeln!(output, "{gutter}").unwrap();
+        writeln!(output).unwrap();
+    }
+
+    fn render_header(&self, output: &mut String, entry: &Entry) {
+        writeln!(
+            output,
+            "{}{}",
+            self.paint(format!("{}[{}]", entry.severity, entry.rule).as_str(), &[BOLD, severity_color(entry.severity)]),
+            self.paint(format!(": {}", entry.text).as_str(), &[BOLD]),
+        ).unwrap();
+    }
+
+    fn render_location(&self, output: &mut String, path: &Path, entry: &Entry, gutter_width: usize) {
+        let location = match (entry.range, entry.line) {
+            (Some(range), _) => format!("{}:{}:{}", path.to_string_lossy(), range.start_line, range.start_column),
+            (None, Some(line)) => format!("{}:{}", path.to_string_lossy(), line),
+            (None, None) => path.to_string_lossy().to_string(),
+        };
+
+        writeln!(output, "{:gutter_width$}{} {location}", "", self.paint("-->", &[BOLD, BLUE])).unwrap();
+    }
+
+    fn render_summary(&self, output: &mut String, report: &Report) {
+        let entries: Vec<&Entry> = report.entries.values().flatten().collect();
+
+        if entries.is_empty() {
+            writeln!(output, "{}", self.paint("No findings", &[BOLD, GREEN])).unwrap();
+            return;
+        }
+
+        let counts: Vec<String> = [Severity::High, Severity::Medium, Severity::Low, Severity::Info]
+            .into_iter()
+            .filter_map(|severity| match entries.iter().filter(|entry| entry.severity == severity).count() {
+                0 => None,
+                count => Some(self.paint(format!("{count} {severity}").as_str(), &[severity_color(severity)])),
+            })
+            .collect();
+
+        writeln!(
+            output,
+            "{} ({})",
+            self.paint(format!("{} finding{}", entries.len(), if entries.len() == 1 { "" } else { "s" }).as_str(), &[BOLD]),
+            counts.join(", "),
+        ).unwrap();
+    }
+
+    fn paint(&self, text: &str, styles: &[&str]) -> String {
+        if self.color {
+            format!("{}{text}{RESET}", styles.concat())
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+fn severity_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => RED,
+        Severity::Medium => YELLOW,
+        Severity::Low => CYAN,
+        Severity::Info => GREEN,
+    }
+}
+
+fn first_non_whitespace_column(line_text: &str) -> usize {
+    line_text.chars().take_while(|c| c.is_whitespace()).count() + 1
+}
+
+/// The display width of the text preceding the supplied 1-based column, with tabs expanded.
+fn display_width(line_text: &str, column: usize) -> usize {
+    line_text.chars()
+        .take(column.saturating_sub(1))
+        .map(|c| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum::<usize>()
+        + column.saturating_sub(1).saturating_sub(line_text.chars().count())
+}
+
+fn expand_tabs(line_text: &str) -> String {
+    line_text.replace('\t', " ".repeat(TAB_WIDTH).as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solidity::ast::{LineTable, SourceLocation, SourceRange};
+
+    fn source_unit(path: &str, source: &str) -> SourceUnit {
+        let mut source_unit = SourceUnit::new(1);
+        source_unit.absolute_path = Some(path.to_string());
+        source_unit.set_source(Some(source.to_string()));
+        source_unit
+    }
+
+    #[test]
+    fn test_render_non_ascii_columns() {
+        let source = "contract C {\n\tstring s = \"héllo\"; uint x;\n}\n";
+        let source_units = vec![source_unit("/project/C.sol", source)];
+
+        let start = source.find("uint x").unwrap();
+        let range = LineTable::new(source).range(source, SourceLocation { start, length: "uint x".len(), file_index: None });
+
+        // Columns are counted in characters, so the multi-byte `é` only occupies a single column
+        assert_eq!(range, SourceRange { start_line: 2, start_column: 22, end_line: 2, end_column: 28 });
+
+        let mut report = Report::default();
+        report.add_entry("/project/C.sol", Some(range), "unused variable");
+
+        let output = PlainTextRenderer::new(&source_units, false).render(&report);
+
+        // The tab is expanded to 4 columns in both the snippet and the underline padding
+        let expected = [
+            "info[]: unused variable".to_string(),
+            " --> /project/C.sol:2:22".to_string(),
+            "  |".to_string(),
+            "2 |     string s = \"héllo\"; uint x;".to_string(),
+            format!("  | {}^^^^^^", " ".repeat(24)),
+            "  |".to_string(),
+            "".to_string(),
+            "1 finding (1 info)".to_string(),
+            "".to_string(),
+        ].join("\n");
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_render_multi_line_range() {
+        let source = "contract C {\n    function f() public {\n        uint x;\n    }\n}\n";
+        let source_units = vec![source_unit("/project/C.sol", source)];
+
+        let mut report = Report::default();
+        report.add_entry(
+            "/project/C.sol",
+            Some(SourceRange { start_line: 2, start_column: 5, end_line: 4, end_column: 6 }),
+            "function f",
+        );
+        report.add_entry(
+            "/project/C.sol",
+            Some(SourceRange { start_line: 3, start_column: 9, end_line: 4, end_column: 6 }),
+            "adjacent lines",
+        );
+
+        let output = PlainTextRenderer::new(&source_units, false).render(&report);
+
+        // Only the first and last lines of a range are shown, separated by an ellipsis when they are not adjacent
+        let expected = [
+            "info[]: function f".to_string(),
+            " --> /project/C.sol:2:5".to_string(),
+            "  |".to_string(),
+            "2 |     function f() public {".to_string(),
+            format!("  |     {}", "^".repeat(21)),
+            "...".to_string(),
+            "4 |     }".to_string(),
+            "  |     ^".to_string(),
+            "  |".to_string(),
+            "".to_string(),
+            "info[]: adjacent lines".to_string(),
+            " --> /project/C.sol:3:9".to_string(),
+            "  |".to_string(),
+            "3 |         uint x;".to_string(),
+            "  |         ^^^^^^^".to_string(),
+            "4 |     }".to_string(),
+            "  |     ^".to_string(),
+            "  |".to_string(),
+            "".to_string(),
+            "2 findings (2 info)".to_string(),
+            "".to_string(),
+        ].join("\n");
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_render_color() {
+        let source = "contract C {}\n";
+        let source_units = vec![source_unit("/project/C.sol", source)];
+
+        let mut report = Report::default();
+        report.add_entry(
+            "/project/C.sol",
+            Some(SourceRange { start_line: 1, start_column: 1, end_line: 1, end_column: 14 }),
+            "empty contract",
+        );
+        report.entries.values_mut().flatten().for_each(|entry| entry.severity = Severity::High);
+
+        let plain = PlainTextRenderer::new(&source_units, false).render(&report);
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.starts_with("high[]: empty contract\n"));
+
+        let colored = PlainTextRenderer::new(&source_units, true).render(&report);
+        assert!(colored.starts_with(format!("{BOLD}{RED}high[]{RESET}{BOLD}: empty contract{RESET}\n").as_str()));
+        assert!(colored.contains(format!("{BOLD}{RED}{}{RESET}", "^".repeat(13)).as_str()));
+
+        // Stripping the escape codes yields the uncolored output
+        let stripped = [RESET, BOLD, RED, GREEN, YELLOW, BLUE, CYAN]
+            .iter()
+            .fold(colored.clone(), |output, code| output.replace(code, ""));
+        assert_eq!(stripped, plain);
+    }
+}
@@ -0,0 +1,151 @@
+use super::{Artifact, Project, ProjectKind, ProjectLoader};
+use crate::errors::LoaderError;
+use serde::{Deserialize, Serialize};
+use solidity::ast::*;
+use std::path::Path;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Compiler {
+    pub version: Option<String>,
+    // TODO: evm_version
+    // TODO: optimizer
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct File {
+    pub abi: Option<serde_json::Value>,
+    // TODO: all_source_paths
+    pub ast: Option<SourceUnit>,
+    pub bytecode: Option<String>,
+    // TODO: bytecode_sha1
+    pub compiler: Option<Compiler>,
+    pub contract_name: Option<String>,
+    // TODO: coverage_map
+    // TODO: dependencies
+    pub deployed_bytecode: Option<String>,
+    pub deployed_source_map: Option<String>,
+    // TODO: language
+    // TODO: natspec
+    // TODO: offset
+    // TODO: opcodes
+    // TODO: pc_map
+    // TODO: sha1
+    pub source: Option<String>,
+    pub source_map: Option<String>,
+    pub source_path: Option<String>,
+    // TODO: type
+}
+
+pub struct BrownieLoader;
+
+impl ProjectLoader for BrownieLoader {
+    fn kind(&self) -> ProjectKind {
+        ProjectKind::Brownie
+    }
+
+    fn detect(&self, project_path: &Path) -> bool {
+        project_path.join("brownie-config.yaml").is_file()
+    }
+
+    fn load(&self, project_path: &Path) -> Result<Project, LoaderError> {
+        let mut project = Project {
+            kind: Some(self.kind()),
+            path: project_path.into(),
+            ..Default::default()
+        };
+
+        //
+        // TODO: load the brownie config and get the actual build paths
+        //
+
+        let build_paths = &[
+            project_path.join("build").join("contracts"),
+            project_path.join("build").join("interfaces"),
+        ];
+
+        for build_path in build_paths {
+            if !build_path.is_dir() {
+                return Err(LoaderError::BuildDirectoryNotFound { kind: self.kind(), path: build_path.clone() })
+            }
+
+            for path in super::artifact_paths(build_path)? {
+                let file: File = super::read_artifact(self.kind(), &path)?;
+
+                project.artifacts.push(Artifact {
+                    path,
+                    contract_name: file.contract_name,
+                    source_path: file.source_path,
+                    compiler_version: file.compiler.and_then(|compiler| compiler.version).map(|version| super::normalize_compiler_version(&version)),
+                    abi: file.abi,
+                    bytecode: file.bytecode,
+                    deployed_bytecode: file.deployed_bytecode,
+                });
+
+                if let Some(mut source_unit) = file.ast {
+                    if !project.contains_source_unit(source_unit.absolute_path.as_deref()) {
+                        source_unit.set_source(file.source);
+                        project.source_units.push(source_unit);
+                    }
+                }
+            }
+        }
+
+        Ok(project.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::TestProject;
+
+    const ARTIFACT: &str = r#"{
+        "contractName": "Token",
+        "abi": [],
+        "bytecode": "6080",
+        "deployedBytecode": "6081",
+        "source": "import \"interfaces/IToken.sol\";\n\ncontract Token is IToken {}\n",
+        "sourcePath": "contracts/Token.sol",
+        "ast": { "nodeType": "SourceUnit", "absolutePath": "contracts/Token.sol", "id": 1, "nodes": [], "src": "0:60:0" },
+        "compiler": { "version": "0.8.19+commit.7dd6d404" }
+    }"#;
+
+    const INTERFACE_ARTIFACT: &str = r#"{
+        "contractName": "IToken",
+        "abi": [],
+        "source": "interface IToken {}\n",
+        "sourcePath": "interfaces/IToken.sol",
+        "ast": { "nodeType": "SourceUnit", "absolutePath": "interfaces/IToken.sol", "id": 2, "nodes": [], "src": "0:20:1" }
+    }"#;
+
+    #[test]
+    fn test_load() {
+        let project = TestProject::new("brownie", &[
+            ("brownie-config.yaml", ""),
+            ("build/contracts/Token.json", ARTIFACT),
+            ("build/interfaces/IToken.json", INTERFACE_ARTIFACT),
+        ]);
+
+        assert!(BrownieLoader.detect(&project.path));
+
+        let loaded_project = BrownieLoader.load(&project.path).unwrap();
+
+        let paths: Vec<_> = loaded_project.source_units.iter().map(|source_unit| source_unit.absolute_path.as_deref().unwrap()).collect();
+        assert_eq!(paths, vec!["contracts/Token.sol", "interfaces/IToken.sol"]);
+        assert_eq!(loaded_project.source_units[1].source(), Some("interface IToken {}\n"));
+
+        let contract_names: Vec<_> = loaded_project.artifacts.iter().map(|artifact| artifact.contract_name.as_deref().unwrap()).collect();
+        assert_eq!(contract_names, vec!["Token", "IToken"]);
+        assert_eq!(loaded_project.compiler_version.as_deref(), Some("0.8.19"));
+
+        // Both the contracts and interfaces build directories are required
+        let project = TestProject::new("brownie-unbuilt", &[
+            ("brownie-config.yaml", ""),
+            ("build/contracts/Token.json", ARTIFACT),
+        ]);
+
+        assert!(matches!(BrownieLoader.load(&project.path), Err(LoaderError::BuildDirectoryNotFound { .. })));
+    }
+}
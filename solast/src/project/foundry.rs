@@ -0,0 +1,358 @@
+use super::{Artifact, Project, ProjectKind, ProjectLoader};
+use crate::errors::LoaderError;
+use serde::{Deserialize, Serialize};
+use solidity::ast::*;
+use std::{collections::HashMap, env, io, path::{Path, PathBuf}};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    pub object: Option<String>,
+    // TODO: link_references
+    // TODO: source_map
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Compiler {
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    pub compiler: Option<Compiler>,
+    // TODO: language
+    // TODO: output
+    // TODO: settings
+    // TODO: sources
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct File {
+    pub abi: Option<serde_json::Value>,
+    pub bytecode: Option<Bytecode>,
+    pub deployed_bytecode: Option<Bytecode>,
+    // TODO: method_identifiers
+    pub metadata: Option<Metadata>,
+    pub ast: SourceUnit,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Profile {
+    pub src: Option<String>,
+    pub out: Option<String>,
+    pub libs: Option<Vec<String>>,
+    pub remappings: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ConfigFile {
+    #[serde(default)]
+    pub profile: HashMap<String, Profile>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Remapping {
+    pub context: Option<String>,
+    pub prefix: String,
+    pub path: String,
+}
+
+impl TryFrom<&str> for Remapping {
+    type Error = io::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (lhs, path) = value.trim().split_once('=').ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid remapping: \"{value}\""))
+        })?;
+
+        let (context, prefix) = match lhs.split_once(':') {
+            Some((context, prefix)) => (Some(context.to_string()), prefix),
+            None => (None, lhs),
+        };
+
+        if prefix.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Invalid remapping: \"{value}\"")));
+        }
+
+        Ok(Self {
+            context,
+            prefix: prefix.to_string(),
+            path: path.to_string(),
+        })
+    }
+}
+
+impl Remapping {
+    /// Loads the remappings from a `remappings.txt` file, which contains one remapping per line.
+    pub fn load_file(path: &Path) -> Result<Vec<Self>, LoaderError> {
+        let mut remappings = vec![];
+
+        for line in std::fs::read_to_string(path).map_err(LoaderError::io(path))?.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            remappings.push(
+                Remapping::try_from(line)
+                    .map_err(|e| LoaderError::InvalidConfig { path: path.into(), message: e.to_string() })?
+            );
+        }
+
+        Ok(remappings)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub src: PathBuf,
+    pub out: PathBuf,
+    pub libs: Vec<PathBuf>,
+    pub remappings: Vec<Remapping>,
+}
+
+impl Config {
+    pub const DEFAULT_PROFILE: &'static str = "default";
+
+    pub fn load(project_path: &Path) -> Result<Self, LoaderError> {
+        let config_path = project_path.join("foundry.toml");
+
+        let config_file: ConfigFile = toml::from_str(std::fs::read_to_string(&config_path).map_err(LoaderError::io(&config_path))?.as_str())
+            .map_err(|e| LoaderError::InvalidConfig { path: config_path.clone(), message: e.to_string() })?;
+
+        let profile_name = env::var("FOUNDRY_PROFILE")
+            .ok()
+            .filter(|profile_name| !profile_name.is_empty())
+            .unwrap_or_else(|| Self::DEFAULT_PROFILE.to_string());
+
+        //
+        // Named profiles inherit any unset values from the default profile
+        //
+
+        let default_profile = config_file.profile.get(Self::DEFAULT_PROFILE).cloned().unwrap_or_default();
+
+        let profile = match config_file.profile.get(&profile_name) {
+            Some(profile) => profile.clone(),
+            None if profile_name == Self::DEFAULT_PROFILE => Profile::default(),
+            None => return Err(LoaderError::InvalidConfig { path: config_path, message: format!("profile not found: {profile_name}") }),
+        };
+
+        let mut remappings = vec![];
+
+        for remapping in profile.remappings.or(default_profile.remappings).unwrap_or_default() {
+            remappings.push(
+                Remapping::try_from(remapping.as_str())
+                    .map_err(|e| LoaderError::InvalidConfig { path: config_path.clone(), message: e.to_string() })?
+            );
+        }
+
+        //
+        // Foundry also picks up remappings from `remappings.txt`, with `foundry.toml` taking precedence
+        //
+
+        let remappings_path = project_path.join("remappings.txt");
+
+        if remappings_path.is_file() {
+            for remapping in Remapping::load_file(&remappings_path)? {
+                if !remappings.iter().any(|existing| existing.context == remapping.context && existing.prefix == remapping.prefix) {
+                    remappings.push(remapping);
+                }
+            }
+        }
+
+        Ok(Self {
+            src: Self::relative_path(profile.src.or(default_profile.src).unwrap_or_else(|| "src".to_string())),
+            out: Self::relative_path(profile.out.or(default_profile.out).unwrap_or_else(|| "out".to_string())),
+            libs: profile.libs.or(default_profile.libs).unwrap_or_else(|| vec!["lib".to_string()]).into_iter().map(Self::relative_path).collect(),
+            remappings,
+        })
+    }
+
+    fn relative_path(path: String) -> PathBuf {
+        PathBuf::from(path.trim_start_matches("./"))
+    }
+
+    /// Determines if the supplied source path belongs to a dependency rather than the project itself.
+    /// Sources under `libs` or reached through a remapping are dependencies unless they live under `src`.
+    pub fn is_library_path(&self, path: &str) -> bool {
+        let path = Path::new(path.trim_start_matches("./"));
+
+        if path.starts_with(&self.src) {
+            return false;
+        }
+
+        self.libs.iter().any(|lib| path.starts_with(lib))
+            || self.remappings.iter().any(|remapping| !remapping.path.is_empty() && path.starts_with(remapping.path.trim_start_matches("./")))
+    }
+}
+
+pub struct FoundryLoader;
+
+impl ProjectLoader for FoundryLoader {
+    fn kind(&self) -> ProjectKind {
+        ProjectKind::Foundry
+    }
+
+    fn detect(&self, project_path: &Path) -> bool {
+        project_path.join("foundry.toml").is_file()
+    }
+
+    fn load(&self, project_path: &Path) -> Result<Project, LoaderError> {
+        let mut project = Project {
+            kind: Some(self.kind()),
+            path: project_path.into(),
+            ..Default::default()
+        };
+
+        let config = Config::load(project_path)?;
+        let build_path = project_path.join(&config.out);
+
+        if !build_path.is_dir() {
+            return Err(LoaderError::BuildDirectoryNotFound { kind: self.kind(), path: build_path })
+        }
+
+        let mut paths = vec![];
+
+        for path in std::fs::read_dir(&build_path).map_err(LoaderError::io(&build_path))? {
+            paths.push(path.map_err(LoaderError::io(&build_path))?.path());
+        }
+
+        paths.sort();
+
+        for path in paths {
+            //
+            // Skip non-artifact directories (i.e: `build-info`)
+            //
+
+            if !path.is_dir() || !path.extension().map(|extension| extension == "sol").unwrap_or(false) {
+                continue;
+            }
+
+            for path in super::artifact_paths(&path)? {
+                let mut file: File = super::read_artifact(self.kind(), &path)?;
+                let absolute_path = file.ast.absolute_path.clone().unwrap_or_default();
+
+                project.artifacts.push(Artifact {
+                    contract_name: path.file_stem().map(|file_stem| file_stem.to_string_lossy().to_string()),
+                    path,
+                    source_path: Some(absolute_path.clone()),
+                    compiler_version: file.metadata
+                        .and_then(|metadata| metadata.compiler)
+                        .and_then(|compiler| compiler.version)
+                        .map(|version| super::normalize_compiler_version(&version)),
+                    abi: file.abi,
+                    bytecode: file.bytecode.and_then(|bytecode| bytecode.object),
+                    deployed_bytecode: file.deployed_bytecode.and_then(|bytecode| bytecode.object),
+                });
+
+                if !project.contains_source_unit(file.ast.absolute_path.as_deref()) {
+                    if config.is_library_path(absolute_path.as_str()) {
+                        project.library_paths.insert(absolute_path.clone());
+                    }
+
+                    file.ast.set_source(Some(super::read_source_file(Some(self.kind()), &project_path.join(absolute_path))?));
+                    project.source_units.push(file.ast);
+                }
+            }
+        }
+
+        Ok(project.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::TestProject;
+    use std::collections::HashSet;
+
+    const ARTIFACT: &str = r#"{
+        "abi": [],
+        "bytecode": { "object": "0x6080" },
+        "deployedBytecode": { "object": "0x6080" },
+        "metadata": { "compiler": { "version": "0.8.19+commit.7dd6d404" } },
+        "ast": { "nodeType": "SourceUnit", "absolutePath": "src/Token.sol", "id": 1, "nodes": [], "src": "0:0:0" }
+    }"#;
+
+    const LIBRARY_ARTIFACT: &str = r#"{
+        "abi": [],
+        "ast": { "nodeType": "SourceUnit", "absolutePath": "lib/oz/Ownable.sol", "id": 2, "nodes": [], "src": "0:0:0" }
+    }"#;
+
+    #[test]
+    fn test_parse_remapping() {
+        assert_eq!(Remapping::try_from("@oz/=lib/oz/").unwrap(), Remapping {
+            context: None,
+            prefix: "@oz/".to_string(),
+            path: "lib/oz/".to_string(),
+        });
+
+        assert_eq!(Remapping::try_from(" src/:@oz/=lib/oz/ ").unwrap(), Remapping {
+            context: Some("src/".to_string()),
+            prefix: "@oz/".to_string(),
+            path: "lib/oz/".to_string(),
+        });
+
+        assert!(Remapping::try_from("@oz/").is_err());
+        assert!(Remapping::try_from("=lib/oz/").is_err());
+    }
+
+    #[test]
+    fn test_load_config() {
+        let project = TestProject::new("foundry-config", &[
+            ("foundry.toml", "[profile.default]\nsrc = \"./contracts\"\nremappings = [\"@oz/=lib/oz/\"]\n"),
+            ("remappings.txt", "# Comment\n@oz/=node_modules/oz/\nsolmate/=lib/solmate/src/\n"),
+        ]);
+
+        let config = Config::load(&project.path).unwrap();
+
+        assert_eq!(config.src, PathBuf::from("contracts"));
+        assert_eq!(config.out, PathBuf::from("out"));
+        assert_eq!(config.libs, vec![PathBuf::from("lib")]);
+
+        // Remappings from `foundry.toml` take precedence over those of `remappings.txt`
+        assert_eq!(config.remappings, vec![
+            Remapping::try_from("@oz/=lib/oz/").unwrap(),
+            Remapping::try_from("solmate/=lib/solmate/src/").unwrap(),
+        ]);
+
+        assert!(!config.is_library_path("contracts/Token.sol"));
+        assert!(config.is_library_path("./lib/forge-std/src/Test.sol"));
+
+        let project = TestProject::new("foundry-invalid-config", &[("foundry.toml", "[profile.default]\nremappings = [\"@oz/\"]\n")]);
+        assert!(matches!(Config::load(&project.path), Err(LoaderError::InvalidConfig { .. })));
+    }
+
+    #[test]
+    fn test_load() {
+        let project = TestProject::new("foundry", &[
+            ("foundry.toml", ""),
+            ("src/Token.sol", "contract Token {}\n"),
+            ("lib/oz/Ownable.sol", "contract Ownable {}\n"),
+            ("out/Token.sol/Token.json", ARTIFACT),
+            ("out/Ownable.sol/Ownable.json", LIBRARY_ARTIFACT),
+            ("out/build-info/0123.json", "{}"),
+        ]);
+
+        assert!(FoundryLoader.detect(&project.path));
+
+        let loaded_project = FoundryLoader.load(&project.path).unwrap();
+
+        let paths: Vec<_> = loaded_project.source_units.iter().map(|source_unit| source_unit.absolute_path.as_deref().unwrap()).collect();
+        assert_eq!(paths, vec!["lib/oz/Ownable.sol", "src/Token.sol"]);
+        assert_eq!(loaded_project.source_units[1].source(), Some("contract Token {}\n"));
+        assert_eq!(loaded_project.library_paths, HashSet::from(["lib/oz/Ownable.sol".to_string()]));
+
+        let artifact = loaded_project.artifacts.iter().find(|artifact| artifact.contract_name.as_deref() == Some("Token")).unwrap();
+        assert_eq!(artifact.source_path.as_deref(), Some("src/Token.sol"));
+        assert_eq!(loaded_project.compiler_version.as_deref(), Some("0.8.19"));
+
+        let project = TestProject::new("foundry-unbuilt", &[("foundry.toml", "")]);
+        assert!(matches!(FoundryLoader.load(&project.path), Err(LoaderError::BuildDirectoryNotFound { .. })));
+    }
+}
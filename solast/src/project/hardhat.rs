@@ -0,0 +1,126 @@
+use super::{
+    standard_json::{Input, Output},
+    Project, ProjectKind, ProjectLoader,
+};
+use crate::errors::LoaderError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct File {
+    pub id: String,
+    #[serde(rename = "_format")]
+    pub format: String,
+    pub solc_version: String,
+    pub solc_long_version: String,
+    pub input: Input,
+    pub output: Output,
+}
+
+pub struct HardhatLoader;
+
+impl ProjectLoader for HardhatLoader {
+    fn kind(&self) -> ProjectKind {
+        ProjectKind::Hardhat
+    }
+
+    fn detect(&self, project_path: &Path) -> bool {
+        project_path.join("hardhat.config.js").is_file() || project_path.join("hardhat.config.ts").is_file()
+    }
+
+    fn load(&self, project_path: &Path) -> Result<Project, LoaderError> {
+        let mut project = Project {
+            kind: Some(self.kind()),
+            path: project_path.into(),
+            ..Default::default()
+        };
+
+        let build_path = project_path.join("artifacts").join("build-info");
+
+        if !build_path.is_dir() {
+            return Err(LoaderError::BuildDirectoryNotFound { kind: self.kind(), path: build_path })
+        }
+
+        let console_path = PathBuf::new()
+            .join("hardhat")
+            .join("console.sol")
+            .to_string_lossy()
+            .to_string();
+
+        for path in super::artifact_paths(&build_path)? {
+            let file: File = super::read_artifact(self.kind(), &path)?;
+            let compiler_version = super::normalize_compiler_version(&file.solc_version);
+
+            file.output.load_into(&mut project, &path, &file.input, Some(compiler_version), |source_path| {
+                source_path.ends_with(console_path.as_str())
+            })?;
+        }
+
+        Ok(project.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::TestProject;
+
+    const BUILD_INFO: &str = r#"{
+        "id": "0123",
+        "_format": "hh-sol-build-info-1",
+        "solcVersion": "0.8.19",
+        "solcLongVersion": "0.8.19+commit.7dd6d404",
+        "input": {
+            "language": "Solidity",
+            "sources": {
+                "contracts/Token.sol": { "content": "contract Token {}\n" },
+                "hardhat/console.sol": { "content": "library console {}\n" }
+            }
+        },
+        "output": {
+            "contracts": {
+                "contracts/Token.sol": {
+                    "Token": { "abi": [], "evm": { "bytecode": { "object": "6080" }, "deployedBytecode": { "object": "6081" } } }
+                },
+                "hardhat/console.sol": {
+                    "console": { "abi": [] }
+                }
+            },
+            "sources": {
+                "contracts/Token.sol": {
+                    "id": 0,
+                    "ast": { "nodeType": "SourceUnit", "absolutePath": "contracts/Token.sol", "id": 1, "nodes": [], "src": "0:18:0" }
+                },
+                "hardhat/console.sol": {
+                    "id": 1,
+                    "ast": { "nodeType": "SourceUnit", "absolutePath": "hardhat/console.sol", "id": 2, "nodes": [], "src": "0:19:1" }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_load() {
+        let project = TestProject::new("hardhat", &[
+            ("hardhat.config.js", ""),
+            ("artifacts/build-info/0123.json", BUILD_INFO),
+        ]);
+
+        assert!(HardhatLoader.detect(&project.path));
+
+        // The `console.sol` library of hardhat is not part of the project
+        let loaded_project = HardhatLoader.load(&project.path).unwrap();
+
+        assert_eq!(loaded_project.source_units.len(), 1);
+        assert_eq!(loaded_project.source_units[0].absolute_path.as_deref(), Some("contracts/Token.sol"));
+        assert_eq!(loaded_project.source_units[0].source(), Some("contract Token {}\n"));
+
+        assert_eq!(loaded_project.artifacts.len(), 1);
+        assert_eq!(loaded_project.artifacts[0].contract_name.as_deref(), Some("Token"));
+        assert_eq!(loaded_project.compiler_version.as_deref(), Some("0.8.19"));
+
+        let project = TestProject::new("hardhat-unbuilt", &[("hardhat.config.ts", "")]);
+        assert!(matches!(HardhatLoader.load(&project.path), Err(LoaderError::BuildDirectoryNotFound { .. })));
+    }
+}
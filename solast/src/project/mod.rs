@@ -0,0 +1,320 @@
+pub mod brownie;
+pub mod foundry;
+pub mod hardhat;
+pub mod sources;
+pub mod standard_json;
+pub mod truffle;
+
+use crate::errors::LoaderError;
+use serde::de::DeserializeOwned;
+use solidity::ast::*;
+use std::{collections::HashSet, fmt::Display, fs::File, io, path::{Path, PathBuf}};
+
+pub use self::{
+    brownie::BrownieLoader, foundry::FoundryLoader, hardhat::HardhatLoader, sources::ImportResolver,
+    standard_json::StandardJsonLoader, truffle::TruffleLoader,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProjectKind {
+    Brownie,
+    Hardhat,
+    Truffle,
+    Foundry,
+    StandardJson,
+}
+
+impl ProjectKind {
+    pub fn build_command(&self) -> &'static str {
+        match self {
+            ProjectKind::Brownie => "brownie compile",
+            ProjectKind::Hardhat => "npx hardhat compile",
+            ProjectKind::Truffle => "truffle compile",
+            ProjectKind::Foundry => "forge build",
+            ProjectKind::StandardJson => "solc --standard-json",
+        }
+    }
+}
+
+impl Display for ProjectKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectKind::StandardJson => write!(f, "Standard JSON"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// A compiled contract artifact produced by a project's build system.
+#[derive(Clone, Debug, Default)]
+pub struct Artifact {
+    pub path: PathBuf,
+    pub contract_name: Option<String>,
+    pub source_path: Option<String>,
+    pub compiler_version: Option<String>,
+    pub abi: Option<serde_json::Value>,
+    pub bytecode: Option<String>,
+    pub deployed_bytecode: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Project {
+    pub kind: Option<ProjectKind>,
+    pub path: PathBuf,
+    pub source_units: Vec<SourceUnit>,
+    pub compiler_version: Option<String>,
+    pub artifacts: Vec<Artifact>,
+    /// The absolute paths of source units which belong to dependencies rather than the project itself.
+    pub library_paths: HashSet<String>,
+}
+
+impl Project {
+    /// Detects the type of project in the supplied directory and loads it with the matching loader.
+    pub fn load<P: AsRef<Path>>(project_path: P) -> Result<Self, LoaderError> {
+        let project_path = project_path.as_ref();
+
+        if !project_path.is_dir() {
+            return Err(LoaderError::ProjectNotFound { path: project_path.into() })
+        }
+
+        detect(project_path)?.load(project_path)
+    }
+
+    pub fn contains_source_unit(&self, absolute_path: Option<&str>) -> bool {
+        self.source_units.iter().any(|source_unit| source_unit.absolute_path.as_deref() == absolute_path)
+    }
+
+    /// Sorts the project's source units and artifacts by path and determines the project's compiler version.
+    pub(crate) fn finish(mut self) -> Self {
+        self.source_units.sort_by(|lhs, rhs| {
+            let lhs = lhs.absolute_path.as_deref().unwrap_or("");
+            let rhs = rhs.absolute_path.as_deref().unwrap_or("");
+            lhs.cmp(rhs)
+        });
+
+        self.artifacts.sort_by(|lhs, rhs| lhs.path.cmp(&rhs.path).then_with(|| lhs.contract_name.cmp(&rhs.contract_name)));
+
+        if self.compiler_version.is_none() {
+            self.compiler_version = self.artifacts.iter().find_map(|artifact| artifact.compiler_version.clone());
+        }
+
+        self
+    }
+}
+
+pub trait ProjectLoader {
+    fn kind(&self) -> ProjectKind;
+
+    /// Determines if the supplied directory contains a project supported by the loader.
+    fn detect(&self, project_path: &Path) -> bool;
+
+    /// Loads the source units and artifacts of the compiled project in the supplied directory.
+    fn load(&self, project_path: &Path) -> Result<Project, LoaderError>;
+}
+
+pub const PROJECT_LOADERS: &[&dyn ProjectLoader] = &[
+    &BrownieLoader,
+    &HardhatLoader,
+    &TruffleLoader,
+    &FoundryLoader,
+];
+
+/// Determines which loader supports the project in the supplied directory.
+pub fn detect(project_path: &Path) -> Result<&'static dyn ProjectLoader, LoaderError> {
+    PROJECT_LOADERS
+        .iter()
+        .copied()
+        .find(|loader| loader.detect(project_path))
+        .ok_or_else(|| LoaderError::UnknownProjectType { path: project_path.into() })
+}
+
+pub fn parse_source_file(builder: &mut AstBuilder, path: &Path, file_no: usize) -> Result<SourceUnit, LoaderError> {
+    let src = read_source_file(None, path)?;
+
+    let (source_unit, comments) = solang_parser::parse(src.as_str(), file_no)
+        .map_err(|diagnostics| LoaderError::SourceParseFailed {
+            path: path.into(),
+            errors: diagnostics.into_iter().map(|diagnostic| diagnostic.message).collect(),
+        })?;
+
+    let mut source_unit = builder.build_source_unit(&source_unit);
+
+    let line_table = LineTable::new(src.as_str());
+
+    let describe = |error: &AstBuilderError| match SourceLocation::try_from(error.src.as_str()) {
+        Ok(location) => {
+            let range = line_table.range(src.as_str(), location);
+            format!("{} at line {}, column {}", error.message, range.start_line, range.start_column)
+        }
+
+        Err(_) => error.to_string(),
+    };
+
+    //
+    // Unsupported top-level items are skipped without affecting the rest of the source unit
+    //
+
+    for warning in builder.take_warnings() {
+        eprintln!("WARNING: {}: {}", path.to_string_lossy(), describe(&warning));
+    }
+
+    //
+    // Constructs which can not be represented in the AST would produce incomplete analysis results
+    //
+
+    let errors = builder.take_errors();
+
+    if !errors.is_empty() {
+        return Err(LoaderError::SourceParseFailed {
+            path: path.into(),
+            errors: errors.iter().map(describe).collect(),
+        });
+    }
+
+    let mut license = None;
+
+    for comment in comments.iter() {
+        if let solang_parser::pt::Comment::Line(_, text) = comment {
+            let text = text.trim_start_matches("//").trim_start_matches(' ');
+            if text.starts_with("SPDX-License-Identifier:") {
+                license = Some(text.trim_start_matches("SPDX-License-Identifier:").trim_start_matches(' ').to_string());
+            }
+        }
+    }
+
+    source_unit.absolute_path = Some(path.to_string_lossy().to_string());
+    source_unit.set_source(Some(src));
+    source_unit.license = license;
+
+    Ok(source_unit)
+}
+
+pub(crate) fn read_source_file(kind: Option<ProjectKind>, path: &Path) -> Result<String, LoaderError> {
+    std::fs::read_to_string(path).map_err(|error| match error.kind() {
+        io::ErrorKind::NotFound => LoaderError::SourceFileNotFound { kind, path: path.into() },
+        _ => LoaderError::Io { path: path.into(), error },
+    })
+}
+
+/// Collects the paths of all `.json` artifact files in the supplied build directory.
+pub(crate) fn artifact_paths(build_path: &Path) -> Result<Vec<PathBuf>, LoaderError> {
+    let mut result = vec![];
+
+    for path in std::fs::read_dir(build_path).map_err(LoaderError::io(build_path))? {
+        let path = path.map_err(LoaderError::io(build_path))?.path();
+
+        if path.is_file() && path.extension().map(|extension| extension == "json").unwrap_or(false) {
+            result.push(path);
+        }
+    }
+
+    result.sort();
+
+    Ok(result)
+}
+
+pub(crate) fn read_artifact<T: DeserializeOwned>(kind: ProjectKind, path: &Path) -> Result<T, LoaderError> {
+    let file = File::open(path).map_err(LoaderError::io(path))?;
+
+    simd_json::from_reader(file).map_err(|e| LoaderError::MalformedArtifact {
+        kind,
+        path: path.into(),
+        message: e.to_string(),
+    })
+}
+
+/// Strips any build metadata from a compiler version, i.e: `0.8.13+commit.abaa5c0e` becomes `0.8.13`.
+pub(crate) fn normalize_compiler_version(version: &str) -> String {
+    version.trim_start_matches('v').split('+').next().unwrap_or(version).to_string()
+}
+
+/// A temporary directory containing the files of a project, which is removed when dropped.
+#[cfg(test)]
+pub(crate) struct TestProject {
+    pub path: PathBuf,
+}
+
+#[cfg(test)]
+impl TestProject {
+    pub fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let path = std::env::temp_dir().join(format!("solast-{name}-{}", std::process::id()));
+
+        if path.exists() {
+            std::fs::remove_dir_all(&path).unwrap();
+        }
+
+        std::fs::create_dir_all(&path).unwrap();
+
+        for (file_path, contents) in files {
+            let file_path = path.join(file_path);
+            std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+            std::fs::write(file_path, contents).unwrap();
+        }
+
+        Self { path }
+    }
+}
+
+#[cfg(test)]
+impl Drop for TestProject {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.path).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_compiler_version() {
+        assert_eq!(normalize_compiler_version("0.8.13+commit.abaa5c0e"), "0.8.13");
+        assert_eq!(normalize_compiler_version("v0.8.19+commit.7dd6d404.Linux.g++"), "0.8.19");
+        assert_eq!(normalize_compiler_version("0.7.6"), "0.7.6");
+    }
+
+    #[test]
+    fn test_detect() {
+        let project = TestProject::new("detect-foundry", &[("foundry.toml", "")]);
+        assert_eq!(detect(&project.path).unwrap().kind(), ProjectKind::Foundry);
+
+        let project = TestProject::new("detect-hardhat", &[("hardhat.config.ts", "")]);
+        assert_eq!(detect(&project.path).unwrap().kind(), ProjectKind::Hardhat);
+
+        let project = TestProject::new("detect-unknown", &[("README.md", "")]);
+        assert!(matches!(Project::load(&project.path), Err(LoaderError::UnknownProjectType { .. })));
+        assert!(matches!(Project::load(project.path.join("missing")), Err(LoaderError::ProjectNotFound { .. })));
+    }
+
+    #[test]
+    fn test_parse_source_file() {
+        let project = TestProject::new("parse-source-file", &[
+            ("Token.sol", "// SPDX-License-Identifier: MIT\npragma solidity ^0.8.0;\n\ncontract Token {}\n"),
+            ("Free.sol", "pragma solidity ^0.8.0;\n\nfunction free() pure {}\n\ncontract Token {}\n"),
+            ("Invalid.sol", "contract Invalid {\n"),
+        ]);
+
+        let mut builder = AstBuilder::default();
+
+        let path = project.path.join("Token.sol");
+        let source_unit = parse_source_file(&mut builder, &path, 0).unwrap();
+        assert_eq!(source_unit.license.as_deref(), Some("MIT"));
+        assert_eq!(source_unit.absolute_path, Some(path.to_string_lossy().to_string()));
+        assert_eq!(source_unit.contract_definitions()[0].name, "Token");
+        assert!(source_unit.source().unwrap().contains("contract Token {}"));
+
+        // Unsupported top-level items are skipped
+        let source_unit = parse_source_file(&mut builder, &project.path.join("Free.sol"), 1).unwrap();
+        assert_eq!(source_unit.contract_definitions()[0].name, "Token");
+
+        assert!(matches!(
+            parse_source_file(&mut builder, &project.path.join("Invalid.sol"), 2),
+            Err(LoaderError::SourceParseFailed { errors, .. }) if !errors.is_empty()
+        ));
+
+        assert!(matches!(
+            parse_source_file(&mut builder, &project.path.join("Missing.sol"), 3),
+            Err(LoaderError::SourceFileNotFound { kind: None, .. })
+        ));
+    }
+}
@@ -0,0 +1,248 @@
+use super::{foundry::Remapping, parse_source_file, Project};
+use crate::errors::LoaderError;
+use eth_lang_utils::ast::*;
+use solidity::ast::*;
+use std::{
+    collections::{HashMap, VecDeque},
+    path::{Path, PathBuf},
+};
+
+/// Locates the files imported by Solidity source files which are analyzed without a build system.
+///
+/// Imports are resolved the same way solc resolves them: relative imports are resolved from the directory of the
+/// importing file, remappings are applied, and the resulting path is looked up in the base path followed by each
+/// include path.
+#[derive(Clone, Debug, Default)]
+pub struct ImportResolver {
+    pub base_path: PathBuf,
+    pub include_paths: Vec<PathBuf>,
+    pub remappings: Vec<Remapping>,
+}
+
+/// The location of an imported file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedImport {
+    pub path: PathBuf,
+    /// Whether the file was located through a remapping or an include path, i.e: it belongs to a dependency.
+    pub is_library: bool,
+}
+
+impl ImportResolver {
+    /// Adds the remappings from `remappings.txt` in the base path, if it exists.
+    /// Remappings which have already been supplied take precedence.
+    pub fn load_remappings_file(&mut self) -> Result<(), LoaderError> {
+        let remappings_path = self.base_path.join("remappings.txt");
+
+        if !remappings_path.is_file() {
+            return Ok(());
+        }
+
+        for remapping in Remapping::load_file(&remappings_path)? {
+            if !self.remappings.iter().any(|existing| existing.context == remapping.context && existing.prefix == remapping.prefix) {
+                self.remappings.push(remapping);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies the best matching remapping to an import path. Remappings with the longest matching context are preferred,
+    /// followed by those with the longest matching prefix. The last remapping wins if several match equally well.
+    pub fn remap(&self, importer: &str, import: &str) -> Option<String> {
+        self.remappings.iter()
+            .filter(|remapping| remapping.context.as_deref().map(|context| importer.starts_with(context)).unwrap_or(true))
+            .filter(|remapping| import.starts_with(remapping.prefix.as_str()))
+            .max_by_key(|remapping| (remapping.context.as_ref().map(|context| context.len()).unwrap_or(0), remapping.prefix.len()))
+            .map(|remapping| format!("{}{}", remapping.path, &import[remapping.prefix.len()..]))
+    }
+
+    /// Locates the file imported by the supplied import path from the supplied source file.
+    pub fn resolve(&self, importer: &Path, import: &str) -> Option<ResolvedImport> {
+        let importer_name = importer.to_string_lossy();
+
+        //
+        // Relative imports are resolved from the directory of the importing file before remappings are applied
+        //
+
+        let import = if import.starts_with("./") || import.starts_with("../") {
+            let directory = importer.parent().unwrap_or_else(|| Path::new(""));
+            normalize_path(&directory.join(import)).to_string_lossy().to_string()
+        } else {
+            import.to_string()
+        };
+
+        let (source_unit_name, is_remapped) = match self.remap(importer_name.as_ref(), import.as_str()) {
+            Some(remapped) => (remapped, true),
+            None => (import, false),
+        };
+
+        let path = normalize_path(&self.base_path.join(source_unit_name.as_str()));
+
+        if path.is_file() {
+            return Some(ResolvedImport { path, is_library: is_remapped });
+        }
+
+        self.include_paths.iter()
+            .map(|include_path| normalize_path(&include_path.join(source_unit_name.as_str())))
+            .find(|path| path.is_file())
+            .map(|path| ResolvedImport { path, is_library: true })
+    }
+}
+
+impl Project {
+    /// Parses the supplied Solidity source files, along with every file they import, into a project without a build system.
+    pub fn from_source_files<P: AsRef<Path>>(paths: &[P], import_resolver: &ImportResolver) -> Result<Self, LoaderError> {
+        let mut project = Project::default();
+
+        // Share a single builder so node IDs are unique across all of the source files
+        let mut builder = AstBuilder::default();
+
+        let mut pending: VecDeque<(PathBuf, bool)> = paths.iter()
+            .map(|path| (normalize_path(path.as_ref()), false))
+            .collect();
+
+        let mut source_unit_indices: HashMap<PathBuf, usize> = HashMap::new();
+        let mut imported_paths: HashMap<NodeID, PathBuf> = HashMap::new();
+
+        //
+        // Follow the imports of each source file until every imported file has been parsed
+        //
+
+        while let Some((path, is_library)) = pending.pop_front() {
+            let key = Self::source_file_key(&path);
+
+            if source_unit_indices.contains_key(&key) {
+                continue;
+            }
+
+            let source_unit = parse_source_file(&mut builder, &path, project.source_units.len())?;
+
+            for import_directive in source_unit.import_directives() {
+                let resolved_import = import_resolver.resolve(&path, import_directive.file.as_str())
+                    .ok_or_else(|| LoaderError::ImportNotFound { path: path.clone(), import: import_directive.file.clone() })?;
+
+                imported_paths.insert(import_directive.id, resolved_import.path.clone());
+
+                // Files imported by dependencies also belong to dependencies
+                pending.push_back((resolved_import.path, is_library || resolved_import.is_library));
+            }
+
+            if is_library {
+                project.library_paths.insert(path.to_string_lossy().to_string());
+            }
+
+            source_unit_indices.insert(key, project.source_units.len());
+            project.source_units.push(source_unit);
+        }
+
+        //
+        // Link each import directive to the source unit it imports
+        //
+
+        let source_units: Vec<(NodeID, Option<String>)> = project.source_units.iter()
+            .map(|source_unit| (source_unit.id, source_unit.absolute_path.clone()))
+            .collect();
+
+        for source_unit in project.source_units.iter_mut() {
+            for node in source_unit.nodes.iter_mut() {
+                let SourceUnitNode::ImportDirective(import_directive) = node else { continue };
+                let Some(path) = imported_paths.get(&import_directive.id) else { continue };
+                let Some(&index) = source_unit_indices.get(&Self::source_file_key(path)) else { continue };
+
+                import_directive.source_unit = source_units[index].0;
+                import_directive.absolute_path = source_units[index].1.clone();
+            }
+        }
+
+        resolve_references(project.source_units.as_mut_slice());
+        check_types(project.source_units.as_mut_slice());
+
+        compute_contract_metadata(project.source_units.as_mut_slice())
+            .map_err(|error| LoaderError::InvalidInheritance { error })?;
+
+        Ok(project)
+    }
+
+    /// Identifies a source file regardless of the path it was reached through.
+    fn source_file_key(path: &Path) -> PathBuf {
+        path.canonicalize().unwrap_or_else(|_| normalize_path(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::TestProject;
+
+    fn remapping(value: &str) -> Remapping {
+        Remapping::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn test_remap() {
+        let import_resolver = ImportResolver {
+            remappings: vec![
+                remapping("@oz/=lib/oz/"),
+                remapping("@oz/token/=lib/oz-token/"),
+                remapping("src/legacy:@oz/=lib/oz-legacy/"),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(import_resolver.remap("src/Token.sol", "@oz/Ownable.sol").as_deref(), Some("lib/oz/Ownable.sol"));
+        assert_eq!(import_resolver.remap("src/Token.sol", "@oz/token/ERC20.sol").as_deref(), Some("lib/oz-token/ERC20.sol"));
+        assert_eq!(import_resolver.remap("src/legacy/Token.sol", "@oz/token/ERC20.sol").as_deref(), Some("lib/oz-legacy/token/ERC20.sol"));
+        assert_eq!(import_resolver.remap("src/Token.sol", "./Base.sol"), None);
+    }
+
+    #[test]
+    fn test_from_source_files() {
+        let project = TestProject::new("sources", &[
+            ("remappings.txt", "@oz/=lib/oz/\n"),
+            ("src/Token.sol", "pragma solidity ^0.8.0;\n\nimport \"./Base.sol\";\nimport \"@oz/Ownable.sol\";\n\ncontract Token is Base, Ownable {\n    function mint() public {\n        value = 1;\n    }\n}\n"),
+            ("src/Base.sol", "pragma solidity ^0.8.0;\n\ncontract Base {\n    uint256 value;\n}\n"),
+            ("src/Broken.sol", "pragma solidity ^0.8.0;\n\nimport \"./Missing.sol\";\n"),
+            ("lib/oz/Ownable.sol", "pragma solidity ^0.8.0;\n\nimport \"./Context.sol\";\n\nabstract contract Ownable is Context {}\n"),
+            ("lib/oz/Context.sol", "pragma solidity ^0.8.0;\n\nabstract contract Context {}\n"),
+        ]);
+
+        let mut import_resolver = ImportResolver { base_path: project.path.clone(), ..Default::default() };
+        import_resolver.load_remappings_file().unwrap();
+        assert_eq!(import_resolver.remappings, vec![remapping("@oz/=lib/oz/")]);
+
+        let loaded_project = Project::from_source_files(&[project.path.join("src").join("Token.sol")], &import_resolver).unwrap();
+
+        let paths: Vec<_> = loaded_project.source_units.iter()
+            .map(|source_unit| source_unit.absolute_path.as_deref().unwrap().trim_start_matches(project.path.to_str().unwrap()))
+            .collect();
+
+        assert_eq!(paths, vec!["/src/Token.sol", "/src/Base.sol", "/lib/oz/Ownable.sol", "/lib/oz/Context.sol"]);
+
+        // Files imported through a remapping belong to dependencies, along with the files they import
+        let mut library_paths: Vec<_> = loaded_project.library_paths.iter().cloned().collect();
+        library_paths.sort();
+        assert_eq!(library_paths, vec![
+            project.path.join("lib").join("oz").join("Context.sol").to_string_lossy().to_string(),
+            project.path.join("lib").join("oz").join("Ownable.sol").to_string_lossy().to_string(),
+        ]);
+
+        let source_units = loaded_project.source_units.as_slice();
+
+        // References are resolved and metadata is computed across files
+        let token = source_units[0].contract_definitions()[0];
+        let base = source_units[1].contract_definitions()[0];
+        let ownable = source_units[2].contract_definitions()[0];
+        let context = source_units[3].contract_definitions()[0];
+
+        assert_eq!(token.linearized_base_contracts, Some(vec![token.id, ownable.id, context.id, base.id]));
+
+        let statements = &token.function_definitions()[0].body.as_ref().unwrap().statements;
+        let Statement::ExpressionStatement(ExpressionStatement { expression }) = &statements[0] else { panic!("expected an expression statement") };
+        assert_eq!(expression.referenced_declarations(), vec![base.variable_declarations()[0].id]);
+
+        assert!(matches!(
+            Project::from_source_files(&[project.path.join("src").join("Broken.sol")], &import_resolver),
+            Err(LoaderError::ImportNotFound { import, .. }) if import == "./Missing.sol"
+        ));
+    }
+}
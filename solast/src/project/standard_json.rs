@@ -0,0 +1,336 @@
+use super::{Artifact, Project, ProjectKind, ProjectLoader};
+use crate::errors::LoaderError;
+use serde::{Deserialize, Serialize};
+use solidity::ast::*;
+use std::{collections::HashMap, path::{Path, PathBuf}};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputSource {
+    pub content: Option<String>,
+    pub urls: Option<Vec<String>>,
+    // TODO: keccak256
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub language: String,
+    pub sources: HashMap<String, InputSource>,
+    #[serde(default)]
+    pub settings: serde_json::Value,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    pub object: Option<String>,
+    // TODO: link_references
+    // TODO: opcodes
+    // TODO: source_map
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Evm {
+    pub bytecode: Option<Bytecode>,
+    pub deployed_bytecode: Option<Bytecode>,
+    // TODO: method_identifiers
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputContract {
+    pub abi: Option<serde_json::Value>,
+    pub metadata: Option<String>,
+    pub evm: Option<Evm>,
+}
+
+impl OutputContract {
+    /// Reads the compiler version from the contract's metadata, if it was requested in the output selection.
+    pub fn compiler_version(&self) -> Option<String> {
+        let metadata: serde_json::Value = serde_json::from_str(self.metadata.as_deref()?).ok()?;
+
+        metadata.get("compiler")?
+            .get("version")?
+            .as_str()
+            .map(super::normalize_compiler_version)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputSource {
+    pub ast: SourceUnit,
+    pub id: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputError {
+    pub severity: String,
+    pub message: String,
+    pub formatted_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default)]
+    pub contracts: HashMap<String, HashMap<String, OutputContract>>,
+    #[serde(default)]
+    pub errors: Vec<OutputError>,
+    #[serde(default)]
+    pub sources: HashMap<String, OutputSource>,
+}
+
+impl Output {
+    /// Adds the output's artifacts and source units to the supplied project, skipping any sources matched by `skip_source`.
+    pub(crate) fn load_into<F: Fn(&str) -> bool>(
+        self,
+        project: &mut Project,
+        path: &Path,
+        input: &Input,
+        compiler_version: Option<String>,
+        skip_source: F,
+    ) -> Result<(), LoaderError> {
+        for (source_path, contracts) in self.contracts {
+            if skip_source(source_path.as_str()) {
+                continue;
+            }
+
+            for (contract_name, contract) in contracts {
+                let compiler_version = compiler_version.clone().or_else(|| contract.compiler_version());
+                let evm = contract.evm;
+
+                project.artifacts.push(Artifact {
+                    path: path.into(),
+                    contract_name: Some(contract_name),
+                    source_path: Some(source_path.clone()),
+                    compiler_version,
+                    abi: contract.abi,
+                    bytecode: evm.as_ref().and_then(|evm| evm.bytecode.as_ref()).and_then(|bytecode| bytecode.object.clone()),
+                    deployed_bytecode: evm.as_ref().and_then(|evm| evm.deployed_bytecode.as_ref()).and_then(|bytecode| bytecode.object.clone()),
+                });
+            }
+        }
+
+        for (source_path, source) in self.sources {
+            let mut source_unit = source.ast;
+
+            if skip_source(source_unit.absolute_path.as_deref().unwrap_or("")) {
+                continue;
+            }
+
+            if project.contains_source_unit(source_unit.absolute_path.as_deref()) {
+                continue;
+            }
+
+            //
+            // Sources supplied by URL are read from disk relative to the project path
+            //
+
+            let source = match input.sources.get(&source_path) {
+                Some(InputSource { content: Some(content), .. }) => Some(content.clone()),
+
+                Some(InputSource { content: None, urls }) => {
+                    let source_file_path = urls.iter()
+                        .flatten()
+                        .map(|url| project.path.join(url))
+                        .find(|path| path.is_file())
+                        .unwrap_or_else(|| project.path.join(&source_path));
+
+                    Some(super::read_source_file(project.kind, &source_file_path)?)
+                }
+
+                None => continue,
+            };
+
+            source_unit.set_source(source);
+            project.source_units.push(source_unit);
+        }
+
+        if project.compiler_version.is_none() {
+            project.compiler_version = compiler_version;
+        }
+
+        Ok(())
+    }
+}
+
+/// Loads a project from a pair of `solc --standard-json` input and output files.
+/// Relative paths are resolved against the project path, which is also the base path for sources supplied by URL.
+#[derive(Clone, Debug)]
+pub struct StandardJsonLoader {
+    pub input_path: PathBuf,
+    pub output_path: PathBuf,
+}
+
+impl StandardJsonLoader {
+    pub fn new<P1: Into<PathBuf>, P2: Into<PathBuf>>(input_path: P1, output_path: P2) -> Self {
+        Self {
+            input_path: input_path.into(),
+            output_path: output_path.into(),
+        }
+    }
+}
+
+/// Determines if the colon at the supplied index follows the drive letter of a Windows path, i.e: `C:\`.
+fn is_drive_separator(value: &str, index: usize) -> bool {
+    let bytes = value.as_bytes();
+
+    index >= 1
+        && bytes[index - 1].is_ascii_alphabetic()
+        && (index == 1 || bytes[index - 2] == b':')
+        && matches!(bytes.get(index + 1), Some(b'\\' | b'/'))
+}
+
+impl TryFrom<&str> for StandardJsonLoader {
+    type Error = LoaderError;
+
+    /// Parses an `<input.json>:<output.json>` pair. Either path may be a Windows path with a drive letter,
+    /// i.e: `C:\project\input.json:C:\project\output.json`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let separator = value.rmatch_indices(':')
+            .map(|(index, _)| index)
+            .find(|&index| !is_drive_separator(value, index));
+
+        match separator.map(|index| (&value[..index], &value[index + 1..])) {
+            Some((input_path, output_path)) if !input_path.is_empty() && !output_path.is_empty() => {
+                Ok(Self::new(input_path, output_path))
+            }
+
+            _ => Err(LoaderError::InvalidConfig {
+                path: PathBuf::from(value),
+                message: "expected `<input.json>:<output.json>`".to_string(),
+            }),
+        }
+    }
+}
+
+impl ProjectLoader for StandardJsonLoader {
+    fn kind(&self) -> ProjectKind {
+        ProjectKind::StandardJson
+    }
+
+    fn detect(&self, project_path: &Path) -> bool {
+        project_path.join(&self.input_path).is_file() && project_path.join(&self.output_path).is_file()
+    }
+
+    fn load(&self, project_path: &Path) -> Result<Project, LoaderError> {
+        let mut project = Project {
+            kind: Some(self.kind()),
+            path: project_path.into(),
+            ..Default::default()
+        };
+
+        let input_path = project_path.join(&self.input_path);
+        let output_path = project_path.join(&self.output_path);
+
+        let input: Input = super::read_artifact(self.kind(), &input_path)?;
+        let output: Output = super::read_artifact(self.kind(), &output_path)?;
+
+        //
+        // Compilation failures produce an output without any sources
+        //
+
+        if output.sources.is_empty() {
+            let message = match output.errors.iter().find(|error| error.severity == "error") {
+                Some(error) => format!("no sources were compiled: {}", error.formatted_message.as_deref().unwrap_or(error.message.as_str()).trim()),
+                None => "no sources were compiled".to_string(),
+            };
+
+            return Err(LoaderError::MalformedArtifact { kind: self.kind(), path: output_path, message })
+        }
+
+        output.load_into(&mut project, &output_path, &input, None, |_| false)?;
+
+        Ok(project.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::TestProject;
+
+    const INPUT: &str = r#"{
+        "language": "Solidity",
+        "sources": {
+            "contracts/Token.sol": { "content": "contract Token {}\n" },
+            "contracts/Vault.sol": { "urls": ["src/Vault.sol"] }
+        }
+    }"#;
+
+    const OUTPUT: &str = r#"{
+        "contracts": {
+            "contracts/Token.sol": {
+                "Token": { "abi": [], "metadata": "{\"compiler\":{\"version\":\"0.8.19+commit.7dd6d404\"}}" }
+            }
+        },
+        "sources": {
+            "contracts/Token.sol": {
+                "id": 0,
+                "ast": { "nodeType": "SourceUnit", "absolutePath": "contracts/Token.sol", "id": 1, "nodes": [], "src": "0:18:0" }
+            },
+            "contracts/Vault.sol": {
+                "id": 1,
+                "ast": { "nodeType": "SourceUnit", "absolutePath": "contracts/Vault.sol", "id": 2, "nodes": [], "src": "0:18:1" }
+            }
+        }
+    }"#;
+
+    const FAILED_OUTPUT: &str = r#"{
+        "errors": [
+            { "severity": "warning", "message": "Unused variable" },
+            { "severity": "error", "message": "Expected ';'", "formattedMessage": "ParserError: Expected ';'\n" }
+        ]
+    }"#;
+
+    #[test]
+    fn test_load() {
+        let project = TestProject::new("standard-json", &[
+            ("input.json", INPUT),
+            ("output.json", OUTPUT),
+            ("failed.json", FAILED_OUTPUT),
+            ("src/Vault.sol", "contract Vault {}\n"),
+        ]);
+
+        let loader = StandardJsonLoader::new("input.json", "output.json");
+        assert!(loader.detect(&project.path));
+
+        let loaded_project = loader.load(&project.path).unwrap();
+
+        // Sources supplied by URL are read relative to the project path
+        let sources: Vec<_> = loaded_project.source_units.iter()
+            .map(|source_unit| (source_unit.absolute_path.as_deref().unwrap(), source_unit.source().unwrap()))
+            .collect();
+
+        assert_eq!(sources, vec![("contracts/Token.sol", "contract Token {}\n"), ("contracts/Vault.sol", "contract Vault {}\n")]);
+        assert_eq!(loaded_project.compiler_version.as_deref(), Some("0.8.19"));
+
+        match StandardJsonLoader::new("input.json", "failed.json").load(&project.path) {
+            Err(LoaderError::MalformedArtifact { message, .. }) => assert_eq!(message, "no sources were compiled: ParserError: Expected ';'"),
+            result => panic!("expected a malformed artifact, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_path_pair() {
+        let loader = StandardJsonLoader::try_from("input.json:output.json").unwrap();
+        assert_eq!(loader.input_path, PathBuf::from("input.json"));
+        assert_eq!(loader.output_path, PathBuf::from("output.json"));
+
+        let loader = StandardJsonLoader::try_from(r"C:\proj\input.json:out.json").unwrap();
+        assert_eq!(loader.input_path, PathBuf::from(r"C:\proj\input.json"));
+        assert_eq!(loader.output_path, PathBuf::from("out.json"));
+
+        let loader = StandardJsonLoader::try_from(r"C:\proj\input.json:D:/proj/output.json").unwrap();
+        assert_eq!(loader.input_path, PathBuf::from(r"C:\proj\input.json"));
+        assert_eq!(loader.output_path, PathBuf::from("D:/proj/output.json"));
+
+        assert!(StandardJsonLoader::try_from("input.json").is_err());
+        assert!(StandardJsonLoader::try_from(r"C:\proj\input.json").is_err());
+        assert!(StandardJsonLoader::try_from("input.json:").is_err());
+    }
+}
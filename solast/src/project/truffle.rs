@@ -0,0 +1,146 @@
+use super::{Artifact, Project, ProjectKind, ProjectLoader};
+use crate::errors::LoaderError;
+use serde::{Deserialize, Serialize};
+use solidity::ast::*;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Compiler {
+    pub name: Option<String>,
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct File {
+    pub contract_name: Option<String>,
+    pub abi: Option<serde_json::Value>,
+    pub metadata: Option<String>,
+    pub bytecode: Option<String>,
+    pub deployed_bytecode: Option<String>,
+    pub source_map: Option<String>,
+    pub deployed_source_map: Option<String>,
+    pub source: Option<String>,
+    pub source_path: Option<String>,
+    pub ast: Option<SourceUnit>,
+    pub compiler: Option<Compiler>,
+    // TODO: networks
+    // TODO: schemaVersion
+    // TODO: updatedAt
+    // TODO: devdoc
+    // TODO: userdoc
+}
+
+pub struct TruffleLoader;
+
+impl ProjectLoader for TruffleLoader {
+    fn kind(&self) -> ProjectKind {
+        ProjectKind::Truffle
+    }
+
+    fn detect(&self, project_path: &Path) -> bool {
+        project_path.join("truffle-config.js").is_file()
+    }
+
+    fn load(&self, project_path: &Path) -> Result<Project, LoaderError> {
+        let mut project = Project {
+            kind: Some(self.kind()),
+            path: project_path.into(),
+            ..Default::default()
+        };
+
+        let build_path = project_path.join("build").join("contracts");
+
+        if !build_path.is_dir() {
+            return Err(LoaderError::BuildDirectoryNotFound { kind: self.kind(), path: build_path })
+        }
+
+        let migrations_path = PathBuf::new()
+            .join("contracts")
+            .join("Migrations.sol")
+            .to_string_lossy()
+            .to_string();
+
+        for path in super::artifact_paths(&build_path)? {
+            let file: File = super::read_artifact(self.kind(), &path)?;
+
+            if file.source_path.as_deref().unwrap_or("").ends_with(migrations_path.as_str()) {
+                continue;
+            }
+
+            project.artifacts.push(Artifact {
+                path,
+                contract_name: file.contract_name,
+                source_path: file.source_path,
+                compiler_version: file.compiler.and_then(|compiler| compiler.version).map(|version| super::normalize_compiler_version(&version)),
+                abi: file.abi,
+                bytecode: file.bytecode,
+                deployed_bytecode: file.deployed_bytecode,
+            });
+
+            if let Some(mut source_unit) = file.ast {
+                if source_unit.absolute_path.as_deref().unwrap_or("").ends_with(migrations_path.as_str()) {
+                    continue;
+                }
+
+                if !project.contains_source_unit(source_unit.absolute_path.as_deref()) {
+                    source_unit.set_source(file.source);
+                    project.source_units.push(source_unit);
+                }
+            }
+        }
+
+        Ok(project.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::TestProject;
+
+    const ARTIFACT: &str = r#"{
+        "contractName": "Token",
+        "abi": [],
+        "bytecode": "0x6080",
+        "deployedBytecode": "0x6081",
+        "source": "contract Token {}\n",
+        "sourcePath": "/project/contracts/Token.sol",
+        "ast": { "nodeType": "SourceUnit", "absolutePath": "project:/contracts/Token.sol", "id": 1, "nodes": [], "src": "0:18:0" },
+        "compiler": { "name": "solc", "version": "0.8.19+commit.7dd6d404.Emscripten.clang" }
+    }"#;
+
+    const MIGRATIONS_ARTIFACT: &str = r#"{
+        "contractName": "Migrations",
+        "abi": [],
+        "sourcePath": "/project/contracts/Migrations.sol",
+        "ast": { "nodeType": "SourceUnit", "absolutePath": "project:/contracts/Migrations.sol", "id": 2, "nodes": [], "src": "0:0:0" }
+    }"#;
+
+    #[test]
+    fn test_load() {
+        let project = TestProject::new("truffle", &[
+            ("truffle-config.js", ""),
+            ("build/contracts/Migrations.json", MIGRATIONS_ARTIFACT),
+            ("build/contracts/Token.json", ARTIFACT),
+        ]);
+
+        assert!(TruffleLoader.detect(&project.path));
+
+        // The `Migrations` contract used for deployment is not part of the project
+        let loaded_project = TruffleLoader.load(&project.path).unwrap();
+
+        assert_eq!(loaded_project.source_units.len(), 1);
+        assert_eq!(loaded_project.source_units[0].absolute_path.as_deref(), Some("project:/contracts/Token.sol"));
+        assert_eq!(loaded_project.source_units[0].source(), Some("contract Token {}\n"));
+
+        assert_eq!(loaded_project.artifacts.len(), 1);
+        assert_eq!(loaded_project.artifacts[0].contract_name.as_deref(), Some("Token"));
+        assert_eq!(loaded_project.artifacts[0].source_path.as_deref(), Some("/project/contracts/Token.sol"));
+        assert_eq!(loaded_project.compiler_version.as_deref(), Some("0.8.19"));
+
+        let project = TestProject::new("truffle-unbuilt", &[("truffle-config.js", "")]);
+        assert!(matches!(TruffleLoader.load(&project.path), Err(LoaderError::BuildDirectoryNotFound { .. })));
+    }
+}
@@ -1,9 +1,115 @@
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display, path::PathBuf};
+use solidity::ast::SourceRange;
+use std::{collections::HashMap, fmt::Display, io, path::PathBuf};
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[default]
+    Info,
+    Low,
+    Medium,
+    High,
+}
+
+impl TryFrom<&str> for Severity {
+    type Error = io::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "info" | "informational" => Ok(Self::Info),
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            s => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid severity: {s}"))),
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Low => write!(f, "low"),
+            Severity::Medium => write!(f, "medium"),
+            Severity::High => write!(f, "high"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    #[default]
+    Low,
+    Medium,
+    High,
+}
+
+impl TryFrom<&str> for Confidence {
+    type Error = io::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            s => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid confidence: {s}"))),
+        }
+    }
+}
+
+impl Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Confidence::Low => write!(f, "low"),
+            Confidence::Medium => write!(f, "medium"),
+            Confidence::High => write!(f, "high"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    #[default]
+    Security,
+    Gas,
+    Style,
+}
+
+impl TryFrom<&str> for Category {
+    type Error = io::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "security" => Ok(Self::Security),
+            "gas" => Ok(Self::Gas),
+            "style" => Ok(Self::Style),
+            s => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid category: {s}"))),
+        }
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Category::Security => write!(f, "security"),
+            Category::Gas => write!(f, "gas"),
+            Category::Style => write!(f, "style"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Entry {
+    /// The stable ID of the rule which produced the entry, i.e: `check_effects_interactions`.
+    pub rule: String,
+    pub severity: Severity,
+    pub confidence: Confidence,
+    pub category: Category,
     pub line: Option<usize>,
+    pub range: Option<SourceRange>,
     pub text: String,
 }
 
@@ -11,12 +117,17 @@ impl Display for Entry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}{}",
+            "{}{}{}",
             if let Some(line) = self.line.as_ref() {
                 format!("L{}: ", line)
             } else {
                 String::new()
             },
+            if self.rule.is_empty() {
+                String::new()
+            } else {
+                format!("[{}/{}] ", self.severity, self.rule)
+            },
             self.text,
         )
     }
@@ -25,38 +136,97 @@ impl Display for Entry {
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Report {
     pub entries: HashMap<PathBuf, Vec<Entry>>,
+    /// Entries of a baseline which are no longer reported.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub fixed_entries: HashMap<PathBuf, Vec<Entry>>,
 }
 
 impl Report {
     pub fn add_entry<P: Into<PathBuf>, S: Into<String>>(
         &mut self,
         file: P,
-        line: Option<usize>,
+        range: Option<SourceRange>,
         text: S,
     ) {
         self.entries
             .entry(file.into())
             .or_insert_with(|| vec![])
             .push(Entry {
-                line,
+                line: range.map(|range| range.start_line),
+                range,
                 text: text.into(),
+                ..Default::default()
             });
     }
 
-    pub fn sort_entries(&mut self) {
+    /// Moves the entries of a report produced by a single rule into this report, tagging each entry with the rule's details.
+    pub fn merge_rule_report(
+        &mut self,
+        rule: &str,
+        severity: Severity,
+        confidence: Confidence,
+        category: Category,
+        report: Report,
+    ) {
+        for (path, entries) in report.entries {
+            self.entries
+                .entry(path)
+                .or_default()
+                .extend(entries.into_iter().map(|entry| Entry {
+                    rule: rule.to_string(),
+                    severity,
+                    confidence,
+                    category,
+                    ..entry
+                }));
+        }
+    }
+
+    /// Determines if the report contains any entries with at least the supplied severity.
+    pub fn has_min_severity(&self, min_severity: Severity) -> bool {
+        self.entries.values().flatten().any(|entry| entry.severity >= min_severity)
+    }
+
+    /// Removes all entries with a severity lower than the supplied minimum severity.
+    pub fn retain_min_severity(&mut self, min_severity: Severity) {
         self.entries.iter_mut().for_each(|(_, entries)| {
+            entries.retain(|entry| entry.severity >= min_severity);
+        });
+
+        self.entries.retain(|_, entries| !entries.is_empty());
+    }
+
+    /// Sorts the entries of each file by line, column and rule ID.
+    pub fn sort_entries(&mut self) {
+        self.entries.iter_mut().chain(self.fixed_entries.iter_mut()).for_each(|(_, entries)| {
             entries.sort_by(|a, b| {
-                a.line
-                    .unwrap_or_else(|| 0)
-                    .cmp(&b.line.unwrap_or_else(|| 0))
+                let a_column = a.range.map(|range| range.start_column).unwrap_or(0);
+                let b_column = b.range.map(|range| range.start_column).unwrap_or(0);
+
+                (a.line.unwrap_or(0), a_column, a.rule.as_str(), a.text.as_str())
+                    .cmp(&(b.line.unwrap_or(0), b_column, b.rule.as_str(), b.text.as_str()))
             });
         });
     }
+
+    /// Gets all files containing entries, sorted by path.
+    pub fn sorted_entries(&self) -> Vec<(&PathBuf, &Vec<Entry>)> {
+        let mut result: Vec<_> = self.entries.iter().collect();
+        result.sort_by_key(|(path, _)| *path);
+        result
+    }
+
+    /// Gets all files containing fixed entries, sorted by path.
+    pub fn sorted_fixed_entries(&self) -> Vec<(&PathBuf, &Vec<Entry>)> {
+        let mut result: Vec<_> = self.fixed_entries.iter().collect();
+        result.sort_by_key(|(path, _)| *path);
+        result
+    }
 }
 
 impl Display for Report {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (i, (path, entries)) in self.entries.iter().enumerate() {
+        for (i, (path, entries)) in self.sorted_entries().into_iter().enumerate() {
             if i > 0 {
                 writeln!(f)?;
             }
@@ -68,6 +238,118 @@ impl Display for Report {
             }
         }
 
+        if !self.fixed_entries.is_empty() {
+            if !self.entries.is_empty() {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "Fixed since baseline:")?;
+
+            for (path, entries) in self.sorted_fixed_entries() {
+                writeln!(f)?;
+                writeln!(f, "{}:", path.to_string_lossy())?;
+
+                for entry in entries.iter() {
+                    writeln!(f, "\t{entry}")?;
+                }
+            }
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(line: usize, column: usize) -> Option<SourceRange> {
+        Some(SourceRange { start_line: line, start_column: column, end_line: line, end_column: column + 1 })
+    }
+
+    fn entry(rule: &str, severity: Severity, line: Option<usize>, range: Option<SourceRange>) -> Entry {
+        Entry {
+            rule: rule.to_string(),
+            severity,
+            line,
+            range,
+            text: format!("{rule} finding"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_retain_min_severity() {
+        let mut report = Report::default();
+        report.entries.insert(PathBuf::from("A.sol"), vec![entry("a", Severity::Info, None, None), entry("b", Severity::High, None, None)]);
+        report.entries.insert(PathBuf::from("B.sol"), vec![entry("c", Severity::Low, None, None)]);
+        report.fixed_entries.insert(PathBuf::from("C.sol"), vec![entry("d", Severity::Info, None, None)]);
+
+        report.retain_min_severity(Severity::Low);
+        assert_eq!(report.entries[&PathBuf::from("A.sol")].iter().map(|entry| entry.rule.as_str()).collect::<Vec<_>>(), vec!["b"]);
+        assert_eq!(report.entries[&PathBuf::from("B.sol")].len(), 1);
+
+        // Files without any remaining entries are removed, while fixed entries are untouched
+        report.retain_min_severity(Severity::Medium);
+        assert_eq!(report.entries.keys().collect::<Vec<_>>(), vec![&PathBuf::from("A.sol")]);
+        assert_eq!(report.fixed_entries.len(), 1);
+
+        report.retain_min_severity(Severity::Info);
+        assert_eq!(report.entries[&PathBuf::from("A.sol")].len(), 1);
+    }
+
+    #[test]
+    fn test_sort_entries() {
+        let mut report = Report::default();
+        report.entries.insert(
+            PathBuf::from("B.sol"),
+            vec![
+                entry("b", Severity::Info, Some(3), range(3, 5)),
+                entry("a", Severity::Info, Some(3), range(3, 5)),
+                entry("c", Severity::Info, Some(3), range(3, 1)),
+                entry("d", Severity::High, Some(2), None),
+                entry("e", Severity::Low, None, None),
+            ],
+        );
+        report.entries.insert(PathBuf::from("A.sol"), vec![entry("f", Severity::Info, Some(1), range(1, 1))]);
+        report.fixed_entries.insert(
+            PathBuf::from("C.sol"),
+            vec![entry("h", Severity::Info, Some(9), None), entry("g", Severity::Info, Some(1), None)],
+        );
+
+        report.sort_entries();
+
+        // Entries are sorted by line, column and rule regardless of severity, with file-level entries first
+        let rules = |entries: &[Entry]| entries.iter().map(|entry| entry.rule.clone()).collect::<Vec<_>>();
+        assert_eq!(rules(&report.entries[&PathBuf::from("B.sol")]), vec!["e", "d", "c", "a", "b"]);
+        assert_eq!(rules(&report.fixed_entries[&PathBuf::from("C.sol")]), vec!["g", "h"]);
+
+        let paths: Vec<_> = report.sorted_entries().into_iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("A.sol"), PathBuf::from("B.sol")]);
+    }
+
+    #[test]
+    fn test_merge_rule_report() {
+        let mut rule_report = Report::default();
+        rule_report.add_entry("A.sol", range(4, 2), "first");
+        rule_report.add_entry("B.sol", None, "second");
+
+        let mut report = Report::default();
+        report.entries.insert(PathBuf::from("A.sol"), vec![entry("existing", Severity::Info, Some(1), None)]);
+        report.merge_rule_report("unchecked_erc20_transfer", Severity::Medium, Confidence::High, Category::Security, rule_report);
+
+        let entries = &report.entries[&PathBuf::from("A.sol")];
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].rule, "existing");
+
+        // Merged entries are tagged with the rule's details while keeping their location and text
+        let merged = &entries[1];
+        assert_eq!(merged.rule, "unchecked_erc20_transfer");
+        assert_eq!((merged.severity, merged.confidence, merged.category), (Severity::Medium, Confidence::High, Category::Security));
+        assert_eq!((merged.line, merged.range, merged.text.as_str()), (Some(4), range(4, 2), "first"));
+
+        let merged = &report.entries[&PathBuf::from("B.sol")][0];
+        assert_eq!(merged.rule, "unchecked_erc20_transfer");
+        assert_eq!((merged.line, merged.range, merged.text.as_str()), (None, None, "second"));
+    }
+}
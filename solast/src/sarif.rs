@@ -0,0 +1,456 @@
+use crate::{
+    analysis::VisitorType,
+    config::Config,
+    report::{Category, Confidence, Report, Severity},
+};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::Path};
+
+pub const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+pub const VERSION: &str = "2.1.0";
+
+/// The characters which are percent-encoded in artifact URIs, which is everything except unreserved characters and `/`.
+const URI_PATH: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~').remove(b'/');
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
+}
+
+impl Message {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            markdown: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingConfiguration {
+    pub level: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingDescriptor {
+    pub id: String,
+    pub name: String,
+    pub short_description: Message,
+    pub full_description: Message,
+    pub help: Message,
+    pub default_configuration: ReportingConfiguration,
+    pub properties: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolComponent {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tool {
+    pub driver: ToolComponent,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+    pub uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    pub artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub physical_location: PhysicalLocation,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Result {
+    pub rule_id: String,
+    pub rule_index: usize,
+    pub level: String,
+    pub message: Message,
+    pub locations: Vec<Location>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Run {
+    pub tool: Tool,
+    pub column_kind: String,
+    pub results: Vec<Result>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<Run>,
+}
+
+/// The SARIF `level` of a result with the supplied severity.
+pub fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low | Severity::Info => "note",
+    }
+}
+
+/// The `problem.severity` property of a rule with the supplied severity, as accepted by GitHub code scanning.
+fn problem_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low | Severity::Info => "recommendation",
+    }
+}
+
+fn precision(confidence: Confidence) -> &'static str {
+    match confidence {
+        Confidence::Low => "low",
+        Confidence::Medium => "medium",
+        Confidence::High => "high",
+    }
+}
+
+fn security_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "8.0",
+        Severity::Medium => "5.0",
+        Severity::Low => "2.0",
+        Severity::Info => "0.0",
+    }
+}
+
+impl ReportingDescriptor {
+    /// Describes the rule of a visitor type, which is reported with the supplied (possibly overridden) severity.
+    pub fn new(visitor_type: &VisitorType, severity: Severity) -> Self {
+        let mut properties = BTreeMap::new();
+
+        properties.insert("tags".to_string(), serde_json::json!([visitor_type.category.to_string()]));
+        properties.insert("precision".to_string(), serde_json::json!(precision(visitor_type.confidence)));
+        properties.insert("problem.severity".to_string(), serde_json::json!(problem_severity(severity)));
+
+        if visitor_type.category == Category::Security {
+            properties.insert("security-severity".to_string(), serde_json::json!(security_severity(severity)));
+        }
+
+        let summary = format!(
+            "{} (severity: {}, confidence: {}, category: {})",
+            visitor_type.description,
+            severity,
+            visitor_type.confidence,
+            visitor_type.category,
+        );
+
+        let example = visitor_type.example.trim_matches('\n');
+
+        let help_text = format!(
+            "{}\n\nRationale:\n    {}\n\nExample:\n{}",
+            summary,
+            visitor_type.rationale,
+            example.lines()
+                .map(|line| if line.is_empty() { String::new() } else { format!("    {line}") })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+
+        let help_markdown = format!(
+            "{}\n\n**Rationale:** {}\n\n**Example:**\n\n```solidity\n{}\n```",
+            summary,
+            visitor_type.rationale,
+            example,
+        );
+
+        Self {
+            id: visitor_type.name.to_string(),
+            name: visitor_type.name.to_string(),
+            short_description: Message::new(visitor_type.description),
+            full_description: Message::new(visitor_type.description),
+            help: Message {
+                text: help_text,
+                markdown: Some(help_markdown),
+            },
+            default_configuration: ReportingConfiguration {
+                level: level(severity).to_string(),
+            },
+            properties,
+        }
+    }
+}
+
+impl Log {
+    /// Builds a SARIF log containing one rule per supplied visitor type and one result per report entry.
+    /// The severity of each rule includes any overrides from the supplied configuration.
+    pub fn new(report: &Report, visitor_types: &[&VisitorType], config: &Config) -> Self {
+        let mut rules: Vec<ReportingDescriptor> = visitor_types.iter()
+            .map(|visitor_type| ReportingDescriptor::new(visitor_type, config.rule_severity(visitor_type)))
+            .collect();
+
+        let mut paths: Vec<_> = report.entries.keys().collect();
+        paths.sort();
+
+        let mut results = vec![];
+
+        for path in paths {
+            for entry in report.entries[path].iter() {
+                let rule_index = match rules.iter().position(|rule| rule.id == entry.rule) {
+                    Some(rule_index) => rule_index,
+
+                    //
+                    // Entries which were not produced by an analyzer (i.e: unused suppressions) get a rule of their own
+                    //
+
+                    None => {
+                        rules.push(ReportingDescriptor {
+                            id: entry.rule.clone(),
+                            name: entry.rule.clone(),
+                            short_description: Message::new(entry.rule.clone()),
+                            full_description: Message::new(entry.rule.clone()),
+                            help: Message::new(entry.rule.clone()),
+                            default_configuration: ReportingConfiguration {
+                                level: level(entry.severity).to_string(),
+                            },
+                            properties: BTreeMap::new(),
+                        });
+
+                        rules.len() - 1
+                    }
+                };
+
+                results.push(Result {
+                    rule_id: entry.rule.clone(),
+                    rule_index,
+                    level: level(entry.severity).to_string(),
+                    message: Message::new(entry.text.clone()),
+                    locations: vec![
+                        Location {
+                            physical_location: PhysicalLocation {
+                                artifact_location: artifact_location(path),
+                                region: match (entry.range, entry.line) {
+                                    (Some(range), _) => Some(Region {
+                                        start_line: range.start_line,
+                                        start_column: Some(range.start_column),
+                                        end_line: Some(range.end_line),
+                                        end_column: Some(range.end_column),
+                                    }),
+
+                                    (None, Some(line)) => Some(Region {
+                                        start_line: line,
+                                        ..Default::default()
+                                    }),
+
+                                    (None, None) => None,
+                                },
+                            },
+                        },
+                    ],
+                });
+            }
+        }
+
+        Self {
+            schema: SCHEMA.to_string(),
+            version: VERSION.to_string(),
+            runs: vec![
+                Run {
+                    tool: Tool {
+                        driver: ToolComponent {
+                            name: "solast".to_string(),
+                            version: env!("CARGO_PKG_VERSION").to_string(),
+                            information_uri: "https://github.com/camden-smallwood/solidity-rs".to_string(),
+                            rules,
+                        },
+                    },
+                    column_kind: "unicodeCodePoints".to_string(),
+                    results,
+                },
+            ],
+        }
+    }
+}
+
+/// Relative source paths are resolved against the `%SRCROOT%` base, i.e: the analyzed project's root directory.
+fn artifact_location(path: &Path) -> ArtifactLocation {
+    let path = path.to_string_lossy().replace('\\', "/");
+
+    //
+    // Absolute paths become `file` URIs, including Windows drive letter (`C:/...`) and UNC (`//server/share/...`) paths
+    //
+
+    let drive_letter = match path.as_bytes() {
+        [letter, b':', b'/', ..] if letter.is_ascii_alphabetic() => Some(*letter as char),
+        _ => None,
+    };
+
+    if let Some(drive_letter) = drive_letter {
+        ArtifactLocation {
+            uri: format!("file:///{drive_letter}:{}", utf8_percent_encode(&path[2..], URI_PATH)),
+            uri_base_id: None,
+        }
+    } else if let Some(unc_path) = path.strip_prefix("//") {
+        ArtifactLocation {
+            uri: format!("file://{}", utf8_percent_encode(unc_path, URI_PATH)),
+            uri_base_id: None,
+        }
+    } else if path.starts_with('/') {
+        ArtifactLocation {
+            uri: format!("file://{}", utf8_percent_encode(&path, URI_PATH)),
+            uri_base_id: None,
+        }
+    } else {
+        ArtifactLocation {
+            uri: utf8_percent_encode(path.trim_start_matches("./"), URI_PATH).to_string(),
+            uri_base_id: Some("%SRCROOT%".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{analysis::VISITOR_TYPES, report::Entry};
+    use solidity::ast::SourceRange;
+    use std::{collections::HashMap, path::PathBuf};
+
+    #[test]
+    fn test_sarif_log() {
+        let visitor_types: Vec<_> = VISITOR_TYPES.iter()
+            .filter(|visitor_type| matches!(visitor_type.name, "no_spdx_identifier" | "floating_solidity_version"))
+            .collect();
+
+        let report = Report {
+            entries: HashMap::from([
+                (PathBuf::from("./contracts/Token.sol"), vec![
+                    Entry {
+                        rule: "floating_solidity_version".to_string(),
+                        severity: Severity::Low,
+                        line: Some(1),
+                        range: Some(SourceRange { start_line: 1, start_column: 1, end_line: 1, end_column: 24 }),
+                        text: "Floating pragma".to_string(),
+                        ..Default::default()
+                    },
+                ]),
+                (PathBuf::from("/project/contracts/Vault.sol"), vec![
+                    Entry {
+                        rule: "unused_suppression".to_string(),
+                        severity: Severity::Info,
+                        line: Some(3),
+                        text: "Unused suppression".to_string(),
+                        ..Default::default()
+                    },
+                ]),
+            ]),
+            ..Default::default()
+        };
+
+        let log = Log::new(&report, visitor_types.as_slice(), &Config::default());
+        let run = &log.runs[0];
+
+        let rules: Vec<_> = run.tool.driver.rules.iter().map(|rule| rule.id.as_str()).collect();
+        assert_eq!(rules, vec!["no_spdx_identifier", "floating_solidity_version", "unused_suppression"]);
+
+        let floating_version_rule = &run.tool.driver.rules[1];
+        assert_eq!(floating_version_rule.default_configuration.level, "note");
+        assert_eq!(floating_version_rule.properties["security-severity"], "2.0");
+        assert_eq!(floating_version_rule.properties["problem.severity"], "recommendation");
+        assert_eq!(floating_version_rule.properties["tags"], serde_json::json!(["security"]));
+        assert!(!run.tool.driver.rules[0].properties.contains_key("security-severity"));
+
+        // Results are ordered by path, and rules are added for entries which were not produced by an analyzer
+        let results: Vec<_> = run.results.iter()
+            .map(|result| (result.rule_id.as_str(), result.rule_index, result.level.as_str()))
+            .collect();
+
+        assert_eq!(results, vec![
+            ("unused_suppression", 2, "note"),
+            ("floating_solidity_version", 1, "note"),
+        ]);
+
+        let json = serde_json::to_value(&log).unwrap();
+        assert_eq!(json["$schema"], SCHEMA);
+        assert_eq!(json["version"], VERSION);
+
+        // Relative paths are resolved against the source root
+        assert_eq!(json["runs"][0]["results"][1]["locations"][0]["physicalLocation"], serde_json::json!({
+            "artifactLocation": { "uri": "contracts/Token.sol", "uriBaseId": "%SRCROOT%" },
+            "region": { "startLine": 1, "startColumn": 1, "endLine": 1, "endColumn": 24 },
+        }));
+
+        assert_eq!(json["runs"][0]["results"][0]["locations"][0]["physicalLocation"], serde_json::json!({
+            "artifactLocation": { "uri": "file:///project/contracts/Vault.sol" },
+            "region": { "startLine": 3 },
+        }));
+    }
+
+    #[test]
+    fn test_severity_overrides() {
+        let visitor_types: Vec<_> = VISITOR_TYPES.iter()
+            .filter(|visitor_type| visitor_type.name == "floating_solidity_version")
+            .collect();
+
+        let config = Config {
+            severity: HashMap::from([("floating_solidity_version".to_string(), Severity::High)]),
+            ..Default::default()
+        };
+
+        // The rule's default level matches the severity its results are reported with
+        let log = Log::new(&Report::default(), visitor_types.as_slice(), &config);
+        let rule = &log.runs[0].tool.driver.rules[0];
+        assert_eq!(rule.default_configuration.level, "error");
+        assert_eq!(rule.properties["security-severity"], "8.0");
+        assert_eq!(rule.properties["problem.severity"], "error");
+        assert!(rule.help.text.contains("(severity: high,"));
+    }
+
+    #[test]
+    fn test_artifact_location() {
+        let uri = |path: &str| {
+            let location = artifact_location(Path::new(path));
+            (location.uri, location.uri_base_id)
+        };
+
+        assert_eq!(uri("/project/My Contracts/Tökén.sol"), ("file:///project/My%20Contracts/T%C3%B6k%C3%A9n.sol".to_string(), None));
+        assert_eq!(uri("C:\\Users\\dev\\project #1\\Token.sol"), ("file:///C:/Users/dev/project%20%231/Token.sol".to_string(), None));
+        assert_eq!(uri("d:/project/Token.sol"), ("file:///d:/project/Token.sol".to_string(), None));
+        assert_eq!(uri("\\\\server\\share\\Token.sol"), ("file://server/share/Token.sol".to_string(), None));
+        assert_eq!(uri("./contracts/100%/Token.sol"), ("contracts/100%25/Token.sol".to_string(), Some("%SRCROOT%".to_string())));
+    }
+}
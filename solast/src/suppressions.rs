@@ -0,0 +1,357 @@
+use crate::report::{Category, Confidence, Entry, Report, Severity};
+use solang_parser::{lexer::Lexer, pt::{Comment, Loc}};
+use solidity::ast::{LineTable, SourceLocation, SourceRange, SourceUnit};
+use std::path::PathBuf;
+
+pub const UNUSED_SUPPRESSION_RULE: &str = "unused_suppression";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuppressionKind {
+    /// `// solast-disable-line <rule>`
+    Line,
+    /// `// solast-disable-next-line <rule>`
+    NextLine,
+    /// `// solast-disable <rule>` until `// solast-enable <rule>` or the end of the file
+    Region,
+    /// `// solast-disable-file <rule>`
+    File,
+}
+
+#[derive(Clone, Debug)]
+pub struct Suppression {
+    pub kind: SuppressionKind,
+    /// The rules which are suppressed, or all rules if empty.
+    pub rules: Vec<String>,
+    /// Rules which were re-enabled by a `solast-enable` comment inside of a region which suppresses all rules.
+    pub except: Vec<String>,
+    /// The range of the suppression comment.
+    pub range: SourceRange,
+    pub start_line: usize,
+    pub end_line: Option<usize>,
+    pub used: bool,
+}
+
+impl Suppression {
+    pub fn matches_rule(&self, rule: &str) -> bool {
+        (self.rules.is_empty() || self.rules.iter().any(|r| r == rule)) && !self.except.iter().any(|r| r == rule)
+    }
+
+    pub fn matches_line(&self, line: Option<usize>) -> bool {
+        match (self.kind, line) {
+            (SuppressionKind::File, _) => true,
+            (_, None) => false,
+            (SuppressionKind::Line | SuppressionKind::NextLine, Some(line)) => line == self.start_line,
+            (SuppressionKind::Region, Some(line)) => line >= self.start_line && self.end_line.map(|end_line| line <= end_line).unwrap_or(true),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Suppressions {
+    pub suppressions: Vec<Suppression>,
+}
+
+impl Suppressions {
+    /// Parses all suppression comments from the supplied source text.
+    /// Both `//` and `/* */` comments are recognized, while directives inside of string literals are ignored.
+    pub fn parse(source: &str) -> Self {
+        let mut suppressions: Vec<Suppression> = vec![];
+        let mut open_regions: Vec<usize> = vec![];
+
+        let line_table = LineTable::new(source);
+
+        for (location, comment) in comments(source) {
+            let (directive, rules) = match parse_directive(comment.as_str()) {
+                Some(directive) => directive,
+                None => continue,
+            };
+
+            let range = line_table.range(source, location);
+            let line = range.start_line;
+
+            let mut suppression = Suppression {
+                kind: SuppressionKind::Line,
+                rules,
+                except: vec![],
+                range,
+                start_line: line,
+                end_line: Some(line),
+                used: false,
+            };
+
+            match directive {
+                "solast-disable-line" => {}
+
+                "solast-disable-next-line" => {
+                    suppression.kind = SuppressionKind::NextLine;
+                    suppression.start_line = range.end_line + 1;
+                    suppression.end_line = Some(range.end_line + 1);
+                }
+
+                "solast-disable-file" => {
+                    suppression.kind = SuppressionKind::File;
+                    suppression.start_line = 1;
+                    suppression.end_line = None;
+                }
+
+                "solast-disable" => {
+                    suppression.kind = SuppressionKind::Region;
+                    suppression.end_line = None;
+                    open_regions.push(suppressions.len());
+                }
+
+                "solast-enable" => {
+                    let enabled_rules = suppression.rules;
+                    let mut still_open_regions = vec![];
+                    let mut reopened_regions = vec![];
+
+                    for region_index in open_regions.drain(..) {
+                        let region = &mut suppressions[region_index];
+
+                        if !enabled_rules.is_empty() && !region.rules.is_empty() && !region.rules.iter().any(|rule| enabled_rules.contains(rule)) {
+                            still_open_regions.push(region_index);
+                            continue;
+                        }
+
+                        region.end_line = Some(line);
+
+                        if enabled_rules.is_empty() {
+                            continue;
+                        }
+
+                        //
+                        // Regions which still suppress other rules are continued by a new region
+                        //
+
+                        let mut reopened_region = region.clone();
+                        reopened_region.start_line = line;
+                        reopened_region.end_line = None;
+                        reopened_region.used = false;
+
+                        if region.rules.is_empty() {
+                            reopened_region.except.extend(enabled_rules.iter().cloned());
+                        } else {
+                            reopened_region.rules.retain(|rule| !enabled_rules.contains(rule));
+
+                            if reopened_region.rules.is_empty() {
+                                continue;
+                            }
+                        }
+
+                        reopened_regions.push(reopened_region);
+                    }
+
+                    for region in reopened_regions {
+                        still_open_regions.push(suppressions.len());
+                        suppressions.push(region);
+                    }
+
+                    open_regions = still_open_regions;
+                    continue;
+                }
+
+                _ => continue,
+            }
+
+            suppressions.push(suppression);
+        }
+
+        Self { suppressions }
+    }
+
+    /// Determines if an entry with the supplied rule and line is suppressed, marking any matching suppressions as used.
+    pub fn suppress(&mut self, rule: &str, line: Option<usize>) -> bool {
+        let mut result = false;
+
+        for suppression in self.suppressions.iter_mut() {
+            if suppression.matches_rule(rule) && suppression.matches_line(line) {
+                suppression.used = true;
+                result = true;
+            }
+        }
+
+        result
+    }
+}
+
+/// Collects the locations and text of all non-documentation comments in the supplied source text, without their delimiters.
+///
+/// Only the lexer is used, so comments are found even when the source text cannot be parsed.
+fn comments(source: &str) -> Vec<(SourceLocation, String)> {
+    let mut comments = vec![];
+    let mut errors = vec![];
+
+    for _ in Lexer::new(source, 0, &mut comments, &mut errors) {}
+
+    comments.into_iter()
+        .filter_map(|comment| {
+            let (loc, text) = match comment {
+                Comment::Line(loc, text) => (loc, text.trim_start_matches('/').to_string()),
+                Comment::Block(loc, text) => {
+                    // Leading asterisks of multi-line block comments are not part of the text
+                    let text = text.trim_start_matches("/*").trim_end_matches("*/")
+                        .lines()
+                        .map(|line| line.trim().trim_start_matches('*').trim())
+                        .filter(|line| !line.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+
+                    (loc, text)
+                }
+                Comment::DocLine(..) | Comment::DocBlock(..) => return None,
+            };
+
+            match loc {
+                Loc::File(_, start, end) => Some((SourceLocation { start, length: end - start, file_index: None }, text)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Parses a suppression directive from the text of a comment, returning the directive and its rules.
+fn parse_directive(comment: &str) -> Option<(&str, Vec<String>)> {
+    let comment = comment.trim();
+
+    if !comment.starts_with("solast-") {
+        return None;
+    }
+
+    //
+    // Anything following `--` is a description of the suppression
+    //
+
+    let comment = match comment.split_once("--") {
+        Some((comment, _)) => comment.trim(),
+        None => comment,
+    };
+
+    let (directive, rules) = match comment.split_once(char::is_whitespace) {
+        Some((directive, rules)) => (directive, rules),
+        None => (comment, ""),
+    };
+
+    if !matches!(directive, "solast-disable" | "solast-enable" | "solast-disable-line" | "solast-disable-next-line" | "solast-disable-file") {
+        return None;
+    }
+
+    let rules = rules
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|rule| !rule.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    Some((directive, rules))
+}
+
+/// Removes all suppressed entries from the report.
+/// If `report_unused` is set, an entry is added for each suppression which did not suppress any entries of the supplied rules.
+pub fn apply(report: &mut Report, source_units: &[SourceUnit], rules: &[&str], report_unused: bool) {
+    for source_unit in source_units.iter() {
+        let (path, source) = match (source_unit.absolute_path.as_ref(), source_unit.source()) {
+            (Some(path), Some(source)) => (PathBuf::from(path), source),
+            _ => continue,
+        };
+
+        let mut suppressions = Suppressions::parse(source);
+
+        if suppressions.suppressions.is_empty() {
+            continue;
+        }
+
+        if let Some(entries) = report.entries.get_mut(&path) {
+            entries.retain(|entry| !suppressions.suppress(entry.rule.as_str(), entry.line));
+        }
+
+        if !report_unused {
+            continue;
+        }
+
+        for suppression in suppressions.suppressions.iter() {
+            //
+            // Suppressions are only unused if the rules they refer to were enabled
+            //
+
+            if suppression.used || (!suppression.rules.is_empty() && !suppression.rules.iter().any(|rule| rules.contains(&rule.as_str()))) {
+                continue;
+            }
+
+            report.entries.entry(path.clone()).or_default().push(Entry {
+                rule: UNUSED_SUPPRESSION_RULE.to_string(),
+                severity: Severity::Info,
+                confidence: Confidence::High,
+                category: Category::Style,
+                line: Some(suppression.range.start_line),
+                range: Some(suppression.range),
+                text: if suppression.rules.is_empty() {
+                    "Suppression comment does not suppress any findings".to_string()
+                } else {
+                    format!("Suppression comment for `{}` does not suppress any findings", suppression.rules.join("`, `"))
+                },
+            });
+        }
+
+        if report.entries.get(&path).map(|entries| entries.is_empty()).unwrap_or(false) {
+            report.entries.remove(&path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_suppressions() {
+        let source = r#"pragma solidity ^0.8.0;
+
+contract Token {
+    string constant NOTE = "// solast-disable-file";
+    uint256 a; // solast-disable-line state_variable_mutability -- intended
+    /* solast-disable-next-line unused_return, divide_before_multiply */
+    uint256 b;
+    /*
+     * solast-disable-next-line
+     */
+    uint256 c;
+    /// solast-disable-file
+    // solast-disable
+    uint256 d;
+    // solast-enable unused_return
+    uint256 e;
+    // solast-enable
+}
+"#;
+
+        let suppressions = Suppressions::parse(source).suppressions;
+
+        assert_eq!(suppressions.len(), 5);
+        assert!(suppressions.iter().all(|suppression| suppression.kind != SuppressionKind::File));
+
+        assert_eq!(suppressions[0].kind, SuppressionKind::Line);
+        assert_eq!(suppressions[0].start_line, 5);
+        assert_eq!(suppressions[0].rules, vec!["state_variable_mutability"]);
+
+        assert_eq!(suppressions[1].kind, SuppressionKind::NextLine);
+        assert_eq!(suppressions[1].start_line, 7);
+        assert_eq!(suppressions[1].rules, vec!["unused_return", "divide_before_multiply"]);
+        assert_eq!(suppressions[1].range.start_column, 5);
+
+        assert_eq!(suppressions[2].kind, SuppressionKind::NextLine);
+        assert_eq!(suppressions[2].start_line, 11);
+        assert!(suppressions[2].rules.is_empty());
+
+        assert_eq!(suppressions[3].kind, SuppressionKind::Region);
+        assert_eq!((suppressions[3].start_line, suppressions[3].end_line), (13, Some(15)));
+
+        assert_eq!(suppressions[4].kind, SuppressionKind::Region);
+        assert_eq!((suppressions[4].start_line, suppressions[4].end_line), (15, Some(17)));
+        assert_eq!(suppressions[4].except, vec!["unused_return"]);
+
+        let mut suppressions = Suppressions::parse(source);
+        assert!(suppressions.suppress("unused_return", Some(14)));
+        assert!(!suppressions.suppress("unused_return", Some(16)));
+        assert!(suppressions.suppress("divide_before_multiply", Some(16)));
+        assert!(!suppressions.suppress("divide_before_multiply", Some(4)));
+    }
+}
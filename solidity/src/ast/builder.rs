@@ -1,13 +1,63 @@
 use yul::ast::*;
 use super::*;
+use std::{error::Error, fmt::Display};
+
+/// A construct which could not be represented in the built AST.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AstBuilderError {
+    pub message: String,
+    pub src: String,
+}
+
+impl Display for AstBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.message, self.src)
+    }
+}
+
+impl Error for AstBuilderError {}
 
 #[derive(Default)]
 pub struct AstBuilder {
     id: i64,
     scope: i64,
+    errors: Vec<AstBuilderError>,
+    warnings: Vec<AstBuilderError>,
 }
 
 impl AstBuilder {
+    /// The errors which were recorded while building, in the order they were encountered.
+    pub fn errors(&self) -> &[AstBuilderError] {
+        self.errors.as_slice()
+    }
+
+    /// Removes and returns all of the recorded errors.
+    pub fn take_errors(&mut self) -> Vec<AstBuilderError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// The top-level constructs which were skipped while building, in the order they were encountered.
+    pub fn warnings(&self) -> &[AstBuilderError] {
+        self.warnings.as_slice()
+    }
+
+    /// Removes and returns all of the recorded warnings.
+    pub fn take_warnings(&mut self) -> Vec<AstBuilderError> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Records an error for a construct which is skipped or only partially represented in the built AST.
+    fn error<S: Into<String>>(&mut self, loc: &solang_parser::pt::Loc, message: S) {
+        let src = self.loc_to_src(loc);
+        self.errors.push(AstBuilderError { message: message.into(), src });
+    }
+
+    /// Records a warning for a top-level construct which is skipped without affecting the rest of the source unit.
+    fn warning<S: Into<String>>(&mut self, loc: &solang_parser::pt::Loc, message: S) {
+        let src = self.loc_to_src(loc);
+        self.warnings.push(AstBuilderError { message: message.into(), src });
+    }
+
     pub fn next_node_id(&mut self) -> i64 {
         let result = self.id;
         self.id += 1;
@@ -29,21 +79,16 @@ impl AstBuilder {
         let source_unit_id = self.next_node_id();
         let source_unit_scope = self.next_scope();
 
-        let mut result = SourceUnit {
-            license: None,
-            nodes: vec![],
-            exported_symbols: None,
-            experimental_solidity: None,
-            absolute_path: None,
-            id: source_unit_id,
-            source: None,
-        };
+        let mut result = SourceUnit::new(source_unit_id);
 
         for part in input.0.iter() {
             match part {
                 solang_parser::pt::SourceUnitPart::PragmaDirective(loc, identifier, literal) => {
-                    let Some(identifier) = identifier else { todo!() };
-                    let Some(literal) = literal else { todo!() };
+                    let (Some(identifier), Some(literal)) = (identifier, literal) else {
+                        self.error(loc, "Incomplete pragma directive");
+                        continue;
+                    };
+
                     result.nodes.push(SourceUnitNode::PragmaDirective(self.build_pragma_directive(loc, identifier, literal)));
                 }
 
@@ -64,7 +109,7 @@ impl AstBuilder {
                 }
 
                 solang_parser::pt::SourceUnitPart::EventDefinition(input) => {
-                    todo!()
+                    self.warning(&input.loc, "Unsupported file-level event definition");
                 }
 
                 solang_parser::pt::SourceUnitPart::ErrorDefinition(input) => {
@@ -72,7 +117,7 @@ impl AstBuilder {
                 }
 
                 solang_parser::pt::SourceUnitPart::FunctionDefinition(input) => {
-                    todo!()
+                    self.warning(&input.loc, "Unsupported free function definition");
                 }
 
                 solang_parser::pt::SourceUnitPart::VariableDefinition(input) => {
@@ -84,11 +129,11 @@ impl AstBuilder {
                 }
 
                 solang_parser::pt::SourceUnitPart::Annotation(annotation) => {
-                    todo!()
+                    self.warning(&annotation.loc, "Unsupported annotation");
                 }
 
                 solang_parser::pt::SourceUnitPart::Using(using) => {
-                    todo!()
+                    self.warning(&using.loc, "Unsupported file-level using for directive");
                 }
 
                 solang_parser::pt::SourceUnitPart::StraySemicolon(_) => {}
@@ -118,23 +163,33 @@ impl AstBuilder {
     }
 
     pub fn build_import_directive(&mut self, scope: i64, input: &solang_parser::pt::Import) -> ImportDirective {
-        match input {
-            solang_parser::pt::Import::Plain(file, loc) => {
-                ImportDirective {
-                    file: file.string.clone(),
-                    source_unit: -1, // TODO: use imported `source_unit.id`
-                    scope,
-                    absolute_path: Some(file.string.clone()),
-                    unit_alias: String::new(),
-                    name_location: Some("-1:-1:-1".to_string()), // TODO
-                    symbol_aliases: vec![],
-                    src: self.loc_to_src(loc),
-                    id: self.next_node_id(),
-                }
+        let (file, unit_alias, symbol_aliases, loc) = match input {
+            solang_parser::pt::Import::Plain(file, loc) => (file, None, vec![], loc),
+            solang_parser::pt::Import::GlobalSymbol(file, alias, loc) => (file, Some(alias), vec![], loc),
+
+            solang_parser::pt::Import::Rename(file, symbols, loc) => {
+                let symbol_aliases = symbols.iter()
+                    .map(|(foreign, local)| SymbolAlias {
+                        foreign: self.build_expression(&solang_parser::pt::Expression::Variable(foreign.clone())),
+                        local: local.as_ref().map(|local| local.name.clone()),
+                        name_location: Some(self.loc_to_src(&local.as_ref().unwrap_or(foreign).loc)),
+                    })
+                    .collect();
+
+                (file, None, symbol_aliases, loc)
             }
+        };
 
-            solang_parser::pt::Import::GlobalSymbol(_, _, _) => todo!(),
-            solang_parser::pt::Import::Rename(_, _, _) => todo!(),
+        ImportDirective {
+            file: file.string.clone(),
+            source_unit: -1, // Assigned by the loader once the imported file has been parsed
+            scope,
+            absolute_path: Some(file.string.clone()),
+            unit_alias: unit_alias.map(|alias| alias.name.clone()).unwrap_or_default(),
+            name_location: Some(unit_alias.map(|alias| self.loc_to_src(&alias.loc)).unwrap_or_else(|| "-1:-1:-1".to_string())),
+            symbol_aliases,
+            src: self.loc_to_src(loc),
+            id: self.next_node_id(),
         }
     }
 
@@ -158,9 +213,9 @@ impl AstBuilder {
                 src: self.loc_to_src(&base.loc),
                 id: self.next_node_id(),
             }).collect(),
-            contract_dependencies: vec![], // TODO
-            used_events: None, // TODO
-            used_errors: None, // TODO
+            contract_dependencies: vec![], // Computed by `compute_contract_metadata`
+            used_events: None, // Computed by `compute_contract_metadata`
+            used_errors: None, // Computed by `compute_contract_metadata`
             nodes: input.parts.iter()
                 .map(|part| {
                     match part {
@@ -181,7 +236,9 @@ impl AstBuilder {
                         }
 
                         solang_parser::pt::ContractPart::VariableDefinition(x) => {
-                            Some(ContractDefinitionNode::VariableDeclaration(self.build_variable_declaration(contract_scope, x)))
+                            let mut variable_declaration = self.build_variable_declaration(contract_scope, x);
+                            variable_declaration.state_variable = true;
+                            Some(ContractDefinitionNode::VariableDeclaration(variable_declaration))
                         }
 
                         solang_parser::pt::ContractPart::FunctionDefinition(x) => {
@@ -208,9 +265,9 @@ impl AstBuilder {
                 .map(|x| x.unwrap())
                 .collect(),
             scope,
-            fully_implemented: None, // TODO
-            linearized_base_contracts: None, // TODO
-            internal_function_ids: None, // TODO
+            fully_implemented: None, // Computed by `compute_contract_metadata`
+            linearized_base_contracts: None, // Computed by `compute_contract_metadata`
+            internal_function_ids: None, // Computed by `compute_contract_metadata`
             src: self.loc_to_src(&input.loc),
             id: self.next_node_id(),
         }
@@ -260,7 +317,7 @@ impl AstBuilder {
                         },
                         type_name: Some(self.build_type_name(&field.ty)),
                         value: None,
-                        visibility: Visibility::Public,
+                        visibility: Visibility::Internal,
                         src: self.loc_to_src(&field.loc),
                         id: self.next_node_id(),
                     }
@@ -279,6 +336,7 @@ impl AstBuilder {
         EventDefinition {
             anonymous: input.anonymous,
             documentation: None,
+            event_selector: None, // Computed by `check_types`
             name: input.name.as_ref().map(|x| x.name.clone()).unwrap_or_else(String::new),
             name_location: input.name.as_ref().map(|x| self.loc_to_src(&x.loc)),
             parameters: ParameterList {
@@ -303,7 +361,7 @@ impl AstBuilder {
                             },
                             type_name: Some(self.build_type_name(&field.ty)),
                             value: None,
-                            visibility: Visibility::Public,
+                            visibility: Visibility::Internal,
                             src: self.loc_to_src(&field.loc),
                             id: self.next_node_id(),
                         }
@@ -322,6 +380,7 @@ impl AstBuilder {
 
         ErrorDefinition {
             documentation: None,
+            error_selector: None, // Computed by `check_types`
             name: input.name.as_ref().map(|x| x.name.clone()).unwrap_or_else(String::new),
             name_location: input.name.as_ref().map(|x| self.loc_to_src(&x.loc)),
             parameters: ParameterList {
@@ -346,7 +405,7 @@ impl AstBuilder {
                             },
                             type_name: Some(self.build_type_name(&field.ty)),
                             value: None,
-                            visibility: Visibility::Public,
+                            visibility: Visibility::Internal,
                             src: self.loc_to_src(&field.loc),
                             id: self.next_node_id(),
                         }
@@ -361,7 +420,8 @@ impl AstBuilder {
     }
 
     pub fn build_function_definition(&mut self, scope: i64, input: &solang_parser::pt::FunctionDefinition) -> FunctionDefinition {
-        let mut visibility = Visibility::Internal;
+        // Constructors and functions without a visibility keyword are public, as in solc
+        let mut visibility = Visibility::Public;
         let mut state_mutability = StateMutability::NonPayable;
         let mut is_virtual = None;
         let mut overrides = None;
@@ -379,13 +439,13 @@ impl AstBuilder {
                 solang_parser::pt::FunctionAttribute::Mutability(x) => match x {
                     solang_parser::pt::Mutability::Pure(_) => state_mutability = StateMutability::Pure,
                     solang_parser::pt::Mutability::View(_) => state_mutability = StateMutability::View,
-                    solang_parser::pt::Mutability::Constant(_) => panic!("Invalid function state mutability: Constant"),
+                    solang_parser::pt::Mutability::Constant(_) => state_mutability = StateMutability::View, // Pre-0.5 spelling of `view`
                     solang_parser::pt::Mutability::Payable(_) => state_mutability = StateMutability::Payable,
                 },
 
                 solang_parser::pt::FunctionAttribute::Virtual(_) => is_virtual = Some(true),
 
-                solang_parser::pt::FunctionAttribute::Immutable(_) => panic!("Invalid function attribute: Immutable"),
+                solang_parser::pt::FunctionAttribute::Immutable(loc) => self.error(loc, "Invalid function attribute: immutable"),
 
                 solang_parser::pt::FunctionAttribute::Override(loc, x) => overrides = Some(OverrideSpecifier {
                     overrides: x.iter()
@@ -414,18 +474,23 @@ impl AstBuilder {
 
         let function_scope = self.next_scope();
 
+        // Modifiers are built by `build_modifier_definition`
+        if input.ty == solang_parser::pt::FunctionTy::Modifier {
+            self.error(&input.loc, "Modifier built as a function definition");
+        }
+
         FunctionDefinition {
             base_functions: None, // TODO
             body: input.body.as_ref().map(|body| self.build_block(function_scope, body)),
             documentation: None, // TODO
-            function_selector: None, // TODO
+            function_selector: None, // Computed by `check_types`
             implemented: input.body.is_some(), // TODO: is this correct?
             kind: match input.ty {
                 solang_parser::pt::FunctionTy::Constructor => FunctionKind::Constructor,
                 solang_parser::pt::FunctionTy::Function => FunctionKind::Function,
                 solang_parser::pt::FunctionTy::Fallback => FunctionKind::Fallback,
                 solang_parser::pt::FunctionTy::Receive => FunctionKind::Receive,
-                solang_parser::pt::FunctionTy::Modifier => panic!("Invalid function kind: Modifier"), // TODO: handle ahead of time?
+                solang_parser::pt::FunctionTy::Modifier => FunctionKind::Function,
             },
             modifiers,
             name: input.name.as_ref().map(|x| x.name.clone()).unwrap_or_else(String::new),
@@ -455,7 +520,7 @@ impl AstBuilder {
                             },
                             type_name: Some(parameter.as_ref().map(|x| self.build_type_name(&x.ty)).unwrap()),
                             value: None,
-                            visibility,
+                            visibility: Visibility::Internal,
                             src: self.loc_to_src(loc),
                             id: self.next_node_id(),
                         }
@@ -488,7 +553,7 @@ impl AstBuilder {
                             },
                             type_name: Some(parameter.as_ref().map(|x| self.build_type_name(&x.ty)).unwrap()),
                             value: None,
-                            visibility,
+                            visibility: Visibility::Internal,
                             src: self.loc_to_src(loc),
                             id: self.next_node_id(),
                         }
@@ -526,13 +591,13 @@ impl AstBuilder {
                 solang_parser::pt::FunctionAttribute::Mutability(x) => match x {
                     solang_parser::pt::Mutability::Pure(_) => state_mutability = StateMutability::Pure,
                     solang_parser::pt::Mutability::View(_) => state_mutability = StateMutability::View,
-                    solang_parser::pt::Mutability::Constant(_) => panic!("Invalid function state mutability: Constant"),
+                    solang_parser::pt::Mutability::Constant(_) => state_mutability = StateMutability::View, // Pre-0.5 spelling of `view`
                     solang_parser::pt::Mutability::Payable(_) => state_mutability = StateMutability::Payable,
                 },
 
                 solang_parser::pt::FunctionAttribute::Virtual(_) => is_virtual = Some(true),
 
-                solang_parser::pt::FunctionAttribute::Immutable(_) => panic!("Invalid function attribute: Immutable"),
+                solang_parser::pt::FunctionAttribute::Immutable(loc) => self.error(loc, "Invalid function attribute: immutable"),
 
                 solang_parser::pt::FunctionAttribute::Override(loc, x) => overrides = Some(OverrideSpecifier {
                     overrides: x.iter()
@@ -562,7 +627,11 @@ impl AstBuilder {
         let modifier_scope = self.next_scope();
 
         ModifierDefinition {
-            body: input.body.as_ref().map(|body| self.build_block(modifier_scope, body)).unwrap(),
+            // Modifiers without an implementation (i.e: `modifier onlyOwner() virtual;`) have an empty body
+            body: match input.body.as_ref() {
+                Some(body) => self.build_block(modifier_scope, body),
+                None => self.build_empty_block(&input.loc),
+            },
             overrides,
             documentation: None,
             name: input.name.as_ref().map(|x| x.name.clone()).unwrap_or_else(String::new),
@@ -591,7 +660,7 @@ impl AstBuilder {
                             },
                             type_name: Some(parameter.as_ref().map(|x| self.build_type_name(&x.ty)).unwrap()),
                             value: None,
-                            visibility,
+                            visibility: Visibility::Internal,
                             src: self.loc_to_src(loc),
                             id: self.next_node_id(),
                         }
@@ -608,7 +677,8 @@ impl AstBuilder {
     }
 
     pub fn build_variable_declaration(&mut self, scope: i64, input: &solang_parser::pt::VariableDefinition) -> VariableDeclaration {
-        let mut visibility = Visibility::Public;
+        // State variables without a visibility keyword are internal, as in solc
+        let mut visibility = Visibility::Internal;
         let mut mutability = None;
         let mut constant = false;
         let mut overrides = None;
@@ -647,7 +717,7 @@ impl AstBuilder {
             name_location: input.name.as_ref().map(|x| self.loc_to_src(&x.loc)),
             overrides,
             scope,
-            state_variable: false, // TODO: is this in the type expression?
+            state_variable: false,
             storage_location: StorageLocation::Default, // TODO: is this in the type expression?
             type_descriptions: TypeDescriptions {
                 type_identifier: None, // TODO
@@ -673,13 +743,45 @@ impl AstBuilder {
     }
 
     pub fn build_using_for_directive(&mut self, input: &solang_parser::pt::Using) -> UsingForDirective {
+        let (library_name, function_list) = match &input.list {
+            solang_parser::pt::UsingList::Library(path) => (Some(self.build_identifier_path(path)), None),
+
+            solang_parser::pt::UsingList::Functions(functions) => {
+                let function_list = functions.iter()
+                    .map(|function| {
+                        let path = Some(self.build_identifier_path(&function.path));
+
+                        // Functions bound to an operator are referred to as definitions
+                        match function.oper.as_ref() {
+                            Some(operator) => UsingForFunction {
+                                function: None,
+                                definition: path,
+                                operator: Some(operator.as_str().to_string()),
+                            },
+
+                            None => UsingForFunction {
+                                function: path,
+                                definition: None,
+                                operator: None,
+                            },
+                        }
+                    })
+                    .collect();
+
+                (None, Some(function_list))
+            }
+
+            solang_parser::pt::UsingList::Error => {
+                self.error(&input.loc, "Invalid using for directive");
+                (None, None)
+            }
+        };
+
         UsingForDirective {
-            library_name: match &input.list {
-                solang_parser::pt::UsingList::Library(path) => self.build_identifier_path(path),
-                solang_parser::pt::UsingList::Functions(_) => todo!(),
-                solang_parser::pt::UsingList::Error => todo!(),
-            },
+            library_name,
+            function_list,
             type_name: input.ty.as_ref().map(|x| self.build_type_name(x)),
+            global: Some(input.global.is_some()),
             src: self.loc_to_src(&input.loc),
             id: self.next_node_id(),
         }
@@ -697,8 +799,8 @@ impl AstBuilder {
 
     pub fn build_identifier_path(&mut self, path: &solang_parser::pt::IdentifierPath) -> IdentifierPath {
         IdentifierPath {
-            name: path.identifiers.iter().map(|x| x.name.clone()).collect::<Vec<_>>().join("."), // TODO
-            referenced_declaration: None, // TODO
+            name: path.identifiers.iter().map(|x| x.name.clone()).collect::<Vec<_>>().join("."),
+            referenced_declaration: None, // Filled in by `NameResolver`
             src: self.loc_to_src(&path.loc),
             id: self.next_node_id(),
         }
@@ -706,7 +808,7 @@ impl AstBuilder {
 
     pub fn build_type_name(&mut self, input: &solang_parser::pt::Expression) -> TypeName {
         match input {
-            solang_parser::pt::Expression::Type(_loc, ty) => match ty {
+            solang_parser::pt::Expression::Type(type_loc, ty) => match ty {
                 solang_parser::pt::Type::Address => TypeName::ElementaryTypeName(ElementaryTypeName {
                     state_mutability: None, // TODO
                     name: "address".to_string(),
@@ -779,7 +881,18 @@ impl AstBuilder {
                     },
                 }),
     
-                solang_parser::pt::Type::Rational => todo!(),
+                solang_parser::pt::Type::Rational => {
+                    self.error(type_loc, "Unsupported rational type name");
+
+                    TypeName::ElementaryTypeName(ElementaryTypeName {
+                        state_mutability: None,
+                        name: "rational".to_string(),
+                        type_descriptions: TypeDescriptions {
+                            type_identifier: None,
+                            type_string: None,
+                        },
+                    })
+                }
     
                 solang_parser::pt::Type::DynamicBytes => TypeName::ElementaryTypeName(ElementaryTypeName {
                     state_mutability: None, // TODO
@@ -818,13 +931,13 @@ impl AstBuilder {
                             solang_parser::pt::FunctionAttribute::Mutability(x) => match x {
                                 solang_parser::pt::Mutability::Pure(_) => state_mutability = StateMutability::Pure,
                                 solang_parser::pt::Mutability::View(_) => state_mutability = StateMutability::View,
-                                solang_parser::pt::Mutability::Constant(_) => panic!("Invalid function state mutability: Constant"),
+                                solang_parser::pt::Mutability::Constant(_) => state_mutability = StateMutability::View, // Pre-0.5 spelling of `view`
                                 solang_parser::pt::Mutability::Payable(_) => state_mutability = StateMutability::Payable,
                             },
             
                             solang_parser::pt::FunctionAttribute::Virtual(_) => is_virtual = Some(true),
             
-                            solang_parser::pt::FunctionAttribute::Immutable(_) => panic!("Invalid function attribute: Immutable"),
+                            solang_parser::pt::FunctionAttribute::Immutable(loc) => self.error(loc, "Invalid function attribute: immutable"),
             
                             solang_parser::pt::FunctionAttribute::Override(loc, x) => overrides = Some(OverrideSpecifier {
                                 overrides: x.iter()
@@ -878,7 +991,7 @@ impl AstBuilder {
                                         },
                                         type_name: Some(parameter.as_ref().map(|x| self.build_type_name(&x.ty)).unwrap()),
                                         value: None,
-                                        visibility,
+                                        visibility: Visibility::Internal,
                                         src: self.loc_to_src(loc),
                                         id: self.next_node_id(),
                                     }
@@ -913,7 +1026,7 @@ impl AstBuilder {
                                                 },
                                                 type_name: Some(parameter.as_ref().map(|x| self.build_type_name(&x.ty)).unwrap()),
                                                 value: None,
-                                                visibility,
+                                                visibility: Visibility::Internal,
                                                 src: self.loc_to_src(loc),
                                                 id: self.next_node_id(),
                                             }
@@ -942,8 +1055,13 @@ impl AstBuilder {
             }),
 
             solang_parser::pt::Expression::Variable(identifier) => TypeName::UserDefinedTypeName(UserDefinedTypeName {
-                path_node: None, // TODO
-                referenced_declaration: -1, // TODO
+                path_node: Some(IdentifierPath {
+                    name: identifier.name.clone(),
+                    referenced_declaration: None,
+                    src: self.loc_to_src(&identifier.loc),
+                    id: self.next_node_id(),
+                }),
+                referenced_declaration: -1,
                 name: Some(identifier.name.clone()),
                 type_descriptions: TypeDescriptions {
                     type_identifier: None, // TODO
@@ -951,7 +1069,54 @@ impl AstBuilder {
                 },
             }),
 
-            _ => panic!("Unhandled type name expression: {input:#?}"),
+            solang_parser::pt::Expression::MemberAccess(loc, _, _) => {
+                let name = self.build_type_path(input);
+
+                TypeName::UserDefinedTypeName(UserDefinedTypeName {
+                    path_node: Some(IdentifierPath {
+                        name: name.clone(),
+                        referenced_declaration: None,
+                        src: self.loc_to_src(loc),
+                        id: self.next_node_id(),
+                    }),
+                    referenced_declaration: -1,
+                    name: Some(name),
+                    type_descriptions: TypeDescriptions {
+                        type_identifier: None, // TODO
+                        type_string: None, // TODO
+                    },
+                })
+            }
+
+            _ => {
+                use solang_parser::helpers::CodeLocation;
+
+                self.error(&input.loc(), format!("Unsupported type name expression: `{input}`"));
+
+                TypeName::ElementaryTypeName(ElementaryTypeName {
+                    state_mutability: None,
+                    name: input.to_string(),
+                    type_descriptions: TypeDescriptions {
+                        type_identifier: None,
+                        type_string: None,
+                    },
+                })
+            }
+        }
+    }
+
+    /// Builds the dotted name of a qualified type name (i.e: `Library.Struct`).
+    pub fn build_type_path(&mut self, input: &solang_parser::pt::Expression) -> String {
+        match input {
+            solang_parser::pt::Expression::Variable(identifier) => identifier.name.clone(),
+            solang_parser::pt::Expression::MemberAccess(_, expression, member) => format!("{}.{}", self.build_type_path(expression), member.name),
+
+            _ => {
+                use solang_parser::helpers::CodeLocation;
+
+                self.error(&input.loc(), format!("Unsupported type name expression: `{input}`"));
+                input.to_string()
+            }
         }
     }
 
@@ -964,7 +1129,16 @@ impl AstBuilder {
                 src: self.loc_to_src(loc),
                 id: self.next_node_id(),
             },
-            stmt => panic!("Invalid block statement: {stmt:?}"),
+            // Single statements are wrapped in a block of their own
+            stmt => {
+                use solang_parser::helpers::CodeLocation;
+
+                Block {
+                    statements: vec![self.build_statement(scope, stmt)],
+                    src: self.loc_to_src(&stmt.loc()),
+                    id: self.next_node_id(),
+                }
+            }
         }
     }
 
@@ -978,20 +1152,23 @@ impl AstBuilder {
     pub fn build_statement(&mut self, scope: i64, input: &solang_parser::pt::Statement) -> Statement {
         match input {
             solang_parser::pt::Statement::Block { unchecked, .. } => {
-                if !*unchecked {
-                    panic!("Generic block passed as statement: {input:#?}");
-                }
-
-                let unchecked_scope = self.next_scope();
+                let block_scope = self.next_scope();
 
-                Statement::UncheckedBlock(self.build_block(unchecked_scope, input))
+                if *unchecked {
+                    Statement::UncheckedBlock(self.build_block(block_scope, input))
+                } else {
+                    Statement::Block(self.build_block(block_scope, input))
+                }
             }
 
             solang_parser::pt::Statement::Assembly { loc, dialect, flags, block } => {
                 Statement::InlineAssembly(self.build_inline_assembly(loc, dialect.as_ref(), flags.as_ref().map(|x| x.as_slice()), block))
             }
             
-            solang_parser::pt::Statement::Args(_, _) => todo!(),
+            solang_parser::pt::Statement::Args(loc, _) => {
+                self.error(loc, "Unexpected named arguments statement");
+                Statement::Block(self.build_empty_block(loc))
+            }
 
             solang_parser::pt::Statement::If(loc, condition, true_body, false_body) => {
                 let if_true_scope = self.next_scope();
@@ -1031,7 +1208,47 @@ impl AstBuilder {
                             })
                         }
                     }
-                    
+
+                    // Tuples of named parameters declare multiple variables (i.e: `(bool success, bytes memory data) = ...`)
+                    solang_parser::pt::Expression::Assign(_, lhs, rhs) if matches!(
+                        lhs.as_ref(),
+                        solang_parser::pt::Expression::List(_, list) if list.iter().any(|(_, x)| x.as_ref().map(|x| x.name.is_some()).unwrap_or(false))
+                    ) => {
+                        let solang_parser::pt::Expression::List(_, list) = lhs.as_ref() else { unreachable!() };
+
+                        Statement::VariableDeclarationStatement(VariableDeclarationStatement {
+                            assignments: vec![], // TODO
+                            declarations: list.iter()
+                                .map(|(_, x)| x.as_ref().map(|x| VariableDeclaration {
+                                    base_functions: None,
+                                    constant: false,
+                                    documentation: None,
+                                    function_selector: None,
+                                    indexed: None,
+                                    mutability: None, // TODO
+                                    name: x.name.as_ref().map(|x| x.name.clone()).unwrap_or_else(String::new),
+                                    name_location: x.name.as_ref().map(|x| self.loc_to_src(&x.loc)),
+                                    overrides: None,
+                                    scope,
+                                    state_variable: false,
+                                    storage_location: self.build_storage_location(&x.storage),
+                                    type_descriptions: TypeDescriptions {
+                                        type_identifier: None,
+                                        type_string: None,
+                                    },
+                                    type_name: Some(self.build_type_name(&x.ty)),
+                                    value: None,
+                                    visibility: Visibility::Internal,
+                                    src: self.loc_to_src(&x.loc),
+                                    id: self.next_node_id(),
+                                }))
+                                .collect(),
+                            initial_value: Some(self.build_expression(rhs)),
+                            src: self.loc_to_src(loc),
+                            id: self.next_node_id(),
+                        })
+                    }
+
                     _ => Statement::ExpressionStatement(ExpressionStatement {
                         expression: self.build_expression(x),
                     })
@@ -1061,7 +1278,7 @@ impl AstBuilder {
                             },
                             type_name: Some(self.build_type_name(&variable.ty)),
                             value: None,
-                            visibility: Visibility::Public, // TODO
+                            visibility: Visibility::Internal,
                             src: self.loc_to_src(&variable.loc),
                             id: self.next_node_id(),
                         })
@@ -1081,7 +1298,11 @@ impl AstBuilder {
                     loop_expression: update.as_ref().map(|x| Box::new(Statement::ExpressionStatement(ExpressionStatement {
                         expression: self.build_expression(x),
                     }))),
-                    body: body.as_ref().map(|x| self.build_block_or_statement(for_scope, x)).unwrap(),
+                    // Loops without a body (i.e: `for (;;);`) have an empty block
+                    body: match body.as_ref() {
+                        Some(body) => self.build_block_or_statement(for_scope, body),
+                        None => BlockOrStatement::Block(Box::new(self.build_empty_block(loc))),
+                    },
                     src: self.loc_to_src(loc),
                     id: self.next_node_id(),
                 })
@@ -1193,7 +1414,27 @@ impl AstBuilder {
                 }
             }
 
-            solang_parser::pt::Statement::RevertNamedArgs(_, _, _) => todo!(),
+            solang_parser::pt::Statement::RevertNamedArgs(loc, function, arguments) => {
+                let mut statement = self.build_statement(scope, &solang_parser::pt::Statement::Revert(
+                    *loc,
+                    function.clone(),
+                    arguments.iter().map(|x| x.expr.clone()).collect(),
+                ));
+
+                let names = arguments.iter().map(|x| x.name.name.clone()).collect();
+
+                match &mut statement {
+                    Statement::RevertStatement(RevertStatement { error_call }) => error_call.names = names,
+
+                    Statement::ExpressionStatement(ExpressionStatement { expression: Expression::FunctionCall(function_call) }) => {
+                        function_call.names = names;
+                    }
+
+                    _ => {}
+                }
+
+                statement
+            }
 
             solang_parser::pt::Statement::Emit(_loc, x) => {
                 Statement::EmitStatement(EmitStatement {
@@ -1201,8 +1442,139 @@ impl AstBuilder {
                 })
             }
 
-            solang_parser::pt::Statement::Try(_, _, _, _) => todo!(),
-            solang_parser::pt::Statement::Error(_) => todo!(),
+            solang_parser::pt::Statement::Try(loc, expression, returns, catch_clauses) => {
+                let external_call = match self.build_expression(expression) {
+                    Expression::FunctionCall(function_call) => function_call,
+                    expression => {
+                        self.error(loc, "Invalid try expression: expected an external function call");
+
+                        FunctionCall {
+                            kind: FunctionCallKind::FunctionCall,
+                            try_call: Some(true),
+                            names: vec![],
+                            arguments: vec![],
+                            expression: Box::new(expression),
+                            argument_types: None,
+                            is_constant: false,
+                            is_l_value: false,
+                            is_pure: false,
+                            l_value_requested: false,
+                            type_descriptions: TypeDescriptions {
+                                type_identifier: None,
+                                type_string: None,
+                            },
+                            src: self.loc_to_src(loc),
+                            id: self.next_node_id(),
+                        }
+                    }
+                };
+
+                // The first clause is executed when the call succeeds, the rest are `catch` clauses
+                let mut clauses = vec![];
+
+                clauses.push(match returns {
+                    Some((parameters, body)) => {
+                        let clause_scope = self.next_scope();
+
+                        let parameters_loc = match (parameters.first(), parameters.last()) {
+                            (Some((first, _)), Some((last, _))) => first.with_end(last.end()),
+                            _ => *loc,
+                        };
+
+                        TryCatchClause {
+                            block: self.build_block(clause_scope, body),
+                            error_name: Some(String::new()),
+                            parameters: Some(ParameterList {
+                                parameters: parameters.iter()
+                                    .filter_map(|(loc, parameter)| parameter.as_ref().map(|parameter| (loc, parameter)))
+                                    .map(|(loc, parameter)| self.build_try_catch_parameter(clause_scope, loc, parameter))
+                                    .collect(),
+                                src: self.loc_to_src(&parameters_loc),
+                                id: self.next_node_id(),
+                            }),
+                        }
+                    }
+
+                    None => TryCatchClause {
+                        block: Block {
+                            statements: vec![],
+                            src: self.loc_to_src(loc),
+                            id: self.next_node_id(),
+                        },
+                        error_name: Some(String::new()),
+                        parameters: None,
+                    },
+                });
+
+                for catch_clause in catch_clauses.iter() {
+                    let clause_scope = self.next_scope();
+
+                    let (error_name, parameter, body) = match catch_clause {
+                        solang_parser::pt::CatchClause::Simple(_, parameter, body) => (String::new(), parameter.as_ref(), body),
+                        solang_parser::pt::CatchClause::Named(_, name, parameter, body) => (name.name.clone(), Some(parameter), body),
+                    };
+
+                    clauses.push(TryCatchClause {
+                        block: self.build_block(clause_scope, body),
+                        error_name: Some(error_name),
+                        parameters: parameter.map(|parameter| ParameterList {
+                            parameters: vec![self.build_try_catch_parameter(clause_scope, &parameter.loc, parameter)],
+                            src: self.loc_to_src(&parameter.loc),
+                            id: self.next_node_id(),
+                        }),
+                    });
+                }
+
+                Statement::TryStatement(TryStatement {
+                    clauses,
+                    external_call,
+                })
+            }
+
+            solang_parser::pt::Statement::Error(loc) => {
+                self.error(loc, "Invalid statement");
+                Statement::Block(self.build_empty_block(loc))
+            }
+        }
+    }
+
+    /// Builds an empty block in place of a statement which could not be built.
+    fn build_empty_block(&mut self, loc: &solang_parser::pt::Loc) -> Block {
+        Block {
+            statements: vec![],
+            src: self.loc_to_src(loc),
+            id: self.next_node_id(),
+        }
+    }
+
+    pub fn build_try_catch_parameter(
+        &mut self,
+        scope: i64,
+        loc: &solang_parser::pt::Loc,
+        parameter: &solang_parser::pt::Parameter,
+    ) -> VariableDeclaration {
+        VariableDeclaration {
+            base_functions: None,
+            constant: false,
+            documentation: None,
+            function_selector: None,
+            indexed: None,
+            mutability: None,
+            name: parameter.name.as_ref().map(|x| x.name.clone()).unwrap_or_default(),
+            name_location: parameter.name.as_ref().map(|x| self.loc_to_src(&x.loc)),
+            overrides: None,
+            scope,
+            state_variable: false,
+            storage_location: self.build_storage_location(&parameter.storage),
+            type_descriptions: TypeDescriptions {
+                type_identifier: None, // TODO
+                type_string: None, // TODO
+            },
+            type_name: Some(self.build_type_name(&parameter.ty)),
+            value: None,
+            visibility: Visibility::Internal,
+            src: self.loc_to_src(loc),
+            id: self.next_node_id(),
         }
     }
 
@@ -1226,10 +1598,28 @@ impl AstBuilder {
                 id: self.next_node_id(),
             },
 
-            solang_parser::pt::Expression::NumberLiteral(loc, x, _, _) => Literal {
+            solang_parser::pt::Expression::NumberLiteral(loc, x, exponent, unit) => Literal {
                 hex_value: None, // TODO
-                value: Some(format!("{x}")),
-                subdenomination: None,
+                value: Some(if exponent.is_empty() { x.clone() } else { format!("{x}e{exponent}") }),
+                subdenomination: unit.as_ref().map(|unit| unit.name.clone()),
+                kind: LiteralKind::Number,
+                argument_types: None,
+                is_constant: false, // TODO
+                is_l_value: false, // TODO
+                is_pure: false, // TODO
+                l_value_requested: false, // TODO
+                type_descriptions: TypeDescriptions {
+                    type_identifier: None, // TODO
+                    type_string: None, // TODO
+                },
+                src: self.loc_to_src(loc),
+                id: self.next_node_id(),
+            },
+
+            solang_parser::pt::Expression::RationalNumberLiteral(loc, integer, fraction, exponent, unit) => Literal {
+                hex_value: None, // TODO
+                value: Some(if exponent.is_empty() { format!("{integer}.{fraction}") } else { format!("{integer}.{fraction}e{exponent}") }),
+                subdenomination: unit.as_ref().map(|unit| unit.name.clone()),
                 kind: LiteralKind::Number,
                 argument_types: None,
                 is_constant: false, // TODO
@@ -1244,11 +1634,11 @@ impl AstBuilder {
                 id: self.next_node_id(),
             },
 
-            solang_parser::pt::Expression::HexNumberLiteral(loc, value, _) => Literal {
+            solang_parser::pt::Expression::HexNumberLiteral(loc, value, unit) => Literal {
                 hex_value: Some(value.clone()), // TODO
-                value: None,
-                subdenomination: None,
-                kind: LiteralKind::String,
+                value: Some(value.clone()),
+                subdenomination: unit.as_ref().map(|unit| unit.name.clone()),
+                kind: LiteralKind::Number,
                 argument_types: None,
                 is_constant: false, // TODO
                 is_l_value: false, // TODO
@@ -1280,6 +1670,32 @@ impl AstBuilder {
                 id: self.next_node_id(),
             },
 
+            solang_parser::pt::Expression::HexLiteral(x) => Literal {
+                hex_value: Some(
+                    x.iter()
+                        .map(|x| x.hex.trim_start_matches("hex").trim_matches(|c| c == '"' || c == '\'').replace('_', ""))
+                        .collect::<Vec<_>>()
+                        .join("")
+                ),
+                value: None,
+                subdenomination: None,
+                kind: LiteralKind::HexString,
+                argument_types: None,
+                is_constant: false, // TODO
+                is_l_value: false, // TODO
+                is_pure: false, // TODO
+                l_value_requested: false, // TODO
+                type_descriptions: TypeDescriptions {
+                    type_identifier: None, // TODO
+                    type_string: None, // TODO
+                },
+                src: match (x.first(), x.last()) {
+                    (Some(first), Some(last)) => self.loc_to_src(&first.loc.with_end_from(&last.loc)),
+                    _ => "-1:-1:-1".to_string(),
+                },
+                id: self.next_node_id(),
+            },
+
             solang_parser::pt::Expression::AddressLiteral(loc, x)=> Literal {
                 hex_value: None, // TODO
                 value: Some(x.clone()),
@@ -1298,7 +1714,30 @@ impl AstBuilder {
                 id: self.next_node_id(),
             },
 
-            _ => panic!("Invalid literal expression: {input:#?}"),
+            _ => {
+                use solang_parser::helpers::CodeLocation;
+
+                let loc = input.loc();
+                self.error(&loc, format!("Invalid literal expression: `{input}`"));
+
+                Literal {
+                    hex_value: None,
+                    value: Some(input.to_string()),
+                    subdenomination: None,
+                    kind: LiteralKind::String,
+                    argument_types: None,
+                    is_constant: false,
+                    is_l_value: false,
+                    is_pure: false,
+                    l_value_requested: false,
+                    type_descriptions: TypeDescriptions {
+                        type_identifier: None,
+                        type_string: None,
+                    },
+                    src: self.loc_to_src(&loc),
+                    id: self.next_node_id(),
+                }
+            }
         }
     }
 
@@ -1485,8 +1924,13 @@ impl AstBuilder {
         call: &solang_parser::pt::Expression,
         options: &solang_parser::pt::Statement,
     ) -> FunctionCallOptions {
-        let solang_parser::pt::Statement::Args(loc, args) = options else {
-            panic!("Invalid function call options: {options:#?}");
+        let (loc, args) = match options {
+            solang_parser::pt::Statement::Args(loc, args) => (loc, args.as_slice()),
+
+            _ => {
+                self.error(loc, "Invalid function call options");
+                (loc, [].as_slice())
+            }
         };
 
         FunctionCallOptions {
@@ -1516,11 +1960,12 @@ impl AstBuilder {
         &mut self,
         loc: &solang_parser::pt::Loc,
         array: &solang_parser::pt::Expression,
-        index: &solang_parser::pt::Expression,
+        index: Option<&solang_parser::pt::Expression>,
     ) -> IndexAccess {
         IndexAccess {
             base_expression: Box::new(self.build_expression(array)),
-            index_expression: Some(Box::new(self.build_expression(index))),
+            // Array type expressions (i.e: `uint256[]` in `abi.decode(data, (uint256[]))`) have no index
+            index_expression: index.map(|index| Box::new(self.build_expression(index))),
             argument_types: None, // TODO
             is_constant: false, // TODO
             is_l_value: false, // TODO
@@ -1569,12 +2014,37 @@ impl AstBuilder {
                 Expression::UnaryOperation(self.build_unary_operation(loc,x, "--", false))
             }
 
-            solang_parser::pt::Expression::New(loc, x) => {
-                Expression::NewExpression(self.build_new_expression(loc, x))
-            }
+            // solang parses `new C(...)` as a `new` wrapping the call, while solc wraps the `new` in the call
+            solang_parser::pt::Expression::New(loc, x) => match x.as_ref() {
+                solang_parser::pt::Expression::FunctionCall(call_loc, callee, arguments) => {
+                    use solang_parser::helpers::CodeLocation;
+
+                    let new_loc = loc.with_end(callee.loc().end());
+
+                    self.build_expression(&solang_parser::pt::Expression::FunctionCall(
+                        *call_loc,
+                        Box::new(solang_parser::pt::Expression::New(new_loc, callee.clone())),
+                        arguments.clone(),
+                    ))
+                }
+
+                solang_parser::pt::Expression::FunctionCallBlock(call_loc, callee, options) => {
+                    use solang_parser::helpers::CodeLocation;
+
+                    let new_loc = loc.with_end(callee.loc().end());
+
+                    self.build_expression(&solang_parser::pt::Expression::FunctionCallBlock(
+                        *call_loc,
+                        Box::new(solang_parser::pt::Expression::New(new_loc, callee.clone())),
+                        options.clone(),
+                    ))
+                }
+
+                _ => Expression::NewExpression(self.build_new_expression(loc, x)),
+            },
 
             solang_parser::pt::Expression::ArraySubscript(loc, array, index) => {
-                Expression::IndexAccess(self.build_index_access(loc, array, index.as_ref().unwrap()))
+                Expression::IndexAccess(self.build_index_access(loc, array, index.as_deref()))
             }
 
             solang_parser::pt::Expression::ArraySlice(loc, array, start, end) => {
@@ -1597,7 +2067,12 @@ impl AstBuilder {
                 Expression::FunctionCallOptions(self.build_function_call_options(loc, call, options))
             }
 
-            solang_parser::pt::Expression::NamedFunctionCall(_, _, _) => todo!(),
+            solang_parser::pt::Expression::NamedFunctionCall(loc, expression, arguments) => {
+                let values: Vec<_> = arguments.iter().map(|x| x.expr.clone()).collect();
+                let mut function_call = self.build_function_call(loc, expression, values.as_slice());
+                function_call.names = arguments.iter().map(|x| x.name.name.clone()).collect();
+                Expression::FunctionCall(function_call)
+            }
 
             solang_parser::pt::Expression::Not(loc, x) => {
                 Expression::UnaryOperation(self.build_unary_operation(loc, x, "!", true))
@@ -1607,7 +2082,9 @@ impl AstBuilder {
                 Expression::UnaryOperation(self.build_unary_operation(loc, x, "~", true))
             }
 
-            solang_parser::pt::Expression::Delete(_, _) => todo!(),
+            solang_parser::pt::Expression::Delete(loc, x) => {
+                Expression::UnaryOperation(self.build_unary_operation(loc, x, "delete", true))
+            }
             
             solang_parser::pt::Expression::PreIncrement(loc, x) => {
                 Expression::UnaryOperation(self.build_unary_operation(loc, x, "++", true))
@@ -1795,10 +2272,10 @@ impl AstBuilder {
                 Expression::TupleExpression(TupleExpression {
                     components: list.iter()
                         .map(|(loc, x)| {
-                            x.as_ref().map(|x| {
-                                Expression::Identifier(Identifier {
+                            x.as_ref().map(|x| match x.name.as_ref() {
+                                Some(name) => Expression::Identifier(Identifier {
                                     argument_types: None, // TODO
-                                    name: x.name.as_ref().map(|x| x.name.clone()).unwrap(),
+                                    name: name.name.clone(),
                                     overloaded_declarations: vec![], // TODO
                                     referenced_declaration: -1, // TODO
                                     type_descriptions: TypeDescriptions {
@@ -1807,7 +2284,10 @@ impl AstBuilder {
                                     },
                                     src: self.loc_to_src(loc),
                                     id: self.next_node_id(),
-                                })
+                                }),
+
+                                // Unnamed components are plain expressions (i.e: `(a, b) = f()` or `abi.decode(x, (uint, bool))`)
+                                None => self.build_expression(&x.ty),
                             })
                         })
                         .collect(),
@@ -1826,7 +2306,25 @@ impl AstBuilder {
                 })
             }
 
-            solang_parser::pt::Expression::ArrayLiteral(_, _) => todo!(),
+            solang_parser::pt::Expression::ArrayLiteral(loc, elements) => {
+                Expression::TupleExpression(TupleExpression {
+                    components: elements.iter()
+                        .map(|x| Some(self.build_expression(x)))
+                        .collect(),
+                    argument_types: None, // TODO
+                    is_inline_array: true,
+                    is_constant: false, // TODO
+                    is_l_value: false, // TODO
+                    is_pure: false, // TODO
+                    l_value_requested: false, // TODO
+                    type_descriptions: TypeDescriptions {
+                        type_identifier: None, // TODO
+                        type_string: None, // TODO
+                    },
+                    src: self.loc_to_src(loc),
+                    id: self.next_node_id(),
+                })
+            }
         }
     }
 
@@ -1840,45 +2338,64 @@ impl AstBuilder {
         InlineAssembly {
             ast: Some(self.build_yul_block(block)),
             evm_version: None, // TODO
-            external_references: vec![], // TODO
+            external_references: vec![], // Computed by `resolve_references`
             operations: None, // TODO
             src: self.loc_to_src(loc),
             id: self.next_node_id(),
         }
     }
 
+    /// Yul nodes of inline assembly share their source locations with the surrounding Solidity code.
+    fn yul_src(&self, loc: &solang_parser::pt::Loc) -> (Option<String>, String) {
+        let src = self.loc_to_src(loc);
+        (Some(src.clone()), src)
+    }
+
     pub fn build_yul_block(&mut self, block: &solang_parser::pt::YulBlock) -> YulBlock {
+        let (native_src, src) = self.yul_src(&block.loc);
+
         YulBlock {
             statements: block.statements.iter()
                 .map(|stmt| self.build_yul_statement(stmt))
                 .collect(),
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
         }
     }
 
     pub fn build_yul_statement(&mut self, stmt: &solang_parser::pt::YulStatement) -> YulStatement {
         match stmt {
-            solang_parser::pt::YulStatement::Assign(_loc, variable_names, value) => {
+            solang_parser::pt::YulStatement::Assign(loc, variable_names, value) => {
+                let (native_src, src) = self.yul_src(loc);
+
                 YulStatement::YulAssignment(YulAssignment {
                     value: self.build_yul_expression(value),
                     variable_names: variable_names.iter()
                         .map(|x| self.build_yul_identifier(x))
                         .collect(),
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
                 })
             }
 
-            solang_parser::pt::YulStatement::VariableDeclaration(_loc, variables, value) => {
+            solang_parser::pt::YulStatement::VariableDeclaration(loc, variables, value) => {
+                let (native_src, src) = self.yul_src(loc);
+
                 YulStatement::YulVariableDeclaration(YulVariableDeclaration {
-                    value: Some(value.as_ref()
-                        .map(|x| self.build_yul_expression(x))
-                        .unwrap()),
+                    value: value.as_ref().map(|x| self.build_yul_expression(x)),
                     variables: variables.iter()
                         .map(|x| self.build_yul_typed_name(x))
                         .collect(),
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
                 })
             }
 
-            solang_parser::pt::YulStatement::If(_loc, condition, body) => {
-                YulStatement::YulIf(self.build_yul_if(condition, body))
+            solang_parser::pt::YulStatement::If(loc, condition, body) => {
+                YulStatement::YulIf(self.build_yul_if(loc, condition, body))
             }
 
             solang_parser::pt::YulStatement::For(x) => {
@@ -1889,104 +2406,190 @@ impl AstBuilder {
                 YulStatement::YulSwitch(self.build_yul_switch(switch))
             }
 
-            solang_parser::pt::YulStatement::Leave(_) => {
-                YulStatement::YulLeave
+            solang_parser::pt::YulStatement::Leave(loc) => {
+                let (native_src, src) = self.yul_src(loc);
+                YulStatement::YulLeave(YulLeave { native_src, src, id: Some(self.next_node_id()) })
             }
 
-            solang_parser::pt::YulStatement::Break(_) => {
-                YulStatement::YulBreak
+            solang_parser::pt::YulStatement::Break(loc) => {
+                let (native_src, src) = self.yul_src(loc);
+                YulStatement::YulBreak(YulBreak { native_src, src, id: Some(self.next_node_id()) })
             }
 
-            solang_parser::pt::YulStatement::Continue(_) => {
-                YulStatement::YulContinue
+            solang_parser::pt::YulStatement::Continue(loc) => {
+                let (native_src, src) = self.yul_src(loc);
+                YulStatement::YulContinue(YulContinue { native_src, src, id: Some(self.next_node_id()) })
             }
 
-            solang_parser::pt::YulStatement::Block(_) => todo!(),
+            solang_parser::pt::YulStatement::Block(block) => {
+                YulStatement::YulBlock(self.build_yul_block(block))
+            }
 
             solang_parser::pt::YulStatement::FunctionDefinition(function) => {
                 YulStatement::YulFunctionDefinition(self.build_yul_function_definition(function))
             }
             
             solang_parser::pt::YulStatement::FunctionCall(call) => {
+                let (native_src, src) = self.yul_src(&call.loc);
+
                 YulStatement::YulExpressionStatement(YulExpressionStatement {
-                    expression: YulExpression::YulFunctionCall(YulFunctionCall {
-                        function_name: YulIdentifier {
-                            name: call.id.name.clone(),
-                        },
-                        arguments: call.arguments.iter()
-                            .map(|arg| self.build_yul_expression(arg))
-                            .collect(),
-                    }),
+                    expression: YulExpression::YulFunctionCall(self.build_yul_function_call(call)),
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
                 })
             }
 
-            solang_parser::pt::YulStatement::Error(_) => panic!("Invalid yul statement: {stmt:#?}"),
+            solang_parser::pt::YulStatement::Error(loc) => {
+                self.error(loc, "Invalid yul statement");
+
+                let (native_src, src) = self.yul_src(loc);
+
+                YulStatement::YulBlock(YulBlock {
+                    statements: vec![],
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
+                })
+            }
         }
     }
 
     pub fn build_yul_identifier(&mut self, expression: &solang_parser::pt::YulExpression) -> YulIdentifier {
         match expression {
-            solang_parser::pt::YulExpression::Variable(identifier) => YulIdentifier {
-                name: identifier.name.clone(),
-            },
+            solang_parser::pt::YulExpression::Variable(identifier) => {
+                let (native_src, src) = self.yul_src(&identifier.loc);
+
+                YulIdentifier {
+                    name: identifier.name.clone(),
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
+                }
+            }
+
+            // Suffix accesses (i.e: `x.slot`) are represented as a single identifier, the same way solc does
+            solang_parser::pt::YulExpression::SuffixAccess(loc, expression, suffix) => {
+                let identifier = self.build_yul_identifier(expression);
+                let (native_src, src) = self.yul_src(loc);
+
+                YulIdentifier {
+                    name: format!("{}.{}", identifier.name, suffix.name),
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
+                }
+            }
+
+            _ => {
+                use solang_parser::helpers::CodeLocation;
+
+                let loc = expression.loc();
+                self.error(&loc, format!("Invalid yul identifier expression: `{expression}`"));
+
+                let (native_src, src) = self.yul_src(&loc);
 
-            _ => panic!("Invalid yul identifier expression: {expression:#?}"),
+                YulIdentifier {
+                    name: expression.to_string(),
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
+                }
+            }
         }
     }
 
     pub fn build_yul_typed_name(&mut self, identifier: &solang_parser::pt::YulTypedIdentifier) -> YulTypedName {
+        let (native_src, src) = self.yul_src(&identifier.loc);
+
         YulTypedName {
             r#type: identifier.ty.as_ref()
                 .map(|x| x.name.clone())
-                .unwrap_or_else(String::new),
+                .unwrap_or_default(),
             name: identifier.id.name.clone(),
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
         }
     }
 
     pub fn build_yul_if(
         &mut self,
+        loc: &solang_parser::pt::Loc,
         condition: &solang_parser::pt::YulExpression,
         body: &solang_parser::pt::YulBlock
     ) -> YulIf {
+        let (native_src, src) = self.yul_src(loc);
+
         YulIf {
             condition: self.build_yul_expression(condition),
             body: self.build_yul_block(body),
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
         }
     }
 
     pub fn build_yul_switch(&mut self, switch: &solang_parser::pt::YulSwitch) -> YulSwitch {
+        let (native_src, src) = self.yul_src(&switch.loc);
+
         YulSwitch {
             cases: switch.cases.iter()
+                .chain(switch.default.iter())
                 .map(|case| self.build_yul_case(case))
                 .collect(),
             expression: self.build_yul_expression(&switch.condition),
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
         }
     }
 
     pub fn build_yul_case(&mut self, case: &solang_parser::pt::YulSwitchOptions) -> YulCase {
         match case {
-            solang_parser::pt::YulSwitchOptions::Case(_loc, expression, body) => YulCase {
-                body: self.build_yul_block(body),
-                value: Some(self.build_yul_expression(expression)),
-            },
+            solang_parser::pt::YulSwitchOptions::Case(loc, expression, body) => {
+                let (native_src, src) = self.yul_src(loc);
+
+                YulCase {
+                    body: self.build_yul_block(body),
+                    value: Some(self.build_yul_expression(expression)),
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
+                }
+            }
 
-            solang_parser::pt::YulSwitchOptions::Default(_loc, body) => YulCase {
-                body: self.build_yul_block(body),
-                value: None,
-            },
+            solang_parser::pt::YulSwitchOptions::Default(loc, body) => {
+                let (native_src, src) = self.yul_src(loc);
+
+                YulCase {
+                    body: self.build_yul_block(body),
+                    value: None,
+                    native_src,
+                    src,
+                    id: Some(self.next_node_id()),
+                }
+            }
         }
     }
 
     pub fn build_yul_for_loop(&mut self, input: &solang_parser::pt::YulFor) -> YulForLoop {
+        let (native_src, src) = self.yul_src(&input.loc);
+
         YulForLoop {
             pre: self.build_yul_block(&input.init_block),
             condition: self.build_yul_expression(&input.condition),
             post: self.build_yul_block(&input.post_block),
             body: self.build_yul_block(&input.execution_block),
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
         }
     }
 
     pub fn build_yul_function_definition(&mut self, function: &solang_parser::pt::YulFunctionDefinition) -> YulFunctionDefinition {
+        let (native_src, src) = self.yul_src(&function.loc);
+
         YulFunctionDefinition {
             name: function.id.name.clone(),
             parameters: Some(function.params.iter()
@@ -1996,62 +2599,189 @@ impl AstBuilder {
                 .map(|param| self.build_yul_typed_name(param))
                 .collect()),
             body: self.build_yul_block(&function.body),
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
+        }
+    }
+
+    pub fn build_yul_function_call(&mut self, function_call: &solang_parser::pt::YulFunctionCall) -> YulFunctionCall {
+        let (native_src, src) = self.yul_src(&function_call.loc);
+
+        YulFunctionCall {
+            function_name: self.build_yul_identifier(&solang_parser::pt::YulExpression::Variable(function_call.id.clone())),
+            arguments: function_call.arguments.iter()
+                .map(|x| self.build_yul_expression(x))
+                .collect(),
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
+        }
+    }
+
+    pub fn build_yul_literal(&mut self, loc: &solang_parser::pt::Loc, kind: YulLiteralKind, value: Option<String>, hex_value: Option<String>) -> YulLiteral {
+        let (native_src, src) = self.yul_src(loc);
+
+        YulLiteral {
+            kind,
+            value,
+            hex_value,
+            native_src,
+            src,
+            id: Some(self.next_node_id()),
         }
     }
 
     pub fn build_yul_expression(&mut self, expression: &solang_parser::pt::YulExpression) -> YulExpression {
         match expression {
-            solang_parser::pt::YulExpression::BoolLiteral(_, value, _) => YulExpression::YulLiteral(YulLiteral {
-                kind: YulLiteralKind::Bool,
-                value: Some(format!("{value}")),
-                hex_value: None,
-            }),
+            solang_parser::pt::YulExpression::BoolLiteral(loc, value, _) => {
+                YulExpression::YulLiteral(self.build_yul_literal(loc, YulLiteralKind::Bool, Some(format!("{value}")), None))
+            }
 
-            solang_parser::pt::YulExpression::NumberLiteral(_, value, _, _) => YulExpression::YulLiteral(YulLiteral {
-                kind: YulLiteralKind::Number,
-                value: Some(value.clone()),
-                hex_value: None,
-            }),
+            solang_parser::pt::YulExpression::NumberLiteral(loc, value, exponent, _) => {
+                let value = if exponent.is_empty() { value.clone() } else { format!("{value}e{exponent}") };
+                YulExpression::YulLiteral(self.build_yul_literal(loc, YulLiteralKind::Number, Some(value), None))
+            }
 
-            solang_parser::pt::YulExpression::HexNumberLiteral(_, _, _) => todo!(),
-            solang_parser::pt::YulExpression::HexStringLiteral(_, _) => todo!(),
+            solang_parser::pt::YulExpression::HexNumberLiteral(loc, value, _) => {
+                YulExpression::YulLiteral(self.build_yul_literal(loc, YulLiteralKind::Number, Some(value.clone()), None))
+            }
 
-            solang_parser::pt::YulExpression::StringLiteral(_, value) => YulExpression::YulLiteral(YulLiteral {
-                kind: YulLiteralKind::String,
-                value: value.as_ref().map(|x| x.name.clone()),
-                hex_value: None,
-            }),
+            solang_parser::pt::YulExpression::HexStringLiteral(literal, _) => {
+                YulExpression::YulLiteral(self.build_yul_literal(&literal.loc, YulLiteralKind::HexString, None, Some(literal.hex.clone())))
+            }
 
-            solang_parser::pt::YulExpression::Variable(identifier) => YulExpression::YulIdentifier(YulIdentifier {
-                name: identifier.name.clone(),
-            }),
+            solang_parser::pt::YulExpression::StringLiteral(literal, _) => {
+                YulExpression::YulLiteral(self.build_yul_literal(&literal.loc, YulLiteralKind::String, Some(literal.string.clone()), None))
+            }
 
-            solang_parser::pt::YulExpression::FunctionCall(function_call) => YulExpression::YulFunctionCall(YulFunctionCall {
-                function_name: YulIdentifier {
-                    name: function_call.id.name.clone(),
-                },
-                arguments: function_call.arguments.iter()
-                    .map(|x| self.build_yul_expression(x))
-                    .collect(),
-            }),
+            solang_parser::pt::YulExpression::Variable(_) | solang_parser::pt::YulExpression::SuffixAccess(_, _, _) => {
+                YulExpression::YulIdentifier(self.build_yul_identifier(expression))
+            }
 
-            solang_parser::pt::YulExpression::SuffixAccess(_, _, _) => todo!(),
+            solang_parser::pt::YulExpression::FunctionCall(function_call) => {
+                YulExpression::YulFunctionCall(self.build_yul_function_call(function_call))
+            }
         }
     }
 }
 
+/// Builds, resolves and type checks a single source file the same way files supplied with `--contract-path` are loaded.
+#[cfg(test)]
+pub(crate) fn build_test_source_units(path: &str, source: &str) -> Vec<SourceUnit> {
+    let (input, _comments) = solang_parser::parse(source, 0).unwrap();
+
+    let mut builder = AstBuilder::default();
+    let mut source_unit = builder.build_source_unit(&input);
+    assert!(builder.errors().is_empty(), "{:?}", builder.errors());
+
+    source_unit.absolute_path = Some(path.to_string());
+    source_unit.set_source(Some(source.to_string()));
+
+    let mut source_units = vec![source_unit];
+
+    resolve_references(source_units.as_mut_slice());
+    check_types(source_units.as_mut_slice());
+    compute_contract_metadata(source_units.as_mut_slice()).unwrap();
+
+    source_units
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_ast_builder() {
-        let src = std::fs::read_to_string("/Users/camden/Source/solidity-test/contracts/USDC.sol_").unwrap();
-        let (input, _comments) = solang_parser::parse(src.as_str(), 0).unwrap();
-        
+    fn build(src: &str) -> (SourceUnit, AstBuilder) {
+        let (input, _comments) = solang_parser::parse(src, 0).unwrap();
+
         let mut builder = AstBuilder::default();
         let source_unit = builder.build_source_unit(&input);
 
-        println!("{:#?}", source_unit);
+        (source_unit, builder)
+    }
+
+    #[test]
+    fn test_ast_builder() {
+        let (source_unit, builder) = build(r#"pragma solidity ^0.8.19;
+
+library Math {
+    function double(uint256 x) internal pure returns (uint256) { return x * 2; }
+}
+
+abstract contract Token {
+    using {Math.double} for uint256;
+
+    error Failed(uint256 code, address who);
+
+    uint256 total;
+    uint256 public supply;
+
+    constructor() {}
+
+    modifier guarded() virtual;
+
+    function f(bytes memory data) external {
+        {
+            uint256[] memory values = abi.decode(data, (uint256[]));
+            delete values;
+        }
+
+        revert Failed({code: 1, who: msg.sender});
+    }
+}
+"#);
+
+        assert!(builder.errors().is_empty(), "{:?}", builder.errors());
+
+        let token = source_unit.contract_definitions().into_iter().find(|c| c.name == "Token").unwrap();
+
+        let visibilities: Vec<_> = token.variable_declarations().iter().map(|v| v.visibility).collect();
+        assert_eq!(visibilities, vec![Visibility::Internal, Visibility::Public]);
+
+        let functions = token.function_definitions();
+        assert_eq!(functions[0].kind, FunctionKind::Constructor);
+        assert_eq!(functions[0].visibility, Visibility::Public);
+        assert_eq!(functions[1].parameters.parameters[0].visibility, Visibility::Internal);
+
+        let using_for_directive = token.nodes.iter()
+            .find_map(|node| match node {
+                ContractDefinitionNode::UsingForDirective(using_for_directive) => Some(using_for_directive),
+                _ => None,
+            })
+            .unwrap();
+
+        assert!(using_for_directive.library_name.is_none());
+        assert_eq!(using_for_directive.to_string(), "using {Math.double} for uint256");
+
+        let body = functions[1].body.as_ref().unwrap();
+        assert!(matches!(body.statements[0], Statement::Block(_)));
+
+        let Statement::RevertStatement(revert_statement) = &body.statements[1] else { panic!("expected a revert statement") };
+        assert_eq!(revert_statement.error_call.names, vec!["code", "who"]);
+    }
+
+    #[test]
+    fn test_ast_builder_errors() {
+        let (source_unit, builder) = build(r#"pragma solidity ^0.8.19;
+
+function free() pure returns (uint256) { return 1; }
+
+using {free} for uint256 global;
+
+contract C {
+    function f() external immutable {}
+}
+"#);
+
+        // Unsupported top-level items are skipped, while the contract is still built
+        assert_eq!(source_unit.contract_definitions().len(), 1);
+
+        let warnings: Vec<_> = builder.warnings().iter().map(|warning| warning.message.as_str()).collect();
+        assert_eq!(warnings, vec!["Unsupported free function definition", "Unsupported file-level using for directive"]);
+        assert_eq!(builder.warnings()[0].src, "26:38:0");
+
+        // Constructs within a contract which can not be represented are errors
+        let errors: Vec<_> = builder.errors().iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(errors, vec!["Invalid function attribute: immutable"]);
     }
 }
@@ -0,0 +1,713 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Display, Write},
+    io,
+    rc::Rc,
+};
+
+/// How a call edge was made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CallKind {
+    /// A call to a function of the current contract, its base contracts or a library from within the library.
+    Internal,
+    /// A call to a function of a base contract through `super`.
+    Super,
+    /// A call to a function of a library referenced by name, i.e: `SafeMath.add(a, b)`.
+    Library,
+    /// A call to a library function attached to a type with `using ... for ...`, i.e: `a.add(b)`.
+    UsingFor,
+    /// The invocation of a modifier by a function.
+    Modifier,
+    /// A message call to a function of another contract, i.e: `token.transfer(to, amount)`.
+    External,
+    /// The creation of a contract with `new`, which executes the constructors of its linearized base contracts.
+    Creation,
+    /// A call to the constructor of a base contract from a constructor's base constructor specifiers.
+    BaseConstructor,
+}
+
+impl Display for CallKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CallKind::Internal => "internal",
+            CallKind::Super => "super",
+            CallKind::Library => "library",
+            CallKind::UsingFor => "using for",
+            CallKind::Modifier => "modifier",
+            CallKind::External => "external",
+            CallKind::Creation => "creation",
+            CallKind::BaseConstructor => "base constructor",
+        })
+    }
+}
+
+/// A function or modifier which can be called.
+#[derive(Clone, Copy, Debug)]
+pub enum Callable<'a> {
+    Function(&'a FunctionDefinition),
+    Modifier(&'a ModifierDefinition),
+}
+
+impl Callable<'_> {
+    pub fn id(&self) -> NodeID {
+        match self {
+            Callable::Function(function_definition) => function_definition.id,
+            Callable::Modifier(modifier_definition) => modifier_definition.id,
+        }
+    }
+
+    pub fn src(&self) -> &str {
+        match self {
+            Callable::Function(function_definition) => function_definition.src.as_str(),
+            Callable::Modifier(modifier_definition) => modifier_definition.src.as_str(),
+        }
+    }
+}
+
+/// A function or modifier within the call graph, along with the contract and source unit which contain it.
+#[derive(Clone, Copy, Debug)]
+pub struct CallGraphNode<'a> {
+    pub source_unit: &'a SourceUnit,
+    pub contract_definition: &'a ContractDefinition,
+    pub callable: Callable<'a>,
+}
+
+impl Display for CallGraphNode<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.callable {
+            Callable::Function(function_definition) => {
+                let name = match function_definition.kind {
+                    FunctionKind::Function => function_definition.name.as_str(),
+                    FunctionKind::Constructor => "constructor",
+                    FunctionKind::Fallback => "fallback",
+                    FunctionKind::Receive => "receive",
+                };
+
+                write!(
+                    f,
+                    "{}.{}({})",
+                    self.contract_definition.name,
+                    name,
+                    function_definition.parameter_type_strings().join(","),
+                )
+            }
+
+            Callable::Modifier(modifier_definition) => {
+                write!(f, "modifier {}.{}", self.contract_definition.name, modifier_definition.name)
+            }
+        }
+    }
+}
+
+/// A call from one function or modifier to another.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CallEdge {
+    pub caller: NodeID,
+    pub callee: NodeID,
+    pub kind: CallKind,
+    /// The ID of the function call, modifier invocation or new expression making the call.
+    pub id: NodeID,
+    pub src: String,
+}
+
+/// The functions and modifiers of a set of source units, and the calls between them.
+///
+/// Calls to virtual functions and modifiers, along with calls through `super`, are resolved against the linearized
+/// base contracts of every contract deriving from the caller's contract, so a call may have several callees.
+/// External calls through an interface or contract type also reach the implementations within derived contracts.
+/// Calls made from state variable initializers and through function pointers are not included.
+pub struct CallGraph<'a> {
+    contract_definitions: HashMap<NodeID, &'a ContractDefinition>,
+    derived_contracts: HashMap<NodeID, Vec<NodeID>>,
+    node_ids: Vec<NodeID>,
+    nodes: HashMap<NodeID, CallGraphNode<'a>>,
+    edges: Vec<CallEdge>,
+}
+
+impl<'a> CallGraph<'a> {
+    pub fn new(source_units: &'a [SourceUnit]) -> io::Result<Self> {
+        let mut call_graph = Self {
+            contract_definitions: HashMap::new(),
+            derived_contracts: HashMap::new(),
+            node_ids: vec![],
+            nodes: HashMap::new(),
+            edges: vec![],
+        };
+
+        //
+        // Collect every function and modifier, along with the contracts deriving from each contract
+        //
+
+        for source_unit in source_units.iter() {
+            for contract_definition in source_unit.contract_definitions() {
+                call_graph.contract_definitions.insert(contract_definition.id, contract_definition);
+
+                for node in contract_definition.nodes.iter() {
+                    let callable = match node {
+                        ContractDefinitionNode::FunctionDefinition(function_definition) => Callable::Function(function_definition),
+                        ContractDefinitionNode::ModifierDefinition(modifier_definition) => Callable::Modifier(modifier_definition),
+                        _ => continue,
+                    };
+
+                    call_graph.node_ids.push(callable.id());
+                    call_graph.nodes.insert(callable.id(), CallGraphNode { source_unit, contract_definition, callable });
+                }
+            }
+        }
+
+        for source_unit in source_units.iter() {
+            for contract_definition in source_unit.contract_definitions() {
+                for &base_contract_id in call_graph.linearized_base_contracts(contract_definition.id).iter() {
+                    call_graph.derived_contracts.entry(base_contract_id).or_default().push(contract_definition.id);
+                }
+            }
+        }
+
+        //
+        // Collect the call sites of every function and modifier, then resolve their callees
+        //
+
+        let call_sites = Rc::new(RefCell::new(vec![]));
+
+        let mut data = AstVisitorData {
+            analyzed_paths: HashSet::new(),
+            visitors: vec![Box::new(CallSiteCollector { call_sites: call_sites.clone() })],
+        };
+
+        for source_unit in source_units.iter() {
+            let mut context = SourceUnitContext {
+                source_units,
+                current_source_unit: source_unit,
+            };
+
+            data.visit_source_unit(&mut context)?;
+            data.leave_source_unit(&mut context)?;
+        }
+
+        let mut edge_set = HashSet::new();
+
+        for call_site in call_sites.borrow().iter() {
+            for (callee, kind) in call_graph.resolve_call_site(call_site) {
+                let edge = CallEdge {
+                    caller: call_site.caller,
+                    callee,
+                    kind,
+                    id: call_site.id,
+                    src: call_site.src.clone(),
+                };
+
+                if edge_set.insert(edge.clone()) {
+                    call_graph.edges.push(edge);
+                }
+            }
+        }
+
+        Ok(call_graph)
+    }
+
+    /// The node of the supplied function or modifier.
+    pub fn node(&self, id: NodeID) -> Option<&CallGraphNode<'a>> {
+        self.nodes.get(&id)
+    }
+
+    /// Iterates over every function and modifier in the order they are declared.
+    pub fn nodes(&self) -> impl Iterator<Item = &CallGraphNode<'a>> {
+        self.node_ids.iter().map(|id| &self.nodes[id])
+    }
+
+    pub fn edges(&self) -> &[CallEdge] {
+        self.edges.as_slice()
+    }
+
+    /// Iterates over the calls made by the supplied function or modifier.
+    pub fn outgoing_edges(&self, id: NodeID) -> impl Iterator<Item = &CallEdge> {
+        self.edges.iter().filter(move |edge| edge.caller == id)
+    }
+
+    /// Iterates over the calls made to the supplied function or modifier.
+    pub fn incoming_edges(&self, id: NodeID) -> impl Iterator<Item = &CallEdge> {
+        self.edges.iter().filter(move |edge| edge.callee == id)
+    }
+
+    /// The IDs of the functions and modifiers called by the supplied function or modifier.
+    pub fn callees(&self, id: NodeID) -> Vec<NodeID> {
+        let mut callees = vec![];
+
+        for edge in self.outgoing_edges(id) {
+            if !callees.contains(&edge.callee) {
+                callees.push(edge.callee);
+            }
+        }
+
+        callees
+    }
+
+    /// The IDs of the functions and modifiers which call the supplied function or modifier.
+    pub fn callers(&self, id: NodeID) -> Vec<NodeID> {
+        let mut callers = vec![];
+
+        for edge in self.incoming_edges(id) {
+            if !callers.contains(&edge.caller) {
+                callers.push(edge.caller);
+            }
+        }
+
+        callers
+    }
+
+    /// The IDs of the functions and modifiers which can be reached from the supplied function or modifier, excluding itself
+    /// unless it is recursive.
+    pub fn reachable_from(&self, id: NodeID) -> Vec<NodeID> {
+        let mut reachable = vec![];
+        let mut visited = HashSet::new();
+        let mut pending: VecDeque<NodeID> = self.callees(id).into();
+
+        while let Some(callee) = pending.pop_front() {
+            if !visited.insert(callee) {
+                continue;
+            }
+
+            reachable.push(callee);
+            pending.extend(self.callees(callee));
+        }
+
+        reachable
+    }
+
+    /// Determines if the supplied function or modifier can reach another, either directly or through other calls.
+    pub fn can_reach(&self, caller: NodeID, callee: NodeID) -> bool {
+        self.reachable_from(caller).contains(&callee)
+    }
+
+    /// Renders the call graph in the Graphviz DOT format, with the functions and modifiers of each contract grouped together.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+
+        writeln!(dot, "digraph \"call graph\" {{").unwrap();
+        writeln!(dot, "    node [shape=box];").unwrap();
+
+        let mut contract_ids: Vec<NodeID> = vec![];
+
+        for node in self.nodes() {
+            if !contract_ids.contains(&node.contract_definition.id) {
+                contract_ids.push(node.contract_definition.id);
+            }
+        }
+
+        for contract_id in contract_ids {
+            let contract_definition = self.contract_definitions[&contract_id];
+
+            writeln!(dot).unwrap();
+            writeln!(dot, "    subgraph \"cluster_{contract_id}\" {{").unwrap();
+            writeln!(dot, "        label = \"{} {}\";", contract_definition.kind, dot_escape(contract_definition.name.as_str())).unwrap();
+
+            for node in self.nodes().filter(|node| node.contract_definition.id == contract_id) {
+                let shape = match node.callable {
+                    Callable::Function(_) => "",
+                    Callable::Modifier(_) => ", shape=ellipse",
+                };
+
+                writeln!(dot, "        \"{}\" [label=\"{}\"{shape}];", node.callable.id(), dot_escape(node.to_string().as_str())).unwrap();
+            }
+
+            writeln!(dot, "    }}").unwrap();
+        }
+
+        if !self.edges.is_empty() {
+            writeln!(dot).unwrap();
+        }
+
+        for edge in self.edges.iter() {
+            let style = match edge.kind {
+                CallKind::External | CallKind::Creation => ", style=dashed",
+                CallKind::Modifier => ", style=dotted",
+                _ => "",
+            };
+
+            writeln!(dot, "    \"{}\" -> \"{}\" [label=\"{}\"{style}];", edge.caller, edge.callee, edge.kind).unwrap();
+        }
+
+        writeln!(dot, "}}").unwrap();
+
+        dot
+    }
+
+    fn linearized_base_contracts(&self, contract_id: NodeID) -> Vec<NodeID> {
+        match self.contract_definitions.get(&contract_id).and_then(|contract_definition| contract_definition.linearized_base_contracts.as_ref()) {
+            Some(linearized_base_contracts) => linearized_base_contracts.clone(),
+            None => vec![contract_id],
+        }
+    }
+
+    fn function_definition(&self, id: NodeID) -> Option<(&'a ContractDefinition, &'a FunctionDefinition)> {
+        let node = self.nodes.get(&id)?;
+
+        match node.callable {
+            Callable::Function(function_definition) => Some((node.contract_definition, function_definition)),
+            Callable::Modifier(_) => None,
+        }
+    }
+
+    fn modifier_definition(&self, id: NodeID) -> Option<(&'a ContractDefinition, &'a ModifierDefinition)> {
+        let node = self.nodes.get(&id)?;
+
+        match node.callable {
+            Callable::Function(_) => None,
+            Callable::Modifier(modifier_definition) => Some((node.contract_definition, modifier_definition)),
+        }
+    }
+
+    /// Finds the first definition matching the supplied function or modifier within the supplied contracts.
+    fn find_override(&self, callable: Callable<'a>, contract_ids: &[NodeID]) -> Option<NodeID> {
+        contract_ids.iter()
+            .filter_map(|contract_id| self.contract_definitions.get(contract_id))
+            .find_map(|contract_definition| match callable {
+                Callable::Function(function_definition) => contract_definition.function_definitions().into_iter()
+                    .find(|other| other.matches_signature(function_definition))
+                    .map(|other| other.id),
+
+                Callable::Modifier(modifier_definition) => contract_definition.modifier_definitions().into_iter()
+                    .find(|other| other.name == modifier_definition.name)
+                    .map(|other| other.id),
+            })
+    }
+
+    /// Resolves a call to a virtual function or modifier within the supplied contract for every contract deriving from it.
+    fn resolve_virtual(&self, callable: Callable<'a>, contract_id: NodeID) -> Vec<NodeID> {
+        let is_virtual = match callable {
+            Callable::Function(function_definition) => function_definition.is_virtual.unwrap_or(false) || function_definition.body.is_none(),
+            Callable::Modifier(modifier_definition) => modifier_definition.is_virtual.unwrap_or(false),
+        };
+
+        if !is_virtual {
+            return vec![callable.id()];
+        }
+
+        let mut callees = vec![];
+
+        for &derived_contract_id in self.derived_contracts.get(&contract_id).into_iter().flatten() {
+            let linearized_base_contracts = self.linearized_base_contracts(derived_contract_id);
+
+            if let Some(id) = self.find_override(callable, linearized_base_contracts.as_slice()) {
+                if !callees.contains(&id) {
+                    callees.push(id);
+                }
+            }
+        }
+
+        if callees.is_empty() {
+            callees.push(callable.id());
+        }
+
+        callees
+    }
+
+    /// Resolves a call through `super` within the supplied contract for every contract deriving from it.
+    fn resolve_super(&self, callable: Callable<'a>, contract_id: NodeID) -> Vec<NodeID> {
+        let mut callees = vec![];
+
+        for &derived_contract_id in self.derived_contracts.get(&contract_id).into_iter().flatten() {
+            let linearized_base_contracts = self.linearized_base_contracts(derived_contract_id);
+
+            let Some(position) = linearized_base_contracts.iter().position(|&id| id == contract_id) else { continue };
+
+            if let Some(id) = self.find_override(callable, &linearized_base_contracts[position + 1..]) {
+                if !callees.contains(&id) {
+                    callees.push(id);
+                }
+            }
+        }
+
+        if callees.is_empty() {
+            callees.push(callable.id());
+        }
+
+        callees
+    }
+
+    /// Resolves an external call to a function of an interface or contract, along with its implementations in deployable derived contracts.
+    fn resolve_external(&self, function_definition: &'a FunctionDefinition, contract_id: NodeID) -> Vec<NodeID> {
+        let mut callees = vec![function_definition.id];
+
+        for &derived_contract_id in self.derived_contracts.get(&contract_id).into_iter().flatten() {
+            let Some(derived_contract_definition) = self.contract_definitions.get(&derived_contract_id) else { continue };
+
+            if derived_contract_definition.kind != ContractKind::Contract || derived_contract_definition.is_abstract.unwrap_or(false) {
+                continue;
+            }
+
+            let linearized_base_contracts = self.linearized_base_contracts(derived_contract_id);
+
+            if let Some(id) = self.find_override(Callable::Function(function_definition), linearized_base_contracts.as_slice()) {
+                if !callees.contains(&id) {
+                    callees.push(id);
+                }
+            }
+        }
+
+        callees
+    }
+
+    /// The constructors executed when creating the supplied contract, from the most derived to the most base.
+    fn constructors(&self, contract_id: NodeID) -> Vec<NodeID> {
+        self.linearized_base_contracts(contract_id).iter()
+            .filter_map(|contract_id| self.contract_definitions.get(contract_id))
+            .filter_map(|contract_definition| {
+                contract_definition.function_definitions().into_iter()
+                    .find(|function_definition| function_definition.kind == FunctionKind::Constructor)
+                    .map(|function_definition| function_definition.id)
+            })
+            .collect()
+    }
+
+    fn resolve_call_site(&self, call_site: &CallSite) -> Vec<(NodeID, CallKind)> {
+        let with_kind = |ids: Vec<NodeID>, kind: CallKind| ids.into_iter().map(|id| (id, kind)).collect::<Vec<_>>();
+
+        match call_site.target {
+            CallTarget::Identifier(id) => {
+                let Some((contract_definition, function_definition)) = self.function_definition(id) else { return vec![] };
+
+                if contract_definition.kind == ContractKind::Library {
+                    return vec![(id, CallKind::Internal)];
+                }
+
+                // Functions are resolved against the contracts deriving from the caller's contract, which may differ from the callee's
+                with_kind(self.resolve_virtual(Callable::Function(function_definition), call_site.contract), CallKind::Internal)
+            }
+
+            CallTarget::Super(id) => {
+                let Some((_, function_definition)) = self.function_definition(id) else { return vec![] };
+                with_kind(self.resolve_super(Callable::Function(function_definition), call_site.contract), CallKind::Super)
+            }
+
+            CallTarget::Member { id, base } => {
+                let Some((contract_definition, function_definition)) = self.function_definition(id) else { return vec![] };
+
+                match base.and_then(|base| self.contract_definitions.get(&base)) {
+                    // Functions of contracts referenced by name, i.e: `SafeMath.add(a, b)` or `Base.f()`
+                    Some(base_contract_definition) => match base_contract_definition.kind {
+                        ContractKind::Library => vec![(id, CallKind::Library)],
+                        _ => vec![(id, CallKind::Internal)],
+                    },
+
+                    None if contract_definition.kind == ContractKind::Library => vec![(id, CallKind::UsingFor)],
+
+                    None => with_kind(self.resolve_external(function_definition, contract_definition.id), CallKind::External),
+                }
+            }
+
+            CallTarget::New(contract_id) => with_kind(self.constructors(contract_id), CallKind::Creation),
+
+            CallTarget::Modifier(id) => {
+                if let Some((_, modifier_definition)) = self.modifier_definition(id) {
+                    return with_kind(self.resolve_virtual(Callable::Modifier(modifier_definition), call_site.contract), CallKind::Modifier);
+                }
+
+                // Base constructor specifiers reference the base contract instead of its constructor
+                match self.contract_definitions.contains_key(&id) {
+                    true => with_kind(self.constructors(id).into_iter().take(1).collect(), CallKind::BaseConstructor),
+                    false => vec![],
+                }
+            }
+        }
+    }
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// The declaration referenced by a call site, before it is resolved to its callees.
+#[derive(Clone, Copy, Debug)]
+enum CallTarget {
+    /// A call to a function referenced by name, i.e: `f()`.
+    Identifier(NodeID),
+    /// A call to a function of a base contract through `super`, i.e: `super.f()`.
+    Super(NodeID),
+    /// A call to a function which is a member of another value, along with the contract referenced by the value (if any).
+    Member { id: NodeID, base: Option<NodeID> },
+    /// The creation of a contract, i.e: `new C()`.
+    New(NodeID),
+    /// A modifier invocation or base constructor specifier.
+    Modifier(NodeID),
+}
+
+#[derive(Clone, Debug)]
+struct CallSite {
+    caller: NodeID,
+    contract: NodeID,
+    target: CallTarget,
+    id: NodeID,
+    src: String,
+}
+
+struct CallSiteCollector {
+    call_sites: Rc<RefCell<Vec<CallSite>>>,
+}
+
+impl CallSiteCollector {
+    fn caller_id(definition_node: &ContractDefinitionNode) -> Option<NodeID> {
+        match definition_node {
+            ContractDefinitionNode::FunctionDefinition(function_definition) => Some(function_definition.id),
+            ContractDefinitionNode::ModifierDefinition(modifier_definition) => Some(modifier_definition.id),
+            _ => None,
+        }
+    }
+}
+
+impl AstVisitor for CallSiteCollector {
+    fn visit_function_call<'a, 'b>(&mut self, context: &mut FunctionCallContext<'a, 'b>) -> io::Result<()> {
+        let Some(caller) = Self::caller_id(context.definition_node) else { return Ok(()) };
+
+        let mut expression = context.function_call.expression.as_ref();
+
+        // Call options are specified before the arguments, i.e: `target.call{value: amount}("")`
+        while let Expression::FunctionCallOptions(function_call_options) = expression {
+            expression = function_call_options.expression.as_ref();
+        }
+
+        let target = match expression {
+            Expression::Identifier(identifier) => CallTarget::Identifier(identifier.referenced_declaration),
+
+            Expression::MemberAccess(member_access) => {
+                let Some(id) = member_access.referenced_declaration else { return Ok(()) };
+
+                match member_access.expression.as_ref() {
+                    Expression::Identifier(identifier) if identifier.name == "super" => CallTarget::Super(id),
+                    Expression::Identifier(identifier) => CallTarget::Member { id, base: Some(identifier.referenced_declaration) },
+                    Expression::MemberAccess(base) => CallTarget::Member { id, base: base.referenced_declaration },
+                    _ => CallTarget::Member { id, base: None },
+                }
+            }
+
+            Expression::NewExpression(new_expression) => match &new_expression.type_name {
+                TypeName::UserDefinedTypeName(user_defined_type_name) => CallTarget::New(user_defined_type_name.referenced_declaration),
+                _ => return Ok(()),
+            },
+
+            _ => return Ok(()),
+        };
+
+        self.call_sites.borrow_mut().push(CallSite {
+            caller,
+            contract: context.contract_definition.id,
+            target,
+            id: context.function_call.id,
+            src: context.function_call.src.clone(),
+        });
+
+        Ok(())
+    }
+
+    fn visit_modifier_invocation<'a>(&mut self, context: &mut ModifierInvocationContext<'a>) -> io::Result<()> {
+        let Some(caller) = Self::caller_id(context.definition_node) else { return Ok(()) };
+        let Some(id) = context.modifier_invocation.modifier_name.referenced_declaration else { return Ok(()) };
+
+        self.call_sites.borrow_mut().push(CallSite {
+            caller,
+            contract: context.contract_definition.id,
+            target: CallTarget::Modifier(id),
+            id: context.modifier_invocation.id,
+            src: context.modifier_invocation.src.clone(),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+library Math {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+}
+
+interface IToken {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract Base {
+    uint256 value;
+
+    constructor(uint256 initial) {
+        value = initial;
+    }
+
+    modifier positive(uint256 amount) {
+        require(amount > 0);
+        _;
+    }
+
+    function update(uint256 amount) public virtual {
+        value = Math.add(value, amount);
+    }
+
+    function run() external {
+        update(1);
+    }
+}
+
+contract Child is Base {
+    using Math for uint256;
+
+    constructor() Base(1) {}
+
+    function update(uint256 amount) public override positive(amount) {
+        super.update(amount.add(1));
+        helper();
+    }
+
+    function helper() internal {}
+
+    function pay(IToken token) external {
+        token.transfer(msg.sender, value);
+    }
+
+    function spawn() external returns (Child) {
+        return new Child();
+    }
+}
+"#;
+
+    #[test]
+    fn test_call_graph() {
+        let source_units = builder::build_test_source_units("Calls.sol", SOURCE);
+        let call_graph = CallGraph::new(source_units.as_slice()).unwrap();
+
+        let edges: Vec<_> = call_graph.edges().iter()
+            .map(|edge| format!("{} -> {} ({})", call_graph.node(edge.caller).unwrap(), call_graph.node(edge.callee).unwrap(), edge.kind))
+            .collect();
+
+        // The virtual call in `Base.run` may reach the override in `Child`
+        assert_eq!(edges, vec![
+            "Base.update(uint256) -> Math.add(uint256,uint256) (library)",
+            "Base.run() -> Base.update(uint256) (internal)",
+            "Base.run() -> Child.update(uint256) (internal)",
+            "Child.constructor() -> Base.constructor(uint256) (base constructor)",
+            "Child.update(uint256) -> modifier Base.positive (modifier)",
+            "Child.update(uint256) -> Base.update(uint256) (super)",
+            "Child.update(uint256) -> Math.add(uint256,uint256) (using for)",
+            "Child.update(uint256) -> Child.helper() (internal)",
+            "Child.pay(contract IToken) -> IToken.transfer(address,uint256) (external)",
+            "Child.spawn() -> Child.constructor() (creation)",
+            "Child.spawn() -> Base.constructor(uint256) (creation)",
+        ]);
+
+        let id = |name: &str| call_graph.nodes()
+            .find(|node| node.to_string() == name)
+            .map(|node| node.callable.id())
+            .unwrap();
+
+        assert!(call_graph.can_reach(id("Base.run()"), id("Child.helper()")));
+        assert!(!call_graph.can_reach(id("Child.spawn()"), id("Math.add(uint256,uint256)")));
+        assert_eq!(call_graph.callers(id("Math.add(uint256,uint256)")), vec![id("Base.update(uint256)"), id("Child.update(uint256)")]);
+    }
+}
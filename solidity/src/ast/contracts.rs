@@ -1,6 +1,6 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Serialize};
 use std::{collections::HashMap, fmt::Display};
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -34,19 +34,19 @@ pub enum ContractDefinitionNode {
 impl<'de> Deserialize<'de> for ContractDefinitionNode {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let json = serde_json::Value::deserialize(deserializer)?;
-        let node_type = json.get("nodeType").unwrap().as_str().unwrap();
-
-        match node_type {
-            "UsingForDirective" => Ok(ContractDefinitionNode::UsingForDirective(serde_json::from_value(json).unwrap())),
-            "StructDefinition" => Ok(ContractDefinitionNode::StructDefinition(serde_json::from_value(json).unwrap())),
-            "EnumDefinition" => Ok(ContractDefinitionNode::EnumDefinition(serde_json::from_value(json).unwrap())),
-            "VariableDeclaration" => Ok(ContractDefinitionNode::VariableDeclaration(serde_json::from_value(json).unwrap())),
-            "EventDefinition" => Ok(ContractDefinitionNode::EventDefinition(serde_json::from_value(json).unwrap())),
-            "FunctionDefinition" => Ok(ContractDefinitionNode::FunctionDefinition(serde_json::from_value(json).unwrap())),
-            "ModifierDefinition" => Ok(ContractDefinitionNode::ModifierDefinition(serde_json::from_value(json).unwrap())),
-            "ErrorDefinition" => Ok(ContractDefinitionNode::ErrorDefinition(serde_json::from_value(json).unwrap())),
-            "UserDefinedValueTypeDefinition" => Ok(ContractDefinitionNode::UserDefinedValueTypeDefinition(serde_json::from_value(json).unwrap())),
-            _ => panic!("Invalid contract definition node type: {node_type}"),
+        let node_type = node_type::<D>(&json)?;
+
+        match node_type.as_str() {
+            "UsingForDirective" => Ok(ContractDefinitionNode::UsingForDirective(from_value::<D, _>(json)?)),
+            "StructDefinition" => Ok(ContractDefinitionNode::StructDefinition(from_value::<D, _>(json)?)),
+            "EnumDefinition" => Ok(ContractDefinitionNode::EnumDefinition(from_value::<D, _>(json)?)),
+            "VariableDeclaration" => Ok(ContractDefinitionNode::VariableDeclaration(from_value::<D, _>(json)?)),
+            "EventDefinition" => Ok(ContractDefinitionNode::EventDefinition(from_value::<D, _>(json)?)),
+            "FunctionDefinition" => Ok(ContractDefinitionNode::FunctionDefinition(from_value::<D, _>(json)?)),
+            "ModifierDefinition" => Ok(ContractDefinitionNode::ModifierDefinition(from_value::<D, _>(json)?)),
+            "ErrorDefinition" => Ok(ContractDefinitionNode::ErrorDefinition(from_value::<D, _>(json)?)),
+            "UserDefinedValueTypeDefinition" => Ok(ContractDefinitionNode::UserDefinedValueTypeDefinition(from_value::<D, _>(json)?)),
+            _ => Err(D::Error::custom(format!("Invalid contract definition node type: {node_type}"))),
         }
     }
 }
@@ -282,33 +282,24 @@ impl ContractDefinition {
         result
     }
 
+    /// Computes the flattened view of the contract combining the definitions of its linearized base contracts.
+    pub fn effective_contract<'a>(&'a self, source_units: &'a [SourceUnit]) -> EffectiveContract<'a> {
+        EffectiveContract::new(source_units, self)
+    }
+
+    /// Computes the storage layout of the contract, including the state variables of its base contracts.
+    ///
+    /// This creates a new `StorageLayoutBuilder`, which should be used directly when computing the layouts of multiple contracts.
+    pub fn storage_layout(&self, source_units: &[SourceUnit]) -> Result<StorageLayout, StorageLayoutError> {
+        StorageLayoutBuilder::new(source_units).contract_layout(source_units, self)
+    }
+
     pub fn hierarchy_contains_state_variable(
         &self,
         source_units: &[SourceUnit],
         state_variable_id: NodeID,
     ) -> bool {
-        // Loop through all of the contracts in the supplied contract's inheritance hierarchy
-        if let Some(contract_ids) = self.linearized_base_contracts.as_ref() {
-            for &contract_id in contract_ids.iter() {
-                // Loop through all of the schema source_units in the project
-                for source_unit in source_units.iter() {
-                    // Attempt to retrieve the current contract in the inheritance hierarchy from the current schema source_unit
-                    let contract_definition = match source_unit.contract_definition(contract_id) {
-                        Some(contract_definition) => contract_definition,
-                        None => continue,
-                    };
-
-                    // Attempt to retrieve the requested state variable from the current contract in the inheritance hierarchy
-                    if contract_definition.variable_declaration(state_variable_id).is_some() {
-                        return true;
-                    }
-                }
-            }
-        } else if self.variable_declaration(state_variable_id).is_some() {
-            return true;
-        }
-
-        false
+        self.effective_contract(source_units).contains_state_variable(state_variable_id)
     }
 
     pub fn get_assigned_state_variables(
@@ -412,6 +403,14 @@ impl ContractDefinition {
                     self.name,
                     self.kind,
                 ),
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => format!(
+                    "{} `{}` state variable in the `{}` {}",
+                    variable_declaration.visibility,
+                    variable_declaration.name,
+                    self.name,
+                    self.kind,
+                ),
     
                 x => panic!("Unsupported definition node: {x:?}"),
             },
@@ -0,0 +1,891 @@
+use super::*;
+use std::collections::{HashSet, VecDeque};
+
+/// The index of a basic block within its control-flow graph.
+pub type BasicBlockId = usize;
+
+/// A node of a basic block. Compound statements are split into the nodes which are executed sequentially.
+#[derive(Clone, Copy, Debug)]
+pub enum ControlFlowNode<'a> {
+    /// A simple statement, i.e: an expression statement, a variable declaration, an `emit`, a `return`, a `revert`,
+    /// `break`, `continue`, an inline assembly block or a modifier placeholder (`_`).
+    Statement(&'a Statement),
+    /// The condition of an `if` statement or a loop, which ends its basic block.
+    Condition(&'a Expression),
+    /// The external call of a `try` statement, which ends its basic block.
+    TryCall(&'a FunctionCall),
+    /// The start of a `try`/`catch` clause, which binds the parameters of the clause.
+    TryCatchClause(&'a TryCatchClause),
+}
+
+impl ControlFlowNode<'_> {
+    pub fn statement(&self) -> Option<&Statement> {
+        match self {
+            ControlFlowNode::Statement(statement) => Some(statement),
+            _ => None,
+        }
+    }
+
+    pub fn is_placeholder(&self) -> bool {
+        matches!(self, ControlFlowNode::Statement(Statement::PlaceholderStatement { .. }))
+    }
+}
+
+/// The reason control is transferred from one basic block to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ControlFlowEdgeKind {
+    /// Execution falls through to the next basic block.
+    Unconditional,
+    /// The condition of an `if` statement or a loop holds.
+    True,
+    /// The condition of an `if` statement or a loop does not hold.
+    False,
+    Break,
+    Continue,
+    Return,
+    /// Execution reverts, either through a `revert` statement or a failed `require` or `assert`.
+    Revert,
+    /// The external call of a `try` statement succeeds.
+    TrySuccess,
+    /// The external call of a `try` statement fails and a `catch` clause is executed.
+    TryCatch,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ControlFlowEdge {
+    pub source: BasicBlockId,
+    pub target: BasicBlockId,
+    pub kind: ControlFlowEdgeKind,
+}
+
+#[derive(Clone, Debug)]
+pub struct BasicBlock<'a> {
+    pub id: BasicBlockId,
+    pub nodes: Vec<ControlFlowNode<'a>>,
+    /// Whether the nodes of the block are inside of an `unchecked` block.
+    pub is_unchecked: bool,
+}
+
+/// The control-flow graph of a function or modifier body.
+///
+/// The graph has a single entry block and two empty exit blocks: `exit`, which is reached when execution completes
+/// normally, and `revert`, which is reached when execution reverts. Modifier placeholders (`_`) are kept in basic
+/// blocks of their own, so the body of the modified function can be spliced in by analyzers which need to.
+#[derive(Clone, Debug)]
+pub struct ControlFlowGraph<'a> {
+    pub blocks: Vec<BasicBlock<'a>>,
+    pub edges: Vec<ControlFlowEdge>,
+    pub entry: BasicBlockId,
+    pub exit: BasicBlockId,
+    pub revert: BasicBlockId,
+}
+
+impl<'a> ControlFlowGraph<'a> {
+    /// Builds the control-flow graph of a function or modifier body.
+    pub fn new(body: &'a Block) -> Self {
+        let mut builder = ControlFlowGraphBuilder::default();
+        builder.build_body(body);
+        builder.graph
+    }
+
+    pub fn block(&self, id: BasicBlockId) -> &BasicBlock<'a> {
+        &self.blocks[id]
+    }
+
+    pub fn outgoing_edges(&self, id: BasicBlockId) -> impl Iterator<Item = &ControlFlowEdge> {
+        self.edges.iter().filter(move |edge| edge.source == id)
+    }
+
+    pub fn incoming_edges(&self, id: BasicBlockId) -> impl Iterator<Item = &ControlFlowEdge> {
+        self.edges.iter().filter(move |edge| edge.target == id)
+    }
+
+    pub fn successors(&self, id: BasicBlockId) -> impl Iterator<Item = BasicBlockId> + '_ {
+        self.outgoing_edges(id).map(|edge| edge.target)
+    }
+
+    pub fn predecessors(&self, id: BasicBlockId) -> impl Iterator<Item = BasicBlockId> + '_ {
+        self.incoming_edges(id).map(|edge| edge.source)
+    }
+
+    /// Iterates over every node of the graph along with the ID of the basic block containing it.
+    pub fn nodes(&self) -> impl Iterator<Item = (BasicBlockId, &ControlFlowNode<'a>)> {
+        self.blocks.iter().flat_map(|block| block.nodes.iter().map(move |node| (block.id, node)))
+    }
+
+    /// Finds the basic block containing the supplied statement, which must be borrowed from the graph's body.
+    pub fn block_containing(&self, statement: &Statement) -> Option<BasicBlockId> {
+        self.nodes()
+            .find(|(_, node)| node.statement().map(|node_statement| std::ptr::eq(node_statement, statement)).unwrap_or(false))
+            .map(|(id, _)| id)
+    }
+
+    /// The basic blocks containing modifier placeholders (`_`).
+    pub fn placeholder_blocks(&self) -> Vec<BasicBlockId> {
+        self.blocks.iter()
+            .filter(|block| block.nodes.iter().any(ControlFlowNode::is_placeholder))
+            .map(|block| block.id)
+            .collect()
+    }
+
+    /// Determines if the `to` block can be reached by following edges from the `from` block.
+    pub fn is_reachable_from(&self, from: BasicBlockId, to: BasicBlockId) -> bool {
+        let mut visited = HashSet::new();
+        let mut pending = VecDeque::from([from]);
+
+        while let Some(id) = pending.pop_front() {
+            if id == to {
+                return true;
+            }
+
+            if visited.insert(id) {
+                pending.extend(self.successors(id));
+            }
+        }
+
+        false
+    }
+
+    /// Determines if the supplied block can be reached from the entry block.
+    pub fn is_reachable(&self, id: BasicBlockId) -> bool {
+        self.is_reachable_from(self.entry, id)
+    }
+
+    /// Determines if execution can complete normally without reaching a `return` statement.
+    pub fn falls_through(&self) -> bool {
+        self.incoming_edges(self.exit)
+            .any(|edge| edge.kind != ControlFlowEdgeKind::Return && self.is_reachable(edge.source))
+    }
+
+    /// The blocks which are reachable from the entry block, in reverse post-order.
+    /// Each block is ordered before its successors, except for the targets of loop back edges.
+    pub fn reverse_post_order(&self) -> Vec<BasicBlockId> {
+        let mut visited = vec![false; self.blocks.len()];
+        let mut post_order = vec![];
+        let mut stack = vec![(self.entry, self.successors(self.entry).collect::<Vec<_>>())];
+        visited[self.entry] = true;
+
+        while let Some((id, successors)) = stack.last_mut() {
+            match successors.pop() {
+                Some(successor) if !visited[successor] => {
+                    visited[successor] = true;
+                    let successors = self.successors(successor).collect();
+                    stack.push((successor, successors));
+                }
+
+                Some(_) => {}
+
+                None => {
+                    post_order.push(*id);
+                    stack.pop();
+                }
+            }
+        }
+
+        post_order.reverse();
+        post_order
+    }
+
+    /// Computes the dominator tree of the blocks which are reachable from the entry block.
+    pub fn dominators(&self) -> Dominators {
+        //
+        // Uses the iterative algorithm described in "A Simple, Fast Dominance Algorithm" by Cooper, Harvey and Kennedy
+        //
+
+        let order = self.reverse_post_order();
+        let mut order_index = vec![usize::MAX; self.blocks.len()];
+
+        for (index, &id) in order.iter().enumerate() {
+            order_index[id] = index;
+        }
+
+        let mut immediate_dominators: Vec<Option<BasicBlockId>> = vec![None; self.blocks.len()];
+        immediate_dominators[self.entry] = Some(self.entry);
+
+        let intersect = |immediate_dominators: &[Option<BasicBlockId>], mut a: BasicBlockId, mut b: BasicBlockId| {
+            while a != b {
+                while order_index[a] > order_index[b] {
+                    a = immediate_dominators[a].unwrap();
+                }
+
+                while order_index[b] > order_index[a] {
+                    b = immediate_dominators[b].unwrap();
+                }
+            }
+
+            a
+        };
+
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for &id in order.iter().skip(1) {
+                let mut new_dominator = None;
+
+                for predecessor in self.predecessors(id) {
+                    if immediate_dominators[predecessor].is_none() {
+                        continue;
+                    }
+
+                    new_dominator = Some(match new_dominator {
+                        Some(dominator) => intersect(&immediate_dominators, predecessor, dominator),
+                        None => predecessor,
+                    });
+                }
+
+                if new_dominator.is_some() && immediate_dominators[id] != new_dominator {
+                    immediate_dominators[id] = new_dominator;
+                    changed = true;
+                }
+            }
+        }
+
+        // The entry block has no immediate dominator
+        immediate_dominators[self.entry] = None;
+
+        Dominators {
+            entry: self.entry,
+            immediate_dominators,
+        }
+    }
+}
+
+/// The dominator tree of a control-flow graph. A block dominates another block if every path from the entry block
+/// to the other block passes through it.
+#[derive(Clone, Debug)]
+pub struct Dominators {
+    entry: BasicBlockId,
+    immediate_dominators: Vec<Option<BasicBlockId>>,
+}
+
+impl Dominators {
+    /// The closest strict dominator of the supplied block, or `None` for the entry block and unreachable blocks.
+    pub fn immediate_dominator(&self, id: BasicBlockId) -> Option<BasicBlockId> {
+        self.immediate_dominators.get(id).copied().flatten()
+    }
+
+    /// The blocks which dominate the supplied block, starting with the block itself and ending with the entry block.
+    /// Unreachable blocks have no dominators.
+    pub fn dominators(&self, id: BasicBlockId) -> Vec<BasicBlockId> {
+        if id != self.entry && self.immediate_dominator(id).is_none() {
+            return vec![];
+        }
+
+        let mut result = vec![id];
+        let mut current = id;
+
+        while let Some(dominator) = self.immediate_dominator(current) {
+            result.push(dominator);
+            current = dominator;
+        }
+
+        result
+    }
+
+    /// Determines if block `a` dominates block `b`. Every reachable block dominates itself.
+    pub fn dominates(&self, a: BasicBlockId, b: BasicBlockId) -> bool {
+        self.dominators(b).contains(&a)
+    }
+}
+
+/// The blocks which jump out of the loop currently being built.
+#[derive(Default)]
+struct LoopContext {
+    breaks: Vec<BasicBlockId>,
+    continues: Vec<BasicBlockId>,
+}
+
+struct ControlFlowGraphBuilder<'a> {
+    graph: ControlFlowGraph<'a>,
+    /// The block which nodes are currently added to, or `None` if the current position is unreachable.
+    current: Option<BasicBlockId>,
+    loops: Vec<LoopContext>,
+    is_unchecked: bool,
+}
+
+impl Default for ControlFlowGraphBuilder<'_> {
+    fn default() -> Self {
+        let mut builder = Self {
+            graph: ControlFlowGraph {
+                blocks: vec![],
+                edges: vec![],
+                entry: 0,
+                exit: 0,
+                revert: 0,
+            },
+            current: None,
+            loops: vec![],
+            is_unchecked: false,
+        };
+
+        builder.graph.entry = builder.new_block();
+        builder.graph.exit = builder.new_block();
+        builder.graph.revert = builder.new_block();
+        builder.current = Some(builder.graph.entry);
+
+        builder
+    }
+}
+
+impl<'a> ControlFlowGraphBuilder<'a> {
+    fn new_block(&mut self) -> BasicBlockId {
+        let id = self.graph.blocks.len();
+
+        self.graph.blocks.push(BasicBlock {
+            id,
+            nodes: vec![],
+            is_unchecked: self.is_unchecked,
+        });
+
+        id
+    }
+
+    fn add_edge(&mut self, source: BasicBlockId, target: BasicBlockId, kind: ControlFlowEdgeKind) {
+        self.graph.edges.push(ControlFlowEdge { source, target, kind });
+    }
+
+    /// Adds a node to the current block. Nodes which follow a jump are added to a new unreachable block.
+    fn push_node(&mut self, node: ControlFlowNode<'a>) -> BasicBlockId {
+        let id = match self.current {
+            Some(id) => id,
+            None => self.new_block(),
+        };
+
+        self.graph.blocks[id].nodes.push(node);
+        self.current = Some(id);
+
+        id
+    }
+
+    /// Ends the current block with a jump to the supplied block. The current position becomes unreachable.
+    fn jump(&mut self, target: BasicBlockId, kind: ControlFlowEdgeKind) {
+        if let Some(current) = self.current.take() {
+            self.add_edge(current, target, kind);
+        }
+    }
+
+    /// Continues in a new block which is reached from each of the supplied blocks.
+    /// The current position becomes unreachable if there are none.
+    fn join(&mut self, sources: Vec<(BasicBlockId, ControlFlowEdgeKind)>) {
+        if sources.is_empty() {
+            self.current = None;
+            return;
+        }
+
+        let id = self.new_block();
+
+        for (source, kind) in sources {
+            self.add_edge(source, id, kind);
+        }
+
+        self.current = Some(id);
+    }
+
+    /// Ends the current block if it contains any nodes, falling through to a new block.
+    fn split(&mut self) {
+        if let Some(current) = self.current {
+            if !self.graph.blocks[current].nodes.is_empty() {
+                self.join(vec![(current, ControlFlowEdgeKind::Unconditional)]);
+            }
+        }
+    }
+
+    fn build_body(&mut self, body: &'a Block) {
+        self.build_block(body);
+        self.jump(self.graph.exit, ControlFlowEdgeKind::Unconditional);
+    }
+
+    fn build_block(&mut self, block: &'a Block) {
+        for statement in block.statements.iter() {
+            self.build_statement(statement);
+        }
+    }
+
+    fn build_block_or_statement(&mut self, block_or_statement: &'a BlockOrStatement) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.build_block(block),
+            BlockOrStatement::Statement(statement) => self.build_statement(statement),
+        }
+    }
+
+    fn build_statement(&mut self, statement: &'a Statement) {
+        match statement {
+            Statement::IfStatement(if_statement) => {
+                let condition_block = self.push_node(ControlFlowNode::Condition(&if_statement.condition));
+                let mut sources = vec![];
+
+                self.join(vec![(condition_block, ControlFlowEdgeKind::True)]);
+                self.build_block_or_statement(&if_statement.true_body);
+                sources.extend(self.current.map(|id| (id, ControlFlowEdgeKind::Unconditional)));
+
+                match if_statement.false_body.as_ref() {
+                    Some(false_body) => {
+                        self.join(vec![(condition_block, ControlFlowEdgeKind::False)]);
+                        self.build_block_or_statement(false_body);
+                        sources.extend(self.current.map(|id| (id, ControlFlowEdgeKind::Unconditional)));
+                    }
+
+                    None => sources.push((condition_block, ControlFlowEdgeKind::False)),
+                }
+
+                self.join(sources);
+            }
+
+            Statement::ForStatement(for_statement) => {
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_ref() {
+                    self.build_statement(initialization_expression);
+                }
+
+                let header_block = self.new_block();
+                self.jump(header_block, ControlFlowEdgeKind::Unconditional);
+                self.current = Some(header_block);
+
+                let mut exits = vec![];
+
+                match for_statement.condition.as_ref() {
+                    Some(condition) => {
+                        self.push_node(ControlFlowNode::Condition(condition));
+                        exits.push((header_block, ControlFlowEdgeKind::False));
+                        self.join(vec![(header_block, ControlFlowEdgeKind::True)]);
+                    }
+
+                    None => self.join(vec![(header_block, ControlFlowEdgeKind::Unconditional)]),
+                }
+
+                let loop_context = self.build_loop_body(&for_statement.body);
+                let continues = Self::loop_continues(self.current, &loop_context);
+
+                match for_statement.loop_expression.as_ref() {
+                    Some(loop_expression) if !continues.is_empty() => {
+                        self.join(continues);
+                        self.build_statement(loop_expression);
+                        self.jump(header_block, ControlFlowEdgeKind::Unconditional);
+                    }
+
+                    _ => {
+                        for (source, kind) in continues {
+                            self.add_edge(source, header_block, kind);
+                        }
+                    }
+                }
+
+                exits.extend(loop_context.breaks.iter().map(|&id| (id, ControlFlowEdgeKind::Break)));
+                self.join(exits);
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                let header_block = self.new_block();
+                self.jump(header_block, ControlFlowEdgeKind::Unconditional);
+                self.current = Some(header_block);
+                self.push_node(ControlFlowNode::Condition(&while_statement.condition));
+                self.join(vec![(header_block, ControlFlowEdgeKind::True)]);
+
+                let loop_context = self.build_loop_body(&while_statement.body);
+
+                for (source, kind) in Self::loop_continues(self.current, &loop_context) {
+                    self.add_edge(source, header_block, kind);
+                }
+
+                let mut exits = vec![(header_block, ControlFlowEdgeKind::False)];
+                exits.extend(loop_context.breaks.iter().map(|&id| (id, ControlFlowEdgeKind::Break)));
+                self.join(exits);
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                let body_block = self.new_block();
+                self.jump(body_block, ControlFlowEdgeKind::Unconditional);
+                self.current = Some(body_block);
+
+                let loop_context = self.build_loop_body(&do_while_statement.body);
+                let continues = Self::loop_continues(self.current, &loop_context);
+                let mut exits = vec![];
+
+                // The condition is only evaluated if the end of the body is reachable
+                if !continues.is_empty() {
+                    self.join(continues);
+                    let condition_block = self.push_node(ControlFlowNode::Condition(&do_while_statement.condition));
+                    self.add_edge(condition_block, body_block, ControlFlowEdgeKind::True);
+                    exits.push((condition_block, ControlFlowEdgeKind::False));
+                }
+
+                exits.extend(loop_context.breaks.iter().map(|&id| (id, ControlFlowEdgeKind::Break)));
+                self.join(exits);
+            }
+
+            Statement::TryStatement(try_statement) => {
+                let call_block = self.push_node(ControlFlowNode::TryCall(&try_statement.external_call));
+                let mut sources = vec![];
+
+                // The first clause is executed when the call succeeds, the rest are `catch` clauses
+                for (index, clause) in try_statement.clauses.iter().enumerate() {
+                    self.join(vec![(
+                        call_block,
+                        if index == 0 { ControlFlowEdgeKind::TrySuccess } else { ControlFlowEdgeKind::TryCatch },
+                    )]);
+
+                    self.push_node(ControlFlowNode::TryCatchClause(clause));
+                    self.build_block(&clause.block);
+                    sources.extend(self.current.map(|id| (id, ControlFlowEdgeKind::Unconditional)));
+                }
+
+                self.join(sources);
+            }
+
+            Statement::UncheckedBlock(block) => {
+                self.set_unchecked(true);
+                self.build_block(block);
+                self.set_unchecked(false);
+            }
+
+            Statement::Block(block) => {
+                self.build_block(block);
+            }
+
+            Statement::Return(_) => {
+                self.push_node(ControlFlowNode::Statement(statement));
+                self.jump(self.graph.exit, ControlFlowEdgeKind::Return);
+            }
+
+            Statement::RevertStatement(_) => {
+                self.push_node(ControlFlowNode::Statement(statement));
+                self.jump(self.graph.revert, ControlFlowEdgeKind::Revert);
+            }
+
+            Statement::Break { .. } => {
+                let id = self.push_node(ControlFlowNode::Statement(statement));
+                self.current = None;
+
+                if let Some(loop_context) = self.loops.last_mut() {
+                    loop_context.breaks.push(id);
+                }
+            }
+
+            Statement::Continue { .. } => {
+                let id = self.push_node(ControlFlowNode::Statement(statement));
+                self.current = None;
+
+                if let Some(loop_context) = self.loops.last_mut() {
+                    loop_context.continues.push(id);
+                }
+            }
+
+            Statement::PlaceholderStatement { .. } => {
+                self.split();
+                self.push_node(ControlFlowNode::Statement(statement));
+                self.split();
+            }
+
+            Statement::ExpressionStatement(expression_statement) => {
+                let id = self.push_node(ControlFlowNode::Statement(statement));
+
+                match Self::called_magic_function(&expression_statement.expression) {
+                    Some("revert") => self.jump(self.graph.revert, ControlFlowEdgeKind::Revert),
+
+                    Some("require" | "assert") => {
+                        self.add_edge(id, self.graph.revert, ControlFlowEdgeKind::Revert);
+                        self.split();
+                    }
+
+                    _ => {}
+                }
+            }
+
+            Statement::VariableDeclarationStatement(_)
+            | Statement::EmitStatement(_)
+            | Statement::InlineAssembly(_) => {
+                self.push_node(ControlFlowNode::Statement(statement));
+            }
+        }
+    }
+
+    /// Builds the body of a loop, returning the blocks which break out of or continue the loop.
+    fn build_loop_body(&mut self, body: &'a BlockOrStatement) -> LoopContext {
+        self.loops.push(LoopContext::default());
+        self.build_block_or_statement(body);
+        self.loops.pop().unwrap_or_default()
+    }
+
+    /// The blocks which proceed to the next iteration of a loop: the end of the loop body and any `continue` statements.
+    fn loop_continues(current: Option<BasicBlockId>, loop_context: &LoopContext) -> Vec<(BasicBlockId, ControlFlowEdgeKind)> {
+        current.map(|id| (id, ControlFlowEdgeKind::Unconditional)).into_iter()
+            .chain(loop_context.continues.iter().map(|&id| (id, ControlFlowEdgeKind::Continue)))
+            .collect()
+    }
+
+    /// Starts a new block when entering or leaving an `unchecked` block.
+    fn set_unchecked(&mut self, is_unchecked: bool) {
+        self.is_unchecked = is_unchecked;
+
+        if let Some(current) = self.current {
+            if self.graph.blocks[current].is_unchecked != is_unchecked {
+                if self.graph.blocks[current].nodes.is_empty() {
+                    self.graph.blocks[current].is_unchecked = is_unchecked;
+                } else {
+                    self.join(vec![(current, ControlFlowEdgeKind::Unconditional)]);
+                }
+            }
+        }
+    }
+
+    /// The name of the global function called by an expression statement, i.e: `revert`, `require` or `assert`.
+    fn called_magic_function(expression: &Expression) -> Option<&str> {
+        let Expression::FunctionCall(function_call) = expression else { return None };
+        let Expression::Identifier(identifier) = function_call.expression.as_ref() else { return None };
+
+        if magic_variable_id(identifier.name.as_str()) == Some(identifier.referenced_declaration) {
+            Some(identifier.name.as_str())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+contract Loops {
+    function sum(uint256 n) public pure returns (uint256 total) {
+        for (uint256 i = 0; i < n; i++) {
+            if (i == 3) continue;
+            if (i == 7) break;
+            total += i;
+        }
+        require(total > 0);
+        return total;
+    }
+
+    function count(uint256 n) public pure returns (uint256 i) {
+        while (true) {
+            i++;
+            if (i < n) continue;
+            break;
+        }
+    }
+}
+"#;
+
+    const CALLS_SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+interface IOracle {
+    function price() external returns (uint256);
+}
+
+contract Calls {
+    error Stale();
+
+    IOracle oracle;
+    uint256 total;
+
+    modifier guarded(uint256 amount) {
+        require(amount > 0);
+        total += 1;
+        _;
+        total -= 1;
+    }
+
+    function read() public returns (uint256 value) {
+        try oracle.price() returns (uint256 price) {
+            value = price;
+        } catch Error(string memory) {
+            revert Stale();
+        } catch {
+            value = 0;
+        }
+        unchecked {
+            value += 1;
+        }
+        return value;
+    }
+
+    function check(uint256 amount) public {
+        if (amount > 10) {
+            revert("too large");
+        }
+        total = amount;
+    }
+}
+"#;
+
+    /// Describes the edges of a graph, labeling each basic block with its nodes.
+    fn edges(graph: &ControlFlowGraph) -> Vec<(String, String, ControlFlowEdgeKind)> {
+        let label = |id: BasicBlockId| {
+            if id == graph.entry && graph.block(id).nodes.is_empty() {
+                return "entry".to_string();
+            } else if id == graph.exit {
+                return "exit".to_string();
+            } else if id == graph.revert {
+                return "revert".to_string();
+            }
+
+            graph.block(id).nodes.iter()
+                .map(|node| match node {
+                    ControlFlowNode::Statement(statement) => statement.to_string(),
+                    ControlFlowNode::Condition(expression) => format!("if {expression}"),
+                    ControlFlowNode::TryCall(function_call) => format!("try {function_call}"),
+                    ControlFlowNode::TryCatchClause(_) => "catch".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+
+        graph.edges.iter()
+            .map(|edge| (label(edge.source), label(edge.target), edge.kind))
+            .collect()
+    }
+
+    fn edge(source: &str, target: &str, kind: ControlFlowEdgeKind) -> (String, String, ControlFlowEdgeKind) {
+        (source.to_string(), target.to_string(), kind)
+    }
+
+    #[test]
+    fn test_for_loop() {
+        let source_units = builder::build_test_source_units("Loops.sol", SOURCE);
+        let function_definition = source_units[0].contract_definitions()[0].function_definitions()[0];
+        let graph = ControlFlowGraph::new(function_definition.body.as_ref().unwrap());
+
+        use ControlFlowEdgeKind::*;
+
+        assert_eq!(edges(&graph), vec![
+            edge("uint256 i = 0", "if i < n", Unconditional),
+            edge("if i < n", "if i == 3", True),
+            edge("if i == 3", "continue", True),
+            edge("if i == 3", "if i == 7", False),
+            edge("if i == 7", "break", True),
+            edge("if i == 7", "total += i", False),
+            edge("total += i", "i++", Unconditional),
+            edge("continue", "i++", Continue),
+            edge("i++", "if i < n", Unconditional),
+            edge("if i < n", "require(total > 0)", False),
+            edge("break", "require(total > 0)", Break),
+            edge("require(total > 0)", "revert", Revert),
+            edge("require(total > 0)", "return total", Unconditional),
+            edge("return total", "exit", Return),
+        ]);
+
+        assert!(!graph.falls_through());
+    }
+
+    #[test]
+    fn test_while_loop() {
+        let source_units = builder::build_test_source_units("Loops.sol", SOURCE);
+        let function_definition = source_units[0].contract_definitions()[0].function_definitions()[1];
+        let graph = ControlFlowGraph::new(function_definition.body.as_ref().unwrap());
+
+        use ControlFlowEdgeKind::*;
+
+        assert_eq!(edges(&graph), vec![
+            edge("entry", "if true", Unconditional),
+            edge("if true", "i++; if i < n", True),
+            edge("i++; if i < n", "continue", True),
+            edge("i++; if i < n", "break", False),
+            edge("continue", "if true", Continue),
+            edge("if true", "", False),
+            edge("break", "", Break),
+            edge("", "exit", Unconditional),
+        ]);
+
+        assert!(graph.falls_through());
+    }
+
+    #[test]
+    fn test_try_catch_and_unchecked() {
+        let source_units = builder::build_test_source_units("Calls.sol", CALLS_SOURCE);
+        let function_definition = source_units[0].contract_definitions()[1].function_definitions()[0];
+        let graph = ControlFlowGraph::new(function_definition.body.as_ref().unwrap());
+
+        use ControlFlowEdgeKind::*;
+
+        assert_eq!(edges(&graph), vec![
+            edge("try oracle.price()", "catch; value = price", TrySuccess),
+            edge("try oracle.price()", "catch; revert Stale()", TryCatch),
+            edge("catch; revert Stale()", "revert", Revert),
+            edge("try oracle.price()", "catch; value = 0", TryCatch),
+            edge("catch; value = price", "value += 1", Unconditional),
+            edge("catch; value = 0", "value += 1", Unconditional),
+            edge("value += 1", "return value", Unconditional),
+            edge("return value", "exit", Return),
+        ]);
+
+        // The statements of the `unchecked` block are kept in a basic block of their own
+        let unchecked_blocks: Vec<_> = graph.blocks.iter().filter(|block| block.is_unchecked).map(|block| block.id).collect();
+        assert_eq!(unchecked_blocks.len(), 1);
+        assert_eq!(graph.block(unchecked_blocks[0]).nodes.len(), 1);
+        assert!(graph.successors(unchecked_blocks[0]).all(|id| !graph.block(id).is_unchecked));
+    }
+
+    #[test]
+    fn test_modifier_placeholder() {
+        let source_units = builder::build_test_source_units("Calls.sol", CALLS_SOURCE);
+        let modifier_definition = source_units[0].contract_definitions()[1].modifier_definitions()[0];
+        let graph = ControlFlowGraph::new(&modifier_definition.body);
+
+        use ControlFlowEdgeKind::*;
+
+        // A failed `require` reverts, otherwise execution continues in a new block
+        assert_eq!(edges(&graph), vec![
+            edge("require(amount > 0)", "revert", Revert),
+            edge("require(amount > 0)", "total += 1", Unconditional),
+            edge("total += 1", "_", Unconditional),
+            edge("_", "total -= 1", Unconditional),
+            edge("total -= 1", "exit", Unconditional),
+        ]);
+
+        let placeholder_blocks = graph.placeholder_blocks();
+        assert_eq!(placeholder_blocks.len(), 1);
+        assert_eq!(graph.block(placeholder_blocks[0]).nodes.len(), 1);
+        assert!(graph.falls_through());
+    }
+
+    #[test]
+    fn test_revert_and_dominators() {
+        let source_units = builder::build_test_source_units("Calls.sol", CALLS_SOURCE);
+        let function_definition = source_units[0].contract_definitions()[1].function_definitions()[1];
+        let graph = ControlFlowGraph::new(function_definition.body.as_ref().unwrap());
+
+        use ControlFlowEdgeKind::*;
+
+        assert_eq!(edges(&graph), vec![
+            edge("if amount > 10", "revert(\"too large\")", True),
+            edge("revert(\"too large\")", "revert", Revert),
+            edge("if amount > 10", "total = amount", False),
+            edge("total = amount", "exit", Unconditional),
+        ]);
+
+        let condition_block = graph.entry;
+        let revert_block = graph.predecessors(graph.revert).next().unwrap();
+        let assignment_block = graph.predecessors(graph.exit).next().unwrap();
+
+        // The reverting branch doesn't reach the exit block, so the assignment is the exit's immediate dominator
+        let dominators = graph.dominators();
+        assert_eq!(dominators.immediate_dominator(graph.entry), None);
+        assert_eq!(dominators.immediate_dominator(revert_block), Some(condition_block));
+        assert_eq!(dominators.immediate_dominator(graph.revert), Some(revert_block));
+        assert_eq!(dominators.dominators(graph.exit), vec![graph.exit, assignment_block, condition_block]);
+        assert!(dominators.dominates(condition_block, graph.revert));
+        assert!(!dominators.dominates(revert_block, graph.exit));
+        assert!(!dominators.dominates(assignment_block, revert_block));
+    }
+
+    #[test]
+    fn test_loop_dominators() {
+        let source_units = builder::build_test_source_units("Loops.sol", SOURCE);
+        let function_definition = source_units[0].contract_definitions()[0].function_definitions()[0];
+        let graph = ControlFlowGraph::new(function_definition.body.as_ref().unwrap());
+        let dominators = graph.dominators();
+
+        let header_block = graph.successors(graph.entry).next().unwrap();
+        let increment_block = graph.predecessors(header_block).find(|&id| id != graph.entry).unwrap();
+
+        // The loop header dominates its body, but the body's `continue` and fall-through paths both reach the increment
+        assert_eq!(dominators.immediate_dominator(header_block), Some(graph.entry));
+        assert!(dominators.dominates(header_block, increment_block));
+        assert!(dominators.dominates(header_block, graph.exit));
+        assert_eq!(graph.predecessors(increment_block).count(), 2);
+        assert!(graph.predecessors(increment_block).all(|id| !dominators.dominates(id, increment_block)));
+    }
+}
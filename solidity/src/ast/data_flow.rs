@@ -0,0 +1,1129 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use yul::ast::*;
+
+/// The position of a node within a control-flow graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeLocation {
+    pub block: BasicBlockId,
+    /// The index of the node within its basic block.
+    pub node: usize,
+}
+
+/// A step from a variable to one of its components.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PlaceElement {
+    /// A member of a struct, i.e: `s.member`.
+    Member(String),
+    /// An element of an array or mapping, i.e: `a[0]`. The index is `None` when it is not a literal,
+    /// in which case any element may be accessed.
+    Index(Option<String>),
+}
+
+/// A variable, or a struct member or array element contained in a variable, which is read or written.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Place {
+    /// The ID of the `VariableDeclaration` of the variable.
+    pub variable: NodeID,
+    pub path: Vec<PlaceElement>,
+}
+
+impl Place {
+    pub fn new(variable: NodeID) -> Self {
+        Self { variable, path: vec![] }
+    }
+
+    /// Determines if the place refers to a single location, i.e: it does not contain an element with an unknown index.
+    pub fn is_exact(&self) -> bool {
+        !self.path.iter().any(|element| matches!(element, PlaceElement::Index(None)))
+    }
+
+    /// Determines if writing to the place overwrites all of the supplied place.
+    pub fn covers(&self, other: &Place) -> bool {
+        self.variable == other.variable
+            && self.is_exact()
+            && self.path.len() <= other.path.len()
+            && self.path.iter().zip(other.path.iter()).all(|(a, b)| a == b)
+    }
+
+    /// Determines if the place and the supplied place may refer to the same location.
+    pub fn overlaps(&self, other: &Place) -> bool {
+        self.variable == other.variable
+            && self.path.iter().zip(other.path.iter()).all(|(a, b)| match (a, b) {
+                (PlaceElement::Index(None), PlaceElement::Index(_)) | (PlaceElement::Index(_), PlaceElement::Index(None)) => true,
+                (a, b) => a == b,
+            })
+    }
+}
+
+/// Where the value of a variable is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VariableKind {
+    /// A state variable, whose value persists after execution completes.
+    Storage,
+    /// A local variable which points to a value in storage.
+    StoragePointer,
+    Memory,
+    Calldata,
+    /// A local variable or parameter with a value type.
+    Stack,
+    /// A constant or immutable variable, which is not kept in storage.
+    Constant,
+}
+
+impl VariableKind {
+    pub fn of(variable_declaration: &VariableDeclaration) -> Self {
+        if variable_declaration.constant || matches!(variable_declaration.mutability, Some(Mutability::Constant | Mutability::Immutable)) {
+            return VariableKind::Constant;
+        }
+
+        if variable_declaration.state_variable {
+            return VariableKind::Storage;
+        }
+
+        match variable_declaration.storage_location {
+            StorageLocation::Storage => VariableKind::StoragePointer,
+            StorageLocation::Memory => VariableKind::Memory,
+            StorageLocation::Calldata => VariableKind::Calldata,
+            StorageLocation::Default => VariableKind::Stack,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    Read,
+    Write,
+}
+
+/// A read or write of a place by a node of a control-flow graph.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Access {
+    pub kind: AccessKind,
+    pub place: Place,
+    /// The ID of the node performing the access, i.e: an identifier, an assignment or a variable declaration.
+    pub id: NodeID,
+    pub src: String,
+}
+
+/// The direction in which facts are propagated through a control-flow graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataFlowDirection {
+    Forward,
+    Backward,
+}
+
+/// A monotone dataflow analysis over a control-flow graph.
+pub trait DataFlowAnalysis {
+    type Fact: Clone + PartialEq;
+
+    fn direction(&self) -> DataFlowDirection;
+
+    /// The initial fact of every block, which must be the identity of `join`.
+    fn bottom(&self) -> Self::Fact;
+
+    /// The fact at the entry block of a forward analysis, or at the exit block of a backward analysis.
+    fn boundary(&self) -> Self::Fact;
+
+    /// Merges a fact into another fact where control flow meets.
+    fn join(&self, fact: &mut Self::Fact, other: &Self::Fact);
+
+    /// Applies the effect of a node to a fact. Backward analyses receive the fact after the node and produce the fact before it.
+    fn transfer(&self, location: NodeLocation, node: &ControlFlowNode<'_>, fact: &mut Self::Fact);
+}
+
+/// The facts computed by a dataflow analysis at the entry and exit of each basic block, in execution order.
+#[derive(Clone, Debug)]
+pub struct DataFlowResults<F> {
+    pub block_entry: Vec<F>,
+    pub block_exit: Vec<F>,
+}
+
+impl<F: Clone + PartialEq> DataFlowResults<F> {
+    /// Computes the fact which holds immediately before the supplied node is executed.
+    pub fn fact_before<A: DataFlowAnalysis<Fact = F>>(&self, graph: &ControlFlowGraph, analysis: &A, location: NodeLocation) -> F {
+        let nodes = &graph.block(location.block).nodes;
+
+        match analysis.direction() {
+            DataFlowDirection::Forward => {
+                let mut fact = self.block_entry[location.block].clone();
+
+                for (index, node) in nodes.iter().enumerate().take(location.node) {
+                    analysis.transfer(NodeLocation { block: location.block, node: index }, node, &mut fact);
+                }
+
+                fact
+            }
+
+            DataFlowDirection::Backward => {
+                let mut fact = self.block_exit[location.block].clone();
+
+                for (index, node) in nodes.iter().enumerate().skip(location.node).rev() {
+                    analysis.transfer(NodeLocation { block: location.block, node: index }, node, &mut fact);
+                }
+
+                fact
+            }
+        }
+    }
+
+    /// Computes the fact which holds immediately after the supplied node is executed.
+    pub fn fact_after<A: DataFlowAnalysis<Fact = F>>(&self, graph: &ControlFlowGraph, analysis: &A, location: NodeLocation) -> F {
+        let nodes = &graph.block(location.block).nodes;
+
+        match analysis.direction() {
+            DataFlowDirection::Forward => self.fact_before(graph, analysis, NodeLocation { block: location.block, node: location.node + 1 }),
+
+            DataFlowDirection::Backward => {
+                let mut fact = self.block_exit[location.block].clone();
+
+                for (index, node) in nodes.iter().enumerate().skip(location.node + 1).rev() {
+                    analysis.transfer(NodeLocation { block: location.block, node: index }, node, &mut fact);
+                }
+
+                fact
+            }
+        }
+    }
+}
+
+/// Computes the fixed point of a dataflow analysis over a control-flow graph using a worklist.
+pub fn solve_data_flow<A: DataFlowAnalysis>(graph: &ControlFlowGraph, analysis: &A) -> DataFlowResults<A::Fact> {
+    let mut results = DataFlowResults {
+        block_entry: vec![analysis.bottom(); graph.blocks.len()],
+        block_exit: vec![analysis.bottom(); graph.blocks.len()],
+    };
+
+    let direction = analysis.direction();
+
+    // Start with the reachable blocks in an order which visits most predecessors (or successors) first
+    let mut order = graph.reverse_post_order();
+
+    if direction == DataFlowDirection::Backward {
+        order.reverse();
+    }
+
+    let mut pending: VecDeque<BasicBlockId> = order.into_iter().collect();
+    let mut is_pending: HashSet<BasicBlockId> = pending.iter().copied().collect();
+
+    while let Some(id) = pending.pop_front() {
+        is_pending.remove(&id);
+
+        let nodes = &graph.block(id).nodes;
+
+        let changed = match direction {
+            DataFlowDirection::Forward => {
+                let mut fact = if id == graph.entry { analysis.boundary() } else { analysis.bottom() };
+
+                for predecessor in graph.predecessors(id) {
+                    analysis.join(&mut fact, &results.block_exit[predecessor]);
+                }
+
+                results.block_entry[id] = fact.clone();
+
+                for (index, node) in nodes.iter().enumerate() {
+                    analysis.transfer(NodeLocation { block: id, node: index }, node, &mut fact);
+                }
+
+                let changed = results.block_exit[id] != fact;
+                results.block_exit[id] = fact;
+                changed
+            }
+
+            DataFlowDirection::Backward => {
+                let mut fact = if id == graph.exit { analysis.boundary() } else { analysis.bottom() };
+
+                for successor in graph.successors(id) {
+                    analysis.join(&mut fact, &results.block_entry[successor]);
+                }
+
+                results.block_exit[id] = fact.clone();
+
+                for (index, node) in nodes.iter().enumerate().rev() {
+                    analysis.transfer(NodeLocation { block: id, node: index }, node, &mut fact);
+                }
+
+                let changed = results.block_entry[id] != fact;
+                results.block_entry[id] = fact;
+                changed
+            }
+        };
+
+        if !changed {
+            continue;
+        }
+
+        let dependents: Vec<BasicBlockId> = match direction {
+            DataFlowDirection::Forward => graph.successors(id).collect(),
+            DataFlowDirection::Backward => graph.predecessors(id).collect(),
+        };
+
+        for dependent in dependents {
+            if is_pending.insert(dependent) {
+                pending.push_back(dependent);
+            }
+        }
+    }
+
+    results
+}
+
+/// The control-flow graph of a function or modifier body, along with the variable accesses of each of its nodes.
+pub struct DataFlowGraph<'a> {
+    pub control_flow_graph: ControlFlowGraph<'a>,
+    /// The declarations of the variables which can be accessed by the body.
+    pub variables: HashMap<NodeID, &'a VariableDeclaration>,
+    /// The variables which have a value before the body is executed, i.e: state variables and parameters.
+    pub initialized_variables: Vec<NodeID>,
+    /// The variables which are returned when the body completes normally.
+    pub return_variables: Vec<NodeID>,
+    accesses: Vec<Vec<Vec<Access>>>,
+}
+
+impl<'a> DataFlowGraph<'a> {
+    pub fn for_function_definition(source_units: &'a [SourceUnit], function_definition: &'a FunctionDefinition) -> Option<Self> {
+        let body = function_definition.body.as_ref()?;
+
+        Some(Self::new(
+            source_units,
+            body,
+            function_definition.parameters.parameters.iter(),
+            function_definition.return_parameters.parameters.iter(),
+        ))
+    }
+
+    pub fn for_modifier_definition(source_units: &'a [SourceUnit], modifier_definition: &'a ModifierDefinition) -> Self {
+        Self::new(source_units, &modifier_definition.body, modifier_definition.parameters.parameters.iter(), [].iter())
+    }
+
+    fn new(
+        source_units: &'a [SourceUnit],
+        body: &'a Block,
+        parameters: impl Iterator<Item = &'a VariableDeclaration>,
+        return_parameters: impl Iterator<Item = &'a VariableDeclaration>,
+    ) -> Self {
+        let control_flow_graph = ControlFlowGraph::new(body);
+
+        let mut graph = Self {
+            control_flow_graph,
+            variables: HashMap::new(),
+            initialized_variables: vec![],
+            return_variables: vec![],
+            accesses: vec![],
+        };
+
+        //
+        // Collect the state variables and constants of every source unit, followed by the parameters and local variables
+        //
+
+        for source_unit in source_units.iter() {
+            for node in source_unit.nodes.iter() {
+                match node {
+                    SourceUnitNode::VariableDeclaration(variable_declaration) => {
+                        graph.add_variable(variable_declaration, true);
+                    }
+
+                    SourceUnitNode::ContractDefinition(contract_definition) => {
+                        for variable_declaration in contract_definition.variable_declarations() {
+                            graph.add_variable(variable_declaration, true);
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        for variable_declaration in parameters {
+            graph.add_variable(variable_declaration, true);
+        }
+
+        // Return variables are zero-initialized
+        for variable_declaration in return_parameters {
+            graph.add_variable(variable_declaration, true);
+            graph.return_variables.push(variable_declaration.id);
+        }
+
+        let mut local_variables = vec![];
+
+        for (_, node) in graph.control_flow_graph.nodes() {
+            match node {
+                ControlFlowNode::Statement(Statement::VariableDeclarationStatement(variable_declaration_statement)) => {
+                    local_variables.extend(variable_declaration_statement.declarations.iter().flatten());
+                }
+
+                ControlFlowNode::TryCatchClause(clause) => {
+                    local_variables.extend(clause.parameters.iter().flat_map(|parameters| parameters.parameters.iter()));
+                }
+
+                _ => {}
+            }
+        }
+
+        for variable_declaration in local_variables {
+            graph.add_variable(variable_declaration, false);
+        }
+
+        //
+        // Collect the accesses of each node in evaluation order
+        //
+
+        let accesses = graph.control_flow_graph.blocks.iter()
+            .map(|block| block.nodes.iter().map(|node| graph.node_accesses(node)).collect())
+            .collect();
+
+        graph.accesses = accesses;
+        graph
+    }
+
+    fn add_variable(&mut self, variable_declaration: &'a VariableDeclaration, is_initialized: bool) {
+        if self.variables.insert(variable_declaration.id, variable_declaration).is_none() && is_initialized {
+            self.initialized_variables.push(variable_declaration.id);
+        }
+    }
+
+    pub fn variable_kind(&self, id: NodeID) -> Option<VariableKind> {
+        self.variables.get(&id).map(|variable_declaration| VariableKind::of(variable_declaration))
+    }
+
+    /// The accesses performed by the supplied node, in evaluation order.
+    pub fn accesses(&self, location: NodeLocation) -> &[Access] {
+        self.accesses.get(location.block)
+            .and_then(|nodes| nodes.get(location.node))
+            .map(|accesses| accesses.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Iterates over every access of the graph along with the location of the node performing it.
+    pub fn all_accesses(&self) -> impl Iterator<Item = (NodeLocation, &Access)> {
+        self.accesses.iter().enumerate().flat_map(|(block, nodes)| {
+            nodes.iter().enumerate().flat_map(move |(node, accesses)| {
+                accesses.iter().map(move |access| (NodeLocation { block, node }, access))
+            })
+        })
+    }
+
+    /// Determines if a write to the supplied place remains observable after execution completes,
+    /// i.e: it writes to storage directly or through a storage pointer.
+    pub fn is_persistent(&self, place: &Place) -> bool {
+        match self.variable_kind(place.variable) {
+            Some(VariableKind::Storage) => true,
+            Some(VariableKind::StoragePointer) => !place.path.is_empty(),
+            _ => false,
+        }
+    }
+
+    pub fn reaching_definitions(&self) -> ReachingDefinitions<'_, 'a> {
+        ReachingDefinitions::new(self)
+    }
+
+    pub fn live_variables(&self) -> LiveVariables<'_, 'a> {
+        LiveVariables { graph: self }
+    }
+
+    pub fn def_use_chains(&self) -> DefUseChains {
+        DefUseChains::new(self)
+    }
+
+    fn node_accesses(&self, node: &ControlFlowNode<'a>) -> Vec<Access> {
+        let mut accesses = vec![];
+
+        match node {
+            ControlFlowNode::Statement(statement) => match statement {
+                Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                    if let Some(initial_value) = variable_declaration_statement.initial_value.as_ref() {
+                        self.read_expression(initial_value, &mut accesses);
+                    }
+
+                    for variable_declaration in variable_declaration_statement.declarations.iter().flatten() {
+                        accesses.push(Access {
+                            kind: AccessKind::Write,
+                            place: Place::new(variable_declaration.id),
+                            id: variable_declaration.id,
+                            src: variable_declaration.src.clone(),
+                        });
+                    }
+                }
+
+                Statement::ExpressionStatement(expression_statement) => {
+                    self.read_expression(&expression_statement.expression, &mut accesses);
+                }
+
+                Statement::EmitStatement(emit_statement) => {
+                    self.read_expression(&emit_statement.event_call, &mut accesses);
+                }
+
+                Statement::RevertStatement(revert_statement) => {
+                    self.read_function_call(&revert_statement.error_call, &mut accesses);
+                }
+
+                Statement::Return(return_statement) => {
+                    if let Some(expression) = return_statement.expression.as_ref() {
+                        self.read_expression(expression, &mut accesses);
+
+                        // Returning a value assigns it to the return variables
+                        for &id in self.return_variables.iter() {
+                            accesses.push(Access {
+                                kind: AccessKind::Write,
+                                place: Place::new(id),
+                                id: return_statement.id,
+                                src: return_statement.src.clone(),
+                            });
+                        }
+                    }
+                }
+
+                Statement::InlineAssembly(inline_assembly) => {
+                    self.inline_assembly_accesses(inline_assembly, &mut accesses);
+                }
+
+                _ => {}
+            },
+
+            ControlFlowNode::Condition(expression) => {
+                self.read_expression(expression, &mut accesses);
+            }
+
+            ControlFlowNode::TryCall(function_call) => {
+                self.read_function_call(function_call, &mut accesses);
+            }
+
+            ControlFlowNode::TryCatchClause(clause) => {
+                for variable_declaration in clause.parameters.iter().flat_map(|parameters| parameters.parameters.iter()) {
+                    accesses.push(Access {
+                        kind: AccessKind::Write,
+                        place: Place::new(variable_declaration.id),
+                        id: variable_declaration.id,
+                        src: variable_declaration.src.clone(),
+                    });
+                }
+            }
+        }
+
+        accesses
+    }
+
+    /// Determines the place referred to by an expression. Reads performed while evaluating the place
+    /// (i.e: the index of an index access) are added to `accesses`.
+    fn place(&self, expression: &Expression, accesses: &mut Vec<Access>) -> Option<Place> {
+        match expression {
+            Expression::Identifier(identifier) => {
+                if self.variables.contains_key(&identifier.referenced_declaration) {
+                    Some(Place::new(identifier.referenced_declaration))
+                } else {
+                    None
+                }
+            }
+
+            Expression::MemberAccess(member_access) => {
+                match self.place(member_access.expression.as_ref(), accesses) {
+                    Some(mut place) => {
+                        place.path.push(PlaceElement::Member(member_access.member_name.clone()));
+                        Some(place)
+                    }
+
+                    None => {
+                        // Constants of other contracts are accessed through their contract, i.e: `Contract.CONSTANT`
+                        let id = member_access.referenced_declaration?;
+                        self.read_expression(member_access.expression.as_ref(), accesses);
+                        self.variables.contains_key(&id).then(|| Place::new(id))
+                    }
+                }
+            }
+
+            Expression::IndexAccess(index_access) => {
+                let place = self.place(index_access.base_expression.as_ref(), accesses);
+
+                let index = match index_access.index_expression.as_deref() {
+                    Some(Expression::Literal(literal)) => literal.value.clone().or_else(|| literal.hex_value.clone()),
+
+                    Some(index_expression) => {
+                        self.read_expression(index_expression, accesses);
+                        None
+                    }
+
+                    None => None,
+                };
+
+                match place {
+                    Some(mut place) => {
+                        place.path.push(PlaceElement::Index(index));
+                        Some(place)
+                    }
+
+                    None => {
+                        self.read_expression(index_access.base_expression.as_ref(), accesses);
+                        None
+                    }
+                }
+            }
+
+            _ => None,
+        }
+    }
+
+    fn read_expression(&self, expression: &Expression, accesses: &mut Vec<Access>) {
+        match expression {
+            Expression::Identifier(_) | Expression::MemberAccess(_) | Expression::IndexAccess(_) => {
+                match self.place(expression, accesses) {
+                    Some(place) => accesses.push(Access {
+                        kind: AccessKind::Read,
+                        place,
+                        id: expression.id(),
+                        src: expression.src().to_string(),
+                    }),
+
+                    None => {
+                        if let Expression::MemberAccess(member_access) = expression {
+                            self.read_expression(member_access.expression.as_ref(), accesses);
+                        }
+                    }
+                }
+            }
+
+            Expression::Assignment(assignment) => {
+                self.read_expression(assignment.right_hand_side.as_ref(), accesses);
+                self.write_expression(assignment.left_hand_side.as_ref(), assignment.operator != "=", assignment.id, accesses);
+            }
+
+            Expression::UnaryOperation(unary_operation) => match unary_operation.operator.as_str() {
+                "++" | "--" => self.write_expression(unary_operation.sub_expression.as_ref(), true, unary_operation.id, accesses),
+                "delete" => self.write_expression(unary_operation.sub_expression.as_ref(), false, unary_operation.id, accesses),
+                _ => self.read_expression(unary_operation.sub_expression.as_ref(), accesses),
+            },
+
+            Expression::BinaryOperation(binary_operation) => {
+                self.read_expression(binary_operation.left_expression.as_ref(), accesses);
+                self.read_expression(binary_operation.right_expression.as_ref(), accesses);
+            }
+
+            Expression::Conditional(conditional) => {
+                self.read_expression(conditional.condition.as_ref(), accesses);
+                self.read_expression(conditional.true_expression.as_ref(), accesses);
+                self.read_expression(conditional.false_expression.as_ref(), accesses);
+            }
+
+            Expression::FunctionCall(function_call) => {
+                self.read_function_call(function_call, accesses);
+            }
+
+            Expression::FunctionCallOptions(function_call_options) => {
+                self.read_expression(function_call_options.expression.as_ref(), accesses);
+
+                for option in function_call_options.options.iter() {
+                    self.read_expression(option, accesses);
+                }
+
+                for argument in function_call_options.arguments.iter().flatten() {
+                    self.read_expression(argument, accesses);
+                }
+            }
+
+            Expression::IndexRangeAccess(index_range_access) => {
+                self.read_expression(index_range_access.base_expression.as_ref(), accesses);
+
+                for expression in [&index_range_access.start_expression, &index_range_access.end_expression].into_iter().flatten() {
+                    self.read_expression(expression, accesses);
+                }
+            }
+
+            Expression::TupleExpression(tuple_expression) => {
+                for component in tuple_expression.components.iter().flatten() {
+                    self.read_expression(component, accesses);
+                }
+            }
+
+            Expression::Literal(_) | Expression::ElementaryTypeNameExpression(_) | Expression::NewExpression(_) => {}
+        }
+    }
+
+    fn read_function_call(&self, function_call: &FunctionCall, accesses: &mut Vec<Access>) {
+        match function_call.expression.as_ref() {
+            // Pushing to or popping from an array reads its length and writes one of its elements
+            Expression::MemberAccess(member_access) if matches!(member_access.member_name.as_str(), "push" | "pop") => {
+                match self.place(member_access.expression.as_ref(), accesses) {
+                    Some(place) => {
+                        let src = member_access.expression.src().to_string();
+
+                        accesses.push(Access { kind: AccessKind::Read, place: place.clone(), id: member_access.id, src: src.clone() });
+
+                        for argument in function_call.arguments.iter() {
+                            self.read_expression(argument, accesses);
+                        }
+
+                        let mut element = place;
+                        element.path.push(PlaceElement::Index(None));
+
+                        accesses.push(Access { kind: AccessKind::Write, place: element, id: function_call.id, src });
+                        return;
+                    }
+
+                    None => self.read_expression(member_access.expression.as_ref(), accesses),
+                }
+            }
+
+            expression => self.read_expression(expression, accesses),
+        }
+
+        for argument in function_call.arguments.iter() {
+            self.read_expression(argument, accesses);
+        }
+    }
+
+    /// Adds the accesses of an expression which is assigned to. Compound assignments also read the previous value.
+    fn write_expression(&self, expression: &Expression, is_compound: bool, id: NodeID, accesses: &mut Vec<Access>) {
+        if let Expression::TupleExpression(tuple_expression) = expression {
+            for component in tuple_expression.components.iter().flatten() {
+                self.write_expression(component, is_compound, id, accesses);
+            }
+
+            return;
+        }
+
+        let Some(place) = self.place(expression, accesses) else {
+            self.read_expression(expression, accesses);
+            return;
+        };
+
+        let src = expression.src().to_string();
+
+        if is_compound {
+            accesses.push(Access { kind: AccessKind::Read, place: place.clone(), id, src: src.clone() });
+        }
+
+        accesses.push(Access { kind: AccessKind::Write, place, id, src });
+    }
+
+    /// Adds the accesses of the Solidity variables referenced by an inline assembly block.
+    fn inline_assembly_accesses(&self, inline_assembly: &InlineAssembly, accesses: &mut Vec<Access>) {
+        let mut assigned = HashSet::new();
+
+        if let Some(yul_block) = inline_assembly.ast.as_ref() {
+            Self::collect_yul_assignments(yul_block, &mut assigned);
+        }
+
+        let external_references = inline_assembly.external_references.iter().flat_map(|external_reference| match external_reference {
+            ExternalReference::Untagged(data) => vec![data],
+            ExternalReference::Tagged(data) => data.values().collect(),
+        });
+
+        let mut writes = vec![];
+
+        for data in external_references {
+            if !self.variables.contains_key(&data.declaration) {
+                continue;
+            }
+
+            // Accessing the slot or offset of a variable does not access its value
+            if data.is_slot || data.is_offset {
+                continue;
+            }
+
+            let access = Access {
+                kind: if assigned.contains(data.src.as_str()) { AccessKind::Write } else { AccessKind::Read },
+                place: Place::new(data.declaration),
+                id: inline_assembly.id,
+                src: data.src.clone(),
+            };
+
+            match access.kind {
+                AccessKind::Read => accesses.push(access),
+                AccessKind::Write => writes.push(access),
+            }
+        }
+
+        // The block is treated as a single node, so its reads are ordered before its writes
+        accesses.extend(writes);
+    }
+
+    fn collect_yul_assignments<'b>(yul_block: &'b YulBlock, assigned: &mut HashSet<&'b str>) {
+        for statement in yul_block.statements.iter() {
+            match statement {
+                YulStatement::YulAssignment(yul_assignment) => {
+                    assigned.extend(yul_assignment.variable_names.iter().map(|yul_identifier| yul_identifier.src.as_str()));
+                }
+
+                YulStatement::YulIf(yul_if) => Self::collect_yul_assignments(&yul_if.body, assigned),
+
+                YulStatement::YulSwitch(yul_switch) => {
+                    for yul_case in yul_switch.cases.iter() {
+                        Self::collect_yul_assignments(&yul_case.body, assigned);
+                    }
+                }
+
+                YulStatement::YulForLoop(yul_for_loop) => {
+                    for yul_block in [&yul_for_loop.pre, &yul_for_loop.body, &yul_for_loop.post] {
+                        Self::collect_yul_assignments(yul_block, assigned);
+                    }
+                }
+
+                YulStatement::YulBlock(yul_block) => Self::collect_yul_assignments(yul_block, assigned),
+
+                _ => {}
+            }
+        }
+    }
+}
+
+/// A write of a place, or the value a variable has before the body is executed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Definition {
+    pub place: Place,
+    /// The location of the node performing the write, or `None` for the initial value of a variable.
+    pub location: Option<NodeLocation>,
+    pub id: NodeID,
+    pub src: String,
+}
+
+/// Computes the definitions which may reach each node without being overwritten.
+pub struct ReachingDefinitions<'g, 'a> {
+    graph: &'g DataFlowGraph<'a>,
+    pub definitions: Vec<Definition>,
+    /// The index of the definition made by each write, keyed by the location of the node and the index of the access.
+    node_definitions: HashMap<(NodeLocation, usize), usize>,
+}
+
+impl<'g, 'a> ReachingDefinitions<'g, 'a> {
+    pub fn new(graph: &'g DataFlowGraph<'a>) -> Self {
+        let mut definitions = vec![];
+        let mut node_definitions = HashMap::new();
+
+        for &id in graph.initialized_variables.iter() {
+            let variable_declaration = graph.variables[&id];
+
+            definitions.push(Definition {
+                place: Place::new(id),
+                location: None,
+                id,
+                src: variable_declaration.src.clone(),
+            });
+        }
+
+        for (block, nodes) in graph.accesses.iter().enumerate() {
+            for (node, accesses) in nodes.iter().enumerate() {
+                for (index, access) in accesses.iter().enumerate() {
+                    if access.kind != AccessKind::Write {
+                        continue;
+                    }
+
+                    let location = NodeLocation { block, node };
+                    node_definitions.insert((location, index), definitions.len());
+
+                    definitions.push(Definition {
+                        place: access.place.clone(),
+                        location: Some(location),
+                        id: access.id,
+                        src: access.src.clone(),
+                    });
+                }
+            }
+        }
+
+        Self { graph, definitions, node_definitions }
+    }
+
+    /// Applies the accesses of a node up to (but excluding) the supplied access index.
+    fn apply(&self, location: NodeLocation, end: usize, fact: &mut BTreeSet<usize>) {
+        for (index, access) in self.graph.accesses(location).iter().enumerate().take(end) {
+            if access.kind != AccessKind::Write {
+                continue;
+            }
+
+            // Writes to an unknown array element may leave every other element unchanged
+            if access.place.is_exact() {
+                fact.retain(|&definition| !access.place.covers(&self.definitions[definition].place));
+            }
+
+            if let Some(&definition) = self.node_definitions.get(&(location, index)) {
+                fact.insert(definition);
+            }
+        }
+    }
+
+    /// The definitions which reach the supplied access of a node and may define the place it accesses.
+    pub fn reaching_access(&self, results: &DataFlowResults<BTreeSet<usize>>, location: NodeLocation, index: usize) -> Vec<usize> {
+        let mut fact = results.fact_before(&self.graph.control_flow_graph, self, location);
+        self.apply(location, index, &mut fact);
+
+        let Some(access) = self.graph.accesses(location).get(index) else { return vec![] };
+
+        fact.into_iter()
+            .filter(|&definition| self.definitions[definition].place.overlaps(&access.place))
+            .collect()
+    }
+
+    pub fn solve(&self) -> DataFlowResults<BTreeSet<usize>> {
+        solve_data_flow(&self.graph.control_flow_graph, self)
+    }
+}
+
+impl DataFlowAnalysis for ReachingDefinitions<'_, '_> {
+    type Fact = BTreeSet<usize>;
+
+    fn direction(&self) -> DataFlowDirection {
+        DataFlowDirection::Forward
+    }
+
+    fn bottom(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn boundary(&self) -> Self::Fact {
+        self.definitions.iter().enumerate()
+            .filter(|(_, definition)| definition.location.is_none())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn join(&self, fact: &mut Self::Fact, other: &Self::Fact) {
+        fact.extend(other.iter().copied());
+    }
+
+    fn transfer(&self, location: NodeLocation, _node: &ControlFlowNode<'_>, fact: &mut Self::Fact) {
+        self.apply(location, usize::MAX, fact);
+    }
+}
+
+/// Computes the places whose current values may be read before being overwritten.
+///
+/// The return variables and state variables are live when execution completes normally, and nothing is live when it reverts.
+/// Aliasing between memory and storage references is not modeled.
+pub struct LiveVariables<'g, 'a> {
+    graph: &'g DataFlowGraph<'a>,
+}
+
+impl LiveVariables<'_, '_> {
+    pub fn solve(&self) -> DataFlowResults<BTreeSet<Place>> {
+        solve_data_flow(&self.graph.control_flow_graph, self)
+    }
+
+    /// Determines if the value written by the supplied access of a node may be read later.
+    pub fn is_live_after(&self, results: &DataFlowResults<BTreeSet<Place>>, location: NodeLocation, index: usize) -> bool {
+        let Some(access) = self.graph.accesses(location).get(index) else { return false };
+
+        if self.graph.is_persistent(&access.place) {
+            return true;
+        }
+
+        let mut fact = results.fact_after(&self.graph.control_flow_graph, self, location);
+        let accesses = self.graph.accesses(location);
+
+        for access in accesses.iter().skip(index + 1).rev() {
+            Self::apply_access(access, &mut fact);
+        }
+
+        fact.iter().any(|place| place.overlaps(&access.place))
+    }
+
+    fn apply_access(access: &Access, fact: &mut BTreeSet<Place>) {
+        match access.kind {
+            AccessKind::Write => {
+                if access.place.is_exact() {
+                    fact.retain(|place| !access.place.covers(place));
+                }
+            }
+
+            AccessKind::Read => {
+                fact.insert(access.place.clone());
+            }
+        }
+    }
+}
+
+impl DataFlowAnalysis for LiveVariables<'_, '_> {
+    type Fact = BTreeSet<Place>;
+
+    fn direction(&self) -> DataFlowDirection {
+        DataFlowDirection::Backward
+    }
+
+    fn bottom(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn boundary(&self) -> Self::Fact {
+        self.graph.variables.iter()
+            .filter(|(id, variable_declaration)| {
+                self.graph.return_variables.contains(id) || VariableKind::of(variable_declaration) == VariableKind::Storage
+            })
+            .map(|(&id, _)| Place::new(id))
+            .collect()
+    }
+
+    fn join(&self, fact: &mut Self::Fact, other: &Self::Fact) {
+        fact.extend(other.iter().cloned());
+    }
+
+    fn transfer(&self, location: NodeLocation, _node: &ControlFlowNode<'_>, fact: &mut Self::Fact) {
+        for access in self.graph.accesses(location).iter().rev() {
+            Self::apply_access(access, fact);
+        }
+    }
+}
+
+/// A read of a place by a node of a control-flow graph.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Use {
+    pub place: Place,
+    pub location: NodeLocation,
+    pub id: NodeID,
+    pub src: String,
+}
+
+/// Links each definition to the uses it may reach, and each use to the definitions which may reach it.
+#[derive(Clone, Debug)]
+pub struct DefUseChains {
+    pub definitions: Vec<Definition>,
+    pub uses: Vec<Use>,
+    definition_uses: Vec<Vec<usize>>,
+    use_definitions: Vec<Vec<usize>>,
+}
+
+impl DefUseChains {
+    pub fn new(graph: &DataFlowGraph) -> Self {
+        let reaching_definitions = graph.reaching_definitions();
+        let results = reaching_definitions.solve();
+
+        let mut uses = vec![];
+        let mut use_definitions = vec![];
+        let mut definition_uses = vec![vec![]; reaching_definitions.definitions.len()];
+
+        for block in graph.control_flow_graph.reverse_post_order() {
+            for node in 0..graph.control_flow_graph.block(block).nodes.len() {
+                let location = NodeLocation { block, node };
+
+                for (index, access) in graph.accesses(location).iter().enumerate() {
+                    if access.kind != AccessKind::Read {
+                        continue;
+                    }
+
+                    let definitions = reaching_definitions.reaching_access(&results, location, index);
+
+                    for &definition in definitions.iter() {
+                        definition_uses[definition].push(uses.len());
+                    }
+
+                    uses.push(Use {
+                        place: access.place.clone(),
+                        location,
+                        id: access.id,
+                        src: access.src.clone(),
+                    });
+
+                    use_definitions.push(definitions);
+                }
+            }
+        }
+
+        Self {
+            definitions: reaching_definitions.definitions,
+            uses,
+            definition_uses,
+            use_definitions,
+        }
+    }
+
+    /// The indices of the uses which may read the value of the supplied definition.
+    pub fn uses_of(&self, definition: usize) -> &[usize] {
+        self.definition_uses.get(definition).map(|uses| uses.as_slice()).unwrap_or_default()
+    }
+
+    /// The indices of the definitions whose values may be read by the supplied use.
+    pub fn definitions_of(&self, use_index: usize) -> &[usize] {
+        self.use_definitions.get(use_index).map(|definitions| definitions.as_slice()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+contract Flow {
+    uint256 stored;
+
+    function f(uint256 a, bool flag) public returns (uint256 result) {
+        uint256 x = a;
+        if (flag) {
+            x = 2;
+        }
+        uint256 y = x + 1;
+        x = 3;
+        result = y;
+        stored = y;
+    }
+}
+"#;
+
+    /// Describes the node at the supplied location, or the declaration of a variable with an initial value.
+    fn describe(graph: &DataFlowGraph, location: Option<NodeLocation>, id: NodeID) -> String {
+        let Some(location) = location else { return format!("initial {}", graph.variables[&id].name) };
+
+        match &graph.control_flow_graph.block(location.block).nodes[location.node] {
+            ControlFlowNode::Statement(statement) => statement.to_string(),
+            ControlFlowNode::Condition(expression) => format!("if {expression}"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_reaching_definitions() {
+        let source_units = builder::build_test_source_units("Flow.sol", SOURCE);
+        let function_definition = source_units[0].contract_definitions()[0].function_definitions()[0];
+        let graph = DataFlowGraph::for_function_definition(source_units.as_slice(), function_definition).unwrap();
+        let chains = graph.def_use_chains();
+
+        let uses: Vec<_> = chains.uses.iter().enumerate()
+            .map(|(index, variable_use)| {
+                let definitions: Vec<_> = chains.definitions_of(index).iter()
+                    .map(|&definition| describe(&graph, chains.definitions[definition].location, chains.definitions[definition].id))
+                    .collect();
+
+                (describe(&graph, Some(variable_use.location), variable_use.id), definitions)
+            })
+            .collect();
+
+        assert_eq!(uses, vec![
+            ("uint256 x = a".to_string(), vec!["initial a".to_string()]),
+            ("if flag".to_string(), vec!["initial flag".to_string()]),
+            ("uint256 y = x + 1".to_string(), vec!["uint256 x = a".to_string(), "x = 2".to_string()]),
+            ("result = y".to_string(), vec!["uint256 y = x + 1".to_string()]),
+            ("stored = y".to_string(), vec!["uint256 y = x + 1".to_string()]),
+        ]);
+    }
+
+    #[test]
+    fn test_live_variables() {
+        let source_units = builder::build_test_source_units("Flow.sol", SOURCE);
+        let function_definition = source_units[0].contract_definitions()[0].function_definitions()[0];
+        let graph = DataFlowGraph::for_function_definition(source_units.as_slice(), function_definition).unwrap();
+        let live_variables = graph.live_variables();
+        let results = live_variables.solve();
+
+        let mut writes = vec![];
+
+        for block in graph.control_flow_graph.reverse_post_order() {
+            for node in 0..graph.control_flow_graph.block(block).nodes.len() {
+                let location = NodeLocation { block, node };
+
+                for (index, access) in graph.accesses(location).iter().enumerate() {
+                    if access.kind == AccessKind::Write {
+                        writes.push((describe(&graph, Some(location), access.id), live_variables.is_live_after(&results, location, index)));
+                    }
+                }
+            }
+        }
+
+        // `x = 3` is never read, while the return variable and the state variable outlive the function
+        assert_eq!(writes, vec![
+            ("uint256 x = a".to_string(), true),
+            ("x = 2".to_string(), true),
+            ("uint256 y = x + 1".to_string(), true),
+            ("x = 3".to_string(), false),
+            ("result = y".to_string(), true),
+            ("stored = y".to_string(), true),
+        ]);
+    }
+}
@@ -0,0 +1,331 @@
+use super::*;
+use eth_lang_utils::ast::*;
+
+/// A definition within the inheritance hierarchy of a contract, along with the contract which declares it.
+#[derive(Debug)]
+pub struct InheritedDefinition<'a, T> {
+    pub contract_definition: &'a ContractDefinition,
+    pub definition: &'a T,
+}
+
+impl<T> Clone for InheritedDefinition<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for InheritedDefinition<'_, T> {}
+
+/// A function of an effective contract, along with the base functions it overrides.
+#[derive(Clone, Debug)]
+pub struct EffectiveFunction<'a> {
+    pub contract_definition: &'a ContractDefinition,
+    pub function_definition: &'a FunctionDefinition,
+    /// The functions overridden by the function, from the most derived to the most base.
+    pub overridden_functions: Vec<InheritedDefinition<'a, FunctionDefinition>>,
+}
+
+impl EffectiveFunction<'_> {
+    /// Determines if the function is the supplied function or one of the functions it overrides.
+    pub fn contains(&self, id: NodeID) -> bool {
+        self.function_definition.id == id || self.overridden_functions.iter().any(|overridden| overridden.definition.id == id)
+    }
+}
+
+/// A modifier of an effective contract, along with the base modifiers it overrides.
+#[derive(Clone, Debug)]
+pub struct EffectiveModifier<'a> {
+    pub contract_definition: &'a ContractDefinition,
+    pub modifier_definition: &'a ModifierDefinition,
+    /// The modifiers overridden by the modifier, from the most derived to the most base.
+    pub overridden_modifiers: Vec<InheritedDefinition<'a, ModifierDefinition>>,
+}
+
+impl EffectiveModifier<'_> {
+    /// Determines if the modifier is the supplied modifier or one of the modifiers it overrides.
+    pub fn contains(&self, id: NodeID) -> bool {
+        self.modifier_definition.id == id || self.overridden_modifiers.iter().any(|overridden| overridden.definition.id == id)
+    }
+}
+
+/// The flattened view of a contract as it is deployed, combining the definitions of its linearized base contracts.
+#[derive(Clone, Debug)]
+pub struct EffectiveContract<'a> {
+    pub contract_definition: &'a ContractDefinition,
+    /// The contracts in the inheritance hierarchy, starting with the contract itself.
+    pub linearized_base_contracts: Vec<&'a ContractDefinition>,
+    /// The state variables of the hierarchy in storage order, i.e: from the most base contract to the most derived.
+    /// Constant and immutable variables are included in declaration order, although they do not occupy storage.
+    pub state_variables: Vec<InheritedDefinition<'a, VariableDeclaration>>,
+    /// The constructors of the hierarchy, from the most derived contract to the most base.
+    pub constructors: Vec<InheritedDefinition<'a, FunctionDefinition>>,
+    /// The functions of the hierarchy with their overrides resolved, starting with those of the most derived contract.
+    /// Functions implemented by public state variables are not included.
+    pub functions: Vec<EffectiveFunction<'a>>,
+    /// The modifiers of the hierarchy with their overrides resolved, starting with those of the most derived contract.
+    pub modifiers: Vec<EffectiveModifier<'a>>,
+    pub events: Vec<InheritedDefinition<'a, EventDefinition>>,
+    pub errors: Vec<InheritedDefinition<'a, ErrorDefinition>>,
+}
+
+impl<'a> EffectiveContract<'a> {
+    pub fn new(source_units: &'a [SourceUnit], contract_definition: &'a ContractDefinition) -> Self {
+        let linearized_base_contracts: Vec<&'a ContractDefinition> = match contract_definition.linearized_base_contracts.as_ref() {
+            Some(contract_ids) => contract_ids.iter()
+                .filter_map(|&contract_id| source_units.iter().find_map(|source_unit| source_unit.contract_definition(contract_id)))
+                .collect(),
+
+            None => vec![contract_definition],
+        };
+
+        let mut effective_contract = Self {
+            contract_definition,
+            linearized_base_contracts,
+            state_variables: vec![],
+            constructors: vec![],
+            functions: vec![],
+            modifiers: vec![],
+            events: vec![],
+            errors: vec![],
+        };
+
+        //
+        // State variables are laid out starting with the most base contract
+        //
+
+        for &base_contract_definition in effective_contract.linearized_base_contracts.iter().rev() {
+            for variable_declaration in base_contract_definition.variable_declarations() {
+                effective_contract.state_variables.push(InheritedDefinition {
+                    contract_definition: base_contract_definition,
+                    definition: variable_declaration,
+                });
+            }
+        }
+
+        //
+        // Functions and modifiers override the matching definitions of the contracts which follow them in the linearization
+        //
+
+        for base_contract_definition in effective_contract.linearized_base_contracts.clone() {
+            for definition_node in base_contract_definition.nodes.iter() {
+                match definition_node {
+                    ContractDefinitionNode::FunctionDefinition(function_definition) => {
+                        effective_contract.add_function_definition(base_contract_definition, function_definition);
+                    }
+
+                    ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
+                        effective_contract.add_modifier_definition(base_contract_definition, modifier_definition);
+                    }
+
+                    ContractDefinitionNode::EventDefinition(event_definition) => {
+                        effective_contract.events.push(InheritedDefinition {
+                            contract_definition: base_contract_definition,
+                            definition: event_definition,
+                        });
+                    }
+
+                    ContractDefinitionNode::ErrorDefinition(error_definition) => {
+                        effective_contract.errors.push(InheritedDefinition {
+                            contract_definition: base_contract_definition,
+                            definition: error_definition,
+                        });
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        effective_contract
+    }
+
+    fn add_function_definition(&mut self, contract_definition: &'a ContractDefinition, function_definition: &'a FunctionDefinition) {
+        if function_definition.kind == FunctionKind::Constructor {
+            self.constructors.push(InheritedDefinition { contract_definition, definition: function_definition });
+            return;
+        }
+
+        if let Some(effective_function) = self.functions.iter_mut().find(|effective_function| effective_function.function_definition.matches_signature(function_definition)) {
+            effective_function.overridden_functions.push(InheritedDefinition { contract_definition, definition: function_definition });
+            return;
+        }
+
+        // Public state variables can implement external functions of base contracts
+        let position = self.hierarchy_position(contract_definition.id);
+
+        let is_implemented_by_getter = self.state_variables.iter().any(|state_variable| {
+            state_variable.definition.overrides.is_some()
+                && state_variable.definition.name == function_definition.name
+                && state_variable.definition.getter_parameter_type_strings() == function_definition.parameter_type_strings()
+                && self.hierarchy_position(state_variable.contract_definition.id) < position
+        });
+
+        if is_implemented_by_getter {
+            return;
+        }
+
+        self.functions.push(EffectiveFunction {
+            contract_definition,
+            function_definition,
+            overridden_functions: vec![],
+        });
+    }
+
+    fn add_modifier_definition(&mut self, contract_definition: &'a ContractDefinition, modifier_definition: &'a ModifierDefinition) {
+        if let Some(effective_modifier) = self.modifiers.iter_mut().find(|effective_modifier| effective_modifier.modifier_definition.name == modifier_definition.name) {
+            effective_modifier.overridden_modifiers.push(InheritedDefinition { contract_definition, definition: modifier_definition });
+            return;
+        }
+
+        self.modifiers.push(EffectiveModifier {
+            contract_definition,
+            modifier_definition,
+            overridden_modifiers: vec![],
+        });
+    }
+
+    /// The position of the supplied contract within the linearized inheritance hierarchy, where 0 is the contract itself.
+    pub fn hierarchy_position(&self, contract_id: NodeID) -> Option<usize> {
+        self.linearized_base_contracts.iter().position(|contract_definition| contract_definition.id == contract_id)
+    }
+
+    /// Determines if the supplied contract is part of the inheritance hierarchy.
+    pub fn inherits_from(&self, contract_id: NodeID) -> bool {
+        self.hierarchy_position(contract_id).is_some()
+    }
+
+    pub fn state_variable(&self, id: NodeID) -> Option<&InheritedDefinition<'a, VariableDeclaration>> {
+        self.state_variables.iter().find(|state_variable| state_variable.definition.id == id)
+    }
+
+    pub fn contains_state_variable(&self, id: NodeID) -> bool {
+        self.state_variable(id).is_some()
+    }
+
+    /// The effective function which is executed in place of the supplied function, i.e: its most derived override.
+    pub fn function(&self, id: NodeID) -> Option<&EffectiveFunction<'a>> {
+        self.functions.iter().find(|effective_function| effective_function.contains(id))
+    }
+
+    /// The effective modifier which is executed in place of the supplied modifier, i.e: its most derived override.
+    pub fn modifier(&self, id: NodeID) -> Option<&EffectiveModifier<'a>> {
+        self.modifiers.iter().find(|effective_modifier| effective_modifier.contains(id))
+    }
+
+    /// Iterates over the functions which can be called from outside the contract.
+    pub fn external_functions(&self) -> impl Iterator<Item = &EffectiveFunction<'a>> {
+        self.functions.iter().filter(|effective_function| {
+            matches!(effective_function.function_definition.visibility, Visibility::Public | Visibility::External)
+        })
+    }
+
+    /// The modifiers applied to the supplied function, resolved to their most derived overrides.
+    /// Base constructor specifiers are not included.
+    pub fn function_modifiers(&self, function_definition: &FunctionDefinition) -> Vec<&EffectiveModifier<'a>> {
+        function_definition.modifiers.iter()
+            .filter_map(|modifier_invocation| modifier_invocation.modifier_name.referenced_declaration)
+            .filter_map(|id| self.modifier(id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+interface IVault {
+    function balanceOf(address account) external view returns (uint256);
+    function deposit() external payable;
+}
+
+abstract contract Guarded {
+    address owner;
+
+    event Deposited(address account, uint256 amount);
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    modifier guarded() virtual {
+        require(msg.sender == owner);
+        _;
+    }
+
+    function deposit() public payable virtual;
+}
+
+contract Vault is IVault, Guarded {
+    mapping(address => uint256) public override balanceOf;
+    uint256 constant LIMIT = 10;
+
+    constructor() {}
+
+    modifier guarded() override {
+        _;
+    }
+
+    function deposit() public payable override(IVault, Guarded) guarded {
+        balanceOf[msg.sender] += msg.value;
+        emit Deposited(msg.sender, msg.value);
+    }
+
+    function sweep() internal {}
+}
+"#;
+
+    /// Names an inherited definition after the contract containing it.
+    fn name(contract_definition: &ContractDefinition, name: &str) -> String {
+        format!("{}.{}", contract_definition.name, name)
+    }
+
+    #[test]
+    fn test_effective_contract() {
+        let source_units = builder::build_test_source_units("Vault.sol", SOURCE);
+        let vault = source_units[0].contract_definitions()[2];
+        let effective_contract = vault.effective_contract(source_units.as_slice());
+
+        let linearized_base_contracts: Vec<_> = effective_contract.linearized_base_contracts.iter()
+            .map(|contract_definition| contract_definition.name.as_str())
+            .collect();
+
+        assert_eq!(linearized_base_contracts, vec!["Vault", "Guarded", "IVault"]);
+
+        let state_variables: Vec<_> = effective_contract.state_variables.iter()
+            .map(|state_variable| name(state_variable.contract_definition, &state_variable.definition.name))
+            .collect();
+
+        assert_eq!(state_variables, vec!["Guarded.owner", "Vault.balanceOf", "Vault.LIMIT"]);
+
+        let constructors: Vec<_> = effective_contract.constructors.iter()
+            .map(|constructor| constructor.contract_definition.name.as_str())
+            .collect();
+
+        assert_eq!(constructors, vec!["Vault", "Guarded"]);
+
+        // `IVault.balanceOf` is implemented by the getter of the public state variable
+        let functions: Vec<_> = effective_contract.functions.iter()
+            .map(|function| (
+                name(function.contract_definition, &function.function_definition.name),
+                function.overridden_functions.iter()
+                    .map(|overridden| name(overridden.contract_definition, &overridden.definition.name))
+                    .collect::<Vec<_>>(),
+            ))
+            .collect();
+
+        assert_eq!(functions, vec![
+            ("Vault.deposit".to_string(), vec!["Guarded.deposit".to_string(), "IVault.deposit".to_string()]),
+            ("Vault.sweep".to_string(), vec![]),
+        ]);
+
+        // The modifier applied to `Vault.deposit` resolves to the most derived override
+        let deposit = effective_contract.functions[0].function_definition;
+        let modifiers = effective_contract.function_modifiers(deposit);
+        assert_eq!(modifiers.len(), 1);
+        assert_eq!(modifiers[0].contract_definition.name, "Vault");
+        assert_eq!(modifiers[0].overridden_modifiers[0].contract_definition.name, "Guarded");
+
+    }
+}
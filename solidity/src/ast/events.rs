@@ -8,6 +8,7 @@ use std::fmt::Display;
 pub struct EventDefinition {
     pub anonymous: bool,
     pub documentation: Option<Documentation>,
+    pub event_selector: Option<String>,
     pub name: String,
     pub name_location: Option<String>,
     pub parameters: ParameterList,
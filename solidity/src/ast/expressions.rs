@@ -1,6 +1,6 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Serialize};
 use std::fmt::{Display, Write};
 
 #[derive(Clone, Debug, Eq, Serialize, PartialEq)]
@@ -25,24 +25,24 @@ pub enum Expression {
 impl<'de> Deserialize<'de> for Expression {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let json = serde_json::Value::deserialize(deserializer)?;
-        let node_type = json.get("nodeType").unwrap().as_str().unwrap();
-
-        match node_type {
-            "Literal" => Ok(Expression::Literal(serde_json::from_value(json).unwrap())),
-            "Identifier" => Ok(Expression::Identifier(serde_json::from_value(json).unwrap())),
-            "UnaryOperation" => Ok(Expression::UnaryOperation(serde_json::from_value(json).unwrap())),
-            "BinaryOperation" => Ok(Expression::BinaryOperation(serde_json::from_value(json).unwrap())),
-            "Conditional" => Ok(Expression::Conditional(serde_json::from_value(json).unwrap())),
-            "Assignment" => Ok(Expression::Assignment(serde_json::from_value(json).unwrap())),
-            "FunctionCall" => Ok(Expression::FunctionCall(serde_json::from_value(json).unwrap())),
-            "FunctionCallOptions" => Ok(Expression::FunctionCallOptions(serde_json::from_value(json).unwrap())),
-            "IndexAccess" => Ok(Expression::IndexAccess(serde_json::from_value(json).unwrap())),
-            "IndexRangeAccess" => Ok(Expression::IndexRangeAccess(serde_json::from_value(json).unwrap())),
-            "MemberAccess" => Ok(Expression::MemberAccess(serde_json::from_value(json).unwrap())),
-            "ElementaryTypeNameExpression" => Ok(Expression::ElementaryTypeNameExpression(serde_json::from_value(json).unwrap())),
-            "TupleExpression" => Ok(Expression::TupleExpression(serde_json::from_value(json).unwrap())),
-            "NewExpression" => Ok(Expression::NewExpression(serde_json::from_value(json).unwrap())),
-            _ => panic!("Invalid expression node type: {node_type:?}"),
+        let node_type = node_type::<D>(&json)?;
+
+        match node_type.as_str() {
+            "Literal" => Ok(Expression::Literal(from_value::<D, _>(json)?)),
+            "Identifier" => Ok(Expression::Identifier(from_value::<D, _>(json)?)),
+            "UnaryOperation" => Ok(Expression::UnaryOperation(from_value::<D, _>(json)?)),
+            "BinaryOperation" => Ok(Expression::BinaryOperation(from_value::<D, _>(json)?)),
+            "Conditional" => Ok(Expression::Conditional(from_value::<D, _>(json)?)),
+            "Assignment" => Ok(Expression::Assignment(from_value::<D, _>(json)?)),
+            "FunctionCall" => Ok(Expression::FunctionCall(from_value::<D, _>(json)?)),
+            "FunctionCallOptions" => Ok(Expression::FunctionCallOptions(from_value::<D, _>(json)?)),
+            "IndexAccess" => Ok(Expression::IndexAccess(from_value::<D, _>(json)?)),
+            "IndexRangeAccess" => Ok(Expression::IndexRangeAccess(from_value::<D, _>(json)?)),
+            "MemberAccess" => Ok(Expression::MemberAccess(from_value::<D, _>(json)?)),
+            "ElementaryTypeNameExpression" => Ok(Expression::ElementaryTypeNameExpression(from_value::<D, _>(json)?)),
+            "TupleExpression" => Ok(Expression::TupleExpression(from_value::<D, _>(json)?)),
+            "NewExpression" => Ok(Expression::NewExpression(from_value::<D, _>(json)?)),
+            _ => Err(D::Error::custom(format!("Invalid expression node type: {node_type:?}"))),
         }
     }
 }
@@ -145,7 +145,15 @@ impl Expression {
     }
 
     pub fn source_line(&self, source_unit: &SourceUnit) -> std::io::Result<usize> {
-        source_unit.source_line(match self {
+        source_unit.source_line(self.src())
+    }
+
+    pub fn source_range(&self, source_unit: &SourceUnit) -> std::io::Result<SourceRange> {
+        source_unit.source_range(self.src())
+    }
+
+    pub fn src(&self) -> &str {
+        match self {
             Expression::Literal(Literal { src, .. }) => src.as_str(),
             Expression::Identifier(Identifier { src, .. }) => src.as_str(),
             Expression::UnaryOperation(UnaryOperation { src, .. }) => src.as_str(),
@@ -160,7 +168,26 @@ impl Expression {
             Expression::ElementaryTypeNameExpression(ElementaryTypeNameExpression { src, .. }) => src.as_str(),
             Expression::TupleExpression(TupleExpression { src, .. }) => src.as_str(),
             Expression::NewExpression(NewExpression { src, .. }) => src.as_str(),
-        })
+        }
+    }
+
+    pub fn id(&self) -> NodeID {
+        match self {
+            Expression::Literal(Literal { id, .. }) => *id,
+            Expression::Identifier(Identifier { id, .. }) => *id,
+            Expression::UnaryOperation(UnaryOperation { id, .. }) => *id,
+            Expression::BinaryOperation(BinaryOperation { id, .. }) => *id,
+            Expression::Conditional(Conditional { id, .. }) => *id,
+            Expression::Assignment(Assignment { id, .. }) => *id,
+            Expression::FunctionCall(FunctionCall { id, .. }) => *id,
+            Expression::FunctionCallOptions(FunctionCallOptions { id, .. }) => *id,
+            Expression::IndexAccess(IndexAccess { id, .. }) => *id,
+            Expression::IndexRangeAccess(IndexRangeAccess { id, .. }) => *id,
+            Expression::MemberAccess(MemberAccess { id, .. }) => *id,
+            Expression::ElementaryTypeNameExpression(ElementaryTypeNameExpression { id, .. }) => *id,
+            Expression::TupleExpression(TupleExpression { id, .. }) => *id,
+            Expression::NewExpression(NewExpression { id, .. }) => *id,
+        }
     }
 }
 
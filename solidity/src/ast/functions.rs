@@ -26,6 +26,25 @@ pub struct ParameterList {
     pub id: NodeID,
 }
 
+impl ParameterList {
+    /// The types of the parameters without their data locations, i.e: `uint256[]` for `uint256[] calldata`.
+    pub fn type_strings(&self) -> Vec<String> {
+        self.parameters.iter()
+            .map(|parameter| {
+                let type_string = match parameter.type_descriptions.type_string.as_ref() {
+                    Some(type_string) => type_string.clone(),
+                    None => parameter.type_name.as_ref().map(|type_name| type_name.to_string()).unwrap_or_default(),
+                };
+
+                [" calldata", " memory", " storage pointer", " storage ref", " storage"].iter()
+                    .find_map(|location| type_string.strip_suffix(location))
+                    .map(str::to_string)
+                    .unwrap_or(type_string)
+            })
+            .collect()
+    }
+}
+
 impl Display for ParameterList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("(")?;
@@ -98,6 +117,20 @@ pub struct FunctionDefinition {
 }
 
 impl FunctionDefinition {
+    /// The types of the function's parameters without their data locations, i.e: `uint256[]` for `uint256[] calldata`.
+    pub fn parameter_type_strings(&self) -> Vec<String> {
+        self.parameters.type_strings()
+    }
+
+    /// Determines if the function can override (or be overridden by) the supplied function, i.e: it has the same
+    /// kind, name and parameter types. Data locations are ignored, since public functions can override external functions.
+    pub fn matches_signature(&self, other: &FunctionDefinition) -> bool {
+        self.kind == other.kind
+            && self.name == other.name
+            && self.parameters.parameters.len() == other.parameters.parameters.len()
+            && self.parameter_type_strings() == other.parameter_type_strings()
+    }
+
     pub fn get_assigned_return_variables(
         &self,
         expression: &Expression,
@@ -0,0 +1,840 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+};
+
+/// An error in the inheritance hierarchy of a contract which solc would reject.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InheritanceError {
+    /// A base contract does not reference a known contract.
+    BaseContractNotFound {
+        path: Option<String>,
+        contract_name: String,
+        base_name: String,
+        src: String,
+    },
+    /// A contract inherits from itself, either directly or through one of its base contracts.
+    CyclicInheritance {
+        path: Option<String>,
+        contract_name: String,
+        src: String,
+    },
+    /// The order of the base contracts is inconsistent with the order of their own base contracts.
+    LinearizationImpossible {
+        path: Option<String>,
+        contract_name: String,
+        src: String,
+    },
+}
+
+impl Display for InheritanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = |path: &Option<String>, src: &str| match path {
+            Some(path) => format!("{path} ({src})"),
+            None => src.to_string(),
+        };
+
+        match self {
+            InheritanceError::BaseContractNotFound { path, contract_name, base_name, src } => write!(
+                f,
+                "Base contract `{base_name}` of contract `{contract_name}` not found in {}",
+                location(path, src),
+            ),
+
+            InheritanceError::CyclicInheritance { path, contract_name, src } => write!(
+                f,
+                "Contract `{contract_name}` inherits from itself in {}",
+                location(path, src),
+            ),
+
+            InheritanceError::LinearizationImpossible { path, contract_name, src } => write!(
+                f,
+                "Linearization of inheritance graph impossible for contract `{contract_name}` in {}",
+                location(path, src),
+            ),
+        }
+    }
+}
+
+impl Error for InheritanceError {}
+
+/// The reason the inheritance hierarchy of a contract could not be linearized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinearizationFailure {
+    Cycle,
+    Impossible,
+}
+
+/// Computes the C3 linearization of a contract's inheritance hierarchy, starting with the contract itself.
+///
+/// The base contracts of each contract are listed in the order they are declared, i.e: from the most base-like
+/// to the most derived. This matches solc, which uses the linearization to resolve `super` and overrides.
+///
+/// The linearizations of the contract and all of its base contracts are cached in `linearized`, so each contract
+/// of a hierarchy is only linearized once.
+pub fn linearize_contract(
+    contract_id: NodeID,
+    base_contracts: &HashMap<NodeID, Vec<NodeID>>,
+    linearized: &mut HashMap<NodeID, Vec<NodeID>>,
+) -> Result<Vec<NodeID>, LinearizationFailure> {
+    fn linearize(
+        contract_id: NodeID,
+        base_contracts: &HashMap<NodeID, Vec<NodeID>>,
+        linearized: &mut HashMap<NodeID, Vec<NodeID>>,
+        visiting: &mut HashSet<NodeID>,
+    ) -> Result<Vec<NodeID>, LinearizationFailure> {
+        if let Some(result) = linearized.get(&contract_id) {
+            return Ok(result.clone());
+        }
+
+        if !visiting.insert(contract_id) {
+            return Err(LinearizationFailure::Cycle);
+        }
+
+        let direct_base_contracts = base_contracts.get(&contract_id).cloned().unwrap_or_default();
+
+        //
+        // Merge the linearizations of the base contracts, starting with the most derived one
+        //
+
+        let mut sequences = vec![];
+
+        for &base_contract in direct_base_contracts.iter().rev() {
+            sequences.push(linearize(base_contract, base_contracts, linearized, visiting)?);
+        }
+
+        sequences.push(direct_base_contracts.iter().rev().copied().collect());
+
+        visiting.remove(&contract_id);
+
+        let mut result = vec![contract_id];
+
+        loop {
+            sequences.retain(|sequence: &Vec<NodeID>| !sequence.is_empty());
+
+            if sequences.is_empty() {
+                break;
+            }
+
+            // The next contract must not appear in the tail of any of the remaining sequences
+            let next = sequences.iter()
+                .map(|sequence| sequence[0])
+                .find(|&candidate| !sequences.iter().any(|sequence| sequence[1..].contains(&candidate)))
+                .ok_or(LinearizationFailure::Impossible)?;
+
+            result.push(next);
+
+            for sequence in sequences.iter_mut() {
+                sequence.retain(|&id| id != next);
+            }
+        }
+
+        linearized.insert(contract_id, result.clone());
+
+        Ok(result)
+    }
+
+    linearize(contract_id, base_contracts, linearized, &mut HashSet::new())
+}
+
+/// The declarations referenced by the body of a function, modifier or state variable.
+#[derive(Default)]
+struct References {
+    declarations: BTreeSet<NodeID>,
+    created_contracts: BTreeSet<NodeID>,
+    internal_functions: BTreeSet<NodeID>,
+}
+
+/// The information about a declaration which is needed to compute the metadata of the contracts using it.
+enum Declaration {
+    Contract {
+        name: String,
+        path: Option<String>,
+        src: String,
+        base_contracts: Vec<NodeID>,
+        members: Vec<NodeID>,
+    },
+    Function {
+        signature: String,
+        implemented: bool,
+        visibility: Visibility,
+        references: References,
+    },
+    Modifier {
+        references: References,
+    },
+    Variable {
+        getter_signature: String,
+        visibility: Visibility,
+        references: References,
+    },
+    Event,
+    Error,
+}
+
+/// Computes the metadata of contracts which solc derives from their inheritance hierarchy and contents.
+///
+/// This fills in `linearized_base_contracts`, `fully_implemented`, `contract_dependencies`, `used_events`,
+/// `used_errors` and `internal_function_ids`. References must be resolved and types checked beforehand.
+#[derive(Default)]
+pub struct ContractMetadataBuilder {
+    declarations: HashMap<NodeID, Declaration>,
+    linearized_base_contracts: HashMap<NodeID, Vec<NodeID>>,
+}
+
+impl ContractMetadataBuilder {
+    pub fn new(source_units: &[SourceUnit]) -> Result<Self, InheritanceError> {
+        let mut builder = Self::default();
+
+        for source_unit in source_units.iter() {
+            for node in source_unit.nodes.iter() {
+                match node {
+                    SourceUnitNode::ContractDefinition(contract_definition) => {
+                        builder.add_contract_definition(source_unit, contract_definition)?;
+                    }
+
+                    SourceUnitNode::ErrorDefinition(error_definition) => {
+                        builder.declarations.insert(error_definition.id, Declaration::Error);
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        //
+        // Linearize the inheritance hierarchy of each contract
+        //
+
+        let base_contracts: HashMap<NodeID, Vec<NodeID>> = builder.declarations.iter()
+            .filter_map(|(&id, declaration)| match declaration {
+                Declaration::Contract { base_contracts, .. } => Some((id, base_contracts.clone())),
+                _ => None,
+            })
+            .collect();
+
+        let mut contract_ids: Vec<NodeID> = base_contracts.keys().copied().collect();
+        contract_ids.sort_unstable();
+
+        for contract_id in contract_ids {
+            linearize_contract(contract_id, &base_contracts, &mut builder.linearized_base_contracts).map_err(|failure| {
+                let Some(Declaration::Contract { name, path, src, .. }) = builder.declarations.get(&contract_id) else { unreachable!() };
+
+                match failure {
+                    LinearizationFailure::Cycle => InheritanceError::CyclicInheritance {
+                        path: path.clone(),
+                        contract_name: name.clone(),
+                        src: src.clone(),
+                    },
+
+                    LinearizationFailure::Impossible => InheritanceError::LinearizationImpossible {
+                        path: path.clone(),
+                        contract_name: name.clone(),
+                        src: src.clone(),
+                    },
+                }
+            })?;
+        }
+
+        Ok(builder)
+    }
+
+    /// The C3 linearization of the supplied contract's inheritance hierarchy, starting with the contract itself.
+    pub fn linearized_base_contracts(&self, contract_id: NodeID) -> Option<&Vec<NodeID>> {
+        self.linearized_base_contracts.get(&contract_id)
+    }
+
+    /// Fills in the metadata of the contracts within the supplied source unit, which must have been supplied to `ContractMetadataBuilder::new`.
+    pub fn update_source_unit(&self, source_unit: &mut SourceUnit) {
+        for node in source_unit.nodes.iter_mut() {
+            if let SourceUnitNode::ContractDefinition(contract_definition) = node {
+                self.update_contract_definition(contract_definition);
+            }
+        }
+    }
+
+    //
+    // Declaration collection
+    //
+
+    fn add_contract_definition(&mut self, source_unit: &SourceUnit, contract_definition: &ContractDefinition) -> Result<(), InheritanceError> {
+        let mut base_contracts = vec![];
+
+        for base_contract in contract_definition.base_contracts.iter() {
+            match base_contract.base_name.referenced_declaration {
+                Some(id) if id >= 0 => base_contracts.push(id),
+
+                _ => return Err(InheritanceError::BaseContractNotFound {
+                    path: source_unit.absolute_path.clone(),
+                    contract_name: contract_definition.name.clone(),
+                    base_name: base_contract.base_name.name.clone(),
+                    src: base_contract.src.clone(),
+                }),
+            }
+        }
+
+        let mut members = vec![];
+
+        for node in contract_definition.nodes.iter() {
+            let (id, declaration) = match node {
+                ContractDefinitionNode::FunctionDefinition(function_definition) => {
+                    let mut references = References::default();
+
+                    for modifier in function_definition.modifiers.iter() {
+                        references.add_modifier_invocation(modifier);
+                    }
+
+                    if let Some(body) = function_definition.body.as_ref() {
+                        references.add_block(body);
+                    }
+
+                    (function_definition.id, Declaration::Function {
+                        signature: Self::function_signature(function_definition),
+                        implemented: function_definition.body.is_some(),
+                        visibility: function_definition.visibility,
+                        references,
+                    })
+                }
+
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
+                    let mut references = References::default();
+                    references.add_block(&modifier_definition.body);
+
+                    (modifier_definition.id, Declaration::Modifier { references })
+                }
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => {
+                    let mut references = References::default();
+
+                    if let Some(value) = variable_declaration.value.as_ref() {
+                        references.add_expression(value, false);
+                    }
+
+                    (variable_declaration.id, Declaration::Variable {
+                        getter_signature: format!("{}({})", variable_declaration.name, variable_declaration.getter_parameter_type_strings().join(",")),
+                        visibility: variable_declaration.visibility,
+                        references,
+                    })
+                }
+
+                ContractDefinitionNode::EventDefinition(event_definition) => (event_definition.id, Declaration::Event),
+                ContractDefinitionNode::ErrorDefinition(error_definition) => (error_definition.id, Declaration::Error),
+
+                _ => continue,
+            };
+
+            members.push(id);
+            self.declarations.insert(id, declaration);
+        }
+
+        self.declarations.insert(contract_definition.id, Declaration::Contract {
+            name: contract_definition.name.clone(),
+            path: source_unit.absolute_path.clone(),
+            src: contract_definition.src.clone(),
+            base_contracts,
+            members,
+        });
+
+        Ok(())
+    }
+
+    /// The name and parameter types of a function, which are the same for a function and the functions it overrides.
+    fn function_signature(function_definition: &FunctionDefinition) -> String {
+        let name = match function_definition.kind {
+            FunctionKind::Constructor => "constructor",
+            FunctionKind::Receive => "receive",
+            FunctionKind::Fallback => "fallback",
+            FunctionKind::Function => function_definition.name.as_str(),
+        };
+
+        // Data locations are ignored, since public functions can override external functions
+        format!("{name}({})", function_definition.parameter_type_strings().join(","))
+    }
+
+    //
+    // Metadata
+    //
+
+    fn update_contract_definition(&self, contract_definition: &mut ContractDefinition) {
+        let Some(linearized_base_contracts) = self.linearized_base_contracts.get(&contract_definition.id) else { return };
+
+        //
+        // Determine whether every function in the hierarchy has an implementation, starting with the most base contract
+        //
+
+        let mut implemented_functions: HashMap<&str, bool> = HashMap::new();
+
+        for contract_id in linearized_base_contracts.iter().rev() {
+            let Some(Declaration::Contract { members, .. }) = self.declarations.get(contract_id) else { continue };
+
+            for member_id in members.iter() {
+                match self.declarations.get(member_id) {
+                    Some(Declaration::Function { signature, implemented, .. }) => {
+                        implemented_functions.insert(signature.as_str(), *implemented);
+                    }
+
+                    // Public state variables implement the functions their getters override
+                    Some(Declaration::Variable { getter_signature, visibility: Visibility::Public, .. }) => {
+                        if let Some(implemented) = implemented_functions.get_mut(getter_signature.as_str()) {
+                            *implemented = true;
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        //
+        // Collect the references of every member in the hierarchy, along with the library functions they use
+        //
+
+        let mut pending: Vec<NodeID> = linearized_base_contracts.iter()
+            .filter_map(|contract_id| match self.declarations.get(contract_id) {
+                Some(Declaration::Contract { members, .. }) => Some(members),
+                _ => None,
+            })
+            .flatten()
+            .copied()
+            .collect();
+
+        let mut visited = HashSet::new();
+        let mut used_events = BTreeSet::new();
+        let mut used_errors = BTreeSet::new();
+        let mut contract_dependencies = BTreeSet::new();
+        let mut internal_functions = BTreeSet::new();
+
+        while let Some(id) = pending.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+
+            let references = match self.declarations.get(&id) {
+                Some(Declaration::Function { references, .. })
+                | Some(Declaration::Modifier { references })
+                | Some(Declaration::Variable { references, .. }) => references,
+
+                _ => continue,
+            };
+
+            for &referenced_id in references.declarations.iter() {
+                match self.declarations.get(&referenced_id) {
+                    Some(Declaration::Event) => {
+                        used_events.insert(referenced_id);
+                    }
+
+                    Some(Declaration::Error) => {
+                        used_errors.insert(referenced_id);
+                    }
+
+                    // Functions outside of the hierarchy (i.e: library functions) are included in the contract's code
+                    Some(Declaration::Function { .. } | Declaration::Modifier { .. }) => {
+                        pending.push(referenced_id);
+                    }
+
+                    _ => {}
+                }
+            }
+
+            contract_dependencies.extend(references.created_contracts.iter().copied());
+
+            internal_functions.extend(
+                references.internal_functions.iter()
+                    .copied()
+                    .filter(|function_id| {
+                        matches!(self.declarations.get(function_id), Some(Declaration::Function { visibility, .. }) if *visibility != Visibility::External)
+                    })
+            );
+        }
+
+        //
+        // Include the contracts created by the contracts this contract creates
+        //
+
+        let mut pending: Vec<NodeID> = contract_dependencies.iter().copied().collect();
+
+        while let Some(contract_id) = pending.pop() {
+            for dependency in self.created_contracts(contract_id) {
+                if contract_dependencies.insert(dependency) {
+                    pending.push(dependency);
+                }
+            }
+        }
+
+        contract_dependencies.remove(&contract_definition.id);
+
+        contract_definition.linearized_base_contracts = Some(linearized_base_contracts.clone());
+        contract_definition.fully_implemented = Some(implemented_functions.values().all(|&implemented| implemented));
+        contract_definition.contract_dependencies = contract_dependencies.into_iter().collect();
+        contract_definition.used_events = Some(used_events.into_iter().collect());
+        contract_definition.used_errors = Some(used_errors.into_iter().collect());
+
+        contract_definition.internal_function_ids = if internal_functions.is_empty() {
+            None
+        } else {
+            Some(
+                internal_functions.into_iter()
+                    .enumerate()
+                    .map(|(index, function_id)| (function_id.to_string(), index as NodeID + 1))
+                    .collect()
+            )
+        };
+    }
+
+    /// The contracts created directly by the members of the supplied contract's inheritance hierarchy.
+    fn created_contracts(&self, contract_id: NodeID) -> BTreeSet<NodeID> {
+        let mut result = BTreeSet::new();
+
+        for base_contract_id in self.linearized_base_contracts.get(&contract_id).into_iter().flatten() {
+            let Some(Declaration::Contract { members, .. }) = self.declarations.get(base_contract_id) else { continue };
+
+            for member_id in members.iter() {
+                match self.declarations.get(member_id) {
+                    Some(Declaration::Function { references, .. })
+                    | Some(Declaration::Modifier { references })
+                    | Some(Declaration::Variable { references, .. }) => {
+                        result.extend(references.created_contracts.iter().copied());
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl References {
+    fn add_modifier_invocation(&mut self, modifier_invocation: &ModifierInvocation) {
+        if let Some(id) = modifier_invocation.modifier_name.referenced_declaration {
+            self.declarations.insert(id);
+        }
+
+        for argument in modifier_invocation.arguments.iter().flatten() {
+            self.add_expression(argument, false);
+        }
+    }
+
+    fn add_block(&mut self, block: &Block) {
+        for statement in block.statements.iter() {
+            self.add_statement(statement);
+        }
+    }
+
+    fn add_block_or_statement(&mut self, block_or_statement: &BlockOrStatement) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.add_block(block),
+            BlockOrStatement::Statement(statement) => self.add_statement(statement),
+        }
+    }
+
+    fn add_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                if let Some(initial_value) = variable_declaration_statement.initial_value.as_ref() {
+                    self.add_expression(initial_value, false);
+                }
+            }
+
+            Statement::IfStatement(if_statement) => {
+                self.add_expression(&if_statement.condition, false);
+                self.add_block_or_statement(&if_statement.true_body);
+
+                if let Some(false_body) = if_statement.false_body.as_ref() {
+                    self.add_block_or_statement(false_body);
+                }
+            }
+
+            Statement::ForStatement(for_statement) => {
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_ref() {
+                    self.add_statement(initialization_expression);
+                }
+
+                if let Some(condition) = for_statement.condition.as_ref() {
+                    self.add_expression(condition, false);
+                }
+
+                if let Some(loop_expression) = for_statement.loop_expression.as_ref() {
+                    self.add_statement(loop_expression);
+                }
+
+                self.add_block_or_statement(&for_statement.body);
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                self.add_expression(&while_statement.condition, false);
+                self.add_block_or_statement(&while_statement.body);
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                self.add_block_or_statement(&do_while_statement.body);
+                self.add_expression(&do_while_statement.condition, false);
+            }
+
+            Statement::EmitStatement(emit_statement) => {
+                self.add_expression(&emit_statement.event_call, false);
+            }
+
+            Statement::TryStatement(try_statement) => {
+                self.add_function_call(&try_statement.external_call);
+
+                for clause in try_statement.clauses.iter() {
+                    self.add_block(&clause.block);
+                }
+            }
+
+            Statement::UncheckedBlock(block) | Statement::Block(block) => {
+                self.add_block(block);
+            }
+
+            Statement::Return(return_statement) => {
+                if let Some(expression) = return_statement.expression.as_ref() {
+                    self.add_expression(expression, false);
+                }
+            }
+
+            Statement::RevertStatement(revert_statement) => {
+                self.add_function_call(&revert_statement.error_call);
+            }
+
+            Statement::ExpressionStatement(expression_statement) => {
+                self.add_expression(&expression_statement.expression, false);
+            }
+
+            Statement::InlineAssembly(_)
+            | Statement::Continue { .. }
+            | Statement::Break { .. }
+            | Statement::PlaceholderStatement { .. } => {}
+        }
+    }
+
+    fn add_function_call(&mut self, function_call: &FunctionCall) {
+        for argument in function_call.arguments.iter() {
+            self.add_expression(argument, false);
+        }
+
+        self.add_expression(function_call.expression.as_ref(), true);
+    }
+
+    /// Collects the references within an expression. Functions which are referenced without being called
+    /// (i.e: assigned to a variable of a function type) are recorded as internal function pointers.
+    fn add_expression(&mut self, expression: &Expression, called: bool) {
+        match expression {
+            Expression::Literal(_) => {}
+
+            Expression::Identifier(identifier) => {
+                if identifier.referenced_declaration >= 0 {
+                    self.declarations.insert(identifier.referenced_declaration);
+
+                    if !called {
+                        self.internal_functions.insert(identifier.referenced_declaration);
+                    }
+                }
+            }
+
+            Expression::UnaryOperation(unary_operation) => {
+                self.add_expression(unary_operation.sub_expression.as_ref(), false);
+            }
+
+            Expression::BinaryOperation(binary_operation) => {
+                self.add_expression(binary_operation.left_expression.as_ref(), false);
+                self.add_expression(binary_operation.right_expression.as_ref(), false);
+            }
+
+            Expression::Conditional(conditional) => {
+                self.add_expression(conditional.condition.as_ref(), false);
+                self.add_expression(conditional.true_expression.as_ref(), false);
+                self.add_expression(conditional.false_expression.as_ref(), false);
+            }
+
+            Expression::Assignment(assignment) => {
+                self.add_expression(assignment.left_hand_side.as_ref(), false);
+                self.add_expression(assignment.right_hand_side.as_ref(), false);
+            }
+
+            Expression::FunctionCall(function_call) => {
+                self.add_function_call(function_call);
+            }
+
+            Expression::FunctionCallOptions(function_call_options) => {
+                for option in function_call_options.options.iter() {
+                    self.add_expression(option, false);
+                }
+
+                self.add_expression(function_call_options.expression.as_ref(), called);
+            }
+
+            Expression::IndexAccess(index_access) => {
+                self.add_expression(index_access.base_expression.as_ref(), false);
+
+                if let Some(index_expression) = index_access.index_expression.as_ref() {
+                    self.add_expression(index_expression.as_ref(), false);
+                }
+            }
+
+            Expression::IndexRangeAccess(index_range_access) => {
+                self.add_expression(index_range_access.base_expression.as_ref(), false);
+
+                if let Some(start_expression) = index_range_access.start_expression.as_ref() {
+                    self.add_expression(start_expression.as_ref(), false);
+                }
+
+                if let Some(end_expression) = index_range_access.end_expression.as_ref() {
+                    self.add_expression(end_expression.as_ref(), false);
+                }
+            }
+
+            Expression::MemberAccess(member_access) => {
+                let base_expression = member_access.expression.as_ref();
+
+                // The creation and runtime code of a contract are included in the bytecode (i.e: `type(C).creationCode`)
+                if matches!(member_access.member_name.as_str(), "creationCode" | "runtimeCode") {
+                    if let Expression::FunctionCall(function_call) = base_expression {
+                        if let Some(Expression::Identifier(identifier)) = function_call.arguments.first() {
+                            self.created_contracts.insert(identifier.referenced_declaration);
+                        }
+                    }
+                }
+
+                // The selector and address of a function do not use it as a value
+                self.add_expression(base_expression, matches!(member_access.member_name.as_str(), "selector" | "address"));
+
+                let Some(id) = member_access.referenced_declaration else { return };
+                self.declarations.insert(id);
+
+                // Only functions referenced through `super` or a contract name are internal function pointers
+                let internal = match base_expression {
+                    Expression::Identifier(identifier) => identifier.name == "super" || identifier.referenced_declaration >= 0,
+                    _ => false,
+                };
+
+                if internal && !called {
+                    self.internal_functions.insert(id);
+                }
+            }
+
+            Expression::ElementaryTypeNameExpression(_) => {}
+
+            Expression::TupleExpression(tuple_expression) => {
+                for component in tuple_expression.components.iter().flatten() {
+                    self.add_expression(component, false);
+                }
+            }
+
+            Expression::NewExpression(new_expression) => {
+                if let TypeName::UserDefinedTypeName(user_defined_type_name) = &new_expression.type_name {
+                    self.created_contracts.insert(user_defined_type_name.referenced_declaration);
+                }
+            }
+        }
+    }
+}
+
+/// Fills in the inheritance-derived metadata of all contracts within the supplied source units,
+/// whose references must already be resolved and whose types must already be checked.
+pub fn compute_contract_metadata(source_units: &mut [SourceUnit]) -> Result<(), InheritanceError> {
+    let builder = ContractMetadataBuilder::new(source_units)?;
+
+    for source_unit in source_units.iter_mut() {
+        builder.update_source_unit(source_unit);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+abstract contract A {
+    function f() public virtual;
+}
+
+contract B is A {
+    function f() public virtual override {}
+}
+
+contract C is A {
+    event Called();
+
+    function f() public virtual override {
+        emit Called();
+    }
+}
+
+contract D is B, C {
+    function f() public override(B, C) {
+        super.f();
+    }
+}
+
+contract E is C, B {
+    function f() public override(B, C) {}
+}
+"#;
+
+    #[test]
+    fn test_linearize_contract() {
+        let base_contracts = HashMap::from([
+            (2, vec![1]),
+            (3, vec![1]),
+            (4, vec![2, 3]),
+            (5, vec![4, 2]),
+            (6, vec![7]),
+            (7, vec![6]),
+        ]);
+
+        let mut linearized = HashMap::new();
+
+        assert_eq!(linearize_contract(4, &base_contracts, &mut linearized), Ok(vec![4, 3, 2, 1]));
+        assert_eq!(linearized.get(&3), Some(&vec![3, 1]));
+
+        // `B` must come before `D`, which is more derived
+        assert_eq!(linearize_contract(5, &base_contracts, &mut linearized), Err(LinearizationFailure::Impossible));
+        assert_eq!(linearize_contract(6, &base_contracts, &mut linearized), Err(LinearizationFailure::Cycle));
+    }
+
+    #[test]
+    fn test_compute_contract_metadata() {
+        let source_units = builder::build_test_source_units("Diamond.sol", SOURCE);
+        let contract_definitions = source_units[0].contract_definitions();
+        let ids: Vec<_> = contract_definitions.iter().map(|contract_definition| contract_definition.id).collect();
+        let (a, b, c, d, e) = (ids[0], ids[1], ids[2], ids[3], ids[4]);
+
+        let linearizations: Vec<_> = contract_definitions.iter()
+            .map(|contract_definition| contract_definition.linearized_base_contracts.clone().unwrap())
+            .collect();
+
+        assert_eq!(linearizations, vec![
+            vec![a],
+            vec![b, a],
+            vec![c, a],
+            vec![d, c, b, a],
+            vec![e, b, c, a],
+        ]);
+
+        let fully_implemented: Vec<_> = contract_definitions.iter()
+            .map(|contract_definition| contract_definition.fully_implemented)
+            .collect();
+
+        assert_eq!(fully_implemented, vec![Some(false), Some(true), Some(true), Some(true), Some(true)]);
+
+        let event_id = contract_definitions[2].nodes.iter()
+            .find_map(|node| match node {
+                ContractDefinitionNode::EventDefinition(event_definition) => Some(event_definition.id),
+                _ => None,
+            })
+            .unwrap();
+
+        // `super.f()` in `D` calls `C.f`, which emits the event
+        assert_eq!(contract_definitions[2].used_events, Some(vec![event_id]));
+        assert_eq!(contract_definitions[3].used_events, Some(vec![event_id]));
+        assert_eq!(contract_definitions[1].used_events, Some(vec![]));
+    }
+}
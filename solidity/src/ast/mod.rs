@@ -1,20 +1,30 @@
 mod blocks;
 mod builder;
+mod call_graph;
 mod contracts;
+mod control_flow;
+mod data_flow;
 mod documentation;
+mod effective_contract;
 mod enumerations;
 mod errors;
 mod events;
 mod expressions;
 mod functions;
 mod identifiers;
+mod inheritance;
 mod import_directives;
 mod literals;
 mod modifiers;
 mod pragma_directives;
+mod resolver;
+mod signatures;
+mod source_locations;
 mod source_units;
 mod statements;
+mod storage_layout;
 mod structures;
+mod type_checker;
 mod types;
 mod user_defined_value_types;
 mod using_for_directives;
@@ -22,8 +32,8 @@ mod variables;
 mod visitor;
 
 pub use self::{
-    blocks::*, builder::*, contracts::*, documentation::*, enumerations::*, errors::*, events::*,
-    expressions::*, functions::*, identifiers::*, import_directives::*, literals::*, modifiers::*,
-    pragma_directives::*, source_units::*, statements::*, structures::*, types::*,
-    user_defined_value_types::*, using_for_directives::*, variables::*, visitor::*,
+    blocks::*, builder::*, call_graph::*, contracts::*, control_flow::*, data_flow::*, documentation::*, effective_contract::*, enumerations::*, errors::*, events::*,
+    expressions::*, functions::*, identifiers::*, import_directives::*, inheritance::*, literals::*, modifiers::*,
+    pragma_directives::*, resolver::*, signatures::*, source_locations::*, source_units::*, statements::*, storage_layout::*, structures::*,
+    type_checker::*, types::*, user_defined_value_types::*, using_for_directives::*, variables::*, visitor::*,
 };
@@ -0,0 +1,1584 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+};
+use yul::ast::*;
+
+/// The IDs solc assigns to the declarations of its global (magic) variables and functions.
+pub const MAGIC_VARIABLES: &[(&str, NodeID)] = &[
+    ("abi", -1),
+    ("addmod", -2),
+    ("assert", -3),
+    ("block", -4),
+    ("blockhash", -5),
+    ("ecrecover", -6),
+    ("gasleft", -7),
+    ("keccak256", -8),
+    ("msg", -15),
+    ("mulmod", -16),
+    ("now", -17),
+    ("require", -18),
+    ("revert", -19),
+    ("ripemd160", -20),
+    ("selfdestruct", -21),
+    ("sha256", -22),
+    ("sha3", -23),
+    ("suicide", -24),
+    ("super", -25),
+    ("tx", -26),
+    ("type", -27),
+    ("this", -28),
+    ("blobhash", -29),
+];
+
+pub fn magic_variable_id(name: &str) -> Option<NodeID> {
+    MAGIC_VARIABLES.iter().find(|&&(magic_name, _)| magic_name == name).map(|&(_, id)| id)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeclarationKind {
+    SourceUnit,
+    Contract,
+    Struct,
+    Enum,
+    EnumValue,
+    UserDefinedValueType,
+    Variable,
+    Function,
+    Modifier,
+    Event,
+    Error,
+}
+
+/// A named declaration which can be referenced from other nodes.
+#[derive(Clone, Debug)]
+pub struct Declaration {
+    pub kind: DeclarationKind,
+    pub name: String,
+    /// The index of the source unit containing the declaration.
+    pub source_unit: usize,
+    /// The ID of the contract containing the declaration, if any.
+    pub contract: Option<NodeID>,
+    /// The IDs of the contract's members, the struct's members or the enum's values.
+    pub members: Vec<NodeID>,
+    pub parameter_count: usize,
+    /// The parameter types of a function or event without their data locations, which distinguish overloads.
+    pub parameter_types: Vec<String>,
+    /// The type of a variable, or the type returned by a function with a single return parameter.
+    pub type_name: Option<TypeName>,
+}
+
+/// The type of a value, which is either a contract or struct declaration or a type name which has not been resolved yet.
+enum ValueType {
+    Declaration(NodeID),
+    TypeName(Box<TypeName>, usize, Option<NodeID>),
+}
+
+/// The names declared in a Yul block, which shadow Solidity declarations.
+#[derive(Default)]
+struct YulScope {
+    functions: HashSet<String>,
+    variables: HashSet<String>,
+}
+
+/// The declarations visible at the current position of the resolver.
+struct Scope {
+    source_unit: usize,
+    contract: Option<NodeID>,
+    locals: Vec<HashMap<String, NodeID>>,
+    return_parameters: Option<NodeID>,
+}
+
+impl Scope {
+    fn new(source_unit: usize, contract: Option<NodeID>) -> Self {
+        Self {
+            source_unit,
+            contract,
+            locals: vec![],
+            return_parameters: None,
+        }
+    }
+
+    fn declare(&mut self, variable_declaration: &VariableDeclaration) {
+        if variable_declaration.name.is_empty() {
+            return;
+        }
+
+        if let Some(locals) = self.locals.last_mut() {
+            locals.insert(variable_declaration.name.clone(), variable_declaration.id);
+        }
+    }
+}
+
+/// Links identifiers, member accesses and type names to the IDs of the nodes they reference.
+///
+/// The AST built from solang-parser does not contain any resolved references, so this fills in
+/// `referenced_declaration` across imports and inheritance the same way solc does.
+#[derive(Default)]
+pub struct NameResolver {
+    source_unit_ids: Vec<NodeID>,
+    declarations: HashMap<NodeID, Declaration>,
+    symbols: Vec<HashMap<String, Vec<NodeID>>>,
+    base_contracts: HashMap<NodeID, Vec<NodeID>>,
+    linearized_base_contracts: HashMap<NodeID, Vec<NodeID>>,
+    using_for_libraries: HashMap<NodeID, Vec<NodeID>>,
+    using_for_functions: HashMap<NodeID, Vec<NodeID>>,
+}
+
+impl NameResolver {
+    pub fn new(source_units: &[SourceUnit]) -> Self {
+        let mut resolver = Self::default();
+
+        //
+        // Collect all declarations and the top-level symbols of each source unit
+        //
+
+        let mut local_symbols = vec![];
+
+        for (index, source_unit) in source_units.iter().enumerate() {
+            resolver.source_unit_ids.push(source_unit.id);
+            resolver.add_declaration(source_unit.id, DeclarationKind::SourceUnit, "", index, None);
+
+            let mut symbols: HashMap<String, Vec<NodeID>> = HashMap::new();
+
+            for node in source_unit.nodes.iter() {
+                let (name, id) = match node {
+                    SourceUnitNode::PragmaDirective(_) | SourceUnitNode::ImportDirective(_) => continue,
+
+                    SourceUnitNode::ContractDefinition(contract_definition) => {
+                        resolver.add_contract_definition(index, contract_definition);
+                        (&contract_definition.name, contract_definition.id)
+                    }
+
+                    SourceUnitNode::StructDefinition(struct_definition) => {
+                        resolver.add_struct_definition(index, None, struct_definition);
+                        (&struct_definition.name, struct_definition.id)
+                    }
+
+                    SourceUnitNode::EnumDefinition(enum_definition) => {
+                        resolver.add_enum_definition(index, None, enum_definition);
+                        (&enum_definition.name, enum_definition.id)
+                    }
+
+                    SourceUnitNode::ErrorDefinition(error_definition) => {
+                        resolver.add_parameters(index, None, &error_definition.parameters);
+                        resolver.add_declaration(error_definition.id, DeclarationKind::Error, &error_definition.name, index, None)
+                            .parameter_count = error_definition.parameters.parameters.len();
+                        (&error_definition.name, error_definition.id)
+                    }
+
+                    SourceUnitNode::VariableDeclaration(variable_declaration) => {
+                        resolver.add_variable_declaration(index, None, variable_declaration);
+                        (&variable_declaration.name, variable_declaration.id)
+                    }
+
+                    SourceUnitNode::UserDefinedValueTypeDefinition(definition) => {
+                        resolver.add_declaration(definition.id, DeclarationKind::UserDefinedValueType, &definition.name, index, None);
+                        (&definition.name, definition.id)
+                    }
+                };
+
+                symbols.entry(name.clone()).or_default().push(id);
+            }
+
+            local_symbols.push(symbols);
+        }
+
+        //
+        // Add the symbols imported by each source unit
+        //
+
+        for index in 0..source_units.len() {
+            let symbols = Self::collect_symbols(source_units, &local_symbols, index, &mut HashSet::new());
+            resolver.symbols.push(symbols);
+        }
+
+        //
+        // Resolve and linearize the inheritance hierarchy of each contract
+        //
+
+        for (index, source_unit) in source_units.iter().enumerate() {
+            for contract_definition in source_unit.contract_definitions() {
+                let scope = Scope::new(index, None);
+
+                let base_contracts = contract_definition.base_contracts.iter()
+                    .filter_map(|base_contract| {
+                        resolver.lookup_path(base_contract.base_name.name.as_str(), &scope).into_iter()
+                            .find(|&id| resolver.is_kind(id, DeclarationKind::Contract))
+                    })
+                    .collect();
+
+                resolver.base_contracts.insert(contract_definition.id, base_contracts);
+            }
+        }
+
+        let contract_ids: Vec<NodeID> = resolver.base_contracts.keys().copied().collect();
+
+        for contract_id in contract_ids {
+            resolver.linearize(contract_id);
+        }
+
+        //
+        // Resolve the libraries and functions attached to types within each contract
+        //
+
+        for (index, source_unit) in source_units.iter().enumerate() {
+            for contract_definition in source_unit.contract_definitions() {
+                let scope = Scope::new(index, Some(contract_definition.id));
+                let mut libraries = vec![];
+                let mut functions = vec![];
+
+                for node in contract_definition.nodes.iter() {
+                    let ContractDefinitionNode::UsingForDirective(using_for_directive) = node else { continue };
+
+                    if let Some(library_name) = using_for_directive.library_name.as_ref() {
+                        libraries.extend(
+                            resolver.lookup_path(library_name.name.as_str(), &scope).into_iter()
+                                .find(|&id| resolver.is_kind(id, DeclarationKind::Contract))
+                        );
+                    }
+
+                    // Functions bound to operators can not be called as members
+                    for function in using_for_directive.function_list.iter().flatten().filter_map(|function| function.function.as_ref()) {
+                        functions.extend(
+                            resolver.lookup_path(function.name.as_str(), &scope).into_iter()
+                                .filter(|&id| resolver.is_kind(id, DeclarationKind::Function))
+                        );
+                    }
+                }
+
+                resolver.using_for_libraries.insert(contract_definition.id, libraries);
+                resolver.using_for_functions.insert(contract_definition.id, functions);
+            }
+        }
+
+        resolver
+    }
+
+    pub fn declaration(&self, id: NodeID) -> Option<&Declaration> {
+        self.declarations.get(&id)
+    }
+
+    /// The C3 linearization of the supplied contract's inheritance hierarchy, starting with the contract itself.
+    pub fn linearized_base_contracts(&self, contract_id: NodeID) -> Option<&Vec<NodeID>> {
+        self.linearized_base_contracts.get(&contract_id)
+    }
+
+    /// Resolves all references within the supplied source unit, which must have been supplied to `NameResolver::new`.
+    pub fn resolve_source_unit(&self, source_unit: &mut SourceUnit) {
+        let Some(index) = self.source_unit_ids.iter().position(|&id| id == source_unit.id) else { return };
+
+        let mut scope = Scope::new(index, None);
+
+        for node in source_unit.nodes.iter_mut() {
+            match node {
+                SourceUnitNode::PragmaDirective(_) => {}
+
+                SourceUnitNode::ImportDirective(import_directive) => {
+                    for symbol_alias in import_directive.symbol_aliases.iter_mut() {
+                        if let Expression::Identifier(identifier) = &mut symbol_alias.foreign {
+                            // Aliased symbols are only visible under their local name
+                            let name = symbol_alias.local.as_deref().unwrap_or(identifier.name.as_str());
+
+                            if let Some(&id) = self.lookup_name(name, &scope).first() {
+                                identifier.referenced_declaration = id;
+                            }
+                        }
+                    }
+                }
+
+                SourceUnitNode::ContractDefinition(contract_definition) => {
+                    self.resolve_contract_definition(index, contract_definition);
+                }
+
+                SourceUnitNode::StructDefinition(struct_definition) => {
+                    for member in struct_definition.members.iter_mut() {
+                        self.resolve_variable_declaration(member, &mut scope);
+                    }
+                }
+
+                SourceUnitNode::EnumDefinition(_) => {}
+
+                SourceUnitNode::ErrorDefinition(error_definition) => {
+                    self.resolve_parameter_list(&mut error_definition.parameters, &mut scope, false);
+                }
+
+                SourceUnitNode::VariableDeclaration(variable_declaration) => {
+                    self.resolve_variable_declaration(variable_declaration, &mut scope);
+                }
+
+                SourceUnitNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.resolve_type_name(&mut definition.underlying_type, &scope);
+                }
+            }
+        }
+    }
+
+    //
+    // Declaration collection
+    //
+
+    fn add_declaration(&mut self, id: NodeID, kind: DeclarationKind, name: &str, source_unit: usize, contract: Option<NodeID>) -> &mut Declaration {
+        self.declarations.entry(id).or_insert(Declaration {
+            kind,
+            name: name.to_string(),
+            source_unit,
+            contract,
+            members: vec![],
+            parameter_count: 0,
+            parameter_types: vec![],
+            type_name: None,
+        })
+    }
+
+    fn add_contract_definition(&mut self, source_unit: usize, contract_definition: &ContractDefinition) {
+        let contract = Some(contract_definition.id);
+        let mut members = vec![];
+
+        for node in contract_definition.nodes.iter() {
+            match node {
+                ContractDefinitionNode::UsingForDirective(_) => continue,
+
+                ContractDefinitionNode::StructDefinition(struct_definition) => {
+                    self.add_struct_definition(source_unit, contract, struct_definition);
+                    members.push(struct_definition.id);
+                }
+
+                ContractDefinitionNode::EnumDefinition(enum_definition) => {
+                    self.add_enum_definition(source_unit, contract, enum_definition);
+                    members.push(enum_definition.id);
+                }
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => {
+                    self.add_variable_declaration(source_unit, contract, variable_declaration);
+                    members.push(variable_declaration.id);
+                }
+
+                ContractDefinitionNode::EventDefinition(event_definition) => {
+                    self.add_parameters(source_unit, contract, &event_definition.parameters);
+                    let declaration = self.add_declaration(event_definition.id, DeclarationKind::Event, &event_definition.name, source_unit, contract);
+                    declaration.parameter_count = event_definition.parameters.parameters.len();
+                    declaration.parameter_types = event_definition.parameters.type_strings();
+                    members.push(event_definition.id);
+                }
+
+                ContractDefinitionNode::FunctionDefinition(function_definition) => {
+                    self.add_parameters(source_unit, contract, &function_definition.parameters);
+                    self.add_parameters(source_unit, contract, &function_definition.return_parameters);
+
+                    if let Some(body) = function_definition.body.as_ref() {
+                        self.add_block(source_unit, contract, body);
+                    }
+
+                    let declaration = self.add_declaration(function_definition.id, DeclarationKind::Function, &function_definition.name, source_unit, contract);
+                    declaration.parameter_count = function_definition.parameters.parameters.len();
+                    declaration.parameter_types = function_definition.parameter_type_strings();
+
+                    if let [return_parameter] = function_definition.return_parameters.parameters.as_slice() {
+                        declaration.type_name = return_parameter.type_name.clone();
+                    }
+
+                    members.push(function_definition.id);
+                }
+
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
+                    self.add_parameters(source_unit, contract, &modifier_definition.parameters);
+                    self.add_block(source_unit, contract, &modifier_definition.body);
+                    self.add_declaration(modifier_definition.id, DeclarationKind::Modifier, &modifier_definition.name, source_unit, contract)
+                        .parameter_count = modifier_definition.parameters.parameters.len();
+                    members.push(modifier_definition.id);
+                }
+
+                ContractDefinitionNode::ErrorDefinition(error_definition) => {
+                    self.add_parameters(source_unit, contract, &error_definition.parameters);
+                    self.add_declaration(error_definition.id, DeclarationKind::Error, &error_definition.name, source_unit, contract)
+                        .parameter_count = error_definition.parameters.parameters.len();
+                    members.push(error_definition.id);
+                }
+
+                ContractDefinitionNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.add_declaration(definition.id, DeclarationKind::UserDefinedValueType, &definition.name, source_unit, contract);
+                    members.push(definition.id);
+                }
+            }
+        }
+
+        self.add_declaration(contract_definition.id, DeclarationKind::Contract, &contract_definition.name, source_unit, None)
+            .members = members;
+    }
+
+    fn add_struct_definition(&mut self, source_unit: usize, contract: Option<NodeID>, struct_definition: &StructDefinition) {
+        for member in struct_definition.members.iter() {
+            self.add_variable_declaration(source_unit, contract, member);
+        }
+
+        self.add_declaration(struct_definition.id, DeclarationKind::Struct, &struct_definition.name, source_unit, contract)
+            .members = struct_definition.members.iter().map(|member| member.id).collect();
+    }
+
+    fn add_enum_definition(&mut self, source_unit: usize, contract: Option<NodeID>, enum_definition: &EnumDefinition) {
+        for value in enum_definition.members.iter() {
+            self.add_declaration(value.id, DeclarationKind::EnumValue, &value.name, source_unit, contract);
+        }
+
+        self.add_declaration(enum_definition.id, DeclarationKind::Enum, &enum_definition.name, source_unit, contract)
+            .members = enum_definition.members.iter().map(|value| value.id).collect();
+    }
+
+    fn add_variable_declaration(&mut self, source_unit: usize, contract: Option<NodeID>, variable_declaration: &VariableDeclaration) {
+        self.add_declaration(variable_declaration.id, DeclarationKind::Variable, &variable_declaration.name, source_unit, contract)
+            .type_name = variable_declaration.type_name.clone();
+    }
+
+    fn add_parameters(&mut self, source_unit: usize, contract: Option<NodeID>, parameter_list: &ParameterList) {
+        for parameter in parameter_list.parameters.iter() {
+            self.add_variable_declaration(source_unit, contract, parameter);
+        }
+    }
+
+    fn add_block(&mut self, source_unit: usize, contract: Option<NodeID>, block: &Block) {
+        for statement in block.statements.iter() {
+            self.add_statement(source_unit, contract, statement);
+        }
+    }
+
+    fn add_block_or_statement(&mut self, source_unit: usize, contract: Option<NodeID>, block_or_statement: &BlockOrStatement) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.add_block(source_unit, contract, block),
+            BlockOrStatement::Statement(statement) => self.add_statement(source_unit, contract, statement),
+        }
+    }
+
+    fn add_statement(&mut self, source_unit: usize, contract: Option<NodeID>, statement: &Statement) {
+        match statement {
+            Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                for variable_declaration in variable_declaration_statement.declarations.iter().flatten() {
+                    self.add_variable_declaration(source_unit, contract, variable_declaration);
+                }
+            }
+
+            Statement::IfStatement(if_statement) => {
+                self.add_block_or_statement(source_unit, contract, &if_statement.true_body);
+
+                if let Some(false_body) = if_statement.false_body.as_ref() {
+                    self.add_block_or_statement(source_unit, contract, false_body);
+                }
+            }
+
+            Statement::ForStatement(for_statement) => {
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_ref() {
+                    self.add_statement(source_unit, contract, initialization_expression);
+                }
+
+                self.add_block_or_statement(source_unit, contract, &for_statement.body);
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                self.add_block_or_statement(source_unit, contract, &while_statement.body);
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                self.add_block_or_statement(source_unit, contract, &do_while_statement.body);
+            }
+
+            Statement::TryStatement(try_statement) => {
+                for clause in try_statement.clauses.iter() {
+                    if let Some(parameters) = clause.parameters.as_ref() {
+                        self.add_parameters(source_unit, contract, parameters);
+                    }
+
+                    self.add_block(source_unit, contract, &clause.block);
+                }
+            }
+
+            Statement::UncheckedBlock(block) | Statement::Block(block) => {
+                self.add_block(source_unit, contract, block);
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Collects the symbols visible at the top level of a source unit, including the symbols it imports.
+    fn collect_symbols(
+        source_units: &[SourceUnit],
+        local_symbols: &[HashMap<String, Vec<NodeID>>],
+        index: usize,
+        visiting: &mut HashSet<usize>,
+    ) -> HashMap<String, Vec<NodeID>> {
+        let mut symbols = local_symbols[index].clone();
+
+        // Stop at import cycles, since the symbols of the source unit are already being collected
+        if !visiting.insert(index) {
+            return symbols;
+        }
+
+        let source_unit = &source_units[index];
+
+        for import_directive in source_unit.import_directives() {
+            let Some(imported_index) = find_imported_source_unit(source_units, source_unit, import_directive) else { continue };
+
+            if !import_directive.symbol_aliases.is_empty() {
+                let imported_symbols = Self::collect_symbols(source_units, local_symbols, imported_index, visiting);
+
+                for symbol_alias in import_directive.symbol_aliases.iter() {
+                    let Expression::Identifier(foreign) = &symbol_alias.foreign else { continue };
+                    let Some(ids) = imported_symbols.get(&foreign.name) else { continue };
+
+                    let name = symbol_alias.local.clone().unwrap_or_else(|| foreign.name.clone());
+                    symbols.entry(name).or_default().extend(ids.iter().copied());
+                }
+            } else if !import_directive.unit_alias.is_empty() {
+                symbols.entry(import_directive.unit_alias.clone()).or_default().push(source_units[imported_index].id);
+            } else {
+                for (name, ids) in Self::collect_symbols(source_units, local_symbols, imported_index, visiting) {
+                    let entry = symbols.entry(name).or_default();
+
+                    for id in ids {
+                        if !entry.contains(&id) {
+                            entry.push(id);
+                        }
+                    }
+                }
+            }
+        }
+
+        visiting.remove(&index);
+
+        symbols
+    }
+
+    fn linearize(&mut self, contract_id: NodeID) {
+        // Hierarchies which can not be linearized are reported by `compute_contract_metadata`
+        if linearize_contract(contract_id, &self.base_contracts, &mut self.linearized_base_contracts).is_err() {
+            self.linearized_base_contracts.insert(contract_id, vec![contract_id]);
+        }
+    }
+
+    //
+    // Name lookup
+    //
+
+    fn is_kind(&self, id: NodeID, kind: DeclarationKind) -> bool {
+        self.declarations.get(&id).map(|declaration| declaration.kind == kind).unwrap_or(false)
+    }
+
+    /// Finds the declarations of the supplied name which are visible from the supplied scope, innermost first.
+    fn lookup_name(&self, name: &str, scope: &Scope) -> Vec<NodeID> {
+        for locals in scope.locals.iter().rev() {
+            if let Some(&id) = locals.get(name) {
+                return vec![id];
+            }
+        }
+
+        if let Some(contract_id) = scope.contract {
+            let result = self.contract_members(contract_id, name, false);
+
+            if !result.is_empty() {
+                return result;
+            }
+        }
+
+        self.symbols.get(scope.source_unit)
+            .and_then(|symbols| symbols.get(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Finds the declarations of a dotted path (i.e: `Library.Struct`) which are visible from the supplied scope.
+    fn lookup_path(&self, path: &str, scope: &Scope) -> Vec<NodeID> {
+        let mut names = path.split('.');
+
+        let Some(first_name) = names.next() else { return vec![] };
+        let mut result = self.lookup_name(first_name, scope);
+
+        for name in names {
+            result = match result.first() {
+                Some(&id) => self.declaration_members(id, name),
+                None => return vec![],
+            };
+        }
+
+        result
+    }
+
+    /// Finds the members of a contract (including inherited members) with the supplied name, most derived first.
+    fn contract_members(&self, contract_id: NodeID, name: &str, skip_self: bool) -> Vec<NodeID> {
+        let linearized_base_contracts = match self.linearized_base_contracts.get(&contract_id) {
+            Some(linearized_base_contracts) => linearized_base_contracts.as_slice(),
+            None => std::slice::from_ref(&contract_id),
+        };
+
+        let mut result: Vec<NodeID> = vec![];
+
+        for &base_contract_id in linearized_base_contracts.iter().skip(if skip_self { 1 } else { 0 }) {
+            let Some(base_contract) = self.declarations.get(&base_contract_id) else { continue };
+
+            for &member_id in base_contract.members.iter() {
+                let member = &self.declarations[&member_id];
+
+                if member.name != name {
+                    continue;
+                }
+
+                // Only include overloads which are not overridden by a more derived function with the same parameter types
+                if member.kind == DeclarationKind::Function || member.kind == DeclarationKind::Event {
+                    let overridden = result.iter().any(|id| {
+                        let declaration = &self.declarations[id];
+                        declaration.kind == member.kind && declaration.parameter_types == member.parameter_types
+                    });
+
+                    if !overridden {
+                        result.push(member_id);
+                    }
+                } else if result.is_empty() {
+                    result.push(member_id);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Finds the members of a contract, enum or imported source unit declaration with the supplied name.
+    fn declaration_members(&self, id: NodeID, name: &str) -> Vec<NodeID> {
+        let Some(declaration) = self.declarations.get(&id) else { return vec![] };
+
+        match declaration.kind {
+            DeclarationKind::SourceUnit => self.symbols[declaration.source_unit].get(name).cloned().unwrap_or_default(),
+            DeclarationKind::Contract => self.contract_members(id, name, false),
+
+            DeclarationKind::Enum | DeclarationKind::Struct => declaration.members.iter()
+                .copied()
+                .filter(|member_id| self.declarations[member_id].name == name)
+                .collect(),
+
+            _ => vec![],
+        }
+    }
+
+    /// Selects the declaration with the expected number of parameters from a set of overloaded declarations.
+    fn select(&self, candidates: &[NodeID], parameter_count: Option<usize>) -> Option<NodeID> {
+        if let Some(parameter_count) = parameter_count {
+            let found = candidates.iter().copied().find(|id| {
+                let declaration = &self.declarations[id];
+
+                matches!(
+                    declaration.kind,
+                    DeclarationKind::Function | DeclarationKind::Event | DeclarationKind::Error | DeclarationKind::Modifier
+                ) && declaration.parameter_count == parameter_count
+            });
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        candidates.first().copied()
+    }
+
+    //
+    // Expression types
+    //
+
+    fn referenced_declaration(expression: &Expression) -> Option<NodeID> {
+        match expression {
+            Expression::Identifier(identifier) => Some(identifier.referenced_declaration),
+            Expression::MemberAccess(member_access) => member_access.referenced_declaration,
+            _ => None,
+        }
+    }
+
+    /// Determines the type of the value produced by the supplied (already resolved) expression, if it is user-defined.
+    fn expression_type(&self, expression: &Expression, scope: &Scope) -> Option<ValueType> {
+        match expression {
+            Expression::Identifier(identifier) if identifier.name == "this" => {
+                scope.contract.map(ValueType::Declaration)
+            }
+
+            Expression::Identifier(_) | Expression::MemberAccess(_) => {
+                let declaration = self.declarations.get(&Self::referenced_declaration(expression)?)?;
+
+                match declaration.kind {
+                    DeclarationKind::Variable => Some(ValueType::TypeName(
+                        Box::new(declaration.type_name.clone()?),
+                        declaration.source_unit,
+                        declaration.contract,
+                    )),
+
+                    _ => None,
+                }
+            }
+
+            Expression::IndexAccess(index_access) => {
+                let ValueType::TypeName(type_name, source_unit, contract) = self.expression_type(index_access.base_expression.as_ref(), scope)? else { return None };
+
+                match *type_name {
+                    TypeName::ArrayTypeName(array_type_name) => Some(ValueType::TypeName(array_type_name.base_type, source_unit, contract)),
+                    TypeName::Mapping(mapping) => Some(ValueType::TypeName(mapping.value_type, source_unit, contract)),
+                    _ => None,
+                }
+            }
+
+            Expression::FunctionCall(function_call) => {
+                let mut callee = function_call.expression.as_ref();
+
+                if let Expression::FunctionCallOptions(function_call_options) = callee {
+                    callee = function_call_options.expression.as_ref();
+                }
+
+                if let Expression::NewExpression(new_expression) = callee {
+                    return Some(ValueType::TypeName(Box::new(new_expression.type_name.clone()), scope.source_unit, scope.contract));
+                }
+
+                let id = Self::referenced_declaration(callee)?;
+                let declaration = self.declarations.get(&id)?;
+
+                match declaration.kind {
+                    DeclarationKind::Contract | DeclarationKind::Struct => Some(ValueType::Declaration(id)),
+
+                    DeclarationKind::Function => Some(ValueType::TypeName(
+                        Box::new(declaration.type_name.clone()?),
+                        declaration.source_unit,
+                        declaration.contract,
+                    )),
+
+                    _ => None,
+                }
+            }
+
+            Expression::Conditional(conditional) => self.expression_type(conditional.true_expression.as_ref(), scope),
+
+            Expression::TupleExpression(tuple_expression) if tuple_expression.components.len() == 1 => {
+                self.expression_type(tuple_expression.components[0].as_ref()?, scope)
+            }
+
+            _ => None,
+        }
+    }
+
+    fn value_type_declaration(&self, value_type: &ValueType) -> Option<NodeID> {
+        match value_type {
+            ValueType::Declaration(id) => Some(*id),
+
+            ValueType::TypeName(type_name, source_unit, contract) => {
+                let TypeName::UserDefinedTypeName(user_defined_type_name) = type_name.as_ref() else { return None };
+                let name = user_defined_type_name.path_node.as_ref()
+                    .map(|path_node| path_node.name.as_str())
+                    .or(user_defined_type_name.name.as_deref())?;
+
+                self.lookup_path(name, &Scope::new(*source_unit, *contract)).first().copied()
+            }
+        }
+    }
+
+    //
+    // Resolution
+    //
+
+    fn resolve_contract_definition(&self, source_unit: usize, contract_definition: &mut ContractDefinition) {
+        let mut scope = Scope::new(source_unit, Some(contract_definition.id));
+
+        for base_contract in contract_definition.base_contracts.iter_mut() {
+            self.resolve_identifier_path(&mut base_contract.base_name, &Scope::new(source_unit, None));
+
+            for argument in base_contract.arguments.iter_mut().flatten() {
+                self.resolve_expression(argument, &mut scope, None);
+            }
+        }
+
+        for node in contract_definition.nodes.iter_mut() {
+            match node {
+                ContractDefinitionNode::UsingForDirective(using_for_directive) => {
+                    if let Some(library_name) = using_for_directive.library_name.as_mut() {
+                        self.resolve_identifier_path(library_name, &scope);
+                    }
+
+                    for function in using_for_directive.function_list.iter_mut().flatten() {
+                        for path in function.function.iter_mut().chain(function.definition.iter_mut()) {
+                            self.resolve_identifier_path(path, &scope);
+                        }
+                    }
+
+                    if let Some(type_name) = using_for_directive.type_name.as_mut() {
+                        self.resolve_type_name(type_name, &scope);
+                    }
+                }
+
+                ContractDefinitionNode::StructDefinition(struct_definition) => {
+                    for member in struct_definition.members.iter_mut() {
+                        self.resolve_variable_declaration(member, &mut scope);
+                    }
+                }
+
+                ContractDefinitionNode::EnumDefinition(_) => {}
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => {
+                    self.resolve_variable_declaration(variable_declaration, &mut scope);
+                }
+
+                ContractDefinitionNode::EventDefinition(event_definition) => {
+                    self.resolve_parameter_list(&mut event_definition.parameters, &mut scope, false);
+                }
+
+                ContractDefinitionNode::FunctionDefinition(function_definition) => {
+                    self.resolve_function_definition(function_definition, &mut scope);
+                }
+
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
+                    if let Some(overrides) = modifier_definition.overrides.as_mut() {
+                        self.resolve_override_specifier(overrides, &scope);
+                    }
+
+                    scope.locals.push(HashMap::new());
+                    self.resolve_parameter_list(&mut modifier_definition.parameters, &mut scope, true);
+                    self.resolve_block(&mut modifier_definition.body, &mut scope);
+                    scope.locals.pop();
+                }
+
+                ContractDefinitionNode::ErrorDefinition(error_definition) => {
+                    self.resolve_parameter_list(&mut error_definition.parameters, &mut scope, false);
+                }
+
+                ContractDefinitionNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.resolve_type_name(&mut definition.underlying_type, &scope);
+                }
+            }
+        }
+    }
+
+    fn resolve_function_definition(&self, function_definition: &mut FunctionDefinition, scope: &mut Scope) {
+        if let Some(overrides) = function_definition.overrides.as_mut() {
+            self.resolve_override_specifier(overrides, scope);
+        }
+
+        scope.locals.push(HashMap::new());
+        scope.return_parameters = Some(function_definition.return_parameters.id);
+
+        self.resolve_parameter_list(&mut function_definition.parameters, scope, true);
+        self.resolve_parameter_list(&mut function_definition.return_parameters, scope, true);
+
+        for modifier in function_definition.modifiers.iter_mut() {
+            self.resolve_identifier_path(&mut modifier.modifier_name, scope);
+
+            if let Some(id) = modifier.modifier_name.referenced_declaration {
+                modifier.kind = Some(if self.is_kind(id, DeclarationKind::Contract) {
+                    ModifierInvocationKind::BaseConstructorSpecifier
+                } else {
+                    ModifierInvocationKind::ModifierInvocation
+                });
+            }
+
+            for argument in modifier.arguments.iter_mut().flatten() {
+                self.resolve_expression(argument, scope, None);
+            }
+        }
+
+        if let Some(body) = function_definition.body.as_mut() {
+            self.resolve_block(body, scope);
+        }
+
+        scope.return_parameters = None;
+        scope.locals.pop();
+    }
+
+    fn resolve_override_specifier(&self, override_specifier: &mut OverrideSpecifier, scope: &Scope) {
+        for identifier_path in override_specifier.overrides.iter_mut() {
+            self.resolve_identifier_path(identifier_path, scope);
+        }
+    }
+
+    fn resolve_parameter_list(&self, parameter_list: &mut ParameterList, scope: &mut Scope, declare: bool) {
+        for parameter in parameter_list.parameters.iter_mut() {
+            self.resolve_variable_declaration(parameter, scope);
+
+            if declare {
+                scope.declare(parameter);
+            }
+        }
+    }
+
+    fn resolve_variable_declaration(&self, variable_declaration: &mut VariableDeclaration, scope: &mut Scope) {
+        if let Some(type_name) = variable_declaration.type_name.as_mut() {
+            self.resolve_type_name(type_name, scope);
+        }
+
+        if let Some(overrides) = variable_declaration.overrides.as_mut() {
+            self.resolve_override_specifier(overrides, scope);
+        }
+
+        if let Some(value) = variable_declaration.value.as_mut() {
+            self.resolve_expression(value, scope, None);
+        }
+    }
+
+    fn resolve_identifier_path(&self, identifier_path: &mut IdentifierPath, scope: &Scope) {
+        if let Some(id) = self.lookup_path(identifier_path.name.as_str(), scope).first() {
+            identifier_path.referenced_declaration = Some(*id);
+        }
+    }
+
+    fn resolve_type_name(&self, type_name: &mut TypeName, scope: &Scope) {
+        match type_name {
+            TypeName::UserDefinedTypeName(user_defined_type_name) => {
+                let name = match (user_defined_type_name.path_node.as_ref(), user_defined_type_name.name.as_ref()) {
+                    (Some(path_node), _) => path_node.name.clone(),
+                    (None, Some(name)) => name.clone(),
+                    (None, None) => return,
+                };
+
+                if let Some(&id) = self.lookup_path(name.as_str(), scope).first() {
+                    user_defined_type_name.referenced_declaration = id;
+
+                    if let Some(path_node) = user_defined_type_name.path_node.as_mut() {
+                        path_node.referenced_declaration = Some(id);
+                    }
+                }
+            }
+
+            TypeName::FunctionTypeName(function_type_name) => {
+                for parameter in function_type_name.parameter_types.parameters.iter_mut()
+                    .chain(function_type_name.return_parameter_types.parameters.iter_mut())
+                {
+                    if let Some(type_name) = parameter.type_name.as_mut() {
+                        self.resolve_type_name(type_name, scope);
+                    }
+                }
+            }
+
+            TypeName::ArrayTypeName(array_type_name) => {
+                self.resolve_type_name(array_type_name.base_type.as_mut(), scope);
+            }
+
+            TypeName::Mapping(mapping) => {
+                self.resolve_type_name(mapping.key_type.as_mut(), scope);
+                self.resolve_type_name(mapping.value_type.as_mut(), scope);
+            }
+
+            TypeName::ElementaryTypeName(_) | TypeName::String(_) => {}
+        }
+    }
+
+    fn resolve_block(&self, block: &mut Block, scope: &mut Scope) {
+        scope.locals.push(HashMap::new());
+
+        for statement in block.statements.iter_mut() {
+            self.resolve_statement(statement, scope);
+        }
+
+        scope.locals.pop();
+    }
+
+    fn resolve_block_or_statement(&self, block_or_statement: &mut BlockOrStatement, scope: &mut Scope) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.resolve_block(block, scope),
+
+            BlockOrStatement::Statement(statement) => {
+                scope.locals.push(HashMap::new());
+                self.resolve_statement(statement, scope);
+                scope.locals.pop();
+            }
+        }
+    }
+
+    fn resolve_statement(&self, statement: &mut Statement, scope: &mut Scope) {
+        match statement {
+            Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                // The declared variables are not visible in their own initial value
+                if let Some(initial_value) = variable_declaration_statement.initial_value.as_mut() {
+                    self.resolve_expression(initial_value, scope, None);
+                }
+
+                for variable_declaration in variable_declaration_statement.declarations.iter_mut().flatten() {
+                    self.resolve_variable_declaration(variable_declaration, scope);
+                    scope.declare(variable_declaration);
+                }
+
+                variable_declaration_statement.assignments = variable_declaration_statement.declarations.iter()
+                    .map(|variable_declaration| variable_declaration.as_ref().map(|variable_declaration| variable_declaration.id))
+                    .collect();
+            }
+
+            Statement::IfStatement(if_statement) => {
+                self.resolve_expression(&mut if_statement.condition, scope, None);
+                self.resolve_block_or_statement(&mut if_statement.true_body, scope);
+
+                if let Some(false_body) = if_statement.false_body.as_mut() {
+                    self.resolve_block_or_statement(false_body, scope);
+                }
+            }
+
+            Statement::ForStatement(for_statement) => {
+                scope.locals.push(HashMap::new());
+
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_mut() {
+                    self.resolve_statement(initialization_expression, scope);
+                }
+
+                if let Some(condition) = for_statement.condition.as_mut() {
+                    self.resolve_expression(condition, scope, None);
+                }
+
+                if let Some(loop_expression) = for_statement.loop_expression.as_mut() {
+                    self.resolve_statement(loop_expression, scope);
+                }
+
+                self.resolve_block_or_statement(&mut for_statement.body, scope);
+
+                scope.locals.pop();
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                self.resolve_expression(&mut while_statement.condition, scope, None);
+                self.resolve_block_or_statement(&mut while_statement.body, scope);
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                self.resolve_block_or_statement(&mut do_while_statement.body, scope);
+                self.resolve_expression(&mut do_while_statement.condition, scope, None);
+            }
+
+            Statement::EmitStatement(emit_statement) => {
+                self.resolve_expression(&mut emit_statement.event_call, scope, None);
+            }
+
+            Statement::TryStatement(try_statement) => {
+                self.resolve_function_call(&mut try_statement.external_call, scope);
+
+                for clause in try_statement.clauses.iter_mut() {
+                    scope.locals.push(HashMap::new());
+
+                    if let Some(parameters) = clause.parameters.as_mut() {
+                        self.resolve_parameter_list(parameters, scope, true);
+                    }
+
+                    self.resolve_block(&mut clause.block, scope);
+
+                    scope.locals.pop();
+                }
+            }
+
+            Statement::UncheckedBlock(block) | Statement::Block(block) => {
+                self.resolve_block(block, scope);
+            }
+
+            Statement::Return(return_statement) => {
+                if let Some(expression) = return_statement.expression.as_mut() {
+                    self.resolve_expression(expression, scope, None);
+                }
+
+                if let Some(return_parameters) = scope.return_parameters {
+                    return_statement.function_return_parameters = return_parameters;
+                }
+            }
+
+            Statement::RevertStatement(revert_statement) => {
+                self.resolve_function_call(&mut revert_statement.error_call, scope);
+            }
+
+            Statement::ExpressionStatement(expression_statement) => {
+                self.resolve_expression(&mut expression_statement.expression, scope, None);
+            }
+
+            Statement::InlineAssembly(inline_assembly) => {
+                self.resolve_inline_assembly(inline_assembly, scope);
+            }
+
+            Statement::Continue { .. }
+            | Statement::Break { .. }
+            | Statement::PlaceholderStatement { .. } => {}
+        }
+    }
+
+    /// Resolves the references of an expression. If the expression is called, `argument_count` is used to select between overloads.
+    fn resolve_expression(&self, expression: &mut Expression, scope: &mut Scope, argument_count: Option<usize>) {
+        match expression {
+            Expression::Literal(_) => {}
+
+            Expression::Identifier(identifier) => {
+                self.resolve_identifier(identifier, scope, argument_count);
+            }
+
+            Expression::UnaryOperation(unary_operation) => {
+                self.resolve_expression(unary_operation.sub_expression.as_mut(), scope, None);
+            }
+
+            Expression::BinaryOperation(binary_operation) => {
+                self.resolve_expression(binary_operation.left_expression.as_mut(), scope, None);
+                self.resolve_expression(binary_operation.right_expression.as_mut(), scope, None);
+            }
+
+            Expression::Conditional(conditional) => {
+                self.resolve_expression(conditional.condition.as_mut(), scope, None);
+                self.resolve_expression(conditional.true_expression.as_mut(), scope, None);
+                self.resolve_expression(conditional.false_expression.as_mut(), scope, None);
+            }
+
+            Expression::Assignment(assignment) => {
+                self.resolve_expression(assignment.left_hand_side.as_mut(), scope, None);
+                self.resolve_expression(assignment.right_hand_side.as_mut(), scope, None);
+            }
+
+            Expression::FunctionCall(function_call) => {
+                self.resolve_function_call(function_call, scope);
+            }
+
+            Expression::FunctionCallOptions(function_call_options) => {
+                for option in function_call_options.options.iter_mut() {
+                    self.resolve_expression(option, scope, None);
+                }
+
+                for argument in function_call_options.arguments.iter_mut().flatten() {
+                    self.resolve_expression(argument, scope, None);
+                }
+
+                self.resolve_expression(function_call_options.expression.as_mut(), scope, argument_count);
+            }
+
+            Expression::IndexAccess(index_access) => {
+                self.resolve_expression(index_access.base_expression.as_mut(), scope, None);
+
+                if let Some(index_expression) = index_access.index_expression.as_mut() {
+                    self.resolve_expression(index_expression.as_mut(), scope, None);
+                }
+            }
+
+            Expression::IndexRangeAccess(index_range_access) => {
+                self.resolve_expression(index_range_access.base_expression.as_mut(), scope, None);
+
+                if let Some(start_expression) = index_range_access.start_expression.as_mut() {
+                    self.resolve_expression(start_expression.as_mut(), scope, None);
+                }
+
+                if let Some(end_expression) = index_range_access.end_expression.as_mut() {
+                    self.resolve_expression(end_expression.as_mut(), scope, None);
+                }
+            }
+
+            Expression::MemberAccess(member_access) => {
+                self.resolve_member_access(member_access, scope, argument_count);
+            }
+
+            Expression::ElementaryTypeNameExpression(elementary_type_name_expression) => {
+                self.resolve_type_name(&mut elementary_type_name_expression.type_name, scope);
+            }
+
+            Expression::TupleExpression(tuple_expression) => {
+                for component in tuple_expression.components.iter_mut().flatten() {
+                    self.resolve_expression(component, scope, None);
+                }
+            }
+
+            Expression::NewExpression(new_expression) => {
+                self.resolve_type_name(&mut new_expression.type_name, scope);
+            }
+        }
+    }
+
+    fn resolve_function_call(&self, function_call: &mut FunctionCall, scope: &mut Scope) {
+        for argument in function_call.arguments.iter_mut() {
+            self.resolve_expression(argument, scope, None);
+        }
+
+        self.resolve_expression(function_call.expression.as_mut(), scope, Some(function_call.arguments.len()));
+    }
+
+    fn resolve_identifier(&self, identifier: &mut Identifier, scope: &Scope, argument_count: Option<usize>) {
+        // Qualified names are used for errors in revert statements (i.e: `revert Errors.Unauthorized()`)
+        let candidates = if identifier.name.contains('.') {
+            self.lookup_path(identifier.name.as_str(), scope)
+        } else {
+            self.lookup_name(identifier.name.as_str(), scope)
+        };
+
+        if let Some(id) = self.select(candidates.as_slice(), argument_count) {
+            identifier.referenced_declaration = id;
+
+            if candidates.len() > 1 {
+                identifier.overloaded_declarations = candidates;
+            }
+        } else if let Some(id) = magic_variable_id(identifier.name.as_str()) {
+            identifier.referenced_declaration = id;
+        }
+    }
+
+    fn resolve_member_access(&self, member_access: &mut MemberAccess, scope: &mut Scope, argument_count: Option<usize>) {
+        self.resolve_expression(member_access.expression.as_mut(), scope, None);
+
+        let member_name = member_access.member_name.as_str();
+        let expression = member_access.expression.as_ref();
+
+        //
+        // Members of `super` are looked up in the base contracts of the current contract
+        //
+
+        if let (Expression::Identifier(Identifier { name, .. }), Some(contract_id)) = (expression, scope.contract) {
+            if name == "super" {
+                let candidates = self.contract_members(contract_id, member_name, true);
+                member_access.referenced_declaration = self.select(candidates.as_slice(), argument_count);
+                return;
+            }
+        }
+
+        //
+        // Members of contracts, enums and imported source units which are referenced by name
+        //
+
+        if let Some(id) = Self::referenced_declaration(expression) {
+            if let Some(declaration) = self.declarations.get(&id) {
+                if matches!(declaration.kind, DeclarationKind::Contract | DeclarationKind::Enum | DeclarationKind::SourceUnit) {
+                    let candidates = self.declaration_members(id, member_name);
+                    member_access.referenced_declaration = self.select(candidates.as_slice(), argument_count);
+                    return;
+                }
+            }
+        }
+
+        //
+        // Members of values with a contract or struct type
+        //
+
+        let value_type = self.expression_type(expression, scope);
+
+        if let Some(type_id) = value_type.as_ref().and_then(|value_type| self.value_type_declaration(value_type)) {
+            let candidates = match self.declarations.get(&type_id).map(|declaration| declaration.kind) {
+                Some(DeclarationKind::Contract) => self.contract_members(type_id, member_name, false),
+                Some(DeclarationKind::Struct) => self.declaration_members(type_id, member_name),
+                _ => vec![],
+            };
+
+            if !candidates.is_empty() {
+                member_access.referenced_declaration = self.select(candidates.as_slice(), argument_count);
+                return;
+            }
+        }
+
+        //
+        // Library functions attached to types with `using L for T` or `using {L.f} for T`, which receive the value as their first argument
+        //
+
+        let Some(contract_id) = scope.contract else { return };
+        let Some(linearized_base_contracts) = self.linearized_base_contracts.get(&contract_id) else { return };
+
+        let library_functions = linearized_base_contracts.iter()
+            .filter_map(|base_contract_id| self.using_for_libraries.get(base_contract_id))
+            .flatten()
+            .flat_map(|&library_id| self.contract_members(library_id, member_name, false));
+
+        let attached_functions = linearized_base_contracts.iter()
+            .filter_map(|base_contract_id| self.using_for_functions.get(base_contract_id))
+            .flatten()
+            .copied()
+            .filter(|id| self.declarations[id].name == member_name);
+
+        let candidates: Vec<NodeID> = library_functions
+            .chain(attached_functions)
+            .filter(|&id| self.is_kind(id, DeclarationKind::Function))
+            .collect();
+
+        if let Some(id) = self.select(candidates.as_slice(), argument_count.map(|argument_count| argument_count + 1)) {
+            member_access.referenced_declaration = Some(id);
+        }
+    }
+
+    /// Resolves the Solidity declarations referenced by an inline assembly block into its external references.
+    fn resolve_inline_assembly(&self, inline_assembly: &mut InlineAssembly, scope: &Scope) {
+        let mut external_references = vec![];
+
+        if let Some(yul_block) = inline_assembly.ast.as_ref() {
+            self.resolve_yul_block(yul_block, &mut vec![], scope, &mut external_references);
+        }
+
+        inline_assembly.external_references = external_references;
+    }
+
+    /// Resolves the external references of a Yul block.
+    fn resolve_yul_block(&self, yul_block: &YulBlock, yul_scopes: &mut Vec<YulScope>, scope: &Scope, external_references: &mut Vec<ExternalReference>) {
+        // Yul functions are visible throughout the block they are defined in
+        yul_scopes.push(YulScope {
+            functions: yul_block.statements.iter()
+                .filter_map(|statement| match statement {
+                    YulStatement::YulFunctionDefinition(function_definition) => Some(function_definition.name.clone()),
+                    _ => None,
+                })
+                .collect(),
+            variables: HashSet::new(),
+        });
+
+        for statement in yul_block.statements.iter() {
+            self.resolve_yul_statement(statement, yul_scopes, scope, external_references);
+        }
+
+        yul_scopes.pop();
+    }
+
+    fn resolve_yul_statement(&self, statement: &YulStatement, yul_scopes: &mut Vec<YulScope>, scope: &Scope, external_references: &mut Vec<ExternalReference>) {
+        match statement {
+            YulStatement::YulIf(yul_if) => {
+                self.resolve_yul_expression(&yul_if.condition, yul_scopes, scope, external_references);
+                self.resolve_yul_block(&yul_if.body, yul_scopes, scope, external_references);
+            }
+
+            YulStatement::YulSwitch(yul_switch) => {
+                self.resolve_yul_expression(&yul_switch.expression, yul_scopes, scope, external_references);
+
+                for yul_case in yul_switch.cases.iter() {
+                    self.resolve_yul_block(&yul_case.body, yul_scopes, scope, external_references);
+                }
+            }
+
+            YulStatement::YulForLoop(yul_for_loop) => {
+                // Variables declared in the pre block are visible in the rest of the loop
+                yul_scopes.push(YulScope::default());
+
+                for statement in yul_for_loop.pre.statements.iter() {
+                    self.resolve_yul_statement(statement, yul_scopes, scope, external_references);
+                }
+
+                self.resolve_yul_expression(&yul_for_loop.condition, yul_scopes, scope, external_references);
+                self.resolve_yul_block(&yul_for_loop.body, yul_scopes, scope, external_references);
+                self.resolve_yul_block(&yul_for_loop.post, yul_scopes, scope, external_references);
+
+                yul_scopes.pop();
+            }
+
+            YulStatement::YulAssignment(yul_assignment) => {
+                for yul_identifier in yul_assignment.variable_names.iter() {
+                    self.resolve_yul_identifier(yul_identifier, yul_scopes, scope, external_references);
+                }
+
+                self.resolve_yul_expression(&yul_assignment.value, yul_scopes, scope, external_references);
+            }
+
+            YulStatement::YulVariableDeclaration(yul_variable_declaration) => {
+                if let Some(value) = yul_variable_declaration.value.as_ref() {
+                    self.resolve_yul_expression(value, yul_scopes, scope, external_references);
+                }
+
+                if let Some(yul_scope) = yul_scopes.last_mut() {
+                    yul_scope.variables.extend(yul_variable_declaration.variables.iter().map(|variable| variable.name.clone()));
+                }
+            }
+
+            YulStatement::YulExpressionStatement(yul_expression_statement) => {
+                self.resolve_yul_expression(&yul_expression_statement.expression, yul_scopes, scope, external_references);
+            }
+
+            YulStatement::YulFunctionDefinition(yul_function_definition) => {
+                // Yul functions can only access their own variables and the functions which are visible to them
+                let mut function_scopes: Vec<YulScope> = yul_scopes.iter()
+                    .map(|yul_scope| YulScope { functions: yul_scope.functions.clone(), variables: HashSet::new() })
+                    .collect();
+
+                function_scopes.push(YulScope {
+                    functions: HashSet::new(),
+                    variables: yul_function_definition.parameters.iter()
+                        .chain(yul_function_definition.return_parameters.iter())
+                        .flatten()
+                        .map(|parameter| parameter.name.clone())
+                        .collect(),
+                });
+
+                self.resolve_yul_block(&yul_function_definition.body, &mut function_scopes, scope, external_references);
+            }
+
+            YulStatement::YulBlock(yul_block) => {
+                self.resolve_yul_block(yul_block, yul_scopes, scope, external_references);
+            }
+
+            YulStatement::YulLeave(_) | YulStatement::YulBreak(_) | YulStatement::YulContinue(_) => {}
+        }
+    }
+
+    fn resolve_yul_expression(&self, expression: &YulExpression, yul_scopes: &mut Vec<YulScope>, scope: &Scope, external_references: &mut Vec<ExternalReference>) {
+        match expression {
+            YulExpression::YulLiteral(_) => {}
+
+            YulExpression::YulIdentifier(yul_identifier) => {
+                self.resolve_yul_identifier(yul_identifier, yul_scopes, scope, external_references);
+            }
+
+            // Function names refer to builtins or Yul functions, never to Solidity declarations
+            YulExpression::YulFunctionCall(yul_function_call) => {
+                for argument in yul_function_call.arguments.iter() {
+                    self.resolve_yul_expression(argument, yul_scopes, scope, external_references);
+                }
+            }
+        }
+    }
+
+    fn resolve_yul_identifier(&self, yul_identifier: &YulIdentifier, yul_scopes: &[YulScope], scope: &Scope, external_references: &mut Vec<ExternalReference>) {
+        if yul_scopes.iter().any(|yul_scope| yul_scope.variables.contains(yul_identifier.name.as_str())) {
+            return;
+        }
+
+        // Suffixes access the storage location of a variable or the components of a calldata value (i.e: `x.slot` or `data.length`)
+        let (name, suffix) = match yul_identifier.name.split_once('.') {
+            Some((name, suffix)) => (name, Some(suffix)),
+            None => (yul_identifier.name.as_str(), None),
+        };
+
+        let Some(declaration) = self.lookup_name(name, scope).into_iter().find(|&id| self.is_kind(id, DeclarationKind::Variable)) else { return };
+
+        external_references.push(ExternalReference::Untagged(ExternalReferenceData {
+            declaration,
+            is_offset: suffix == Some("offset"),
+            is_slot: suffix == Some("slot"),
+            src: yul_identifier.src.clone(),
+            suffix: suffix.map(str::to_string),
+            value_size: 1,
+        }));
+    }
+}
+
+/// Resolves the references of all of the supplied source units, which may import each other.
+pub fn resolve_references(source_units: &mut [SourceUnit]) {
+    let resolver = NameResolver::new(source_units);
+
+    for source_unit in source_units.iter_mut() {
+        resolver.resolve_source_unit(source_unit);
+    }
+}
+
+/// Finds the index of the source unit imported by an import directive.
+pub fn find_imported_source_unit(source_units: &[SourceUnit], importer: &SourceUnit, import_directive: &ImportDirective) -> Option<usize> {
+    if let Some(index) = source_units.iter().position(|source_unit| source_unit.id == import_directive.source_unit) {
+        return Some(index);
+    }
+
+    let source_unit_paths: Vec<Option<PathBuf>> = source_units.iter()
+        .map(|source_unit| source_unit.absolute_path.as_deref().map(|path| normalize_path(Path::new(path))))
+        .collect();
+
+    let find_path = |path: &Path| {
+        let path = normalize_path(path);
+        source_unit_paths.iter().position(|source_unit_path| source_unit_path.as_deref() == Some(path.as_path()))
+    };
+
+    if let Some(index) = import_directive.absolute_path.as_deref().and_then(|path| find_path(Path::new(path))) {
+        return Some(index);
+    }
+
+    //
+    // Relative imports are resolved from the directory of the importing source unit
+    //
+
+    if import_directive.file.starts_with('.') {
+        let directory = importer.absolute_path.as_deref()
+            .and_then(|path| Path::new(path).parent())
+            .unwrap_or_else(|| Path::new(""));
+
+        if let Some(index) = find_path(directory.join(import_directive.file.as_str()).as_path()) {
+            return Some(index);
+        }
+    }
+
+    let file = normalize_path(Path::new(import_directive.file.as_str()));
+
+    source_unit_paths.iter().position(|source_unit_path| {
+        source_unit_path.as_ref().map(|path| path.ends_with(file.as_path())).unwrap_or(false)
+    })
+}
+
+/// Removes `.` and `..` components from a path without accessing the file system.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+
+            Component::ParentDir => {
+                if matches!(result.components().next_back(), None | Some(Component::ParentDir)) {
+                    result.push("..");
+                } else {
+                    result.pop();
+                }
+            }
+
+            component => result.push(component),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+interface IERC20 {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+}
+
+library Math {
+    function double(uint256 x) internal pure returns (uint256) {
+        return x * 2;
+    }
+}
+
+contract Token is IERC20 {
+    using Math for uint256;
+
+    mapping(address => uint256) balances;
+
+    function transfer(address to, uint256 amount) external returns (bool) {
+        uint256 total = amount.double();
+        balances[to] += total;
+        emit Transfer(msg.sender, to, total);
+        return true;
+    }
+}
+"#;
+
+    #[test]
+    fn test_resolve_references() {
+        let source_units = builder::build_test_source_units("Token.sol", SOURCE);
+        let contract_definitions = source_units[0].contract_definitions();
+
+        let transfer_event = contract_definitions[0].nodes.iter()
+            .find_map(|node| match node {
+                ContractDefinitionNode::EventDefinition(event_definition) => Some(event_definition),
+                _ => None,
+            })
+            .unwrap();
+
+        let double = contract_definitions[1].function_definitions()[0];
+
+        let token = contract_definitions[2];
+        let balances = token.variable_declarations()[0];
+        let transfer = token.function_definitions()[0];
+        let to = &transfer.parameters.parameters[0];
+        let amount = &transfer.parameters.parameters[1];
+
+        let statements = &transfer.body.as_ref().unwrap().statements;
+
+        // uint256 total = amount.double();
+        let Statement::VariableDeclarationStatement(declaration) = &statements[0] else { panic!("expected a variable declaration") };
+        let total = declaration.declarations[0].as_ref().unwrap();
+        let Some(Expression::FunctionCall(call)) = declaration.initial_value.as_ref() else { panic!("expected a function call") };
+        let Expression::MemberAccess(member_access) = call.expression.as_ref() else { panic!("expected a member access") };
+        assert_eq!(member_access.referenced_declaration, Some(double.id));
+        assert_eq!(member_access.expression.referenced_declarations(), vec![amount.id]);
+
+        // balances[to] += total;
+        let Statement::ExpressionStatement(ExpressionStatement { expression: Expression::Assignment(assignment) }) = &statements[1] else { panic!("expected an assignment") };
+        let Expression::IndexAccess(index_access) = assignment.left_hand_side.as_ref() else { panic!("expected an index access") };
+        assert_eq!(index_access.base_expression.referenced_declarations(), vec![balances.id]);
+        assert_eq!(index_access.index_expression.as_ref().unwrap().referenced_declarations(), vec![to.id]);
+        assert_eq!(assignment.right_hand_side.referenced_declarations(), vec![total.id]);
+
+        // emit Transfer(msg.sender, to, total);
+        let Statement::EmitStatement(emit_statement) = &statements[2] else { panic!("expected an emit statement") };
+        let Expression::FunctionCall(event_call) = &emit_statement.event_call else { panic!("expected a function call") };
+        let Expression::Identifier(event) = event_call.expression.as_ref() else { panic!("expected an identifier") };
+        assert_eq!(event.referenced_declaration, transfer_event.id);
+        let Expression::MemberAccess(sender) = &event_call.arguments[0] else { panic!("expected a member access") };
+        let Expression::Identifier(msg) = sender.expression.as_ref() else { panic!("expected an identifier") };
+        assert_eq!(Some(msg.referenced_declaration), magic_variable_id("msg"));
+    }
+}
@@ -0,0 +1,335 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use std::collections::HashMap;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Computes the selector of a function or error from its canonical signature, i.e: `transfer(address,uint256)` becomes `a9059cbb`.
+pub fn signature_selector(signature: &str) -> String {
+    signature_topic(signature)[..8].to_string()
+}
+
+/// Computes the topic of an event from its canonical signature, which is the keccak256 hash of the signature.
+pub fn signature_topic(signature: &str) -> String {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(signature.as_bytes());
+    keccak.finalize(&mut hash);
+
+    hash.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// The name of a type within the canonical signature of an external function, event or error.
+/// Library functions refer to structs and enums by their names instead of expanding them.
+pub fn abi_type_name(source_units: &[SourceUnit], type_name: &TypeName, in_library: bool) -> Option<String> {
+    Some(match type_name {
+        TypeName::ElementaryTypeName(ElementaryTypeName { name, .. }) | TypeName::String(name) => match name.as_str() {
+            "address payable" => "address".to_string(),
+            "uint" => "uint256".to_string(),
+            "int" => "int256".to_string(),
+            "byte" => "bytes1".to_string(),
+            name => name.to_string(),
+        },
+
+        TypeName::ArrayTypeName(array_type_name) => {
+            let length = match array_type_name.length.as_ref() {
+                Some(length) => {
+                    let value = Rational::parse(length.value.as_deref()?, length.subdenomination.as_deref())?;
+
+                    if !value.is_integer() || value.negative {
+                        return None;
+                    }
+
+                    value.numerator.to_string()
+                }
+
+                None => String::new(),
+            };
+
+            format!("{}[{length}]", abi_type_name(source_units, array_type_name.base_type.as_ref(), in_library)?)
+        }
+
+        TypeName::Mapping(mapping) if in_library => match mapping.type_descriptions.type_string.as_ref() {
+            Some(type_string) => type_string.clone(),
+            None => mapping.to_string(),
+        },
+
+        TypeName::Mapping(_) => return None,
+
+        TypeName::FunctionTypeName(function_type_name) if function_type_name.visibility == Visibility::External => "function".to_string(),
+        TypeName::FunctionTypeName(_) => return None,
+
+        TypeName::UserDefinedTypeName(user_defined_type_name) => {
+            let id = user_defined_type_name.referenced_declaration;
+
+            for source_unit in source_units.iter() {
+                if source_unit.contract_definition(id).is_some() {
+                    return Some("address".to_string());
+                }
+
+                if let Some(struct_definition) = source_unit.struct_definition(id) {
+                    if in_library {
+                        return Some(canonical_name(source_units, id, struct_definition.canonical_name.as_deref(), &struct_definition.name));
+                    }
+
+                    // Structs are encoded as tuples of their members
+                    let member_type_names = struct_definition.members.iter()
+                        .map(|member| abi_type_name(source_units, member.type_name.as_ref()?, in_library))
+                        .collect::<Option<Vec<_>>>()?;
+
+                    return Some(format!("({})", member_type_names.join(",")));
+                }
+
+                if let Some(enum_definition) = source_unit.enum_definition(id) {
+                    if in_library {
+                        return Some(canonical_name(source_units, id, enum_definition.canonical_name.as_deref(), &enum_definition.name));
+                    }
+
+                    return Some("uint8".to_string());
+                }
+
+                if let Some(definition) = source_unit.user_defined_value_type_definition(id) {
+                    return abi_type_name(source_units, &definition.underlying_type, in_library);
+                }
+            }
+
+            return None;
+        }
+    })
+}
+
+/// The canonical name of a struct or enum, qualified with the name of the contract which declares it.
+fn canonical_name(source_units: &[SourceUnit], id: NodeID, canonical_name: Option<&str>, name: &str) -> String {
+    if let Some(canonical_name) = canonical_name {
+        return canonical_name.to_string();
+    }
+
+    for source_unit in source_units.iter() {
+        for contract_definition in source_unit.contract_definitions() {
+            let declares = contract_definition.nodes.iter().any(|node| match node {
+                ContractDefinitionNode::StructDefinition(struct_definition) => struct_definition.id == id,
+                ContractDefinitionNode::EnumDefinition(enum_definition) => enum_definition.id == id,
+                _ => false,
+            });
+
+            if declares {
+                return format!("{}.{name}", contract_definition.name);
+            }
+        }
+    }
+
+    name.to_string()
+}
+
+fn signature(source_units: &[SourceUnit], name: &str, parameters: &[VariableDeclaration], in_library: bool) -> Option<String> {
+    let parameter_type_names = parameters.iter()
+        .map(|parameter| {
+            let type_name = parameter.type_name.as_ref()?;
+            let abi_type_name = abi_type_name(source_units, type_name, in_library)?;
+
+            // Library functions can receive references to storage, which are marked in their signatures
+            Some(if in_library && (parameter.storage_location == StorageLocation::Storage || matches!(type_name, TypeName::Mapping(_))) {
+                format!("{abi_type_name} storage")
+            } else {
+                abi_type_name
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(format!("{name}({})", parameter_type_names.join(",")))
+}
+
+/// The canonical signature of a public or external function, i.e: `transfer(address,uint256)`.
+pub fn function_signature(source_units: &[SourceUnit], function_definition: &FunctionDefinition) -> Option<String> {
+    if function_definition.kind != FunctionKind::Function || !matches!(function_definition.visibility, Visibility::Public | Visibility::External) {
+        return None;
+    }
+
+    let in_library = source_units.iter()
+        .find_map(|source_unit| source_unit.function_and_contract_definition(function_definition.id))
+        .map(|(contract_definition, _)| contract_definition.kind == ContractKind::Library)
+        .unwrap_or(false);
+
+    signature(source_units, function_definition.name.as_str(), function_definition.parameters.parameters.as_slice(), in_library)
+}
+
+/// The canonical signature of the getter function of a public state variable, i.e: `balanceOf(address)`.
+pub fn getter_signature(source_units: &[SourceUnit], variable_declaration: &VariableDeclaration) -> Option<String> {
+    if !variable_declaration.state_variable || variable_declaration.visibility != Visibility::Public {
+        return None;
+    }
+
+    // Getters take a key for each mapping and an index for each array
+    let mut parameter_type_names = vec![];
+    let mut type_name = variable_declaration.type_name.as_ref()?;
+
+    loop {
+        match type_name {
+            TypeName::Mapping(mapping) => {
+                parameter_type_names.push(abi_type_name(source_units, mapping.key_type.as_ref(), false)?);
+                type_name = mapping.value_type.as_ref();
+            }
+
+            TypeName::ArrayTypeName(array_type_name) => {
+                parameter_type_names.push("uint256".to_string());
+                type_name = array_type_name.base_type.as_ref();
+            }
+
+            _ => break,
+        }
+    }
+
+    Some(format!("{}({})", variable_declaration.name, parameter_type_names.join(",")))
+}
+
+/// The canonical signature of an event, i.e: `Transfer(address,address,uint256)`.
+pub fn event_signature(source_units: &[SourceUnit], event_definition: &EventDefinition) -> Option<String> {
+    signature(source_units, event_definition.name.as_str(), event_definition.parameters.parameters.as_slice(), false)
+}
+
+/// The canonical signature of an error, i.e: `InsufficientBalance(uint256,uint256)`.
+pub fn error_signature(source_units: &[SourceUnit], error_definition: &ErrorDefinition) -> Option<String> {
+    signature(source_units, error_definition.name.as_str(), error_definition.parameters.parameters.as_slice(), false)
+}
+
+/// Fills in the selectors of the functions, public state variables, events and errors which do not have one,
+/// i.e: those of an AST built from source code instead of being supplied by solc.
+pub fn compute_selectors(source_units: &mut [SourceUnit]) {
+    let mut selectors = HashMap::new();
+
+    for source_unit in source_units.iter() {
+        for node in source_unit.nodes.iter() {
+            match node {
+                SourceUnitNode::ErrorDefinition(error_definition) if error_definition.error_selector.is_none() => {
+                    if let Some(signature) = error_signature(source_units, error_definition) {
+                        selectors.insert(error_definition.id, signature_selector(&signature));
+                    }
+                }
+
+                SourceUnitNode::ContractDefinition(contract_definition) => {
+                    for node in contract_definition.nodes.iter() {
+                        let (id, selector) = match node {
+                            ContractDefinitionNode::FunctionDefinition(function_definition) if function_definition.function_selector.is_none() => {
+                                (function_definition.id, function_signature(source_units, function_definition).map(|signature| signature_selector(&signature)))
+                            }
+
+                            ContractDefinitionNode::VariableDeclaration(variable_declaration) if variable_declaration.function_selector.is_none() => {
+                                (variable_declaration.id, getter_signature(source_units, variable_declaration).map(|signature| signature_selector(&signature)))
+                            }
+
+                            ContractDefinitionNode::EventDefinition(event_definition) if event_definition.event_selector.is_none() => {
+                                (event_definition.id, event_signature(source_units, event_definition).map(|signature| signature_topic(&signature)))
+                            }
+
+                            ContractDefinitionNode::ErrorDefinition(error_definition) if error_definition.error_selector.is_none() => {
+                                (error_definition.id, error_signature(source_units, error_definition).map(|signature| signature_selector(&signature)))
+                            }
+
+                            _ => continue,
+                        };
+
+                        if let Some(selector) = selector {
+                            selectors.insert(id, selector);
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    for source_unit in source_units.iter_mut() {
+        for node in source_unit.nodes.iter_mut() {
+            match node {
+                SourceUnitNode::ErrorDefinition(error_definition) => {
+                    if let Some(selector) = selectors.remove(&error_definition.id) {
+                        error_definition.error_selector = Some(selector);
+                    }
+                }
+
+                SourceUnitNode::ContractDefinition(contract_definition) => {
+                    for node in contract_definition.nodes.iter_mut() {
+                        let (id, selector) = match node {
+                            ContractDefinitionNode::FunctionDefinition(function_definition) => (function_definition.id, &mut function_definition.function_selector),
+                            ContractDefinitionNode::VariableDeclaration(variable_declaration) => (variable_declaration.id, &mut variable_declaration.function_selector),
+                            ContractDefinitionNode::EventDefinition(event_definition) => (event_definition.id, &mut event_definition.event_selector),
+                            ContractDefinitionNode::ErrorDefinition(error_definition) => (error_definition.id, &mut error_definition.error_selector),
+                            _ => continue,
+                        };
+
+                        if let Some(value) = selectors.remove(&id) {
+                            *selector = Some(value);
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+library Pairs {
+    struct Pair { uint a; address b; }
+
+    function swap(Pair storage pair, mapping(address => uint256) storage balances, Pair memory other) public {}
+}
+
+contract Token {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    error Unauthorized(address caller);
+
+    mapping(address => mapping(uint256 => Pairs.Pair)) public pairs;
+
+    function transfer(address payable to, uint amount) external returns (bool) {}
+
+    function store(Pairs.Pair calldata pair, uint256[2][] memory grid) public {}
+
+    function helper() internal {}
+}
+"#;
+
+    #[test]
+    fn test_signatures() {
+        assert_eq!(signature_selector("transfer(address,uint256)"), "a9059cbb");
+        assert_eq!(signature_topic("Transfer(address,address,uint256)"), "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+        let source_units = builder::build_test_source_units("Token.sol", SOURCE);
+        let source_units = source_units.as_slice();
+        let contract_definitions = source_units[0].contract_definitions();
+
+        // Library functions name structs and mark references to storage
+        let swap = contract_definitions[0].function_definitions()[0];
+        assert_eq!(function_signature(source_units, swap).as_deref(), Some("swap(Pairs.Pair storage,mapping(address => uint256) storage,Pairs.Pair)"));
+
+        let signatures: Vec<_> = contract_definitions[1].nodes.iter()
+            .map(|node| match node {
+                ContractDefinitionNode::EventDefinition(event_definition) => event_signature(source_units, event_definition),
+                ContractDefinitionNode::ErrorDefinition(error_definition) => error_signature(source_units, error_definition),
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => getter_signature(source_units, variable_declaration),
+                ContractDefinitionNode::FunctionDefinition(function_definition) => function_signature(source_units, function_definition),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(signatures, vec![
+            Some("Transfer(address,address,uint256)".to_string()),
+            Some("Unauthorized(address)".to_string()),
+            Some("pairs(address,uint256)".to_string()),
+            Some("transfer(address,uint256)".to_string()),
+            Some("store((uint256,address),uint256[2][])".to_string()),
+            None,
+        ]);
+
+        // The selectors are filled in while type checking
+        let transfer = contract_definitions[1].function_definitions()[0];
+        assert_eq!(transfer.function_selector.as_deref(), Some("a9059cbb"));
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use std::io;
+
+/// A parsed `src` string in the form of `start:length:file`, where `start` and `length` are byte offsets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    pub start: usize,
+    pub length: usize,
+    pub file_index: Option<usize>,
+}
+
+impl SourceLocation {
+    pub fn end(&self) -> usize {
+        self.start + self.length
+    }
+}
+
+impl TryFrom<&str> for SourceLocation {
+    type Error = io::Error;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        let mut values: Vec<Option<i64>> = vec![];
+
+        for token in src.split(':') {
+            values.push(if token.is_empty() {
+                None
+            } else {
+                Some(token.parse().map_err(|error| {
+                    io::Error::new(io::ErrorKind::InvalidData, error)
+                })?)
+            });
+        }
+
+        let start = match values.first() {
+            Some(&Some(start)) if start >= 0 => start as usize,
+            _ => return Err(io::Error::new(io::ErrorKind::NotFound, format!("Invalid source location: \"{src}\""))),
+        };
+
+        let length = match values.get(1) {
+            Some(&Some(length)) if length >= 0 => length as usize,
+            _ => 0,
+        };
+
+        let file_index = match values.get(2) {
+            Some(&Some(file_index)) if file_index >= 0 => Some(file_index as usize),
+            _ => None,
+        };
+
+        Ok(Self { start, length, file_index })
+    }
+}
+
+/// A range of source text with 1-based lines and columns.
+/// Columns are counted in Unicode code points, and the end column is exclusive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceRange {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+/// The byte offsets of the start of each line in a source file, used to map byte offsets to lines and columns.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LineTable {
+    line_starts: Vec<usize>,
+}
+
+impl LineTable {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+
+        for (offset, byte) in source.bytes().enumerate() {
+            if byte == b'\n' {
+                line_starts.push(offset + 1);
+            }
+        }
+
+        Self { line_starts }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Gets the byte offset of the start of the supplied 1-based line.
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.line_starts.get(line.checked_sub(1)?).copied()
+    }
+
+    /// Maps a byte offset to a 1-based line and column.
+    /// Offsets past the end of the source or inside of a multi-byte character are clamped.
+    pub fn position(&self, source: &str, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(source.len());
+
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let line_index = match self.line_starts.binary_search(&offset) {
+            Ok(line_index) => line_index,
+            Err(line_index) => line_index - 1,
+        };
+
+        let column = source[self.line_starts[line_index]..offset].chars().count() + 1;
+
+        (line_index + 1, column)
+    }
+
+    pub fn range(&self, source: &str, location: SourceLocation) -> SourceRange {
+        let (start_line, start_column) = self.position(source, location.start);
+        let (end_line, end_column) = self.position(source, location.end());
+
+        SourceRange {
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, io};
+use serde::{de::Error as _, Deserialize, Serialize};
+use std::{borrow::Cow, cell::OnceCell, collections::HashMap, io};
 
 #[derive(Clone, Debug, Eq, Serialize, PartialEq)]
 #[serde(untagged)]
@@ -19,18 +19,18 @@ pub enum SourceUnitNode {
 impl<'de> Deserialize<'de> for SourceUnitNode {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let json = serde_json::Value::deserialize(deserializer)?;
-        let node_type = json.get("nodeType").unwrap().as_str().unwrap();
-
-        match node_type {
-            "PragmaDirective" => Ok(SourceUnitNode::PragmaDirective(serde_json::from_value(json).unwrap())),
-            "ImportDirective" => Ok(SourceUnitNode::ImportDirective(serde_json::from_value(json).unwrap())),
-            "ContractDefinition" => Ok(SourceUnitNode::ContractDefinition(serde_json::from_value(json).unwrap())),
-            "StructDefinition" => Ok(SourceUnitNode::StructDefinition(serde_json::from_value(json).unwrap())),
-            "EnumDefinition" => Ok(SourceUnitNode::EnumDefinition(serde_json::from_value(json).unwrap())),
-            "ErrorDefinition" => Ok(SourceUnitNode::ErrorDefinition(serde_json::from_value(json).unwrap())),
-            "VariableDeclaration" => Ok(SourceUnitNode::VariableDeclaration(serde_json::from_value(json).unwrap())),
-            "UserDefinedValueTypeDefinition" => Ok(SourceUnitNode::UserDefinedValueTypeDefinition(serde_json::from_value(json).unwrap())),
-            _ => panic!("Invalid source unit node type: {node_type}"),
+        let node_type = node_type::<D>(&json)?;
+
+        match node_type.as_str() {
+            "PragmaDirective" => Ok(SourceUnitNode::PragmaDirective(from_value::<D, _>(json)?)),
+            "ImportDirective" => Ok(SourceUnitNode::ImportDirective(from_value::<D, _>(json)?)),
+            "ContractDefinition" => Ok(SourceUnitNode::ContractDefinition(from_value::<D, _>(json)?)),
+            "StructDefinition" => Ok(SourceUnitNode::StructDefinition(from_value::<D, _>(json)?)),
+            "EnumDefinition" => Ok(SourceUnitNode::EnumDefinition(from_value::<D, _>(json)?)),
+            "ErrorDefinition" => Ok(SourceUnitNode::ErrorDefinition(from_value::<D, _>(json)?)),
+            "VariableDeclaration" => Ok(SourceUnitNode::VariableDeclaration(from_value::<D, _>(json)?)),
+            "UserDefinedValueTypeDefinition" => Ok(SourceUnitNode::UserDefinedValueTypeDefinition(from_value::<D, _>(json)?)),
+            _ => Err(D::Error::custom(format!("Invalid source unit node type: {node_type}"))),
         }
     }
 }
@@ -47,36 +47,63 @@ pub struct SourceUnit {
 
     #[serde(skip_serializing)]
     pub source: Option<String>,
+
+    /// The lazily-built line table of `source`, along with the source it was built from.
+    #[serde(skip)]
+    line_table: OnceCell<(String, LineTable)>,
 }
 
 impl SourceUnit {
+    /// Creates an empty source unit without any source code.
+    pub fn new(id: NodeID) -> Self {
+        Self {
+            license: None,
+            nodes: vec![],
+            exported_symbols: None,
+            experimental_solidity: None,
+            absolute_path: None,
+            id,
+            source: None,
+            line_table: OnceCell::new(),
+        }
+    }
+
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Replaces the source code of the source unit, discarding its cached line table.
+    pub fn set_source(&mut self, source: Option<String>) {
+        self.source = source;
+        self.line_table = OnceCell::new();
+    }
+
+    /// The line table of the source code, which is built the first time it is requested.
+    /// If `source` has been modified since then, a new line table is built instead of using the cached one.
+    pub fn line_table(&self) -> Option<Cow<'_, LineTable>> {
+        let source = self.source.as_deref()?;
+        let (cached_source, line_table) = self.line_table.get_or_init(|| (source.to_string(), LineTable::new(source)));
+
+        if cached_source == source {
+            Some(Cow::Borrowed(line_table))
+        } else {
+            Some(Cow::Owned(LineTable::new(source)))
+        }
+    }
+
     pub fn source_line(&self, src: &str) -> io::Result<usize> {
-        let source = match self.source.as_ref() {
-            Some(source) => source.as_str(),
+        Ok(self.source_range(src)?.start_line)
+    }
+
+    pub fn source_range(&self, src: &str) -> io::Result<SourceRange> {
+        let (source, line_table) = match (self.source.as_deref(), self.line_table()) {
+            (Some(source), Some(line_table)) => (source, line_table),
             _ => return Err(io::Error::from(io::ErrorKind::NotFound))
         };
 
-        let mut values: Vec<Option<usize>> = vec![];
-
-        for token in src.split(':') {
-            values.push(if token.is_empty() {
-                None
-            } else {
-                Some(token.parse().map_err(|error| {
-                    io::Error::new(io::ErrorKind::InvalidData, error)
-                })?)
-            });
-        }
+        let location = SourceLocation::try_from(src)?;
 
-        Ok(
-            source[..match values.first() {
-                Some(&Some(value)) => value,
-                _ => return Err(io::Error::from(io::ErrorKind::NotFound))
-            }]
-            .chars()
-            .filter(|&c| c == '\n')
-            .count() + 1
-        )
+        Ok(line_table.range(source, location))
     }
 
     pub fn pragma_directives(&self) -> Vec<&PragmaDirective> {
@@ -351,3 +378,26 @@ impl<'a> SourceUnitContext<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_range() {
+        let mut source_unit = SourceUnit::new(1);
+        assert!(source_unit.source_range("0:1:0").is_err());
+
+        source_unit.set_source(Some("contract A {}\n".to_string()));
+        assert_eq!(source_unit.source_line("9:1:0").unwrap(), 1);
+
+        // Assigning the source directly doesn't use the line table of the previous source
+        source_unit.source = Some("\n\ncontract A {}\n".to_string());
+        assert_eq!(source_unit.source_range("11:1:0").unwrap(), SourceRange { start_line: 3, start_column: 10, end_line: 3, end_column: 11 });
+        assert!(matches!(source_unit.line_table(), Some(Cow::Owned(_))));
+
+        source_unit.set_source(source_unit.source.clone());
+        assert!(matches!(source_unit.line_table(), Some(Cow::Borrowed(_))));
+        assert_eq!(source_unit.source_line("11:1:0").unwrap(), 3);
+    }
+}
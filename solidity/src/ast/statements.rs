@@ -1,6 +1,6 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Serialize};
 use std::fmt::Display;
 use yul::ast::*;
 
@@ -37,35 +37,35 @@ pub enum Statement {
 impl<'de> Deserialize<'de> for Statement {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let json = serde_json::Value::deserialize(deserializer)?;
-        let node_type = json.get("nodeType").unwrap().as_str().unwrap();
-
-        match node_type {
-            "VariableDeclarationStatement" => Ok(Statement::VariableDeclarationStatement(serde_json::from_value(json).unwrap())),
-            "IfStatement" => Ok(Statement::IfStatement(serde_json::from_value(json).unwrap())),
-            "ForStatement" => Ok(Statement::ForStatement(serde_json::from_value(json).unwrap())),
-            "WhileStatement" => Ok(Statement::WhileStatement(serde_json::from_value(json).unwrap())),
-            "DoWhileStatement" => Ok(Statement::DoWhileStatement(serde_json::from_value(json).unwrap())),
-            "EmitStatement" => Ok(Statement::EmitStatement(serde_json::from_value(json).unwrap())),
-            "TryStatement" => Ok(Statement::TryStatement(serde_json::from_value(json).unwrap())),
-            "UncheckedBlock" => Ok(Statement::UncheckedBlock(serde_json::from_value(json).unwrap())),
-            "Return" => Ok(Statement::Return(serde_json::from_value(json).unwrap())),
-            "RevertStatement" => Ok(Statement::RevertStatement(serde_json::from_value(json).unwrap())),
-            "ExpressionStatement" => Ok(Statement::ExpressionStatement(serde_json::from_value(json).unwrap())),
-            "Block" => Ok(Statement::Block(serde_json::from_value(json).unwrap())),
-            "InlineAssembly" => Ok(Statement::InlineAssembly(serde_json::from_value(json).unwrap())),
+        let node_type = node_type::<D>(&json)?;
+
+        match node_type.as_str() {
+            "VariableDeclarationStatement" => Ok(Statement::VariableDeclarationStatement(from_value::<D, _>(json)?)),
+            "IfStatement" => Ok(Statement::IfStatement(from_value::<D, _>(json)?)),
+            "ForStatement" => Ok(Statement::ForStatement(from_value::<D, _>(json)?)),
+            "WhileStatement" => Ok(Statement::WhileStatement(from_value::<D, _>(json)?)),
+            "DoWhileStatement" => Ok(Statement::DoWhileStatement(from_value::<D, _>(json)?)),
+            "EmitStatement" => Ok(Statement::EmitStatement(from_value::<D, _>(json)?)),
+            "TryStatement" => Ok(Statement::TryStatement(from_value::<D, _>(json)?)),
+            "UncheckedBlock" => Ok(Statement::UncheckedBlock(from_value::<D, _>(json)?)),
+            "Return" => Ok(Statement::Return(from_value::<D, _>(json)?)),
+            "RevertStatement" => Ok(Statement::RevertStatement(from_value::<D, _>(json)?)),
+            "ExpressionStatement" => Ok(Statement::ExpressionStatement(from_value::<D, _>(json)?)),
+            "Block" => Ok(Statement::Block(from_value::<D, _>(json)?)),
+            "InlineAssembly" => Ok(Statement::InlineAssembly(from_value::<D, _>(json)?)),
             "Continue" => Ok(Statement::Continue {
-                src: json.get("src").unwrap().as_str().unwrap().to_string(),
-                id: json.get("id").unwrap().as_i64().unwrap(),
+                src: field::<D, _>(&json, "src")?,
+                id: field::<D, _>(&json, "id")?,
             }),
-            "Break" => Ok(Statement::Continue {
-                src: json.get("src").unwrap().as_str().unwrap().to_string(),
-                id: json.get("id").unwrap().as_i64().unwrap(),
+            "Break" => Ok(Statement::Break {
+                src: field::<D, _>(&json, "src")?,
+                id: field::<D, _>(&json, "id")?,
             }),
-            "PlaceholderStatement" => Ok(Statement::Continue {
-                src: json.get("src").unwrap().as_str().unwrap().to_string(),
-                id: json.get("id").unwrap().as_i64().unwrap(),
+            "PlaceholderStatement" => Ok(Statement::PlaceholderStatement {
+                src: field::<D, _>(&json, "src")?,
+                id: field::<D, _>(&json, "id")?,
             }),
-            _ => panic!("Invalid statement node type: {node_type:?}"),
+            _ => Err(D::Error::custom(format!("Invalid statement node type: {node_type:?}"))),
         }
     }
 }
@@ -385,8 +385,26 @@ pub struct TryStatement {
 }
 
 impl Display for TryStatement {
-    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("try {}", self.external_call))?;
+
+        // The first clause is executed when the external call succeeds
+        let (success_clause, catch_clauses) = match self.clauses.split_first() {
+            Some(clauses) => clauses,
+            None => return Ok(()),
+        };
+
+        if let Some(parameters) = success_clause.parameters.as_ref().filter(|parameters| !parameters.parameters.is_empty()) {
+            f.write_fmt(format_args!(" returns {}", parameters))?;
+        }
+
+        f.write_fmt(format_args!(" {}", success_clause.block))?;
+
+        for clause in catch_clauses {
+            f.write_fmt(format_args!(" {}", clause))?;
+        }
+
+        Ok(())
     }
 }
 
@@ -429,8 +447,16 @@ pub struct TryCatchClause {
 }
 
 impl Display for TryCatchClause {
-    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("catch")?;
+
+        match (self.error_name.as_deref().filter(|error_name| !error_name.is_empty()), self.parameters.as_ref()) {
+            (Some(error_name), Some(parameters)) => f.write_fmt(format_args!(" {}{}", error_name, parameters))?,
+            (None, Some(parameters)) => f.write_fmt(format_args!(" {}", parameters))?,
+            _ => {}
+        }
+
+        f.write_fmt(format_args!(" {}", self.block))
     }
 }
 
@@ -486,3 +512,26 @@ pub struct InlineAssemblyContext<'a, 'b> {
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_statement_errors() {
+        let statement: Statement = serde_json::from_str(r#"{"nodeType": "Break", "src": "10:6:0", "id": 4}"#).unwrap();
+        assert_eq!(statement, Statement::Break { src: "10:6:0".to_string(), id: 4 });
+
+        let error = serde_json::from_str::<Statement>(r#"{"src": "10:6:0", "id": 4}"#).unwrap_err();
+        assert!(error.to_string().contains("missing field `nodeType`"));
+
+        let error = serde_json::from_str::<Statement>(r#"{"nodeType": "Continue", "src": "10:9:0"}"#).unwrap_err();
+        assert!(error.to_string().contains("missing field `id`"));
+
+        let error = serde_json::from_str::<Statement>(r#"{"nodeType": "GotoStatement", "src": "10:9:0", "id": 4}"#).unwrap_err();
+        assert!(error.to_string().contains("Invalid statement node type"));
+
+        let error = serde_json::from_str::<Statement>(r#"{"nodeType": "IfStatement", "src": "10:9:0", "id": 4}"#).unwrap_err();
+        assert!(error.to_string().contains("missing field"));
+    }
+}
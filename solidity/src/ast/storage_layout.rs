@@ -0,0 +1,554 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt::Display,
+};
+
+/// An error which prevents the storage layout of a contract from being computed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageLayoutError {
+    /// The type of a state variable or struct member could not be determined.
+    UnknownType {
+        contract_name: String,
+        variable_name: String,
+        src: String,
+    },
+    /// The type of a state variable or struct member cannot be kept in storage.
+    InvalidType {
+        contract_name: String,
+        variable_name: String,
+        type_string: String,
+        src: String,
+    },
+}
+
+impl Display for StorageLayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageLayoutError::UnknownType { contract_name, variable_name, src } => write!(
+                f,
+                "Unknown type of `{variable_name}` in the storage layout of contract `{contract_name}` ({src})",
+            ),
+
+            StorageLayoutError::InvalidType { contract_name, variable_name, type_string, src } => write!(
+                f,
+                "Type `{type_string}` of `{variable_name}` cannot be kept in the storage of contract `{contract_name}` ({src})",
+            ),
+        }
+    }
+}
+
+impl Error for StorageLayoutError {}
+
+/// How a value is stored, matching the `encoding` of a type in solc's `storageLayout` output.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageEncoding {
+    /// The value is stored in the slots it occupies.
+    Inplace,
+    /// The value is stored at the slot computed from the keccak256 hash of its key and the mapping's slot.
+    Mapping,
+    /// The length is stored in the array's slot, and its elements start at the keccak256 hash of the slot.
+    DynamicArray,
+    /// Short values are stored in the slot along with their length, and long values are stored like dynamic arrays.
+    Bytes,
+}
+
+/// The location of a state variable or struct member in storage.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageLayoutEntry {
+    pub ast_id: NodeID,
+    /// The fully qualified name of the contract whose layout contains the entry, i.e: `contracts/Token.sol:Token`.
+    pub contract: String,
+    pub label: String,
+    /// The offset of the value within its slot, in bytes.
+    pub offset: u32,
+    pub slot: String,
+    /// The key of the value's type within `StorageLayout::types`.
+    #[serde(rename = "type")]
+    pub type_key: String,
+}
+
+/// A type occurring within a storage layout.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageLayoutType {
+    /// The element type of an array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    pub encoding: StorageEncoding,
+    /// The key type of a mapping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    pub label: String,
+    /// The members of a struct, whose slots are relative to the slot of the struct.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<StorageLayoutEntry>>,
+    pub number_of_bytes: String,
+    /// The value type of a mapping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// The storage layout of a contract, in the same format as solc's `storageLayout` output.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct StorageLayout {
+    pub storage: Vec<StorageLayoutEntry>,
+    /// The types referenced by the entries, keyed by their identifiers. This is `None` when the contract has no storage.
+    pub types: Option<BTreeMap<String, StorageLayoutType>>,
+}
+
+impl StorageLayout {
+    pub fn entry(&self, id: NodeID) -> Option<&StorageLayoutEntry> {
+        self.storage.iter().find(|entry| entry.ast_id == id)
+    }
+
+    pub fn type_info(&self, type_key: &str) -> Option<&StorageLayoutType> {
+        self.types.as_ref()?.get(type_key)
+    }
+}
+
+/// The position of a value within storage, relative to the slot of the contract or struct containing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StoragePosition {
+    pub slot: U256,
+    /// The offset of the value within its slot, in bytes.
+    pub offset: u32,
+    /// The number of bytes the value occupies within its slot, which is 32 for values occupying entire slots.
+    pub bytes: u32,
+    /// The number of slots the value occupies.
+    pub slots: U256,
+}
+
+/// Computes the slots and offsets of state variables and struct members the same way solc does.
+///
+/// State variables are laid out in the order of the contract's linearized base contracts, starting with the most
+/// base contract. Constant and immutable variables are not kept in storage. Values are packed into a slot
+/// while they fit, except for structs and arrays, which always start a new slot and are followed by a new slot.
+pub struct StorageLayoutBuilder {
+    type_checker: TypeChecker,
+    /// The paths of the source units containing each contract, keyed by the contract's ID.
+    contract_paths: HashMap<NodeID, String>,
+    variable_names: HashMap<NodeID, (String, String)>,
+}
+
+impl StorageLayoutBuilder {
+    pub fn new(source_units: &[SourceUnit]) -> Self {
+        let mut variable_names = HashMap::new();
+
+        for source_unit in source_units.iter() {
+            for node in source_unit.nodes.iter() {
+                match node {
+                    SourceUnitNode::StructDefinition(struct_definition) => {
+                        for member in struct_definition.members.iter() {
+                            variable_names.insert(member.id, (member.name.clone(), member.src.clone()));
+                        }
+                    }
+
+                    SourceUnitNode::ContractDefinition(contract_definition) => {
+                        for struct_definition in contract_definition.struct_definitions() {
+                            for member in struct_definition.members.iter() {
+                                variable_names.insert(member.id, (member.name.clone(), member.src.clone()));
+                            }
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        Self {
+            type_checker: TypeChecker::new(source_units),
+            // Contract scopes are not source unit IDs in ASTs built from source, so contracts are found by containment
+            contract_paths: source_units.iter()
+                .flat_map(|source_unit| {
+                    let path = source_unit.absolute_path.clone().unwrap_or_default();
+
+                    source_unit.contract_definitions().into_iter()
+                        .map(move |contract_definition| (contract_definition.id, path.clone()))
+                })
+                .collect(),
+            variable_names,
+        }
+    }
+
+    /// Computes the storage layout of a contract, including the state variables of its base contracts.
+    pub fn contract_layout(&self, source_units: &[SourceUnit], contract_definition: &ContractDefinition) -> Result<StorageLayout, StorageLayoutError> {
+        let contract = format!(
+            "{}:{}",
+            self.contract_paths.get(&contract_definition.id).map(String::as_str).unwrap_or_default(),
+            contract_definition.name,
+        );
+
+        let mut variables = vec![];
+
+        for state_variable in contract_definition.effective_contract(source_units).state_variables {
+            let variable_declaration = state_variable.definition;
+
+            if variable_declaration.constant || matches!(variable_declaration.mutability, Some(Mutability::Constant | Mutability::Immutable)) {
+                continue;
+            }
+
+            let Some(variable_type) = self.type_checker.variable_type(variable_declaration.id) else {
+                return Err(StorageLayoutError::UnknownType {
+                    contract_name: contract_definition.name.clone(),
+                    variable_name: variable_declaration.name.clone(),
+                    src: variable_declaration.src.clone(),
+                });
+            };
+
+            variables.push((variable_declaration.id, variable_declaration.name.clone(), variable_declaration.src.clone(), variable_type.clone()));
+        }
+
+        let mut types = BTreeMap::new();
+        let storage = self.layout_entries(contract_definition, contract.as_str(), variables, &mut types)?;
+
+        Ok(StorageLayout {
+            storage,
+            types: if types.is_empty() { None } else { Some(types) },
+        })
+    }
+
+    /// The number of bytes a value of the supplied type occupies within a slot. Types occupying entire slots use 32 bytes.
+    pub fn storage_bytes(&self, value_type: &Type) -> Option<u32> {
+        Some(match value_type {
+            Type::Address { .. } | Type::Contract { .. } => 20,
+            Type::Bool | Type::Enum { .. } => 1,
+            Type::Integer { bits, .. } => *bits as u32 / 8,
+            Type::FixedBytes(size) => *size as u32,
+            Type::UserDefinedValueType { id, .. } => self.storage_bytes(self.type_checker.underlying_type(*id)?)?,
+
+            Type::Function(function_type) => match function_type.kind {
+                FunctionTypeKind::Internal => 8,
+                FunctionTypeKind::External => 24,
+                _ => return None,
+            },
+
+            Type::Bytes(_) | Type::String(_) | Type::Array { .. } | Type::Struct { .. } | Type::Mapping { .. } => 32,
+
+            _ => return None,
+        })
+    }
+
+    /// The number of slots a value of the supplied type occupies.
+    pub fn storage_size(&self, value_type: &Type) -> Option<U256> {
+        match value_type {
+            Type::Array { base_type, length: Some(length), .. } => {
+                let length = U256::from_dec_str(length).ok()?;
+                let base_bytes = self.storage_bytes(base_type)?;
+
+                if base_bytes < 32 {
+                    // Multiple elements are packed into each slot
+                    let elements_per_slot = U256::from(32 / base_bytes);
+                    Some((length + elements_per_slot - 1) / elements_per_slot)
+                } else {
+                    length.checked_mul(self.storage_size(base_type)?)
+                }
+            }
+
+            Type::Struct { id, .. } => {
+                let member_types = self.struct_member_types(*id)?;
+                let (slot_count, _) = self.compute_offsets(member_types.iter().map(|(_, member_type)| member_type))?;
+                Some(slot_count)
+            }
+
+            value_type => {
+                self.storage_bytes(value_type)?;
+                Some(U256::one())
+            }
+        }
+    }
+
+    /// The positions of the members of a struct, relative to the slot of the struct.
+    pub fn struct_member_positions(&self, id: NodeID) -> Option<Vec<(NodeID, StoragePosition)>> {
+        let member_types = self.struct_member_types(id)?;
+        let (_, offsets) = self.compute_offsets(member_types.iter().map(|(_, member_type)| member_type))?;
+
+        member_types.iter().zip(offsets)
+            .map(|((member_id, member_type), (slot, offset))| {
+                Some((*member_id, StoragePosition {
+                    slot,
+                    offset,
+                    bytes: self.storage_bytes(member_type)?,
+                    slots: self.storage_size(member_type)?,
+                }))
+            })
+            .collect()
+    }
+
+    /// The IDs and storage types of the members of a struct.
+    fn struct_member_types(&self, id: NodeID) -> Option<Vec<(NodeID, Type)>> {
+        self.type_checker.struct_members(id)?.iter()
+            .map(|&(_, member_id)| {
+                let member_type = self.type_checker.variable_type(member_id)?;
+                Some((member_id, member_type.with_location(DataLocation::StorageRef)))
+            })
+            .collect()
+    }
+
+    /// Computes the slot and offset of each supplied type when laid out in order, along with the total number of slots used.
+    fn compute_offsets<'t>(&self, types: impl Iterator<Item = &'t Type>) -> Option<(U256, Vec<(U256, u32)>)> {
+        let mut slot = U256::zero();
+        let mut offset = 0u32;
+        let mut offsets = vec![];
+
+        for value_type in types {
+            let bytes = self.storage_bytes(value_type)?;
+            let size = self.storage_size(value_type)?;
+
+            // Values which do not fit in the remainder of the current slot start a new slot
+            if offset + bytes > 32 {
+                slot += U256::one();
+                offset = 0;
+            }
+
+            offsets.push((slot, offset));
+
+            if size == U256::one() && offset + bytes <= 32 {
+                offset += bytes;
+            } else {
+                slot += size;
+                offset = 0;
+            }
+        }
+
+        if offset > 0 {
+            slot += U256::one();
+        }
+
+        Some((slot, offsets))
+    }
+
+    fn layout_entries(
+        &self,
+        contract_definition: &ContractDefinition,
+        contract: &str,
+        variables: Vec<(NodeID, String, String, Type)>,
+        types: &mut BTreeMap<String, StorageLayoutType>,
+    ) -> Result<Vec<StorageLayoutEntry>, StorageLayoutError> {
+        let Some((_, offsets)) = self.compute_offsets(variables.iter().map(|(_, _, _, variable_type)| variable_type)) else {
+            // Find the first variable whose type cannot be kept in storage
+            let (_, name, src, variable_type) = variables.iter()
+                .find(|(_, _, _, variable_type)| self.storage_size(variable_type).is_none())
+                .unwrap();
+
+            return Err(StorageLayoutError::InvalidType {
+                contract_name: contract_definition.name.clone(),
+                variable_name: name.clone(),
+                type_string: variable_type.type_string(true),
+                src: src.clone(),
+            });
+        };
+
+        let mut entries = vec![];
+
+        for ((id, name, _, variable_type), (slot, offset)) in variables.into_iter().zip(offsets) {
+            self.add_type(contract_definition, contract, &variable_type, types)?;
+
+            entries.push(StorageLayoutEntry {
+                ast_id: id,
+                contract: contract.to_string(),
+                label: name,
+                offset,
+                slot: slot.to_string(),
+                type_key: variable_type.rich_identifier(),
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn add_type(
+        &self,
+        contract_definition: &ContractDefinition,
+        contract: &str,
+        value_type: &Type,
+        types: &mut BTreeMap<String, StorageLayoutType>,
+    ) -> Result<(), StorageLayoutError> {
+        let type_key = value_type.rich_identifier();
+
+        if types.contains_key(&type_key) {
+            return Ok(());
+        }
+
+        // The sizes were already validated when computing the offsets of the values containing this type
+        let number_of_bytes = U256::from(self.storage_bytes(value_type).unwrap_or(32)) * self.storage_size(value_type).unwrap_or_default();
+
+        // Register the type before its members, since structs can contain themselves through arrays and mappings
+        types.insert(type_key.clone(), StorageLayoutType {
+            base: None,
+            encoding: StorageEncoding::Inplace,
+            key: None,
+            label: value_type.type_string(true),
+            members: None,
+            number_of_bytes: number_of_bytes.to_string(),
+            value: None,
+        });
+
+        let mut type_info = types[&type_key].clone();
+
+        match value_type {
+            Type::Struct { id, .. } => {
+                let member_types = self.struct_member_types(*id).unwrap_or_default();
+
+                let members = member_types.into_iter()
+                    .map(|(member_id, member_type)| {
+                        let (name, src) = self.variable_names.get(&member_id).cloned().unwrap_or_default();
+                        (member_id, name, src, member_type)
+                    })
+                    .collect();
+
+                type_info.members = Some(self.layout_entries(contract_definition, contract, members, types)?);
+            }
+
+            Type::Mapping { key_type, value_type } => {
+                type_info.encoding = StorageEncoding::Mapping;
+                type_info.key = Some(key_type.rich_identifier());
+                type_info.value = Some(value_type.rich_identifier());
+
+                self.add_type(contract_definition, contract, key_type, types)?;
+                self.add_type(contract_definition, contract, value_type, types)?;
+            }
+
+            Type::Bytes(_) | Type::String(_) => {
+                type_info.encoding = StorageEncoding::Bytes;
+            }
+
+            Type::Array { base_type, length, .. } => {
+                type_info.encoding = match length {
+                    Some(_) => StorageEncoding::Inplace,
+                    None => StorageEncoding::DynamicArray,
+                };
+
+                type_info.base = Some(base_type.rich_identifier());
+
+                self.add_type(contract_definition, contract, base_type, types)?;
+            }
+
+            _ => {}
+        }
+
+        types.insert(type_key, type_info);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+type Price is uint128;
+
+enum Status { A, B }
+
+struct Pair {
+    uint8 x;
+    uint256 y;
+    bool z;
+}
+
+contract Base {
+    uint128 a;
+    uint128 b;
+    uint8 c;
+    address d;
+    bool e;
+}
+
+contract Child is Base {
+    uint256 public constant K = 1;
+    address immutable owner = msg.sender;
+    Pair pair;
+    uint8[40] small;
+    uint256[] dynamic;
+    mapping(address => Pair) pairs;
+    string name;
+    Status status;
+    Price price;
+    bytes3 tag;
+    uint128[3] wide;
+    mapping(uint => mapping(address => uint[])) nested;
+}
+"#;
+
+    #[test]
+    fn test_contract_layout() {
+        let source_units = builder::build_test_source_units("contracts/Layout.sol", SOURCE);
+        let storage_layout_builder = StorageLayoutBuilder::new(source_units.as_slice());
+
+        let source_unit = &source_units[0];
+        let child = source_unit.contract_definitions().into_iter().find(|c| c.name == "Child").unwrap();
+
+        let id = |name: &str| source_unit.nodes.iter()
+            .find_map(|node| match node {
+                SourceUnitNode::StructDefinition(definition) if definition.name == name => Some(definition.id),
+                SourceUnitNode::EnumDefinition(definition) if definition.name == name => Some(definition.id),
+                SourceUnitNode::UserDefinedValueTypeDefinition(definition) if definition.name == name => Some(definition.id),
+                _ => None,
+            })
+            .unwrap();
+
+        let pair = format!("t_struct(Pair){}_storage", id("Pair"));
+
+        // The `storageLayout` of `Child` produced by solc 0.8.19, without the AST IDs of the variables
+        let expected = vec![
+            ("a", "0", 0, "t_uint128".to_string()),
+            ("b", "0", 16, "t_uint128".to_string()),
+            ("c", "1", 0, "t_uint8".to_string()),
+            ("d", "1", 1, "t_address".to_string()),
+            ("e", "1", 21, "t_bool".to_string()),
+            ("pair", "2", 0, pair.clone()),
+            ("small", "5", 0, "t_array(t_uint8)40_storage".to_string()),
+            ("dynamic", "7", 0, "t_array(t_uint256)dyn_storage".to_string()),
+            ("pairs", "8", 0, format!("t_mapping(t_address,{pair})")),
+            ("name", "9", 0, "t_string_storage".to_string()),
+            ("status", "10", 0, format!("t_enum(Status){}", id("Status"))),
+            ("price", "10", 1, format!("t_userDefinedValueType(Price){}", id("Price"))),
+            ("tag", "10", 17, "t_bytes3".to_string()),
+            ("wide", "11", 0, "t_array(t_uint128)3_storage".to_string()),
+            ("nested", "13", 0, "t_mapping(t_uint256,t_mapping(t_address,t_array(t_uint256)dyn_storage))".to_string()),
+        ];
+
+        let storage_layout = storage_layout_builder.contract_layout(source_units.as_slice(), child).unwrap();
+
+        let actual: Vec<_> = storage_layout.storage.iter()
+            .map(|entry| (entry.label.as_str(), entry.slot.as_str(), entry.offset, entry.type_key.clone()))
+            .collect();
+
+        assert_eq!(actual, expected);
+        assert!(storage_layout.storage.iter().all(|entry| entry.contract == "contracts/Layout.sol:Child"));
+
+        let types = storage_layout.types.unwrap();
+
+        let labels: Vec<_> = [pair.as_str(), "t_array(t_uint8)40_storage", "t_array(t_uint128)3_storage", "t_string_storage"].iter()
+            .map(|key| (types[*key].label.as_str(), types[*key].number_of_bytes.as_str(), types[*key].encoding))
+            .collect();
+
+        assert_eq!(labels, vec![
+            ("struct Pair", "96", StorageEncoding::Inplace),
+            ("uint8[40]", "64", StorageEncoding::Inplace),
+            ("uint128[3]", "64", StorageEncoding::Inplace),
+            ("string", "32", StorageEncoding::Bytes),
+        ]);
+
+        let members: Vec<_> = types[pair.as_str()].members.as_ref().unwrap().iter()
+            .map(|member| (member.label.as_str(), member.slot.as_str(), member.offset))
+            .collect();
+
+        assert_eq!(members, vec![("x", "0", 0), ("y", "1", 0), ("z", "2", 0)]);
+
+        let base = source_unit.contract_definitions().into_iter().find(|c| c.name == "Base").unwrap();
+        let base_layout = storage_layout_builder.contract_layout(source_units.as_slice(), base).unwrap();
+        assert_eq!(base_layout.storage[0].contract, "contracts/Layout.sol:Base");
+    }
+}
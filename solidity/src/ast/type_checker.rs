@@ -0,0 +1,2595 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use primitive_types::U512;
+use std::{cmp::Ordering, collections::HashMap, fmt::Display};
+use tiny_keccak::{Hasher, Keccak};
+
+/// The data location of a value with a reference type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataLocation {
+    /// A value which lives in storage, i.e: a state variable or a member of one.
+    StorageRef,
+    /// A local variable which points to a value in storage.
+    StoragePointer,
+    Memory,
+    Calldata,
+}
+
+impl DataLocation {
+    /// The location of the values contained in a value at this location.
+    pub fn member_location(self) -> Self {
+        match self {
+            DataLocation::StoragePointer => DataLocation::StorageRef,
+            location => location,
+        }
+    }
+
+    fn identifier_suffix(self) -> &'static str {
+        match self {
+            DataLocation::StorageRef => "_storage",
+            DataLocation::StoragePointer => "_storage_ptr",
+            DataLocation::Memory => "_memory_ptr",
+            DataLocation::Calldata => "_calldata_ptr",
+        }
+    }
+}
+
+impl Display for DataLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DataLocation::StorageRef => "storage ref",
+            DataLocation::StoragePointer => "storage pointer",
+            DataLocation::Memory => "memory",
+            DataLocation::Calldata => "calldata",
+        })
+    }
+}
+
+/// The kind of a function type, which determines how the function is called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FunctionTypeKind {
+    Declaration,
+    Internal,
+    External,
+    DelegateCall,
+    BareCall,
+    BareDelegateCall,
+    BareStaticCall,
+    Creation,
+    Send,
+    Transfer,
+    Keccak256,
+    Selfdestruct,
+    Revert,
+    ECRecover,
+    Sha256,
+    Ripemd160,
+    GasLeft,
+    Event,
+    Error,
+    Wrap,
+    Unwrap,
+    BlockHash,
+    BlobHash,
+    AddMod,
+    MulMod,
+    ArrayPush,
+    ArrayPop,
+    BytesConcat,
+    StringConcat,
+    ObjectCreation,
+    Assert,
+    Require,
+    ABIEncode,
+    ABIEncodePacked,
+    ABIEncodeWithSelector,
+    ABIEncodeCall,
+    ABIEncodeWithSignature,
+    ABIDecode,
+    MetaType,
+}
+
+impl FunctionTypeKind {
+    fn identifier(self) -> &'static str {
+        match self {
+            FunctionTypeKind::Declaration => "declaration",
+            FunctionTypeKind::Internal => "internal",
+            FunctionTypeKind::External => "external",
+            FunctionTypeKind::DelegateCall => "delegatecall",
+            FunctionTypeKind::BareCall => "barecall",
+            FunctionTypeKind::BareDelegateCall => "baredelegatecall",
+            FunctionTypeKind::BareStaticCall => "barestaticcall",
+            FunctionTypeKind::Creation => "creation",
+            FunctionTypeKind::Send => "send",
+            FunctionTypeKind::Transfer => "transfer",
+            FunctionTypeKind::Keccak256 => "keccak256",
+            FunctionTypeKind::Selfdestruct => "selfdestruct",
+            FunctionTypeKind::Revert => "revert",
+            FunctionTypeKind::ECRecover => "ecrecover",
+            FunctionTypeKind::Sha256 => "sha256",
+            FunctionTypeKind::Ripemd160 => "ripemd160",
+            FunctionTypeKind::GasLeft => "gasleft",
+            FunctionTypeKind::Event => "event",
+            FunctionTypeKind::Error => "error",
+            FunctionTypeKind::Wrap => "wrap",
+            FunctionTypeKind::Unwrap => "unwrap",
+            FunctionTypeKind::BlockHash => "blockhash",
+            FunctionTypeKind::BlobHash => "blobhash",
+            FunctionTypeKind::AddMod => "addmod",
+            FunctionTypeKind::MulMod => "mulmod",
+            FunctionTypeKind::ArrayPush => "arraypush",
+            FunctionTypeKind::ArrayPop => "arraypop",
+            FunctionTypeKind::BytesConcat => "bytesconcat",
+            FunctionTypeKind::StringConcat => "stringconcat",
+            FunctionTypeKind::ObjectCreation => "objectcreation",
+            FunctionTypeKind::Assert => "assert",
+            FunctionTypeKind::Require => "require",
+            FunctionTypeKind::ABIEncode => "abiencode",
+            FunctionTypeKind::ABIEncodePacked => "abiencodepacked",
+            FunctionTypeKind::ABIEncodeWithSelector => "abiencodewithselector",
+            FunctionTypeKind::ABIEncodeCall => "abiencodecall",
+            FunctionTypeKind::ABIEncodeWithSignature => "abiencodewithsignature",
+            FunctionTypeKind::ABIDecode => "abidecode",
+            FunctionTypeKind::MetaType => "metatype",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FunctionType {
+    pub kind: FunctionTypeKind,
+    pub parameter_types: Vec<Type>,
+    pub return_parameter_types: Vec<Type>,
+    pub state_mutability: StateMutability,
+    /// The qualified name (i.e: `Contract.function`) of a function which is referenced by its declaration.
+    pub declaration_name: Option<String>,
+    pub gas_set: bool,
+    pub value_set: bool,
+    pub salt_set: bool,
+    /// Whether the function is attached to the type of its first parameter (i.e: with `using ... for ...`).
+    pub attached: bool,
+}
+
+impl FunctionType {
+    pub fn new(kind: FunctionTypeKind, parameter_types: Vec<Type>, return_parameter_types: Vec<Type>, state_mutability: StateMutability) -> Self {
+        Self {
+            kind,
+            parameter_types,
+            return_parameter_types,
+            state_mutability,
+            declaration_name: None,
+            gas_set: false,
+            value_set: false,
+            salt_set: false,
+            attached: false,
+        }
+    }
+
+    /// The type of the value produced by calling the function.
+    pub fn return_type(&self) -> Type {
+        match self.return_parameter_types.as_slice() {
+            [return_type] => return_type.clone(),
+            return_types => Type::Tuple(return_types.iter().cloned().map(Some).collect()),
+        }
+    }
+}
+
+/// The type of a magic variable or of a `type(...)` expression.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MagicType {
+    Message,
+    Block,
+    Transaction,
+    Abi,
+    MetaType(Box<Type>),
+}
+
+/// The type of a value, matching the types used by solc.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Type {
+    Address { payable: bool },
+    Bool,
+    Integer { signed: bool, bits: u16 },
+    FixedBytes(u8),
+    Bytes(DataLocation),
+    String(DataLocation),
+    Array { base_type: Box<Type>, length: Option<String>, location: DataLocation },
+    ArraySlice(Box<Type>),
+    Mapping { key_type: Box<Type>, value_type: Box<Type> },
+    Struct { id: NodeID, name: String, canonical_name: String, location: DataLocation },
+    Enum { id: NodeID, name: String, canonical_name: String },
+    UserDefinedValueType { id: NodeID, name: String, canonical_name: String },
+    Contract { id: NodeID, name: String, is_library: bool, is_super: bool },
+    Function(Box<FunctionType>),
+    Modifier(Vec<Type>),
+    Tuple(Vec<Option<Type>>),
+    RationalNumber(Rational),
+    StringLiteral(Vec<u8>),
+    Magic(MagicType),
+    TypeType(Box<Type>),
+    Module { id: NodeID, path: String },
+}
+
+impl Type {
+    pub fn uint256() -> Self {
+        Type::Integer { signed: false, bits: 256 }
+    }
+
+    pub fn location(&self) -> Option<DataLocation> {
+        match self {
+            Type::Bytes(location) | Type::String(location) => Some(*location),
+            Type::Array { location, .. } | Type::Struct { location, .. } => Some(*location),
+            Type::ArraySlice(array_type) => array_type.location(),
+            _ => None,
+        }
+    }
+
+    /// Copies the type to a different data location. Types which are not reference types are unaffected.
+    pub fn with_location(&self, location: DataLocation) -> Self {
+        match self {
+            Type::Bytes(_) => Type::Bytes(location),
+            Type::String(_) => Type::String(location),
+
+            Type::Array { base_type, length, .. } => Type::Array {
+                base_type: Box::new(base_type.with_location(location.member_location())),
+                length: length.clone(),
+                location,
+            },
+
+            Type::Struct { id, name, canonical_name, .. } => Type::Struct {
+                id: *id,
+                name: name.clone(),
+                canonical_name: canonical_name.clone(),
+                location,
+            },
+
+            value_type => value_type.clone(),
+        }
+    }
+
+    /// The type a literal is converted to when it is stored in a variable without an explicit type.
+    pub fn mobile_type(&self) -> Option<Self> {
+        match self {
+            Type::RationalNumber(rational) => rational.integer_type(),
+            Type::StringLiteral(_) => Some(Type::String(DataLocation::Memory)),
+            value_type => Some(value_type.clone()),
+        }
+    }
+
+    /// The identifier of the type before it is escaped, which uses parentheses and commas for lists of types.
+    /// This is the key of the type within solc's `storageLayout` output (i.e: `t_array(t_uint256)dyn_storage`).
+    pub fn rich_identifier(&self) -> String {
+        fn list<'a>(types: impl Iterator<Item = Option<&'a Type>>) -> String {
+            let identifiers: Vec<String> = types
+                .map(|value_type| value_type.map(Type::rich_identifier).unwrap_or_else(|| "t_empty".to_string()))
+                .collect();
+
+            format!("({})", identifiers.join(","))
+        }
+
+        match self {
+            Type::Address { payable: false } => "t_address".to_string(),
+            Type::Address { payable: true } => "t_address_payable".to_string(),
+            Type::Bool => "t_bool".to_string(),
+            Type::Integer { signed, bits } => format!("t_{}int{bits}", if *signed { "" } else { "u" }),
+            Type::FixedBytes(size) => format!("t_bytes{size}"),
+            Type::Bytes(location) => format!("t_bytes{}", location.identifier_suffix()),
+            Type::String(location) => format!("t_string{}", location.identifier_suffix()),
+
+            Type::Array { base_type, length, location } => format!(
+                "t_array{}{}{}",
+                list(std::iter::once(Some(base_type.as_ref()))),
+                length.as_deref().unwrap_or("dyn"),
+                location.identifier_suffix(),
+            ),
+
+            Type::ArraySlice(array_type) => format!("{}_slice", array_type.rich_identifier()),
+            Type::Mapping { key_type, value_type } => format!("t_mapping{}", list([Some(key_type.as_ref()), Some(value_type.as_ref())].into_iter())),
+            Type::Struct { id, name, location, .. } => format!("t_struct({name}){id}{}", location.identifier_suffix()),
+            Type::Enum { id, name, .. } => format!("t_enum({name}){id}"),
+            Type::UserDefinedValueType { id, name, .. } => format!("t_userDefinedValueType({name}){id}"),
+            Type::Contract { id, name, is_super, .. } => format!("t_{}({name}){id}", if *is_super { "super" } else { "contract" }),
+
+            Type::Function(function_type) => {
+                let mut result = format!(
+                    "t_function_{}_{}{}returns{}",
+                    function_type.kind.identifier(),
+                    function_type.state_mutability,
+                    list(function_type.parameter_types.iter().map(Some)),
+                    list(function_type.return_parameter_types.iter().map(Some)),
+                );
+
+                if function_type.gas_set {
+                    result.push_str("gas");
+                }
+
+                if function_type.value_set {
+                    result.push_str("value");
+                }
+
+                if function_type.salt_set {
+                    result.push_str("salt");
+                }
+
+                if function_type.attached {
+                    result.push_str(format!("attached_to{}", list(function_type.parameter_types.first().into_iter().map(Some))).as_str());
+                }
+
+                result
+            }
+
+            Type::Modifier(parameter_types) => format!("t_modifier{}", list(parameter_types.iter().map(Some))),
+            Type::Tuple(components) => format!("t_tuple{}", list(components.iter().map(Option::as_ref))),
+            Type::RationalNumber(rational) => rational.identifier(),
+
+            Type::StringLiteral(value) => {
+                let mut hash = [0u8; 32];
+                let mut keccak = Keccak::v256();
+                keccak.update(value.as_slice());
+                keccak.finalize(&mut hash);
+
+                format!("t_stringliteral_{}", to_hex(&hash))
+            }
+
+            Type::Magic(MagicType::Message) => "t_magic_message".to_string(),
+            Type::Magic(MagicType::Block) => "t_magic_block".to_string(),
+            Type::Magic(MagicType::Transaction) => "t_magic_transaction".to_string(),
+            Type::Magic(MagicType::Abi) => "t_magic_abi".to_string(),
+            Type::Magic(MagicType::MetaType(type_argument)) => format!("t_magic_meta_type_{}", type_argument.rich_identifier()),
+            Type::TypeType(actual_type) => format!("t_type{}", list(std::iter::once(Some(actual_type.as_ref())))),
+            Type::Module { id, .. } => format!("t_module_{id}"),
+        }
+    }
+
+    /// The type identifier used by solc (i.e: `t_array$_t_uint256_$dyn_storage`).
+    pub fn identifier(&self) -> String {
+        self.rich_identifier()
+            .replace('$', "$$$")
+            .replace(',', "_$_")
+            .replace('(', "$_")
+            .replace(')', "_$")
+    }
+
+    /// The type string used by solc (i.e: `uint256[] storage ref`), optionally without the data location of reference types.
+    pub fn type_string(&self, without_data_location: bool) -> String {
+        let location_suffix = |location: &DataLocation| {
+            if without_data_location {
+                String::new()
+            } else {
+                format!(" {location}")
+            }
+        };
+
+        let join = |types: &[Type]| {
+            types.iter()
+                .map(|value_type| value_type.type_string(without_data_location))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+
+        match self {
+            Type::Address { payable: false } => "address".to_string(),
+            Type::Address { payable: true } => "address payable".to_string(),
+            Type::Bool => "bool".to_string(),
+            Type::Integer { signed, bits } => format!("{}int{bits}", if *signed { "" } else { "u" }),
+            Type::FixedBytes(size) => format!("bytes{size}"),
+            Type::Bytes(location) => format!("bytes{}", location_suffix(location)),
+            Type::String(location) => format!("string{}", location_suffix(location)),
+
+            Type::Array { base_type, length, location } => format!(
+                "{}[{}]{}",
+                base_type.type_string(true),
+                length.as_deref().unwrap_or(""),
+                location_suffix(location),
+            ),
+
+            Type::ArraySlice(array_type) => format!("{} slice", array_type.type_string(without_data_location)),
+            Type::Mapping { key_type, value_type } => format!("mapping({} => {})", key_type.type_string(true), value_type.type_string(true)),
+            Type::Struct { canonical_name, location, .. } => format!("struct {canonical_name}{}", location_suffix(location)),
+            Type::Enum { canonical_name, .. } => format!("enum {canonical_name}"),
+            Type::UserDefinedValueType { canonical_name, .. } => canonical_name.clone(),
+            Type::Contract { name, is_super: true, .. } => format!("contract super {name}"),
+            Type::Contract { name, is_library: true, .. } => format!("library {name}"),
+            Type::Contract { name, .. } => format!("contract {name}"),
+
+            Type::Function(function_type) => {
+                let mut result = format!(
+                    "function {}({})",
+                    function_type.declaration_name.as_deref().unwrap_or(""),
+                    join(function_type.parameter_types.as_slice()),
+                );
+
+                if function_type.state_mutability != StateMutability::NonPayable {
+                    result.push_str(format!(" {}", function_type.state_mutability).as_str());
+                }
+
+                if function_type.kind == FunctionTypeKind::External {
+                    result.push_str(" external");
+                }
+
+                if !function_type.return_parameter_types.is_empty() {
+                    result.push_str(format!(" returns ({})", join(function_type.return_parameter_types.as_slice())).as_str());
+                }
+
+                result
+            }
+
+            Type::Modifier(parameter_types) => format!("modifier ({})", join(parameter_types.as_slice())),
+
+            Type::Tuple(components) => format!(
+                "tuple({})",
+                components.iter()
+                    .map(|component| component.as_ref().map(|component| component.type_string(without_data_location)).unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+
+            Type::RationalNumber(rational) => rational.to_string(),
+
+            Type::StringLiteral(value) => {
+                if value.iter().all(|&c| (0x20..0x7f).contains(&c)) {
+                    format!("literal_string \"{}\"", String::from_utf8_lossy(value.as_slice()))
+                } else {
+                    format!("literal_string hex\"{}\"", to_hex(value.as_slice()))
+                }
+            }
+
+            Type::Magic(MagicType::Message) => "msg".to_string(),
+            Type::Magic(MagicType::Block) => "block".to_string(),
+            Type::Magic(MagicType::Transaction) => "tx".to_string(),
+            Type::Magic(MagicType::Abi) => "abi".to_string(),
+            Type::Magic(MagicType::MetaType(type_argument)) => format!("type({})", type_argument.type_string(without_data_location)),
+            Type::TypeType(actual_type) => format!("type({})", actual_type.type_string(without_data_location)),
+            Type::Module { path, .. } => format!("module \"{path}\""),
+        }
+    }
+
+    pub fn type_descriptions(&self, without_data_location: bool) -> TypeDescriptions {
+        TypeDescriptions {
+            type_identifier: Some(self.identifier()),
+            type_string: Some(self.type_string(without_data_location)),
+        }
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.type_string(false).as_str())
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn gcd(mut a: U512, mut b: U512) -> U512 {
+    while !b.is_zero() {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+
+    a
+}
+
+/// The exact value of a number literal or of a constant expression containing only number literals.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    pub negative: bool,
+    pub numerator: U512,
+    pub denominator: U512,
+}
+
+impl Rational {
+    pub fn new(negative: bool, numerator: U512, denominator: U512) -> Option<Self> {
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator);
+
+        Some(Self {
+            negative: negative && !numerator.is_zero(),
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    pub fn from_integer(value: U512) -> Self {
+        Self {
+            negative: false,
+            numerator: value,
+            denominator: U512::one(),
+        }
+    }
+
+    /// Parses the value of a number literal (i.e: `1_000`, `0xff` or `1.5e18`), multiplied by its subdenomination.
+    pub fn parse(value: &str, subdenomination: Option<&str>) -> Option<Self> {
+        let value: String = value.chars().filter(|&c| c != '_').collect();
+
+        let mut result = if let Some(digits) = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+            Self::from_integer(U512::from_str_radix(digits, 16).ok()?)
+        } else {
+            let (mantissa, exponent) = match value.find(['e', 'E']) {
+                Some(index) => (&value[..index], value[index + 1..].parse::<i64>().ok()?),
+                None => (value.as_str(), 0),
+            };
+
+            let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+            let digits = format!("{integer}{fraction}");
+
+            let mut result = Self::new(
+                false,
+                U512::from_dec_str(if digits.is_empty() { "0" } else { digits.as_str() }).ok()?,
+                U512::from(10u64).checked_pow(U512::from(fraction.len()))?,
+            )?;
+
+            let scale = U512::from(10u64).checked_pow(U512::from(exponent.unsigned_abs()))?;
+
+            result = if exponent < 0 {
+                Self::new(false, result.numerator, result.denominator.checked_mul(scale)?)?
+            } else {
+                Self::new(false, result.numerator.checked_mul(scale)?, result.denominator)?
+            };
+
+            result
+        };
+
+        let factor: u64 = match subdenomination {
+            None | Some("wei") | Some("seconds") => 1,
+            Some("gwei") => 1_000_000_000,
+            Some("szabo") => 1_000_000_000_000,
+            Some("finney") => 1_000_000_000_000_000,
+            Some("ether") => 1_000_000_000_000_000_000,
+            Some("minutes") => 60,
+            Some("hours") => 3_600,
+            Some("days") => 86_400,
+            Some("weeks") => 604_800,
+            Some("years") => 31_536_000,
+            Some(_) => return None,
+        };
+
+        if factor != 1 {
+            result = result.mul(&Self::from_integer(U512::from(factor)))?;
+        }
+
+        Some(result)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == U512::one()
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            negative: !self.negative && !self.numerator.is_zero(),
+            numerator: self.numerator,
+            denominator: self.denominator,
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Option<Self> {
+        let a = self.numerator.checked_mul(other.denominator)?;
+        let b = other.numerator.checked_mul(self.denominator)?;
+        let denominator = self.denominator.checked_mul(other.denominator)?;
+
+        if self.negative == other.negative {
+            Self::new(self.negative, a.checked_add(b)?, denominator)
+        } else if a >= b {
+            Self::new(self.negative, a - b, denominator)
+        } else {
+            Self::new(other.negative, b - a, denominator)
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Option<Self> {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &Self) -> Option<Self> {
+        Self::new(
+            self.negative != other.negative,
+            self.numerator.checked_mul(other.numerator)?,
+            self.denominator.checked_mul(other.denominator)?,
+        )
+    }
+
+    pub fn div(&self, other: &Self) -> Option<Self> {
+        Self::new(
+            self.negative != other.negative,
+            self.numerator.checked_mul(other.denominator)?,
+            self.denominator.checked_mul(other.numerator)?,
+        )
+    }
+
+    pub fn rem(&self, other: &Self) -> Option<Self> {
+        if !self.is_integer() || !other.is_integer() || other.numerator.is_zero() {
+            return None;
+        }
+
+        Self::new(self.negative, self.numerator % other.numerator, U512::one())
+    }
+
+    pub fn pow(&self, exponent: &Self) -> Option<Self> {
+        if !exponent.is_integer() || exponent.numerator > U512::from(4096u64) {
+            return None;
+        }
+
+        let negative = self.negative && exponent.numerator.bit(0);
+        let numerator = self.numerator.checked_pow(exponent.numerator)?;
+        let denominator = self.denominator.checked_pow(exponent.numerator)?;
+
+        if exponent.negative {
+            Self::new(negative, denominator, numerator)
+        } else {
+            Self::new(negative, numerator, denominator)
+        }
+    }
+
+    pub fn shl(&self, other: &Self) -> Option<Self> {
+        if !self.is_integer() || !other.is_integer() || other.negative || other.numerator > U512::from(4096u64) {
+            return None;
+        }
+
+        let factor = U512::from(2u64).checked_pow(other.numerator)?;
+        Self::new(self.negative, self.numerator.checked_mul(factor)?, U512::one())
+    }
+
+    pub fn shr(&self, other: &Self) -> Option<Self> {
+        if !self.is_integer() || !other.is_integer() || other.negative {
+            return None;
+        }
+
+        if other.numerator > U512::from(4096u64) {
+            return Some(if self.negative { Self::from_integer(U512::one()).neg() } else { Self::from_integer(U512::zero()) });
+        }
+
+        let divisor = U512::from(2u64).checked_pow(other.numerator)?;
+
+        // Negative values are rounded towards negative infinity
+        if self.negative {
+            Self::new(true, (self.numerator + divisor - 1) / divisor, U512::one())
+        } else {
+            Self::new(false, self.numerator / divisor, U512::one())
+        }
+    }
+
+    fn bitwise(&self, other: &Self, operation: impl Fn(U512, U512) -> U512) -> Option<Self> {
+        if !self.is_integer() || !other.is_integer() || self.negative || other.negative {
+            return None;
+        }
+
+        Some(Self::from_integer(operation(self.numerator, other.numerator)))
+    }
+
+    /// Evaluates a binary operation between two constants, if it produces another constant.
+    pub fn binary_operation(&self, operator: &str, other: &Self) -> Option<Self> {
+        match operator {
+            "+" => self.add(other),
+            "-" => self.sub(other),
+            "*" => self.mul(other),
+            "/" => self.div(other),
+            "%" => self.rem(other),
+            "**" => self.pow(other),
+            "<<" => self.shl(other),
+            ">>" => self.shr(other),
+            "&" => self.bitwise(other, |a, b| a & b),
+            "|" => self.bitwise(other, |a, b| a | b),
+            "^" => self.bitwise(other, |a, b| a ^ b),
+            _ => None,
+        }
+    }
+
+    pub fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self.negative, other.negative) {
+            (false, true) => Some(Ordering::Greater),
+            (true, false) => Some(Ordering::Less),
+
+            (negative, _) => {
+                let ordering = self.numerator.checked_mul(other.denominator)?
+                    .cmp(&other.numerator.checked_mul(self.denominator)?);
+
+                Some(if negative { ordering.reverse() } else { ordering })
+            }
+        }
+    }
+
+    /// The smallest integer type which can hold the value, if it is an integer which fits in 256 bits.
+    pub fn integer_type(&self) -> Option<Type> {
+        if !self.is_integer() {
+            return None;
+        }
+
+        // Negative values require the same number of bits as a positive value of twice their magnitude
+        let value = if self.negative {
+            (self.numerator - 1).checked_mul(U512::from(2u64))?
+        } else {
+            self.numerator
+        };
+
+        if value.bits() > 256 {
+            return None;
+        }
+
+        let bytes = value.bits().div_ceil(8).max(1);
+
+        Some(Type::Integer {
+            signed: self.negative,
+            bits: (bytes * 8) as u16,
+        })
+    }
+
+    fn identifier(&self) -> String {
+        format!(
+            "t_rational_{}{}_by_{}",
+            if self.negative { "minus_" } else { "" },
+            self.numerator,
+            self.denominator,
+        )
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn readable(value: String) -> String {
+            if value.len() > 32 {
+                format!("{}...({} digits omitted)...{}", &value[..4], value.len() - 8, &value[value.len() - 4..])
+            } else {
+                value
+            }
+        }
+
+        let numerator = readable(format!("{}{}", if self.negative { "-" } else { "" }, self.numerator));
+
+        if self.is_integer() {
+            f.write_fmt(format_args!("int_const {numerator}"))
+        } else {
+            f.write_fmt(format_args!("rational_const {numerator} / {}", readable(self.denominator.to_string())))
+        }
+    }
+}
+
+/// Converts the escape sequences of a string literal to the bytes they represent.
+pub fn unescape_string_literal(value: &str) -> Vec<u8> {
+    let mut result = vec![];
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0u8; 4];
+            result.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+
+        match take_escape(&mut chars) {
+            Some(EscapeKind::Hex) => {
+                if let Some(byte) = take_hex_digits(&mut chars, 2) {
+                    result.push(byte as u8);
+                }
+            }
+
+            Some(EscapeKind::Unicode) => {
+                if let Some(c) = take_hex_digits(&mut chars, 4).and_then(char::from_u32) {
+                    let mut buffer = [0u8; 4];
+                    result.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                }
+            }
+
+            Some(EscapeKind::Char(c)) => result.push(c),
+            Some(EscapeKind::LineContinuation) | None => {}
+        }
+    }
+
+    result
+}
+
+enum EscapeKind {
+    Hex,
+    Unicode,
+    Char(u8),
+    LineContinuation,
+}
+
+fn take_hex_digits(chars: &mut std::str::Chars, count: usize) -> Option<u32> {
+    let digits: String = chars.take(count).collect();
+    u32::from_str_radix(digits.as_str(), 16).ok()
+}
+
+fn take_escape(chars: &mut std::str::Chars) -> Option<EscapeKind> {
+    Some(match chars.next()? {
+        'x' => EscapeKind::Hex,
+        'u' => EscapeKind::Unicode,
+        'n' => EscapeKind::Char(b'\n'),
+        'r' => EscapeKind::Char(b'\r'),
+        't' => EscapeKind::Char(b'\t'),
+        '\n' => EscapeKind::LineContinuation,
+        c => EscapeKind::Char(c as u8),
+    })
+}
+
+/// The type information of a declaration which can be referenced from expressions.
+#[derive(Clone, Debug)]
+enum Definition {
+    SourceUnit {
+        path: String,
+    },
+    Contract {
+        name: String,
+        kind: ContractKind,
+        base_contracts: Vec<NodeID>,
+        constructor: Option<NodeID>,
+    },
+    Struct {
+        name: String,
+        canonical_name: String,
+        members: Vec<(String, NodeID)>,
+    },
+    Enum {
+        name: String,
+        canonical_name: String,
+    },
+    EnumValue {
+        enum_id: NodeID,
+    },
+    UserDefinedValueType {
+        name: String,
+        canonical_name: String,
+        underlying_type: Option<Type>,
+    },
+    Variable {
+        variable_type: Option<Type>,
+        state_variable: bool,
+    },
+    Function {
+        name: String,
+        contract: Option<NodeID>,
+        visibility: Visibility,
+        function_type: Option<FunctionType>,
+    },
+    Modifier {
+        parameter_types: Option<Vec<Type>>,
+    },
+    Event {
+        function_type: Option<FunctionType>,
+    },
+    Error {
+        function_type: Option<FunctionType>,
+    },
+}
+
+/// The declarations visible at the current position of the type checker.
+#[derive(Clone, Copy)]
+struct Scope {
+    contract: Option<NodeID>,
+}
+
+/// Infers the types of expressions, variables and type names and fills in their `TypeDescriptions`,
+/// along with the selectors of public functions, getters, events and errors.
+///
+/// The AST built from solang-parser does not contain any type information, so this produces the same
+/// type identifiers and type strings as solc. References must be resolved by `NameResolver` beforehand.
+#[derive(Default)]
+pub struct TypeChecker {
+    source_unit_ids: Vec<NodeID>,
+    definitions: HashMap<NodeID, Definition>,
+}
+
+impl TypeChecker {
+    pub fn new(source_units: &[SourceUnit]) -> Self {
+        let mut checker = Self::default();
+
+        //
+        // Collect the types which can be named, since the types of all other declarations depend on them
+        //
+
+        for source_unit in source_units.iter() {
+            checker.source_unit_ids.push(source_unit.id);
+
+            checker.definitions.insert(source_unit.id, Definition::SourceUnit {
+                path: source_unit.absolute_path.clone().unwrap_or_default(),
+            });
+
+            for node in source_unit.nodes.iter() {
+                match node {
+                    SourceUnitNode::ContractDefinition(contract_definition) => {
+                        checker.add_contract_definition(contract_definition);
+                    }
+
+                    SourceUnitNode::StructDefinition(struct_definition) => {
+                        checker.add_struct_definition(None, struct_definition);
+                    }
+
+                    SourceUnitNode::EnumDefinition(enum_definition) => {
+                        checker.add_enum_definition(None, enum_definition);
+                    }
+
+                    SourceUnitNode::UserDefinedValueTypeDefinition(definition) => {
+                        checker.add_user_defined_value_type_definition(None, definition);
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        //
+        // Collect the types of all variables, functions, modifiers, events and errors
+        //
+
+        for source_unit in source_units.iter() {
+            for node in source_unit.nodes.iter() {
+                match node {
+                    SourceUnitNode::ContractDefinition(contract_definition) => {
+                        checker.add_contract_members(contract_definition);
+                    }
+
+                    SourceUnitNode::StructDefinition(struct_definition) => {
+                        checker.add_struct_members(struct_definition);
+                    }
+
+                    SourceUnitNode::ErrorDefinition(error_definition) => {
+                        checker.add_error_definition(error_definition);
+                    }
+
+                    SourceUnitNode::VariableDeclaration(variable_declaration) => {
+                        checker.add_variable_declaration(variable_declaration, DataLocation::Memory, false);
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        checker
+    }
+
+    /// The type of a variable, including its data location.
+    pub fn variable_type(&self, id: NodeID) -> Option<&Type> {
+        match self.definitions.get(&id)? {
+            Definition::Variable { variable_type, .. } => variable_type.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The names and IDs of the members of a struct, in declaration order.
+    pub fn struct_members(&self, id: NodeID) -> Option<&[(String, NodeID)]> {
+        match self.definitions.get(&id)? {
+            Definition::Struct { members, .. } => Some(members.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// The value type underlying a user-defined value type.
+    pub fn underlying_type(&self, id: NodeID) -> Option<&Type> {
+        match self.definitions.get(&id)? {
+            Definition::UserDefinedValueType { underlying_type, .. } => underlying_type.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Fills in the type descriptions within the supplied source unit, which must have been supplied to `TypeChecker::new`.
+    pub fn check_source_unit(&self, source_unit: &mut SourceUnit) {
+        if !self.source_unit_ids.contains(&source_unit.id) {
+            return;
+        }
+
+        let scope = Scope { contract: None };
+
+        for node in source_unit.nodes.iter_mut() {
+            match node {
+                SourceUnitNode::PragmaDirective(_) | SourceUnitNode::ImportDirective(_) | SourceUnitNode::EnumDefinition(_) => {}
+
+                SourceUnitNode::ContractDefinition(contract_definition) => {
+                    self.check_contract_definition(contract_definition);
+                }
+
+                SourceUnitNode::StructDefinition(struct_definition) => {
+                    for member in struct_definition.members.iter_mut() {
+                        self.check_variable_declaration(member, scope);
+                    }
+                }
+
+                SourceUnitNode::ErrorDefinition(error_definition) => {
+                    self.check_parameter_list(&mut error_definition.parameters, scope);
+                }
+
+                SourceUnitNode::VariableDeclaration(variable_declaration) => {
+                    self.check_variable_declaration(variable_declaration, scope);
+                }
+
+                SourceUnitNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.check_type_name(&mut definition.underlying_type);
+                }
+            }
+        }
+    }
+
+    //
+    // Declaration collection
+    //
+
+    fn add_contract_definition(&mut self, contract_definition: &ContractDefinition) {
+        let contract = Some(contract_definition);
+        let mut constructor = None;
+
+        for node in contract_definition.nodes.iter() {
+            match node {
+                ContractDefinitionNode::StructDefinition(struct_definition) => {
+                    self.add_struct_definition(contract, struct_definition);
+                }
+
+                ContractDefinitionNode::EnumDefinition(enum_definition) => {
+                    self.add_enum_definition(contract, enum_definition);
+                }
+
+                ContractDefinitionNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.add_user_defined_value_type_definition(contract, definition);
+                }
+
+                ContractDefinitionNode::FunctionDefinition(function_definition) if function_definition.kind == FunctionKind::Constructor => {
+                    constructor = Some(function_definition.id);
+                }
+
+                _ => {}
+            }
+        }
+
+        self.definitions.insert(contract_definition.id, Definition::Contract {
+            name: contract_definition.name.clone(),
+            kind: contract_definition.kind.clone(),
+            base_contracts: contract_definition.base_contracts.iter()
+                .filter_map(|base_contract| base_contract.base_name.referenced_declaration)
+                .collect(),
+            constructor,
+        });
+    }
+
+    fn canonical_name(contract: Option<&ContractDefinition>, name: &str) -> String {
+        match contract {
+            Some(contract_definition) => format!("{}.{name}", contract_definition.name),
+            None => name.to_string(),
+        }
+    }
+
+    fn add_struct_definition(&mut self, contract: Option<&ContractDefinition>, struct_definition: &StructDefinition) {
+        self.definitions.insert(struct_definition.id, Definition::Struct {
+            name: struct_definition.name.clone(),
+            canonical_name: Self::canonical_name(contract, struct_definition.name.as_str()),
+            members: struct_definition.members.iter().map(|member| (member.name.clone(), member.id)).collect(),
+        });
+    }
+
+    fn add_enum_definition(&mut self, contract: Option<&ContractDefinition>, enum_definition: &EnumDefinition) {
+        for value in enum_definition.members.iter() {
+            self.definitions.insert(value.id, Definition::EnumValue { enum_id: enum_definition.id });
+        }
+
+        self.definitions.insert(enum_definition.id, Definition::Enum {
+            name: enum_definition.name.clone(),
+            canonical_name: Self::canonical_name(contract, enum_definition.name.as_str()),
+        });
+    }
+
+    fn add_user_defined_value_type_definition(&mut self, contract: Option<&ContractDefinition>, definition: &UserDefinedValueTypeDefinition) {
+        // The underlying type is always an elementary type, so it does not depend on any other declarations
+        let underlying_type = match &definition.underlying_type {
+            TypeName::ElementaryTypeName(elementary_type_name) => Self::elementary_type(elementary_type_name.name.as_str(), elementary_type_name.state_mutability, DataLocation::Memory),
+            TypeName::String(name) => Self::elementary_type(name.as_str(), None, DataLocation::Memory),
+            _ => None,
+        };
+
+        self.definitions.insert(definition.id, Definition::UserDefinedValueType {
+            name: definition.name.clone(),
+            canonical_name: Self::canonical_name(contract, definition.name.as_str()),
+            underlying_type,
+        });
+    }
+
+    fn add_contract_members(&mut self, contract_definition: &ContractDefinition) {
+        let contract = Some(contract_definition.id);
+
+        for node in contract_definition.nodes.iter() {
+            match node {
+                ContractDefinitionNode::StructDefinition(struct_definition) => {
+                    self.add_struct_members(struct_definition);
+                }
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => {
+                    let location = if variable_declaration.constant {
+                        DataLocation::Memory
+                    } else {
+                        DataLocation::StorageRef
+                    };
+
+                    self.add_variable_declaration(variable_declaration, location, true);
+                }
+
+                ContractDefinitionNode::EventDefinition(event_definition) => {
+                    let parameter_types = self.add_parameter_list(&event_definition.parameters, DataLocation::Memory);
+
+                    self.definitions.insert(event_definition.id, Definition::Event {
+                        function_type: parameter_types.map(|parameter_types| {
+                            FunctionType::new(FunctionTypeKind::Event, parameter_types, vec![], StateMutability::NonPayable)
+                        }),
+                    });
+                }
+
+                ContractDefinitionNode::FunctionDefinition(function_definition) => {
+                    self.add_function_definition(contract, contract_definition.kind.clone(), function_definition);
+                }
+
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
+                    let parameter_types = self.add_parameter_list(&modifier_definition.parameters, DataLocation::Memory);
+                    self.add_block(&modifier_definition.body);
+                    self.definitions.insert(modifier_definition.id, Definition::Modifier { parameter_types });
+                }
+
+                ContractDefinitionNode::ErrorDefinition(error_definition) => {
+                    self.add_error_definition(error_definition);
+                }
+
+                ContractDefinitionNode::UsingForDirective(_)
+                | ContractDefinitionNode::EnumDefinition(_)
+                | ContractDefinitionNode::UserDefinedValueTypeDefinition(_) => {}
+            }
+        }
+    }
+
+    fn add_struct_members(&mut self, struct_definition: &StructDefinition) {
+        for member in struct_definition.members.iter() {
+            self.add_variable_declaration(member, DataLocation::StoragePointer, false);
+        }
+    }
+
+    fn add_error_definition(&mut self, error_definition: &ErrorDefinition) {
+        let parameter_types = self.add_parameter_list(&error_definition.parameters, DataLocation::Memory);
+
+        self.definitions.insert(error_definition.id, Definition::Error {
+            function_type: parameter_types.map(|parameter_types| {
+                FunctionType::new(FunctionTypeKind::Error, parameter_types, vec![], StateMutability::Pure)
+            }),
+        });
+    }
+
+    fn add_function_definition(&mut self, contract: Option<NodeID>, contract_kind: ContractKind, function_definition: &FunctionDefinition) {
+        // Reference parameters without a data location (before 0.5.0) are in calldata for external functions
+        let parameter_location = if function_definition.visibility == Visibility::External && contract_kind != ContractKind::Library {
+            DataLocation::Calldata
+        } else {
+            DataLocation::Memory
+        };
+
+        let parameter_types = self.add_parameter_list(&function_definition.parameters, parameter_location);
+        let return_parameter_types = self.add_parameter_list(&function_definition.return_parameters, DataLocation::Memory);
+
+        if let Some(body) = function_definition.body.as_ref() {
+            self.add_block(body);
+        }
+
+        let kind = if function_definition.visibility == Visibility::External {
+            FunctionTypeKind::External
+        } else {
+            FunctionTypeKind::Internal
+        };
+
+        self.definitions.insert(function_definition.id, Definition::Function {
+            name: function_definition.name.clone(),
+            contract,
+            visibility: function_definition.visibility,
+            function_type: parameter_types.zip(return_parameter_types).map(|(parameter_types, return_parameter_types)| {
+                FunctionType::new(kind, parameter_types, return_parameter_types, function_definition.state_mutability)
+            }),
+        });
+    }
+
+    fn add_variable_declaration(&mut self, variable_declaration: &VariableDeclaration, default_location: DataLocation, state_variable: bool) -> Option<Type> {
+        let variable_type = self.variable_declaration_type(variable_declaration, default_location);
+
+        self.definitions.insert(variable_declaration.id, Definition::Variable {
+            variable_type: variable_type.clone(),
+            state_variable,
+        });
+
+        variable_type
+    }
+
+    fn add_parameter_list(&mut self, parameter_list: &ParameterList, default_location: DataLocation) -> Option<Vec<Type>> {
+        parameter_list.parameters.iter()
+            .map(|parameter| self.add_variable_declaration(parameter, default_location, false))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    fn add_block(&mut self, block: &Block) {
+        for statement in block.statements.iter() {
+            self.add_statement(statement);
+        }
+    }
+
+    fn add_block_or_statement(&mut self, block_or_statement: &BlockOrStatement) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.add_block(block),
+            BlockOrStatement::Statement(statement) => self.add_statement(statement),
+        }
+    }
+
+    fn add_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                // Local variables without a data location (before 0.5.0) point to storage
+                for variable_declaration in variable_declaration_statement.declarations.iter().flatten() {
+                    self.add_variable_declaration(variable_declaration, DataLocation::StoragePointer, false);
+                }
+            }
+
+            Statement::IfStatement(if_statement) => {
+                self.add_block_or_statement(&if_statement.true_body);
+
+                if let Some(false_body) = if_statement.false_body.as_ref() {
+                    self.add_block_or_statement(false_body);
+                }
+            }
+
+            Statement::ForStatement(for_statement) => {
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_ref() {
+                    self.add_statement(initialization_expression);
+                }
+
+                self.add_block_or_statement(&for_statement.body);
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                self.add_block_or_statement(&while_statement.body);
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                self.add_block_or_statement(&do_while_statement.body);
+            }
+
+            Statement::TryStatement(try_statement) => {
+                for clause in try_statement.clauses.iter() {
+                    if let Some(parameters) = clause.parameters.as_ref() {
+                        self.add_parameter_list(parameters, DataLocation::Memory);
+                    }
+
+                    self.add_block(&clause.block);
+                }
+            }
+
+            Statement::UncheckedBlock(block) | Statement::Block(block) => {
+                self.add_block(block);
+            }
+
+            _ => {}
+        }
+    }
+
+    //
+    // Type names
+    //
+
+    /// Determines the type of an elementary type name (i.e: `uint256`, `address payable` or `string`).
+    fn elementary_type(name: &str, state_mutability: Option<StateMutability>, location: DataLocation) -> Option<Type> {
+        let bits = |suffix: &str| -> Option<u16> {
+            if suffix.is_empty() {
+                return Some(256);
+            }
+
+            let bits = suffix.parse::<u16>().ok()?;
+
+            if bits == 0 || bits > 256 || bits % 8 != 0 {
+                return None;
+            }
+
+            Some(bits)
+        };
+
+        Some(match name {
+            "address" => Type::Address { payable: state_mutability == Some(StateMutability::Payable) },
+            "address payable" | "payable" => Type::Address { payable: true },
+            "bool" => Type::Bool,
+            "string" => Type::String(location),
+            "bytes" => Type::Bytes(location),
+            "byte" => Type::FixedBytes(1),
+
+            _ => {
+                if let Some(suffix) = name.strip_prefix("uint") {
+                    Type::Integer { signed: false, bits: bits(suffix)? }
+                } else if let Some(suffix) = name.strip_prefix("int") {
+                    Type::Integer { signed: true, bits: bits(suffix)? }
+                } else if let Some(suffix) = name.strip_prefix("bytes") {
+                    let size = suffix.parse::<u8>().ok()?;
+
+                    if size == 0 || size > 32 {
+                        return None;
+                    }
+
+                    Type::FixedBytes(size)
+                } else {
+                    return None;
+                }
+            }
+        })
+    }
+
+    /// Determines the type of a type name, placing reference types in the supplied data location.
+    pub fn type_name_type(&self, type_name: &TypeName, location: DataLocation) -> Option<Type> {
+        match type_name {
+            TypeName::ElementaryTypeName(elementary_type_name) => {
+                Self::elementary_type(elementary_type_name.name.as_str(), elementary_type_name.state_mutability, location)
+            }
+
+            TypeName::String(name) => Self::elementary_type(name.as_str(), None, location),
+
+            TypeName::UserDefinedTypeName(user_defined_type_name) => {
+                self.declaration_value_type(user_defined_type_name.referenced_declaration, location)
+            }
+
+            TypeName::ArrayTypeName(array_type_name) => {
+                let length = match array_type_name.length.as_ref() {
+                    Some(length) => {
+                        let value = Rational::parse(length.value.as_deref()?, length.subdenomination.as_deref())?;
+
+                        if !value.is_integer() || value.negative {
+                            return None;
+                        }
+
+                        Some(value.numerator.to_string())
+                    }
+
+                    None => None,
+                };
+
+                Some(Type::Array {
+                    base_type: Box::new(self.type_name_type(array_type_name.base_type.as_ref(), location.member_location())?),
+                    length,
+                    location,
+                })
+            }
+
+            TypeName::Mapping(mapping) => Some(Type::Mapping {
+                key_type: Box::new(self.type_name_type(mapping.key_type.as_ref(), DataLocation::Memory)?),
+                value_type: Box::new(self.type_name_type(mapping.value_type.as_ref(), DataLocation::StorageRef)?),
+            }),
+
+            TypeName::FunctionTypeName(function_type_name) => {
+                let types = |parameter_list: &ParameterList| -> Option<Vec<Type>> {
+                    parameter_list.parameters.iter()
+                        .map(|parameter| self.variable_declaration_type(parameter, DataLocation::Memory))
+                        .collect()
+                };
+
+                let kind = if function_type_name.visibility == Visibility::External {
+                    FunctionTypeKind::External
+                } else {
+                    FunctionTypeKind::Internal
+                };
+
+                Some(Type::Function(Box::new(FunctionType::new(
+                    kind,
+                    types(&function_type_name.parameter_types)?,
+                    types(&function_type_name.return_parameter_types)?,
+                    function_type_name.state_mutability,
+                ))))
+            }
+        }
+    }
+
+    /// Determines the type of the values of a struct, enum, contract or user-defined value type declaration.
+    fn declaration_value_type(&self, id: NodeID, location: DataLocation) -> Option<Type> {
+        match self.definitions.get(&id)? {
+            Definition::Contract { name, kind, .. } => Some(Type::Contract {
+                id,
+                name: name.clone(),
+                is_library: *kind == ContractKind::Library,
+                is_super: false,
+            }),
+
+            Definition::Struct { name, canonical_name, .. } => Some(Type::Struct {
+                id,
+                name: name.clone(),
+                canonical_name: canonical_name.clone(),
+                location,
+            }),
+
+            Definition::Enum { name, canonical_name } => Some(Type::Enum {
+                id,
+                name: name.clone(),
+                canonical_name: canonical_name.clone(),
+            }),
+
+            Definition::UserDefinedValueType { name, canonical_name, .. } => Some(Type::UserDefinedValueType {
+                id,
+                name: name.clone(),
+                canonical_name: canonical_name.clone(),
+            }),
+
+            _ => None,
+        }
+    }
+
+    fn variable_declaration_type(&self, variable_declaration: &VariableDeclaration, default_location: DataLocation) -> Option<Type> {
+        let location = match variable_declaration.storage_location {
+            StorageLocation::Default => default_location,
+            StorageLocation::Memory => DataLocation::Memory,
+            StorageLocation::Calldata => DataLocation::Calldata,
+            StorageLocation::Storage => DataLocation::StoragePointer,
+        };
+
+        self.type_name_type(variable_declaration.type_name.as_ref()?, location)
+    }
+
+    //
+    // Declaration types
+    //
+
+    /// Determines whether `base_contract_id` is the supplied contract or one of the contracts it inherits from.
+    fn inherits_from(&self, contract_id: NodeID, base_contract_id: NodeID) -> bool {
+        let mut pending = vec![contract_id];
+        let mut visited = vec![];
+
+        while let Some(id) = pending.pop() {
+            if id == base_contract_id {
+                return true;
+            }
+
+            if visited.contains(&id) {
+                continue;
+            }
+
+            visited.push(id);
+
+            if let Some(Definition::Contract { base_contracts, .. }) = self.definitions.get(&id) {
+                pending.extend(base_contracts.iter().copied());
+            }
+        }
+
+        false
+    }
+
+    fn is_library(&self, id: NodeID) -> bool {
+        matches!(self.definitions.get(&id), Some(Definition::Contract { kind: ContractKind::Library, .. }))
+    }
+
+    /// Determines the type of an identifier which references the supplied declaration.
+    fn declaration_type(&self, id: NodeID) -> Option<Type> {
+        match self.definitions.get(&id)? {
+            Definition::SourceUnit { path } => Some(Type::Module { id, path: path.clone() }),
+
+            Definition::Contract { .. } | Definition::Enum { .. } | Definition::UserDefinedValueType { .. } => {
+                Some(Type::TypeType(Box::new(self.declaration_value_type(id, DataLocation::StoragePointer)?)))
+            }
+
+            Definition::Struct { .. } => Some(Type::TypeType(Box::new(self.declaration_value_type(id, DataLocation::StoragePointer)?))),
+            Definition::EnumValue { enum_id } => self.declaration_value_type(*enum_id, DataLocation::Memory),
+            Definition::Variable { variable_type, .. } => variable_type.clone(),
+
+            Definition::Function { function_type, .. }
+            | Definition::Event { function_type }
+            | Definition::Error { function_type } => function_type.clone().map(|function_type| Type::Function(Box::new(function_type))),
+
+            Definition::Modifier { parameter_types } => Some(Type::Modifier(parameter_types.clone()?)),
+        }
+    }
+
+    /// Converts the type of a function declaration to the type used to call it externally.
+    fn external_function_type(function_type: &FunctionType, kind: FunctionTypeKind) -> FunctionType {
+        let convert = |value_type: &Type| match value_type.location() {
+            Some(DataLocation::Calldata) => value_type.with_location(DataLocation::Memory),
+            _ => value_type.clone(),
+        };
+
+        FunctionType {
+            kind,
+            parameter_types: function_type.parameter_types.iter().map(convert).collect(),
+            return_parameter_types: function_type.return_parameter_types.iter().map(convert).collect(),
+            ..FunctionType::new(kind, vec![], vec![], function_type.state_mutability)
+        }
+    }
+
+    /// Determines the type of the getter function of a public state variable.
+    fn getter_type(&self, variable_type: &Type) -> Option<FunctionType> {
+        let mut parameter_types = vec![];
+        let mut value_type = variable_type;
+
+        loop {
+            match value_type {
+                Type::Mapping { key_type, value_type: mapping_value_type } => {
+                    parameter_types.push(key_type.with_location(DataLocation::Memory));
+                    value_type = mapping_value_type.as_ref();
+                }
+
+                Type::Array { base_type, .. } => {
+                    parameter_types.push(Type::uint256());
+                    value_type = base_type.as_ref();
+                }
+
+                _ => break,
+            }
+        }
+
+        let return_parameter_types = match value_type {
+            // Getters of structs return each of the members which are not mappings or arrays
+            Type::Struct { id, .. } => {
+                let Some(Definition::Struct { members, .. }) = self.definitions.get(id) else { return None };
+
+                members.iter()
+                    .filter_map(|(_, member_id)| self.variable_type(*member_id))
+                    .filter(|member_type| !matches!(member_type, Type::Mapping { .. } | Type::Array { .. }))
+                    .map(|member_type| member_type.with_location(DataLocation::Memory))
+                    .collect()
+            }
+
+            value_type => vec![value_type.with_location(DataLocation::Memory)],
+        };
+
+        Some(FunctionType::new(FunctionTypeKind::External, parameter_types, return_parameter_types, StateMutability::View))
+    }
+
+    fn builtin_function(kind: FunctionTypeKind, parameter_types: Vec<Type>, return_parameter_types: Vec<Type>, state_mutability: StateMutability) -> Option<Type> {
+        Some(Type::Function(Box::new(FunctionType::new(kind, parameter_types, return_parameter_types, state_mutability))))
+    }
+
+    /// Determines the type of a global (magic) variable or function.
+    fn magic_variable_type(&self, name: &str, scope: Scope, arguments: Option<&[Option<Type>]>) -> Option<Type> {
+        let argument_count = arguments.map(|arguments| arguments.len()).unwrap_or(0);
+        let bytes_memory = Type::Bytes(DataLocation::Memory);
+        let string_memory = Type::String(DataLocation::Memory);
+
+        match name {
+            "abi" => Some(Type::Magic(MagicType::Abi)),
+            "block" => Some(Type::Magic(MagicType::Block)),
+            "msg" => Some(Type::Magic(MagicType::Message)),
+            "tx" => Some(Type::Magic(MagicType::Transaction)),
+            "now" => Some(Type::uint256()),
+
+            "this" | "super" => {
+                let contract_id = scope.contract?;
+                let Some(Definition::Contract { name: contract_name, kind, .. }) = self.definitions.get(&contract_id) else { return None };
+
+                Some(Type::Contract {
+                    id: contract_id,
+                    name: contract_name.clone(),
+                    is_library: *kind == ContractKind::Library,
+                    is_super: name == "super",
+                })
+            }
+
+            "addmod" | "mulmod" => Self::builtin_function(
+                if name == "addmod" { FunctionTypeKind::AddMod } else { FunctionTypeKind::MulMod },
+                vec![Type::uint256(), Type::uint256(), Type::uint256()],
+                vec![Type::uint256()],
+                StateMutability::Pure,
+            ),
+
+            "assert" => Self::builtin_function(FunctionTypeKind::Assert, vec![Type::Bool], vec![], StateMutability::Pure),
+
+            "require" => Self::builtin_function(
+                FunctionTypeKind::Require,
+                if argument_count == 2 { vec![Type::Bool, string_memory] } else { vec![Type::Bool] },
+                vec![],
+                StateMutability::Pure,
+            ),
+
+            "revert" => Self::builtin_function(
+                FunctionTypeKind::Revert,
+                if argument_count == 1 { vec![string_memory] } else { vec![] },
+                vec![],
+                StateMutability::Pure,
+            ),
+
+            "blockhash" => Self::builtin_function(FunctionTypeKind::BlockHash, vec![Type::uint256()], vec![Type::FixedBytes(32)], StateMutability::View),
+            "blobhash" => Self::builtin_function(FunctionTypeKind::BlobHash, vec![Type::uint256()], vec![Type::FixedBytes(32)], StateMutability::View),
+
+            "ecrecover" => Self::builtin_function(
+                FunctionTypeKind::ECRecover,
+                vec![Type::FixedBytes(32), Type::Integer { signed: false, bits: 8 }, Type::FixedBytes(32), Type::FixedBytes(32)],
+                vec![Type::Address { payable: false }],
+                StateMutability::Pure,
+            ),
+
+            "gasleft" => Self::builtin_function(FunctionTypeKind::GasLeft, vec![], vec![Type::uint256()], StateMutability::View),
+            "keccak256" | "sha3" => Self::builtin_function(FunctionTypeKind::Keccak256, vec![bytes_memory], vec![Type::FixedBytes(32)], StateMutability::Pure),
+            "sha256" => Self::builtin_function(FunctionTypeKind::Sha256, vec![bytes_memory], vec![Type::FixedBytes(32)], StateMutability::Pure),
+            "ripemd160" => Self::builtin_function(FunctionTypeKind::Ripemd160, vec![bytes_memory], vec![Type::FixedBytes(20)], StateMutability::Pure),
+
+            "selfdestruct" | "suicide" => {
+                Self::builtin_function(FunctionTypeKind::Selfdestruct, vec![Type::Address { payable: true }], vec![], StateMutability::NonPayable)
+            }
+
+            "type" => Self::builtin_function(FunctionTypeKind::MetaType, vec![], vec![], StateMutability::Pure),
+
+            _ => None,
+        }
+    }
+
+    /// Determines the type of a member of a value or type (i.e: `msg.sender`, `array.length` or `Contract.function`).
+    fn member_type(&self, base_type: &Type, member_name: &str, referenced_declaration: Option<NodeID>, scope: Scope, arguments: Option<&[Option<Type>]>) -> Option<Type> {
+        let function = |kind: FunctionTypeKind, parameter_types: Vec<Type>, return_parameter_types: Vec<Type>, state_mutability: StateMutability| {
+            Self::builtin_function(kind, parameter_types, return_parameter_types, state_mutability)
+        };
+
+        let bytes_memory = Type::Bytes(DataLocation::Memory);
+
+        //
+        // Library functions attached to the type of the value with `using ... for ...`
+        //
+
+        if !matches!(base_type, Type::TypeType(_) | Type::Module { .. } | Type::Magic(_)) {
+            if let Some(Definition::Function { contract: Some(contract_id), visibility, function_type, .. }) = referenced_declaration.and_then(|id| self.definitions.get(&id)) {
+                if self.is_library(*contract_id) {
+                    let mut function_type = function_type.clone()?;
+
+                    if matches!(visibility, Visibility::Public | Visibility::External) {
+                        function_type = Self::external_function_type(&function_type, FunctionTypeKind::DelegateCall);
+                    }
+
+                    function_type.attached = true;
+
+                    return Some(Type::Function(Box::new(function_type)));
+                }
+            }
+        }
+
+        match base_type {
+            Type::Magic(MagicType::Message) => match member_name {
+                "sender" => Some(Type::Address { payable: false }),
+                "value" | "gas" => Some(Type::uint256()),
+                "data" => Some(Type::Bytes(DataLocation::Calldata)),
+                "sig" => Some(Type::FixedBytes(4)),
+                _ => None,
+            },
+
+            Type::Magic(MagicType::Block) => match member_name {
+                "coinbase" => Some(Type::Address { payable: true }),
+                "timestamp" | "number" | "difficulty" | "prevrandao" | "gaslimit" | "chainid" | "basefee" | "blobbasefee" => Some(Type::uint256()),
+                "blockhash" => function(FunctionTypeKind::BlockHash, vec![Type::uint256()], vec![Type::FixedBytes(32)], StateMutability::View),
+                _ => None,
+            },
+
+            Type::Magic(MagicType::Transaction) => match member_name {
+                "origin" => Some(Type::Address { payable: false }),
+                "gasprice" => Some(Type::uint256()),
+                _ => None,
+            },
+
+            Type::Magic(MagicType::Abi) => match member_name {
+                "encode" => function(FunctionTypeKind::ABIEncode, vec![], vec![bytes_memory], StateMutability::Pure),
+                "encodePacked" => function(FunctionTypeKind::ABIEncodePacked, vec![], vec![bytes_memory], StateMutability::Pure),
+                "encodeWithSelector" => function(FunctionTypeKind::ABIEncodeWithSelector, vec![Type::FixedBytes(4)], vec![bytes_memory], StateMutability::Pure),
+                "encodeWithSignature" => function(FunctionTypeKind::ABIEncodeWithSignature, vec![Type::String(DataLocation::Memory)], vec![bytes_memory], StateMutability::Pure),
+                "encodeCall" => function(FunctionTypeKind::ABIEncodeCall, vec![], vec![bytes_memory], StateMutability::Pure),
+                "decode" => function(FunctionTypeKind::ABIDecode, vec![], vec![], StateMutability::Pure),
+                _ => None,
+            },
+
+            Type::Magic(MagicType::MetaType(type_argument)) => match member_name {
+                "name" => Some(Type::String(DataLocation::Memory)),
+                "creationCode" | "runtimeCode" => Some(bytes_memory),
+                "interfaceId" => Some(Type::FixedBytes(4)),
+                "min" | "max" => Some(type_argument.as_ref().clone()),
+                _ => None,
+            },
+
+            Type::Address { .. } => match member_name {
+                "balance" => Some(Type::uint256()),
+                "code" => Some(bytes_memory),
+                "codehash" => Some(Type::FixedBytes(32)),
+                "transfer" => function(FunctionTypeKind::Transfer, vec![Type::uint256()], vec![], StateMutability::NonPayable),
+                "send" => function(FunctionTypeKind::Send, vec![Type::uint256()], vec![Type::Bool], StateMutability::NonPayable),
+                "call" => function(FunctionTypeKind::BareCall, vec![bytes_memory.clone()], vec![Type::Bool, bytes_memory], StateMutability::Payable),
+                "delegatecall" => function(FunctionTypeKind::BareDelegateCall, vec![bytes_memory.clone()], vec![Type::Bool, bytes_memory], StateMutability::NonPayable),
+                "staticcall" => function(FunctionTypeKind::BareStaticCall, vec![bytes_memory.clone()], vec![Type::Bool, bytes_memory], StateMutability::View),
+                _ => None,
+            },
+
+            Type::Contract { is_super: true, .. } => self.declaration_type(referenced_declaration?),
+
+            Type::Contract { .. } => match referenced_declaration.and_then(|id| self.definitions.get(&id)) {
+                Some(Definition::Function { function_type, .. }) => {
+                    Some(Type::Function(Box::new(Self::external_function_type(function_type.as_ref()?, FunctionTypeKind::External))))
+                }
+
+                Some(Definition::Variable { variable_type, state_variable: true }) => {
+                    Some(Type::Function(Box::new(self.getter_type(variable_type.as_ref()?)?)))
+                }
+
+                // Contracts could be used as addresses before 0.5.0 (i.e: `this.balance`)
+                _ => self.member_type(&Type::Address { payable: false }, member_name, None, scope, arguments),
+            },
+
+            Type::TypeType(actual_type) => match actual_type.as_ref() {
+                Type::Contract { id: contract_id, is_library, .. } => {
+                    let id = referenced_declaration?;
+
+                    let Some(Definition::Function { name, visibility, function_type, .. }) = self.definitions.get(&id) else {
+                        return self.declaration_type(id);
+                    };
+
+                    let function_type = function_type.as_ref()?;
+
+                    if *is_library {
+                        if matches!(visibility, Visibility::Public | Visibility::External) {
+                            return Some(Type::Function(Box::new(Self::external_function_type(function_type, FunctionTypeKind::DelegateCall))));
+                        }
+
+                        return Some(Type::Function(Box::new(function_type.clone())));
+                    }
+
+                    if scope.contract.map(|scope_contract_id| self.inherits_from(scope_contract_id, *contract_id)).unwrap_or(false) {
+                        return Some(Type::Function(Box::new(FunctionType {
+                            kind: FunctionTypeKind::Internal,
+                            ..function_type.clone()
+                        })));
+                    }
+
+                    let Some(Definition::Contract { name: contract_name, .. }) = self.definitions.get(contract_id) else { return None };
+
+                    Some(Type::Function(Box::new(FunctionType {
+                        kind: FunctionTypeKind::Declaration,
+                        declaration_name: Some(format!("{contract_name}.{name}")),
+                        ..function_type.clone()
+                    })))
+                }
+
+                Type::Enum { .. } => Some(actual_type.as_ref().clone()),
+
+                Type::UserDefinedValueType { id, .. } => {
+                    let Some(Definition::UserDefinedValueType { underlying_type, .. }) = self.definitions.get(id) else { return None };
+                    let underlying_type = underlying_type.clone()?;
+
+                    match member_name {
+                        "wrap" => function(FunctionTypeKind::Wrap, vec![underlying_type], vec![actual_type.as_ref().clone()], StateMutability::Pure),
+                        "unwrap" => function(FunctionTypeKind::Unwrap, vec![actual_type.as_ref().clone()], vec![underlying_type], StateMutability::Pure),
+                        _ => None,
+                    }
+                }
+
+                Type::String(_) if member_name == "concat" => {
+                    function(FunctionTypeKind::StringConcat, vec![], vec![Type::String(DataLocation::Memory)], StateMutability::Pure)
+                }
+
+                Type::Bytes(_) if member_name == "concat" => {
+                    function(FunctionTypeKind::BytesConcat, vec![], vec![bytes_memory], StateMutability::Pure)
+                }
+
+                _ => None,
+            },
+
+            Type::Module { .. } => self.declaration_type(referenced_declaration?),
+
+            Type::Struct { id, location, .. } => {
+                let Some(Definition::Struct { members, .. }) = self.definitions.get(id) else { return None };
+
+                let member_id = referenced_declaration.or_else(|| {
+                    members.iter()
+                        .find(|(name, _)| name == member_name)
+                        .map(|(_, member_id)| *member_id)
+                })?;
+
+                Some(self.variable_type(member_id)?.with_location(location.member_location()))
+            }
+
+            Type::Array { base_type, location, .. } => {
+                let array_type = base_type.as_ref().clone();
+                self.array_member_type(base_type, *location, member_name, array_type, arguments)
+            }
+
+            Type::Bytes(location) => self.array_member_type(&Type::Bytes(*location), *location, member_name, Type::FixedBytes(1), arguments),
+
+            Type::ArraySlice(_) if member_name == "length" => Some(Type::uint256()),
+
+            Type::Function(function_type) => match member_name {
+                "selector" if function_type.kind == FunctionTypeKind::Event => Some(Type::FixedBytes(32)),
+                "selector" => Some(Type::FixedBytes(4)),
+                "address" => Some(Type::Address { payable: false }),
+                _ => None,
+            },
+
+            _ => None,
+        }
+    }
+
+    /// Determines the type of a member of an array or `bytes` value.
+    fn array_member_type(&self, base_type: &Type, location: DataLocation, member_name: &str, element_type: Type, arguments: Option<&[Option<Type>]>) -> Option<Type> {
+        // The type of `base_type` is only used to reconstruct the array type for attached functions
+        let array_type = match base_type {
+            Type::Bytes(_) => Type::Bytes(DataLocation::StoragePointer),
+
+            _ => Type::Array {
+                base_type: Box::new(base_type.clone()),
+                length: None,
+                location: DataLocation::StoragePointer,
+            },
+        };
+
+        let attached = |kind: FunctionTypeKind, parameter_types: Vec<Type>, return_parameter_types: Vec<Type>| {
+            let mut function_type = FunctionType::new(kind, parameter_types, return_parameter_types, StateMutability::NonPayable);
+            function_type.attached = true;
+            Some(Type::Function(Box::new(function_type)))
+        };
+
+        match member_name {
+            "length" => Some(Type::uint256()),
+
+            "push" if matches!(location, DataLocation::StorageRef | DataLocation::StoragePointer) => {
+                if arguments.map(|arguments| arguments.is_empty()).unwrap_or(true) {
+                    attached(FunctionTypeKind::ArrayPush, vec![array_type], vec![element_type.with_location(DataLocation::StorageRef)])
+                } else {
+                    attached(FunctionTypeKind::ArrayPush, vec![array_type, element_type], vec![])
+                }
+            }
+
+            "pop" if matches!(location, DataLocation::StorageRef | DataLocation::StoragePointer) => {
+                attached(FunctionTypeKind::ArrayPop, vec![array_type], vec![])
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Determines the type a value is converted to with an explicit conversion (i.e: `address(x)` or `string(x)`).
+    fn conversion_type(target_type: &Type, argument_type: Option<&Type>) -> Type {
+        let location = match argument_type.and_then(Type::location) {
+            Some(DataLocation::StorageRef | DataLocation::StoragePointer) => DataLocation::StoragePointer,
+            Some(DataLocation::Calldata) => DataLocation::Calldata,
+            _ => DataLocation::Memory,
+        };
+
+        target_type.with_location(location)
+    }
+
+    /// Determines the type resulting from a binary operation whose operands have already been checked.
+    fn binary_operation_type(operator: &str, left_type: &Type, right_type: &Type) -> Option<(Type, Type)> {
+        if let (Type::RationalNumber(left), Type::RationalNumber(right)) = (left_type, right_type) {
+            return match operator {
+                "==" | "!=" | "<" | "<=" | ">" | ">=" => Some((Type::Bool, left_type.clone())),
+
+                _ => {
+                    let result = Type::RationalNumber(left.binary_operation(operator, right)?);
+                    Some((result.clone(), result))
+                }
+            };
+        }
+
+        let common_type = match operator {
+            // The type of exponentiations and shifts only depends on the left operand
+            "**" | "<<" | ">>" => match left_type {
+                Type::RationalNumber(left) => Type::Integer { signed: left.negative, bits: 256 },
+                left_type => left_type.clone(),
+            },
+
+            _ => match (left_type, right_type) {
+                (Type::RationalNumber(_) | Type::StringLiteral(_), right_type) => right_type.clone(),
+                (left_type, Type::RationalNumber(_) | Type::StringLiteral(_)) => left_type.clone(),
+
+                (Type::Integer { signed: left_signed, bits: left_bits }, Type::Integer { signed: right_signed, bits: right_bits }) if left_signed == right_signed => {
+                    Type::Integer { signed: *left_signed, bits: *left_bits.max(right_bits) }
+                }
+
+                (left_type, _) => left_type.clone(),
+            },
+        };
+
+        match operator {
+            "==" | "!=" | "<" | "<=" | ">" | ">=" | "&&" | "||" => Some((Type::Bool, common_type)),
+            _ => Some((common_type.clone(), common_type)),
+        }
+    }
+
+    //
+    // Type checking
+    //
+
+    fn check_contract_definition(&self, contract_definition: &mut ContractDefinition) {
+        let scope = Scope { contract: Some(contract_definition.id) };
+
+        for base_contract in contract_definition.base_contracts.iter_mut() {
+            for argument in base_contract.arguments.iter_mut().flatten() {
+                self.check_expression(argument, scope, None);
+            }
+        }
+
+        for node in contract_definition.nodes.iter_mut() {
+            match node {
+                ContractDefinitionNode::UsingForDirective(using_for_directive) => {
+                    if let Some(type_name) = using_for_directive.type_name.as_mut() {
+                        self.check_type_name(type_name);
+                    }
+                }
+
+                ContractDefinitionNode::StructDefinition(struct_definition) => {
+                    for member in struct_definition.members.iter_mut() {
+                        self.check_variable_declaration(member, scope);
+                    }
+                }
+
+                ContractDefinitionNode::EnumDefinition(_) => {}
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => {
+                    self.check_variable_declaration(variable_declaration, scope);
+                }
+
+                ContractDefinitionNode::EventDefinition(event_definition) => {
+                    self.check_parameter_list(&mut event_definition.parameters, scope);
+                }
+
+                ContractDefinitionNode::FunctionDefinition(function_definition) => {
+                    self.check_parameter_list(&mut function_definition.parameters, scope);
+                    self.check_parameter_list(&mut function_definition.return_parameters, scope);
+
+                    for modifier in function_definition.modifiers.iter_mut() {
+                        for argument in modifier.arguments.iter_mut().flatten() {
+                            self.check_expression(argument, scope, None);
+                        }
+                    }
+
+                    if let Some(body) = function_definition.body.as_mut() {
+                        self.check_block(body, scope);
+                    }
+                }
+
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
+                    self.check_parameter_list(&mut modifier_definition.parameters, scope);
+                    self.check_block(&mut modifier_definition.body, scope);
+                }
+
+                ContractDefinitionNode::ErrorDefinition(error_definition) => {
+                    self.check_parameter_list(&mut error_definition.parameters, scope);
+                }
+
+                ContractDefinitionNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.check_type_name(&mut definition.underlying_type);
+                }
+            }
+        }
+    }
+
+    fn check_parameter_list(&self, parameter_list: &mut ParameterList, scope: Scope) {
+        for parameter in parameter_list.parameters.iter_mut() {
+            self.check_variable_declaration(parameter, scope);
+        }
+    }
+
+    fn check_variable_declaration(&self, variable_declaration: &mut VariableDeclaration, scope: Scope) {
+        if let Some(variable_type) = self.variable_type(variable_declaration.id) {
+            variable_declaration.type_descriptions = variable_type.type_descriptions(true);
+        }
+
+        if let Some(type_name) = variable_declaration.type_name.as_mut() {
+            self.check_type_name(type_name);
+        }
+
+        if let Some(value) = variable_declaration.value.as_mut() {
+            self.check_expression(value, scope, None);
+        }
+    }
+
+    fn check_type_name(&self, type_name: &mut TypeName) {
+        let type_descriptions = self.type_name_type(type_name, DataLocation::StoragePointer)
+            .map(|type_name_type| type_name_type.type_descriptions(true));
+
+        match type_name {
+            TypeName::FunctionTypeName(function_type_name) => {
+                for parameter in function_type_name.parameter_types.parameters.iter_mut()
+                    .chain(function_type_name.return_parameter_types.parameters.iter_mut())
+                {
+                    if let Some(parameter_type) = self.variable_declaration_type(parameter, DataLocation::Memory) {
+                        parameter.type_descriptions = parameter_type.type_descriptions(true);
+                    }
+
+                    if let Some(type_name) = parameter.type_name.as_mut() {
+                        self.check_type_name(type_name);
+                    }
+                }
+
+                if let Some(type_descriptions) = type_descriptions {
+                    function_type_name.type_descriptions = type_descriptions;
+                }
+            }
+
+            TypeName::ArrayTypeName(array_type_name) => {
+                self.check_type_name(array_type_name.base_type.as_mut());
+
+                if let Some(length) = array_type_name.length.as_mut() {
+                    if let Some(length_type) = Self::literal_type(length) {
+                        length.type_descriptions = length_type.type_descriptions(false);
+                    }
+                }
+
+                if let Some(type_descriptions) = type_descriptions {
+                    array_type_name.type_descriptions = type_descriptions;
+                }
+            }
+
+            TypeName::Mapping(mapping) => {
+                self.check_type_name(mapping.key_type.as_mut());
+                self.check_type_name(mapping.value_type.as_mut());
+
+                if let Some(type_descriptions) = type_descriptions {
+                    mapping.type_descriptions = type_descriptions;
+                }
+            }
+
+            TypeName::UserDefinedTypeName(user_defined_type_name) => {
+                if let Some(type_descriptions) = type_descriptions {
+                    user_defined_type_name.type_descriptions = type_descriptions;
+                }
+            }
+
+            TypeName::ElementaryTypeName(elementary_type_name) => {
+                if let Some(type_descriptions) = type_descriptions {
+                    elementary_type_name.type_descriptions = type_descriptions;
+                }
+            }
+
+            TypeName::String(_) => {}
+        }
+    }
+
+    fn check_block(&self, block: &mut Block, scope: Scope) {
+        for statement in block.statements.iter_mut() {
+            self.check_statement(statement, scope);
+        }
+    }
+
+    fn check_block_or_statement(&self, block_or_statement: &mut BlockOrStatement, scope: Scope) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.check_block(block, scope),
+            BlockOrStatement::Statement(statement) => self.check_statement(statement, scope),
+        }
+    }
+
+    fn check_statement(&self, statement: &mut Statement, scope: Scope) {
+        match statement {
+            Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                for variable_declaration in variable_declaration_statement.declarations.iter_mut().flatten() {
+                    self.check_variable_declaration(variable_declaration, scope);
+                }
+
+                if let Some(initial_value) = variable_declaration_statement.initial_value.as_mut() {
+                    self.check_expression(initial_value, scope, None);
+                }
+            }
+
+            Statement::IfStatement(if_statement) => {
+                self.check_expression(&mut if_statement.condition, scope, None);
+                self.check_block_or_statement(&mut if_statement.true_body, scope);
+
+                if let Some(false_body) = if_statement.false_body.as_mut() {
+                    self.check_block_or_statement(false_body, scope);
+                }
+            }
+
+            Statement::ForStatement(for_statement) => {
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_mut() {
+                    self.check_statement(initialization_expression, scope);
+                }
+
+                if let Some(condition) = for_statement.condition.as_mut() {
+                    self.check_expression(condition, scope, None);
+                }
+
+                if let Some(loop_expression) = for_statement.loop_expression.as_mut() {
+                    self.check_statement(loop_expression, scope);
+                }
+
+                self.check_block_or_statement(&mut for_statement.body, scope);
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                self.check_expression(&mut while_statement.condition, scope, None);
+                self.check_block_or_statement(&mut while_statement.body, scope);
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                self.check_block_or_statement(&mut do_while_statement.body, scope);
+                self.check_expression(&mut do_while_statement.condition, scope, None);
+            }
+
+            Statement::EmitStatement(emit_statement) => {
+                self.check_expression(&mut emit_statement.event_call, scope, None);
+            }
+
+            Statement::TryStatement(try_statement) => {
+                self.check_function_call(&mut try_statement.external_call, scope);
+
+                for clause in try_statement.clauses.iter_mut() {
+                    if let Some(parameters) = clause.parameters.as_mut() {
+                        self.check_parameter_list(parameters, scope);
+                    }
+
+                    self.check_block(&mut clause.block, scope);
+                }
+            }
+
+            Statement::UncheckedBlock(block) | Statement::Block(block) => {
+                self.check_block(block, scope);
+            }
+
+            Statement::Return(return_statement) => {
+                if let Some(expression) = return_statement.expression.as_mut() {
+                    self.check_expression(expression, scope, None);
+                }
+            }
+
+            Statement::RevertStatement(revert_statement) => {
+                self.check_function_call(&mut revert_statement.error_call, scope);
+            }
+
+            Statement::ExpressionStatement(expression_statement) => {
+                self.check_expression(&mut expression_statement.expression, scope, None);
+            }
+
+            Statement::InlineAssembly(_)
+            | Statement::Continue { .. }
+            | Statement::Break { .. }
+            | Statement::PlaceholderStatement { .. } => {}
+        }
+    }
+
+    /// Infers the type of an expression and fills in the type descriptions of it and its subexpressions.
+    ///
+    /// The types of the arguments are supplied when the expression is being called, which is used to select
+    /// between overloaded built-in functions and is recorded in the `argument_types` of the expression.
+    fn check_expression(&self, expression: &mut Expression, scope: Scope, arguments: Option<&[Option<Type>]>) -> Option<Type> {
+        let argument_types = arguments.and_then(|arguments| {
+            arguments.iter()
+                .map(|argument| argument.as_ref().map(|argument| argument.type_descriptions(false)))
+                .collect::<Option<Vec<_>>>()
+        });
+
+        match expression {
+            Expression::Literal(literal) => {
+                let literal_type = Self::literal_type(literal);
+
+                if let Some(literal_type) = literal_type.as_ref() {
+                    literal.type_descriptions = literal_type.type_descriptions(false);
+                }
+
+                literal_type
+            }
+
+            Expression::Identifier(identifier) => {
+                let id = identifier.referenced_declaration;
+
+                let identifier_type = if id < 0 {
+                    self.magic_variable_type(identifier.name.as_str(), scope, arguments)
+                } else {
+                    self.declaration_type(id)
+                };
+
+                if arguments.is_some() {
+                    identifier.argument_types = argument_types;
+                }
+
+                if let Some(identifier_type) = identifier_type.as_ref() {
+                    identifier.type_descriptions = identifier_type.type_descriptions(false);
+                }
+
+                identifier_type
+            }
+
+            Expression::UnaryOperation(unary_operation) => {
+                let operand_type = self.check_expression(unary_operation.sub_expression.as_mut(), scope, None);
+
+                let result_type = match (unary_operation.operator.as_str(), operand_type) {
+                    ("!", _) => Some(Type::Bool),
+                    ("delete", _) => Some(Type::Tuple(vec![])),
+                    ("-", Some(Type::RationalNumber(value))) => Some(Type::RationalNumber(value.neg())),
+
+                    ("~", Some(Type::RationalNumber(value))) if value.is_integer() => {
+                        value.neg().sub(&Rational::from_integer(U512::one())).map(Type::RationalNumber)
+                    }
+
+                    (_, operand_type) => operand_type,
+                };
+
+                if let Some(result_type) = result_type.as_ref() {
+                    unary_operation.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+
+            Expression::BinaryOperation(binary_operation) => {
+                let left_type = self.check_expression(binary_operation.left_expression.as_mut(), scope, None);
+                let right_type = self.check_expression(binary_operation.right_expression.as_mut(), scope, None);
+
+                let (result_type, common_type) = Self::binary_operation_type(binary_operation.operator.as_str(), left_type.as_ref()?, right_type.as_ref()?)?;
+
+                binary_operation.common_type = common_type.type_descriptions(false);
+                binary_operation.type_descriptions = result_type.type_descriptions(false);
+
+                Some(result_type)
+            }
+
+            Expression::Conditional(conditional) => {
+                self.check_expression(conditional.condition.as_mut(), scope, None);
+
+                let true_type = self.check_expression(conditional.true_expression.as_mut(), scope, None);
+                let false_type = self.check_expression(conditional.false_expression.as_mut(), scope, None);
+
+                let result_type = match (true_type, false_type) {
+                    (Some(Type::RationalNumber(_) | Type::StringLiteral(_)), Some(false_type)) if !matches!(false_type, Type::RationalNumber(_) | Type::StringLiteral(_)) => Some(false_type),
+                    (Some(true_type), _) => true_type.mobile_type(),
+                    (None, _) => None,
+                };
+
+                if let Some(result_type) = result_type.as_ref() {
+                    conditional.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+
+            Expression::Assignment(assignment) => {
+                self.check_expression(assignment.right_hand_side.as_mut(), scope, None);
+                let result_type = self.check_expression(assignment.left_hand_side.as_mut(), scope, None);
+
+                if let Some(result_type) = result_type.as_ref() {
+                    assignment.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+
+            Expression::FunctionCall(function_call) => {
+                self.check_function_call(function_call, scope)
+            }
+
+            Expression::FunctionCallOptions(function_call_options) => {
+                for option in function_call_options.options.iter_mut() {
+                    self.check_expression(option, scope, None);
+                }
+
+                let mut result_type = self.check_expression(function_call_options.expression.as_mut(), scope, arguments);
+
+                if let Some(Type::Function(function_type)) = result_type.as_mut() {
+                    for name in function_call_options.names.iter() {
+                        match name.as_str() {
+                            "gas" => function_type.gas_set = true,
+                            "value" => function_type.value_set = true,
+                            "salt" => function_type.salt_set = true,
+                            _ => {}
+                        }
+                    }
+                }
+
+                if arguments.is_some() {
+                    function_call_options.argument_types = argument_types;
+                }
+
+                if let Some(result_type) = result_type.as_ref() {
+                    function_call_options.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+
+            Expression::IndexAccess(index_access) => {
+                let base_type = self.check_expression(index_access.base_expression.as_mut(), scope, None);
+
+                let index_type = match index_access.index_expression.as_mut() {
+                    Some(index_expression) => self.check_expression(index_expression.as_mut(), scope, None),
+                    None => None,
+                };
+
+                let result_type = match base_type? {
+                    Type::Array { base_type, .. } => Some(*base_type),
+                    Type::ArraySlice(array_type) => match *array_type {
+                        Type::Array { base_type, .. } => Some(*base_type),
+                        _ => Some(Type::FixedBytes(1)),
+                    },
+                    Type::Bytes(_) | Type::FixedBytes(_) => Some(Type::FixedBytes(1)),
+                    Type::Mapping { value_type, .. } => Some(*value_type),
+
+                    // Array types used in expressions (i.e: `abi.decode(data, (uint256[]))`)
+                    Type::TypeType(actual_type) => {
+                        let length = match index_type {
+                            Some(Type::RationalNumber(length)) => Some(length.numerator.to_string()),
+                            _ => None,
+                        };
+
+                        Some(Type::TypeType(Box::new(Type::Array {
+                            base_type: Box::new(actual_type.with_location(DataLocation::Memory)),
+                            length,
+                            location: DataLocation::Memory,
+                        })))
+                    }
+
+                    _ => None,
+                };
+
+                if let Some(result_type) = result_type.as_ref() {
+                    index_access.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+
+            Expression::IndexRangeAccess(index_range_access) => {
+                let base_type = self.check_expression(index_range_access.base_expression.as_mut(), scope, None);
+
+                if let Some(start_expression) = index_range_access.start_expression.as_mut() {
+                    self.check_expression(start_expression.as_mut(), scope, None);
+                }
+
+                if let Some(end_expression) = index_range_access.end_expression.as_mut() {
+                    self.check_expression(end_expression.as_mut(), scope, None);
+                }
+
+                let result_type = match base_type? {
+                    Type::ArraySlice(array_type) => Type::ArraySlice(array_type),
+                    array_type => Type::ArraySlice(Box::new(array_type)),
+                };
+
+                index_range_access.type_descriptions = result_type.type_descriptions(false);
+
+                Some(result_type)
+            }
+
+            Expression::MemberAccess(member_access) => {
+                let base_type = self.check_expression(member_access.expression.as_mut(), scope, None);
+
+                let result_type = base_type.and_then(|base_type| {
+                    self.member_type(&base_type, member_access.member_name.as_str(), member_access.referenced_declaration, scope, arguments)
+                });
+
+                if arguments.is_some() {
+                    member_access.argument_types = argument_types;
+                }
+
+                if let Some(result_type) = result_type.as_ref() {
+                    member_access.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+
+            Expression::ElementaryTypeNameExpression(elementary_type_name_expression) => {
+                self.check_type_name(&mut elementary_type_name_expression.type_name);
+
+                let result_type = self.type_name_type(&elementary_type_name_expression.type_name, DataLocation::StoragePointer)
+                    .map(|actual_type| Type::TypeType(Box::new(actual_type)));
+
+                if arguments.is_some() {
+                    elementary_type_name_expression.argument_types = argument_types;
+                }
+
+                if let Some(result_type) = result_type.as_ref() {
+                    elementary_type_name_expression.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+
+            Expression::TupleExpression(tuple_expression) => {
+                let mut component_types = vec![];
+
+                for component in tuple_expression.components.iter_mut() {
+                    component_types.push(match component.as_mut() {
+                        Some(component) => Some(self.check_expression(component, scope, None)?),
+                        None => None,
+                    });
+                }
+
+                let result_type = if tuple_expression.is_inline_array {
+                    // The type of the elements is the type of the first element which is not a literal
+                    let element_type = component_types.iter()
+                        .flatten()
+                        .find(|component_type| !matches!(component_type, Type::RationalNumber(_) | Type::StringLiteral(_)))
+                        .or_else(|| component_types.iter().flatten().next())?
+                        .mobile_type()?;
+
+                    Type::Array {
+                        base_type: Box::new(element_type.with_location(DataLocation::Memory)),
+                        length: Some(component_types.len().to_string()),
+                        location: DataLocation::Memory,
+                    }
+                } else if component_types.len() == 1 {
+                    component_types.remove(0)?
+                } else {
+                    Type::Tuple(component_types)
+                };
+
+                tuple_expression.type_descriptions = result_type.type_descriptions(false);
+
+                Some(result_type)
+            }
+
+            Expression::NewExpression(new_expression) => {
+                self.check_type_name(&mut new_expression.type_name);
+
+                let result_type = match self.type_name_type(&new_expression.type_name, DataLocation::Memory) {
+                    Some(contract_type @ Type::Contract { .. }) => {
+                        let Type::Contract { id, .. } = &contract_type else { unreachable!() };
+                        let Some(Definition::Contract { constructor, .. }) = self.definitions.get(id) else { return None };
+
+                        let (parameter_types, state_mutability) = match constructor.and_then(|constructor| self.definitions.get(&constructor)) {
+                            Some(Definition::Function { function_type: Some(function_type), .. }) => {
+                                (function_type.parameter_types.clone(), function_type.state_mutability)
+                            }
+
+                            _ => (vec![], StateMutability::NonPayable),
+                        };
+
+                        Self::builtin_function(FunctionTypeKind::Creation, parameter_types, vec![contract_type], state_mutability)
+                    }
+
+                    Some(array_type @ (Type::Array { .. } | Type::Bytes(_) | Type::String(_))) => {
+                        Self::builtin_function(FunctionTypeKind::ObjectCreation, vec![Type::uint256()], vec![array_type], StateMutability::Pure)
+                    }
+
+                    _ => None,
+                };
+
+                if arguments.is_some() {
+                    new_expression.argument_types = argument_types;
+                }
+
+                if let Some(result_type) = result_type.as_ref() {
+                    new_expression.type_descriptions = result_type.type_descriptions(false);
+                }
+
+                result_type
+            }
+        }
+    }
+
+    fn check_function_call(&self, function_call: &mut FunctionCall, scope: Scope) -> Option<Type> {
+        let argument_types: Vec<Option<Type>> = function_call.arguments.iter_mut()
+            .map(|argument| self.check_expression(argument, scope, None))
+            .collect();
+
+        let callee_type = self.check_expression(function_call.expression.as_mut(), scope, Some(argument_types.as_slice()))?;
+
+        let result_type = match callee_type {
+            Type::TypeType(actual_type) => match *actual_type {
+                Type::Struct { .. } => {
+                    function_call.kind = FunctionCallKind::StructConstructorCall;
+                    actual_type.with_location(DataLocation::Memory)
+                }
+
+                actual_type => {
+                    function_call.kind = FunctionCallKind::TypeConversion;
+                    Self::conversion_type(&actual_type, argument_types.first().and_then(Option::as_ref))
+                }
+            },
+
+            Type::Function(function_type) => match function_type.kind {
+                FunctionTypeKind::ABIDecode => match argument_types.get(1).cloned().flatten()? {
+                    Type::TypeType(actual_type) => actual_type.with_location(DataLocation::Memory),
+
+                    Type::Tuple(components) => Type::Tuple(
+                        components.into_iter()
+                            .map(|component| match component {
+                                Some(Type::TypeType(actual_type)) => Some(actual_type.with_location(DataLocation::Memory)),
+                                _ => None,
+                            })
+                            .collect()
+                    ),
+
+                    _ => return None,
+                },
+
+                FunctionTypeKind::MetaType => match argument_types.first().cloned().flatten()? {
+                    Type::TypeType(actual_type) => Type::Magic(MagicType::MetaType(actual_type)),
+                    _ => return None,
+                },
+
+                _ => function_type.return_type(),
+            },
+
+            _ => return None,
+        };
+
+        function_call.type_descriptions = result_type.type_descriptions(false);
+
+        Some(result_type)
+    }
+
+    /// Determines the type of a literal value.
+    fn literal_type(literal: &Literal) -> Option<Type> {
+        match literal.kind {
+            LiteralKind::Bool => Some(Type::Bool),
+            LiteralKind::Address => Some(Type::Address { payable: false }),
+
+            LiteralKind::Number => {
+                let value = literal.value.as_deref()?;
+
+                // Hexadecimal literals with 40 digits are address literals
+                if value.len() == 42 && (value.starts_with("0x") || value.starts_with("0X")) && literal.subdenomination.is_none() {
+                    return Some(Type::Address { payable: false });
+                }
+
+                Some(Type::RationalNumber(Rational::parse(value, literal.subdenomination.as_deref())?))
+            }
+
+            LiteralKind::String => Some(Type::StringLiteral(unescape_string_literal(literal.value.as_deref()?))),
+
+            LiteralKind::HexString => {
+                let digits = literal.hex_value.as_deref()?;
+
+                Some(Type::StringLiteral(
+                    (0..digits.len() / 2)
+                        .map(|i| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).ok())
+                        .collect::<Option<Vec<_>>>()?
+                ))
+            }
+        }
+    }
+}
+
+/// Fills in the type descriptions of all of the supplied source units, whose references must already be resolved.
+pub fn check_types(source_units: &mut [SourceUnit]) {
+    let checker = TypeChecker::new(source_units);
+
+    for source_unit in source_units.iter_mut() {
+        checker.check_source_unit(source_unit);
+    }
+
+    compute_selectors(source_units);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"pragma solidity ^0.8.0;
+
+contract Token {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    error Unauthorized(address caller);
+
+    function transfer(address to, uint256 amount) external returns (bool) {
+        emit Transfer(msg.sender, to, amount);
+        return true;
+    }
+
+    function forward(address target, bytes memory data) public returns (bool, bytes memory) {
+        uint256 one = 1;
+        (bool success, bytes memory result) = target.call{value: one}(data);
+        return (success, result);
+    }
+}
+"#;
+
+    fn type_descriptions(expression: &Expression) -> (&str, &str) {
+        let type_descriptions = expression.type_descriptions().unwrap();
+
+        (
+            type_descriptions.type_identifier.as_deref().unwrap(),
+            type_descriptions.type_string.as_deref().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_check_types() {
+        let source_units = builder::build_test_source_units("Token.sol", SOURCE);
+        let token = source_units[0].contract_definitions()[0];
+
+        // emit Transfer(msg.sender, to, amount);
+        let statements = &token.function_definitions()[0].body.as_ref().unwrap().statements;
+        let Statement::EmitStatement(emit_statement) = &statements[0] else { panic!("expected an emit statement") };
+        let Expression::FunctionCall(event_call) = &emit_statement.event_call else { panic!("expected a function call") };
+        assert_eq!(type_descriptions(&event_call.arguments[0]), ("t_address", "address"));
+        assert_eq!(type_descriptions(&event_call.arguments[2]), ("t_uint256", "uint256"));
+
+        // uint256 one = 1;
+        let statements = &token.function_definitions()[1].body.as_ref().unwrap().statements;
+        let Statement::VariableDeclarationStatement(declaration) = &statements[0] else { panic!("expected a variable declaration") };
+        assert_eq!(type_descriptions(declaration.initial_value.as_ref().unwrap()), ("t_rational_1_by_1", "int_const 1"));
+
+        // (bool success, bytes memory result) = target.call{value: one}(data);
+        let Statement::VariableDeclarationStatement(declaration) = &statements[1] else { panic!("expected a variable declaration") };
+        let Some(Expression::FunctionCall(call)) = declaration.initial_value.as_ref() else { panic!("expected a function call") };
+        assert_eq!(type_descriptions(&call.arguments[0]), ("t_bytes_memory_ptr", "bytes memory"));
+        assert_eq!(
+            type_descriptions(declaration.initial_value.as_ref().unwrap()),
+            ("t_tuple$_t_bool_$_t_bytes_memory_ptr_$", "tuple(bool,bytes memory)"),
+        );
+    }
+}
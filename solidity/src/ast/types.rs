@@ -21,10 +21,23 @@ pub enum TypeName {
     String(String),
 }
 
+impl TypeName {
+    pub fn type_descriptions(&self) -> Option<&TypeDescriptions> {
+        match self {
+            TypeName::FunctionTypeName(FunctionTypeName { type_descriptions, .. }) => Some(type_descriptions),
+            TypeName::ArrayTypeName(ArrayTypeName { type_descriptions, .. }) => Some(type_descriptions),
+            TypeName::Mapping(Mapping { type_descriptions, .. }) => Some(type_descriptions),
+            TypeName::UserDefinedTypeName(UserDefinedTypeName { type_descriptions, .. }) => Some(type_descriptions),
+            TypeName::ElementaryTypeName(ElementaryTypeName { type_descriptions, .. }) => Some(type_descriptions),
+            TypeName::String(_) => None,
+        }
+    }
+}
+
 impl Display for TypeName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            TypeName::FunctionTypeName(function_type_name) => function_type_name.type_descriptions.type_string.as_ref().unwrap().fmt(f),
+            TypeName::FunctionTypeName(function_type_name) => function_type_name.fmt(f),
             TypeName::ArrayTypeName(array_type_name) => array_type_name.fmt(f),
             TypeName::Mapping(mapping) => mapping.fmt(f),
             TypeName::UserDefinedTypeName(user_defined_type_name) => user_defined_type_name.fmt(f),
@@ -98,6 +111,34 @@ pub struct FunctionTypeName {
     pub type_descriptions: TypeDescriptions,
 }
 
+impl Display for FunctionTypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(type_string) = self.type_descriptions.type_string.as_ref() {
+            return type_string.fmt(f);
+        }
+
+        //
+        // Function types which have not been type checked are formatted the same way as their type string
+        //
+
+        f.write_fmt(format_args!("function ({})", self.parameter_types.type_strings().join(",")))?;
+
+        if self.visibility == Visibility::External {
+            f.write_str(" external")?;
+        }
+
+        if self.state_mutability != StateMutability::NonPayable {
+            f.write_fmt(format_args!(" {}", self.state_mutability))?;
+        }
+
+        if !self.return_parameter_types.parameters.is_empty() {
+            f.write_fmt(format_args!(" returns ({})", self.return_parameter_types.type_strings().join(",")))?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ArrayTypeName {
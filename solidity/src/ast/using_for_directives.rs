@@ -6,22 +6,74 @@ use std::fmt::Display;
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct UsingForDirective {
-    pub library_name: IdentifierPath,
+    pub library_name: Option<IdentifierPath>,
+    pub function_list: Option<Vec<UsingForFunction>>,
     pub type_name: Option<TypeName>,
+    pub global: Option<bool>,
     pub src: String,
     pub id: NodeID,
 }
 
+impl UsingForDirective {
+    /// The paths of every function attached by the directive, i.e: `f` and `g` in `using {f, g as +} for T`.
+    pub fn function_paths(&self) -> impl Iterator<Item = &IdentifierPath> {
+        self.function_list.iter()
+            .flatten()
+            .filter_map(|function| function.function.as_ref().or(function.definition.as_ref()))
+    }
+}
+
 impl Display for UsingForDirective {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("using ")?;
+
+        match (self.library_name.as_ref(), self.function_list.as_ref()) {
+            (Some(library_name), _) => f.write_fmt(format_args!("{}", library_name))?,
+
+            (None, Some(function_list)) => f.write_fmt(format_args!(
+                "{{{}}}",
+                function_list.iter().map(|function| function.to_string()).collect::<Vec<_>>().join(", "),
+            ))?,
+
+            (None, None) => f.write_str("{}")?,
+        }
+
         f.write_fmt(format_args!(
-            "using {} for {}",
-            self.library_name,
+            " for {}",
             match self.type_name.as_ref() {
                 Some(type_name) => format!("{}", type_name),
-                None => "_".to_string(),
+                None => "*".to_string(),
             }
-        ))
+        ))?;
+
+        if self.global.unwrap_or(false) {
+            f.write_str(" global")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A function attached by a `using {...} for` directive, optionally bound to a user-defined operator.
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsingForFunction {
+    pub function: Option<IdentifierPath>,
+    pub definition: Option<IdentifierPath>,
+    pub operator: Option<String>,
+}
+
+impl Display for UsingForFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(function) = self.function.as_ref() {
+            f.write_fmt(format_args!("{}", function))?;
+        }
+
+        if let (Some(definition), Some(operator)) = (self.definition.as_ref(), self.operator.as_ref()) {
+            f.write_fmt(format_args!("{} as {}", definition, operator))?;
+        }
+
+        Ok(())
     }
 }
 
@@ -85,6 +85,39 @@ pub struct VariableDeclaration {
     pub id: NodeID,
 }
 
+impl VariableDeclaration {
+    /// The parameter types of the getter function of a public state variable, i.e: `address` for `mapping(address => uint256)`.
+    /// Like `FunctionDefinition::parameter_type_strings`, the types do not include data locations.
+    pub fn getter_parameter_type_strings(&self) -> Vec<String> {
+        let mut result = vec![];
+        let mut type_name = self.type_name.as_ref();
+
+        while let Some(current_type_name) = type_name {
+            match current_type_name {
+                TypeName::Mapping(mapping) => {
+                    let key_type = mapping.key_type.as_ref();
+
+                    result.push(match key_type.type_descriptions().and_then(|type_descriptions| type_descriptions.type_string.as_ref()) {
+                        Some(type_string) => type_string.clone(),
+                        None => key_type.to_string(),
+                    });
+
+                    type_name = Some(mapping.value_type.as_ref());
+                }
+
+                TypeName::ArrayTypeName(array_type_name) => {
+                    result.push("uint256".to_string());
+                    type_name = Some(array_type_name.base_type.as_ref());
+                }
+
+                _ => break,
+            }
+        }
+
+        result
+    }
+}
+
 impl Display for VariableDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("{}", self.type_name.as_ref().unwrap()))?;
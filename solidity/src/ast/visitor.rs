@@ -2399,17 +2399,17 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_yul_block(&mut context)?;
             }
 
-            YulStatement::YulLeave => {
+            YulStatement::YulLeave(_) => {
                 self.visit_yul_leave(context)?;
                 self.leave_yul_leave(context)?;
             }
 
-            YulStatement::YulBreak => {
+            YulStatement::YulBreak(_) => {
                 self.visit_yul_break(context)?;
                 self.leave_yul_break(context)?;
             }
 
-            YulStatement::YulContinue => {
+            YulStatement::YulContinue(_) => {
                 self.visit_yul_continue(context)?;
                 self.leave_yul_continue(context)?;
             }
@@ -1,5 +1,5 @@
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -12,11 +12,12 @@ pub enum ExternalReference {
 #[derive(Clone, Debug, Eq, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ExternalReferenceData {
-    declaration: NodeID,
-    is_offset: bool,
-    is_slot: bool,
-    src: String,
-    value_size: NodeID,
+    pub declaration: NodeID,
+    pub is_offset: bool,
+    pub is_slot: bool,
+    pub src: String,
+    pub suffix: Option<String>,
+    pub value_size: NodeID,
 }
 
 #[derive(Clone, Debug, Eq, Serialize, PartialEq)]
@@ -30,23 +31,28 @@ pub enum YulExpression {
 impl<'de> Deserialize<'de> for YulExpression {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let json = serde_json::Value::deserialize(deserializer)?;
-        let node_type = json.get("nodeType").unwrap().as_str().unwrap();
+        let node_type = node_type::<D>(&json)?;
 
-        match node_type {
-            "YulLiteral" => Ok(YulExpression::YulLiteral(serde_json::from_value(json).unwrap())),
-            "YulIdentifier" => Ok(YulExpression::YulIdentifier(serde_json::from_value(json).unwrap())),
-            "YulFunctionCall" => Ok(YulExpression::YulFunctionCall(serde_json::from_value(json).unwrap())),
-            _ => panic!("Invalid yul expression node type: {node_type}"),
+        match node_type.as_str() {
+            "YulLiteral" => from_value::<D, _>(json).map(YulExpression::YulLiteral),
+            "YulIdentifier" => from_value::<D, _>(json).map(YulExpression::YulIdentifier),
+            "YulFunctionCall" => from_value::<D, _>(json).map(YulExpression::YulFunctionCall),
+            _ => Err(D::Error::unknown_variant(node_type.as_str(), YUL_EXPRESSION_NODE_TYPES)),
         }
     }
 }
 
+const YUL_EXPRESSION_NODE_TYPES: &[&str] = &["YulLiteral", "YulIdentifier", "YulFunctionCall"];
+
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulLiteral {
     pub kind: YulLiteralKind,
     pub value: Option<String>,
     pub hex_value: Option<String>,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -63,6 +69,9 @@ pub enum YulLiteralKind {
 #[serde(rename_all = "camelCase")]
 pub struct YulIdentifier {
     pub name: String,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -70,12 +79,18 @@ pub struct YulIdentifier {
 pub struct YulFunctionCall {
     pub function_name: YulIdentifier,
     pub arguments: Vec<YulExpression>,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulBlock {
     pub statements: Vec<YulStatement>,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 pub struct YulBlockContext<'a, 'b> {
@@ -94,38 +109,55 @@ pub enum YulStatement {
     YulExpressionStatement(YulExpressionStatement),
     YulFunctionDefinition(YulFunctionDefinition),
     YulBlock(YulBlock),
-    YulLeave,
-    YulBreak,
-    YulContinue,
+    YulLeave(YulLeave),
+    YulBreak(YulBreak),
+    YulContinue(YulContinue),
 }
 
 impl<'de> Deserialize<'de> for YulStatement {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let json = serde_json::Value::deserialize(deserializer)?;
-        let node_type = json.get("nodeType").unwrap().as_str().unwrap();
-
-        match node_type {
-            "YulIf" => Ok(YulStatement::YulIf(serde_json::from_value(json).unwrap())),
-            "YulSwitch" => Ok(YulStatement::YulSwitch(serde_json::from_value(json).unwrap())),
-            "YulForLoop" => Ok(YulStatement::YulForLoop(serde_json::from_value(json).unwrap())),
-            "YulAssignment" => Ok(YulStatement::YulAssignment(serde_json::from_value(json).unwrap())),
-            "YulVariableDeclaration" => Ok(YulStatement::YulVariableDeclaration(serde_json::from_value(json).unwrap())),
-            "YulExpressionStatement" => Ok(YulStatement::YulExpressionStatement(serde_json::from_value(json).unwrap())),
-            "YulFunctionDefinition" => Ok(YulStatement::YulFunctionDefinition(serde_json::from_value(json).unwrap())),
-            "YulBlock" => Ok(YulStatement::YulBlock(serde_json::from_value(json).unwrap())),
-            "YulLeave" => Ok(YulStatement::YulLeave),
-            "YulBreak" => Ok(YulStatement::YulBreak),
-            "YulContinue" => Ok(YulStatement::YulContinue),
-            _ => panic!("Invalid yul statement node type: {node_type}"),
+        let node_type = node_type::<D>(&json)?;
+
+        match node_type.as_str() {
+            "YulIf" => from_value::<D, _>(json).map(YulStatement::YulIf),
+            "YulSwitch" => from_value::<D, _>(json).map(YulStatement::YulSwitch),
+            "YulForLoop" => from_value::<D, _>(json).map(YulStatement::YulForLoop),
+            "YulAssignment" => from_value::<D, _>(json).map(YulStatement::YulAssignment),
+            "YulVariableDeclaration" => from_value::<D, _>(json).map(YulStatement::YulVariableDeclaration),
+            "YulExpressionStatement" => from_value::<D, _>(json).map(YulStatement::YulExpressionStatement),
+            "YulFunctionDefinition" => from_value::<D, _>(json).map(YulStatement::YulFunctionDefinition),
+            "YulBlock" => from_value::<D, _>(json).map(YulStatement::YulBlock),
+            "YulLeave" => from_value::<D, _>(json).map(YulStatement::YulLeave),
+            "YulBreak" => from_value::<D, _>(json).map(YulStatement::YulBreak),
+            "YulContinue" => from_value::<D, _>(json).map(YulStatement::YulContinue),
+            _ => Err(D::Error::unknown_variant(node_type.as_str(), YUL_STATEMENT_NODE_TYPES)),
         }
     }
 }
 
+const YUL_STATEMENT_NODE_TYPES: &[&str] = &[
+    "YulIf",
+    "YulSwitch",
+    "YulForLoop",
+    "YulAssignment",
+    "YulVariableDeclaration",
+    "YulExpressionStatement",
+    "YulFunctionDefinition",
+    "YulBlock",
+    "YulLeave",
+    "YulBreak",
+    "YulContinue",
+];
+
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulIf {
     pub condition: YulExpression,
     pub body: YulBlock,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -133,6 +165,9 @@ pub struct YulIf {
 pub struct YulSwitch {
     pub cases: Vec<YulCase>,
     pub expression: YulExpression,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Eq, Serialize, PartialEq)]
@@ -140,21 +175,37 @@ pub struct YulSwitch {
 pub struct YulCase {
     pub body: YulBlock,
     pub value: Option<YulExpression>,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 impl<'de> Deserialize<'de> for YulCase {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let json = serde_json::Value::deserialize(deserializer)?;
-        let body = json.get("body").unwrap();
-        let value = json.get("value").unwrap();
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RawYulCase {
+            body: YulBlock,
+            value: serde_json::Value,
+            native_src: Option<String>,
+            src: String,
+            id: Option<NodeID>,
+        }
+
+        let raw = RawYulCase::deserialize(deserializer)?;
+
+        // The value of the default case is the string "default" instead of a literal
+        let value = match raw.value {
+            serde_json::Value::String(value) if value == "default" => None,
+            value => Some(from_value::<D, _>(value)?),
+        };
 
         Ok(YulCase {
-            body: serde_json::from_value(body.clone()).unwrap(),
-            value: if matches!(value.as_str(), Some("default")) {
-                None
-            } else {
-                Some(serde_json::from_value(value.clone()).unwrap())
-            },
+            body: raw.body,
+            value,
+            native_src: raw.native_src,
+            src: raw.src,
+            id: raw.id,
         })
     }
 }
@@ -166,6 +217,9 @@ pub struct YulForLoop {
     pub condition: YulExpression,
     pub post: YulBlock,
     pub body: YulBlock,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -173,6 +227,9 @@ pub struct YulForLoop {
 pub struct YulAssignment {
     pub value: YulExpression,
     pub variable_names: Vec<YulIdentifier>,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -180,6 +237,9 @@ pub struct YulAssignment {
 pub struct YulVariableDeclaration {
     pub value: Option<YulExpression>,
     pub variables: Vec<YulTypedName>,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -187,12 +247,18 @@ pub struct YulVariableDeclaration {
 pub struct YulTypedName {
     pub r#type: String,
     pub name: String,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulExpressionStatement {
     pub expression: YulExpression,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -202,4 +268,98 @@ pub struct YulFunctionDefinition {
     pub parameters: Option<Vec<YulTypedName>>,
     pub return_parameters: Option<Vec<YulTypedName>>,
     pub body: YulBlock,
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulLeave {
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulBreak {
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulContinue {
+    pub native_src: Option<String>,
+    pub src: String,
+    pub id: Option<NodeID>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_locations() {
+        let expression: YulExpression = serde_json::from_str(r#"{
+            "nodeType": "YulFunctionCall",
+            "functionName": { "nodeType": "YulIdentifier", "name": "sload", "nativeSrc": "20:5:0", "src": "120:5:0" },
+            "arguments": [{ "nodeType": "YulLiteral", "kind": "number", "value": "0", "nativeSrc": "26:1:0", "src": "126:1:0", "id": 7 }],
+            "nativeSrc": "20:8:0",
+            "src": "120:8:0",
+            "id": 8
+        }"#).unwrap();
+
+        let YulExpression::YulFunctionCall(function_call) = expression else { panic!("expected a function call") };
+        assert_eq!(function_call.function_name.name, "sload");
+        assert_eq!((function_call.native_src.as_deref(), function_call.src.as_str(), function_call.id), (Some("20:8:0"), "120:8:0", Some(8)));
+
+        let YulExpression::YulLiteral(literal) = &function_call.arguments[0] else { panic!("expected a literal") };
+        assert_eq!((literal.native_src.as_deref(), literal.id), (Some("26:1:0"), Some(7)));
+
+        // Nodes from older compilers do not have a native source location or ID
+        let identifier: YulIdentifier = serde_json::from_str(r#"{ "nodeType": "YulIdentifier", "name": "x", "src": "1:1:0" }"#).unwrap();
+        assert_eq!((identifier.native_src, identifier.id), (None, None));
+    }
+
+    #[test]
+    fn test_deserialize_unknown_node_type() {
+        let error = serde_json::from_str::<YulExpression>(r#"{ "nodeType": "YulTypedName", "name": "x", "src": "0:1:0" }"#).unwrap_err();
+        assert!(error.to_string().contains("unknown variant `YulTypedName`"), "{error}");
+
+        let error = serde_json::from_str::<YulStatement>(r#"{ "nodeType": "YulGoto", "src": "0:1:0" }"#).unwrap_err();
+        assert!(error.to_string().contains("unknown variant `YulGoto`"), "{error}");
+
+        assert!(serde_json::from_str::<YulStatement>(r#"{ "src": "0:1:0" }"#).unwrap_err().to_string().contains("missing field `nodeType`"));
+    }
+
+    #[test]
+    fn test_deserialize_switch_cases() {
+        let statement: YulStatement = serde_json::from_str(r#"{
+            "nodeType": "YulSwitch",
+            "expression": { "nodeType": "YulIdentifier", "name": "x", "src": "7:1:0" },
+            "cases": [
+                {
+                    "nodeType": "YulCase",
+                    "value": { "nodeType": "YulLiteral", "kind": "number", "value": "1", "src": "14:1:0" },
+                    "body": { "nodeType": "YulBlock", "statements": [], "src": "16:2:0" },
+                    "src": "9:9:0"
+                },
+                {
+                    "nodeType": "YulCase",
+                    "value": "default",
+                    "body": { "nodeType": "YulBlock", "statements": [{ "nodeType": "YulLeave", "src": "29:5:0" }], "src": "27:9:0" },
+                    "src": "19:17:0"
+                }
+            ],
+            "src": "0:36:0"
+        }"#).unwrap();
+
+        let YulStatement::YulSwitch(switch) = statement else { panic!("expected a switch") };
+        assert!(matches!(&switch.cases[0].value, Some(YulExpression::YulLiteral(YulLiteral { value: Some(value), .. })) if value == "1"));
+        assert_eq!(switch.cases[1].value, None);
+        assert!(matches!(switch.cases[1].body.statements[0], YulStatement::YulLeave(_)));
+    }
 }